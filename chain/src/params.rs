@@ -74,6 +74,12 @@ pub struct ChainParameters {
     pub signed_blocks_window_len: u64,
     /// The maximum number of blocks in the window each validator can miss signing without slashing.
     pub missed_blocks_maximum: u64,
+    /// The minimum number of blocks a jailed validator must wait before it can be released from jail.
+    pub min_jail_duration: u64,
+    /// The maximum total commission a validator can charge, in basis points.
+    pub max_validator_commission_bps: u64,
+    /// The maximum amount a validator can raise its total commission by in a single epoch, in basis points.
+    pub max_commission_increase_bps_per_epoch: u64,
 
     /// Whether IBC (forming connections, processing IBC packets) is enabled.
     pub ibc_enabled: bool,
@@ -115,6 +121,9 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
             slashing_penalty_misbehavior: Penalty(msg.slashing_penalty_misbehavior),
             base_reward_rate: msg.base_reward_rate,
             missed_blocks_maximum: msg.missed_blocks_maximum,
+            min_jail_duration: msg.min_jail_duration,
+            max_validator_commission_bps: msg.max_validator_commission_bps,
+            max_commission_increase_bps_per_epoch: msg.max_commission_increase_bps_per_epoch,
             signed_blocks_window_len: msg.signed_blocks_window_len,
             ibc_enabled: msg.ibc_enabled,
             inbound_ics20_transfers_enabled: msg.inbound_ics20_transfers_enabled,
@@ -169,6 +178,9 @@ impl From<ChainParameters> for pb_chain::ChainParameters {
             active_validator_limit: params.active_validator_limit,
             signed_blocks_window_len: params.signed_blocks_window_len,
             missed_blocks_maximum: params.missed_blocks_maximum,
+            min_jail_duration: params.min_jail_duration,
+            max_validator_commission_bps: params.max_validator_commission_bps,
+            max_commission_increase_bps_per_epoch: params.max_commission_increase_bps_per_epoch,
             slashing_penalty_downtime: params.slashing_penalty_downtime.0,
             slashing_penalty_misbehavior: params.slashing_penalty_misbehavior.0,
             base_reward_rate: params.base_reward_rate,
@@ -197,6 +209,11 @@ impl Default for ChainParameters {
             // copied from cosmos hub
             signed_blocks_window_len: 10000,
             missed_blocks_maximum: 9500,
+            min_jail_duration: 720, // 1 hour, at a 5 second block time
+            // 5000 basis points = 50%
+            max_validator_commission_bps: 5000,
+            // 100 basis points = 1% per epoch
+            max_commission_increase_bps_per_epoch: 100,
             // 1000 basis points = 10%
             slashing_penalty_misbehavior: Penalty(1000_0000),
             // 1 basis point = 0.01%
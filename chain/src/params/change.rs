@@ -24,6 +24,9 @@ impl ChainParameters {
             slashing_penalty_downtime: _,
             signed_blocks_window_len,
             missed_blocks_maximum: _,
+            min_jail_duration: _,
+            max_validator_commission_bps: _,
+            max_commission_increase_bps_per_epoch: _,
             ibc_enabled: _,
             inbound_ics20_transfers_enabled: _,
             outbound_ics20_transfers_enabled: _,
@@ -83,6 +86,9 @@ impl ChainParameters {
             slashing_penalty_downtime,
             signed_blocks_window_len,
             missed_blocks_maximum,
+            min_jail_duration: _,
+            max_validator_commission_bps,
+            max_commission_increase_bps_per_epoch: _,
             ibc_enabled,
             inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled,
@@ -137,6 +143,10 @@ impl ChainParameters {
                 *missed_blocks_maximum >= 1,
                 "missed blocks maximum must be at least 1",
             ),
+            (
+                *max_validator_commission_bps <= 10_000,
+                "max validator commission must be at most 10,000 basis points",
+            ),
             (
                 (!*inbound_ics20_transfers_enabled && !*outbound_ics20_transfers_enabled)
                     || *ibc_enabled,
@@ -18,11 +18,14 @@ mod ics20;
 mod output;
 mod position;
 mod proposal;
+mod redelegate;
+mod redelegate_claim;
 mod spend;
 mod swap;
 mod swap_claim;
 mod undelegate;
 mod undelegate_claim;
+mod unjail;
 mod validator_definition;
 mod validator_vote;
 
@@ -33,6 +36,9 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.check_stateless(context),
             Action::Undelegate(action) => action.check_stateless(context),
             Action::UndelegateClaim(action) => action.check_stateless(context),
+            Action::Redelegate(action) => action.check_stateless(context),
+            Action::RedelegateClaim(action) => action.check_stateless(context),
+            Action::Unjail(action) => action.check_stateless(context),
             Action::ValidatorDefinition(action) => action.check_stateless(context),
             Action::DelegatorVote(action) => action.check_stateless(context),
             Action::ValidatorVote(action) => action.check_stateless(context),
@@ -61,6 +67,9 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.check_stateful(state).await,
             Action::Undelegate(action) => action.check_stateful(state).await,
             Action::UndelegateClaim(action) => action.check_stateful(state).await,
+            Action::Redelegate(action) => action.check_stateful(state).await,
+            Action::RedelegateClaim(action) => action.check_stateful(state).await,
+            Action::Unjail(action) => action.check_stateful(state).await,
             Action::ValidatorDefinition(action) => action.check_stateful(state).await,
             Action::DelegatorVote(action) => action.check_stateful(state).await,
             Action::ValidatorVote(action) => action.check_stateful(state).await,
@@ -96,6 +105,9 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.execute(state).await,
             Action::Undelegate(action) => action.execute(state).await,
             Action::UndelegateClaim(action) => action.execute(state).await,
+            Action::Redelegate(action) => action.execute(state).await,
+            Action::RedelegateClaim(action) => action.execute(state).await,
+            Action::Unjail(action) => action.execute(state).await,
             Action::ValidatorDefinition(action) => action.execute(state).await,
             Action::DelegatorVote(action) => action.execute(state).await,
             Action::ValidatorVote(action) => action.execute(state).await,
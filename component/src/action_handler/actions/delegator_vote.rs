@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{
@@ -15,35 +15,9 @@ use crate::{
 
 #[async_trait]
 impl ActionHandler for DelegatorVote {
-    async fn check_stateless(&self, context: Arc<Transaction>) -> Result<()> {
-        let DelegatorVote {
-            auth_sig,
-            proof,
-            body:
-                DelegatorVoteBody {
-                    start_position,
-                    value,
-                    nullifier,
-                    rk,
-                    // Unused in stateless checks:
-                    vote: _,            // Only used when executing the vote
-                    proposal: _,        // Checked against the current open proposals statefully
-                    unbonded_amount: _, // Checked against the proposal's snapshot exchange rate statefully
-                },
-        } = self;
-
-        let effect_hash = context.transaction_body().effect_hash();
-        let anchor = context.anchor;
-
-        // 1. Check spend auth signature using provided spend auth key.
-        rk.verify(effect_hash.as_ref(), auth_sig)
-            .context("delegator vote auth signature failed to verify")?;
-
-        // 2. Verify the proof against the provided anchor and start position:
-        proof
-            .verify(anchor, *start_position, *value, *nullifier, *rk)
-            .context("a delegator vote proof did not verify")?;
-
+    async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
+        // The auth signature and proof are batch verified along with the rest of the
+        // transaction's signatures and proofs, in the transaction's `check_stateless`.
         Ok(())
     }
 
@@ -58,6 +32,7 @@ impl ActionHandler for DelegatorVote {
                     unbonded_amount,
                     nullifier,
                     rk: _, // We already used this to check the auth sig in stateless verification
+                    replaces_previous,
                 },
             auth_sig: _, // We already checked this in stateless verification
             proof: _,    // We already checked this in stateless verification
@@ -70,8 +45,10 @@ impl ActionHandler for DelegatorVote {
         state
             .check_nullifier_unspent_before_start_block_height(*proposal, nullifier)
             .await?;
+        // Voting again with the same nullifier replaces the earlier vote, but mints no further
+        // voting receipt tokens, so the vote must say whether it is a replacement.
         state
-            .check_nullifier_unvoted_for_proposal(*proposal, nullifier)
+            .check_nullifier_vote_replacement(*proposal, nullifier, *replaces_previous)
             .await?;
         state
             .check_unbonded_amount_correct_exchange_for_proposal(*proposal, value, unbonded_amount)
@@ -91,6 +68,7 @@ impl ActionHandler for DelegatorVote {
                     value,
                     start_position: _, // Not needed to execute: used to check validity of vote
                     rk: _,             // Not needed to execute: used to check auth sig
+                    replaces_previous: _, // Not needed to execute: the earlier vote is looked up
                 },
             ..
         } = self;
@@ -3,7 +3,6 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_chain::sync::StatePayload;
-use penumbra_proof_params::OUTPUT_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 
 use penumbra_transaction::{action::Output, Transaction};
@@ -13,14 +12,8 @@ use crate::{action_handler::ActionHandler, shielded_pool::NoteManager};
 #[async_trait]
 impl ActionHandler for Output {
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        let output = self;
-
-        output.proof.verify(
-            &OUTPUT_PROOF_VERIFICATION_KEY,
-            output.body.balance_commitment,
-            output.body.note_payload.note_commitment,
-        )?;
-
+        // The output proof is batch verified along with the rest of the transaction's proofs, in
+        // the transaction's `check_stateless`.
         Ok(())
     }
 
@@ -20,7 +20,7 @@ use penumbra_transaction::{action::ProposalSubmit, Transaction};
 use penumbra_transaction::{AuthorizationData, WitnessData};
 
 use crate::action_handler::ActionHandler;
-use crate::governance::{StateReadExt as _, StateWriteExt as _};
+use crate::governance::{ProposalEvent, StateReadExt as _, StateWriteExt as _};
 use crate::shielded_pool::{StateReadExt, StateWriteExt as _, SupplyWrite};
 
 // IMPORTANT: these length limits are enforced by consensus! Changing them will change which
@@ -88,9 +88,17 @@ impl ActionHandler for ProposalSubmit {
                             // actions because they could cause recursion.
                             anyhow::bail!("invalid action in DAO spend proposal (not allowed to manipulate proposals from within proposals)")
                         }
+                        Ics20Withdrawal(_) => {
+                            // Sending DAO funds out of the chain isn't allowed from DAO spend
+                            // proposals.
+                            anyhow::bail!("invalid action in DAO spend proposal (ICS-20 withdrawals are not supported)")
+                        }
                         Delegate(_)
                         | Undelegate(_)
                         | UndelegateClaim(_)
+                        | Redelegate(_)
+                        | RedelegateClaim(_)
+                        | Unjail(_)
                         | ValidatorDefinition(_)
                         | IBCAction(_)
                         | ValidatorVote(_)
@@ -230,6 +238,12 @@ impl ActionHandler for ProposalSubmit {
         let voting_end = current_block + chain_params.proposal_voting_blocks;
         state.put_proposal_voting_start(proposal_id, current_block);
         state.put_proposal_voting_end(proposal_id, voting_end);
+        state.record_proposal_event(ProposalEvent::submitted(
+            proposal_id,
+            proposal.title.clone(),
+            current_block,
+            voting_end,
+        ));
 
         // Compute the effective starting TCT position for the proposal, by rounding the current
         // position down to the start of the block.
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use penumbra_storage::{StateRead, StateWrite};
+use penumbra_transaction::{action::Redelegate, Transaction};
+
+use crate::{
+    action_handler::ActionHandler,
+    shielded_pool::SupplyWrite,
+    stake::{component::StateWriteExt as _, validator, StateReadExt as _},
+};
+
+#[async_trait]
+impl ActionHandler for Redelegate {
+    async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
+        ensure!(
+            self.from_validator_identity != self.to_validator_identity,
+            "cannot redelegate from validator {} to itself",
+            self.from_validator_identity,
+        );
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let r = self;
+        let from_rate_data = state
+            .next_validator_rate(&r.from_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.from_validator_identity)
+            })?;
+        let to_rate_data = state
+            .next_validator_rate(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.to_validator_identity)
+            })?;
+
+        // Check whether the epoch is correct first, to give a more helpful
+        // error message if it's wrong.
+        if r.epoch_index != from_rate_data.epoch_index {
+            return Err(anyhow::anyhow!(
+                "redelegation was prepared for next epoch {} but the next epoch is {}",
+                r.epoch_index,
+                from_rate_data.epoch_index
+            ));
+        }
+
+        // Redelegating is only allowed into validators that would accept a
+        // delegation, but it's allowed out of any validator, just like
+        // undelegating.
+        let to_validator = state
+            .validator(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing definition for validator"))?;
+        let to_validator_state = state
+            .validator_state(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+
+        use validator::State::*;
+        if !to_validator.enabled {
+            return Err(anyhow::anyhow!(
+                "redelegations are only allowed to enabled validators, but {} is disabled",
+                r.to_validator_identity,
+            ));
+        }
+        if !matches!(to_validator_state, Inactive | Active) {
+            return Err(anyhow::anyhow!(
+                "redelegations are only allowed to active or inactive validators, but {} is in state {:?}",
+                r.to_validator_identity,
+                to_validator_state,
+            ));
+        }
+
+        // The redelegation tokens stay liable for slashing of the source
+        // validator for as long as an undelegation would have, so the end epoch
+        // is checked exactly as for an undelegation.
+        let expected_end_epoch = state
+            .current_unbonding_end_epoch_for(&r.from_validator_identity)
+            .await?;
+        if r.end_epoch_index < expected_end_epoch {
+            return Err(anyhow::anyhow!(
+                "redelegation end epoch must be at least {} but {} was specified",
+                expected_end_epoch,
+                r.end_epoch_index,
+            ));
+        }
+
+        // The amounts are computed in the same direction as an undelegation
+        // followed by a delegation: the delegator starts from the amount of
+        // delegation tokens they wish to move, and the amount of stake they
+        // represent determines the destination delegation tokens.
+        let expected_unbonded_amount =
+            from_rate_data.unbonded_amount(r.from_delegation_amount.into());
+        ensure!(
+            u64::from(r.unbonded_amount) == expected_unbonded_amount,
+            "redelegation unbonded amount {} does not match expected amount {}",
+            r.unbonded_amount,
+            expected_unbonded_amount,
+        );
+
+        let expected_to_delegation_amount =
+            to_rate_data.delegation_amount(expected_unbonded_amount);
+        ensure!(
+            u64::from(r.to_delegation_amount) == expected_to_delegation_amount,
+            "given {} redelegated stake, expected {} delegation tokens but description produces {}",
+            r.unbonded_amount,
+            expected_to_delegation_amount,
+            r.to_delegation_amount,
+        );
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(?self, "queuing redelegation for next epoch");
+        state.stub_push_redelegation(self.clone());
+        // Register the redelegation's denom, so clients can look it up later.
+        state
+            .register_denom(&self.redelegation_token().denom())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use penumbra_chain::StateReadExt;
+use penumbra_storage::{StateRead, StateWrite};
+use penumbra_transaction::{action::RedelegateClaim, Transaction};
+
+use crate::{action_handler::ActionHandler, shielded_pool::SupplyWrite, stake::StateReadExt as _};
+
+#[async_trait]
+impl ActionHandler for RedelegateClaim {
+    async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
+        ensure!(
+            self.from_validator_identity != self.to_validator_identity,
+            "cannot claim a redelegation from validator {} to itself",
+            self.from_validator_identity,
+        );
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // We need to check two things:
+
+        // 1. That we're past the specified end epoch, after which the
+        // redelegated stake is no longer liable for slashing of the source
+        // validator.
+        ensure!(
+            state.get_current_epoch().await?.index >= self.end_epoch_index,
+            "cannot claim redelegation tokens before the end epoch"
+        );
+
+        // 2. That the penalty is correct: the redelegation tokens are slashed
+        // by whatever penalty the source validator incurred over that period.
+        let expected_penalty = state
+            .compounded_penalty_over_range(
+                &self.from_validator_identity,
+                self.start_epoch_index,
+                self.end_epoch_index,
+            )
+            .await?;
+        ensure!(
+            self.penalty == expected_penalty,
+            "penalty does not match expected penalty"
+        );
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The redelegation was counted towards the destination validator's
+        // delegation token supply in full, so the tokens withheld by the
+        // penalty are burned from it.
+        let slashed_amount =
+            u64::from(self.redelegation_amount) - u64::from(self.delegation_amount());
+        if slashed_amount > 0 {
+            tracing::debug!(?self, slashed_amount, "burning slashed redelegation");
+            state
+                .update_token_supply(&self.to_delegation_token().id(), -(slashed_amount as i64))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,7 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use async_trait::async_trait;
-use penumbra_proof_params::SPEND_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::Spend, Transaction};
 
@@ -13,30 +12,9 @@ use crate::{
 
 #[async_trait]
 impl ActionHandler for Spend {
-    async fn check_stateless(&self, context: Arc<Transaction>) -> Result<()> {
-        let spend = self;
-        let effect_hash = context.transaction_body().effect_hash();
-        let anchor = context.anchor;
-
-        // 2. Check spend auth signature using provided spend auth key.
-        spend
-            .body
-            .rk
-            .verify(effect_hash.as_ref(), &spend.auth_sig)
-            .context("spend auth signature failed to verify")?;
-
-        // 3. Check that the proof verifies.
-        spend
-            .proof
-            .verify(
-                &SPEND_PROOF_VERIFICATION_KEY,
-                anchor,
-                spend.body.balance_commitment,
-                spend.body.nullifier,
-                spend.body.rk,
-            )
-            .context("a spend proof did not verify")?;
-
+    async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
+        // The spend auth signature and proof are batch verified along with the rest of the
+        // transaction's signatures and proofs, in the transaction's `check_stateless`.
         Ok(())
     }
 
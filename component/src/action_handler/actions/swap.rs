@@ -10,22 +10,16 @@ use async_trait::async_trait;
 
 use penumbra_chain::sync::StatePayload;
 use penumbra_crypto::MockFlowCiphertext;
-use penumbra_proof_params::SWAP_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
-use penumbra_transaction::{action::Swap, IsAction, Transaction};
+use penumbra_transaction::{action::Swap, Transaction};
 
 use crate::action_handler::ActionHandler;
 
 #[async_trait]
 impl ActionHandler for Swap {
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        self.proof.verify(
-            &SWAP_PROOF_VERIFICATION_KEY,
-            self.balance_commitment(),
-            self.body.payload.commitment,
-            self.body.fee_commitment,
-        )?;
-
+        // The swap proof is batch verified along with the rest of the transaction's proofs, in
+        // the transaction's `check_stateless`.
         Ok(())
     }
 
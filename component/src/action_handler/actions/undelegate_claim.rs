@@ -3,7 +3,6 @@ use std::sync::Arc;
 use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use penumbra_chain::StateReadExt;
-use penumbra_crypto::stake::UnbondingToken;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::UndelegateClaim, Transaction};
 
@@ -12,19 +11,8 @@ use crate::{action_handler::ActionHandler, stake::StateReadExt as _};
 #[async_trait]
 impl ActionHandler for UndelegateClaim {
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        let unbonding_id = UnbondingToken::new(
-            self.body.validator_identity,
-            self.body.start_epoch_index,
-            self.body.end_epoch_index,
-        )
-        .id();
-
-        self.proof.verify(
-            self.body.balance_commitment,
-            unbonding_id,
-            self.body.penalty,
-        )?;
-
+        // The undelegate claim proof is batch verified along with the rest of the transaction's
+        // proofs, in the transaction's `check_stateless`.
         Ok(())
     }
 
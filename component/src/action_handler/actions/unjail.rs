@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_chain::StateReadExt as _;
+use penumbra_storage::{StateRead, StateWrite};
+use penumbra_transaction::{
+    action::{Unjail, UnjailBody},
+    Transaction,
+};
+
+use crate::{
+    action_handler::ActionHandler,
+    stake::{component::StakingImpl as _, validator, StateReadExt as _},
+};
+
+#[async_trait]
+impl ActionHandler for Unjail {
+    async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
+        // The unjail signature is batch verified along with the rest of the transaction's
+        // signatures, in the transaction's `check_stateless`.
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let UnjailBody {
+            identity_key,
+            jailed_at_height,
+        } = &self.body;
+
+        let validator_state = state
+            .validator_state(identity_key)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("unknown validator identity {}", identity_key))?;
+        if validator_state != validator::State::Jailed {
+            anyhow::bail!(
+                "only jailed validators can be unjailed, but {} is in state {:?}",
+                identity_key,
+                validator_state,
+            );
+        }
+
+        // Check that the unjailing was authorized for this particular jailing,
+        // so that an old authorization can't be replayed.
+        let actual_jailed_at_height = state
+            .validator_jailed_at_height(identity_key)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing jail height for validator"))?;
+        if *jailed_at_height != actual_jailed_at_height {
+            anyhow::bail!(
+                "unjail was prepared for a jailing at height {} but {} was jailed at height {}",
+                jailed_at_height,
+                identity_key,
+                actual_jailed_at_height,
+            );
+        }
+
+        let min_jail_duration = state.get_chain_params().await?.min_jail_duration;
+        let current_height = state.get_block_height().await?;
+        let release_height = actual_jailed_at_height + min_jail_duration;
+        if current_height < release_height {
+            anyhow::bail!(
+                "validator {} cannot be unjailed until height {}, but the current height is {}",
+                identity_key,
+                release_height,
+                current_height,
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(identity_key = ?self.body.identity_key, "releasing validator from jail");
+        state
+            .set_validator_state(&self.body.identity_key, validator::State::Inactive)
+            .await
+    }
+}
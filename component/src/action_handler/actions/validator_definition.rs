@@ -51,6 +51,17 @@ impl ActionHandler for ValidatorDefinition {
         let v = validator::Definition::try_from(self.clone())
             .context("supplied proto is not a valid definition")?;
 
+        // Check that the validator's commission is within the chain's limits.
+        let chain_params = state.get_chain_params().await?;
+        let commission_bps = v.validator.funding_streams.total_rate_bps();
+        if commission_bps > chain_params.max_validator_commission_bps {
+            return Err(anyhow::anyhow!(
+                "validator defined {} bps of funding streams, greater than the maximum commission of {}bps",
+                commission_bps,
+                chain_params.max_validator_commission_bps,
+            ));
+        }
+
         // Check whether we are redefining an existing validator.
         if let Some(existing_v) = state.validator(&v.validator.identity_key).await? {
             // Ensure that the highest existing sequence number is less than
@@ -62,6 +73,25 @@ impl ActionHandler for ValidatorDefinition {
                     current_seq
                 ));
             }
+
+            // Ensure that the validator doesn't raise its commission by more
+            // than the limit over the course of the epoch.  Lowering it is
+            // always allowed.
+            let epoch_index = state.get_current_epoch().await?.index;
+            let epoch_start_commission_bps = state
+                .validator_commission_at_epoch_start(&v.validator.identity_key, epoch_index)
+                .await?
+                .unwrap_or_else(|| existing_v.funding_streams.total_rate_bps());
+            let max_commission_bps =
+                epoch_start_commission_bps + chain_params.max_commission_increase_bps_per_epoch;
+            if commission_bps > max_commission_bps {
+                return Err(anyhow::anyhow!(
+                    "validator commission can rise to at most {}bps this epoch (from {}bps at the start of the epoch), but {}bps was defined",
+                    max_commission_bps,
+                    epoch_start_commission_bps,
+                    commission_bps,
+                ));
+            }
         }
 
         // Check whether the consensus key has already been used by another validator.
@@ -88,6 +118,22 @@ impl ActionHandler for ValidatorDefinition {
                     existing_v.identity_key,
                 ));
             }
+
+            // This is a redefinition of the same validator, which may rotate
+            // its consensus key.  Keys retired by an earlier rotation can't be
+            // reused, but a rotation that hasn't taken effect yet can be undone.
+            if v.validator.consensus_key != existing_v.consensus_key
+                && state
+                    .validator_consensus_key(&v.validator.identity_key)
+                    .await?
+                    != Some(v.validator.consensus_key)
+            {
+                return Err(anyhow::anyhow!(
+                    "consensus key {:?} was retired by validator {} and cannot be reused",
+                    v.validator.consensus_key,
+                    existing_v.identity_key,
+                ));
+            }
         }
 
         // the validator definition has now passed all verification checks
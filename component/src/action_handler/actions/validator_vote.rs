@@ -1,12 +1,11 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_chain::StateReadExt as _;
-use penumbra_proto::DomainType;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{
-    action::{ValidatorVote, ValidatorVoteBody},
+    action::{ValidatorVote, ValidatorVoteBody, ValidatorVoteReason},
     proposal, Transaction,
 };
 
@@ -18,17 +17,20 @@ use crate::{
 #[async_trait]
 impl ActionHandler for ValidatorVote {
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        let ValidatorVote { body, auth_sig } = self;
+        let ValidatorVote { body, auth_sig: _ } = self;
 
-        // Check the signature using the GOVERNANCE KEY:
-        let body_bytes = body.encode_to_vec();
-        body.governance_key
-            .0
-            .verify(&body_bytes, auth_sig)
-            .context("validator vote signature failed to verify")?;
+        if body.reason.0.len() > ValidatorVoteReason::MAX_LEN {
+            anyhow::bail!(
+                "validator vote reason must be at most {} bytes",
+                ValidatorVoteReason::MAX_LEN
+            );
+        }
+
+        // The signature using the GOVERNANCE KEY is batch verified along with the rest of the
+        // transaction's signatures, in the transaction's `check_stateless`.
 
         // This is stateless verification, so we still need to check that the proposal being voted
-        // on exists, and that this validator hasn't voted on it already.
+        // on exists and is still open for voting.
 
         Ok(())
     }
@@ -41,6 +43,7 @@ impl ActionHandler for ValidatorVote {
                     vote: _, // All votes are valid, so we don't need to do anything with this
                     identity_key,
                     governance_key,
+                    reason: _, // Only checked for length in stateless verification
                 },
             auth_sig: _, // We already checked this in stateless verification
         } = self;
@@ -49,9 +52,8 @@ impl ActionHandler for ValidatorVote {
         state
             .check_validator_active_at_proposal_start(*proposal, identity_key)
             .await?;
-        state
-            .check_validator_has_not_voted(*proposal, identity_key)
-            .await?;
+        // Note that the validator may have already voted on this proposal: if so, this vote
+        // replaces the earlier one.
         state
             .check_governance_key_matches_validator(identity_key, governance_key)
             .await?;
@@ -68,6 +70,7 @@ impl ActionHandler for ValidatorVote {
                     vote,
                     identity_key,
                     governance_key: _, // This is only used for checks so that stateless verification can be done on the signature
+                    reason: _,         // The reason is recorded in the transaction, not the state
                 },
         } = self;
 
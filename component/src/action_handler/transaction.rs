@@ -14,10 +14,12 @@ use self::stateful::{claimed_anchor_is_valid, fmd_parameters_valid};
 
 use super::ActionHandler;
 
+mod batch;
 mod stateful;
 mod stateless;
 
-use stateless::{no_duplicate_nullifiers, valid_binding_signature};
+use batch::valid_proofs_and_signatures;
+use stateless::no_duplicate_nullifiers;
 
 #[async_trait]
 impl ActionHandler for Transaction {
@@ -27,11 +29,14 @@ impl ActionHandler for Transaction {
         // TODO: add a check that ephemeral_key is not identity to prevent scanning dos attack ?
 
         // TODO: unify code organization
-        valid_binding_signature(self)?;
         no_duplicate_nullifiers(self)?;
         consensus_rules::stateless::num_clues_equal_to_num_outputs(self)?;
         consensus_rules::stateless::check_memo_exists_if_outputs_absent_if_not(self)?;
 
+        // Proofs and signatures are checked here for the whole transaction at once, rather than in
+        // each action's stateless checks, so that they can be batch verified.
+        valid_proofs_and_signatures(self).await?;
+
         // Currently, we need to clone the component actions so that the spawned
         // futures can have 'static lifetimes. In the future, we could try to
         // use the yoke crate, but cloning is almost certainly not a big deal
@@ -0,0 +1,233 @@
+use anyhow::{Context, Result};
+use ark_ff::Zero;
+use decaf377::Fr;
+use penumbra_crypto::{
+    proofs::groth16::BatchVerifier,
+    rdsa::{self, VerificationKeyBytes},
+    stake::UnbondingToken,
+};
+use penumbra_proof_params::{
+    DELEGATOR_VOTE_PROOF_VERIFICATION_KEY, OUTPUT_PROOF_VERIFICATION_KEY,
+    SPEND_PROOF_VERIFICATION_KEY, SWAPCLAIM_PROOF_VERIFICATION_KEY, SWAP_PROOF_VERIFICATION_KEY,
+    UNDELEGATECLAIM_PROOF_VERIFICATION_KEY,
+};
+use penumbra_proto::DomainType;
+use penumbra_transaction::{Action, AuthorizingData, IsAction, Transaction};
+use rand_core::OsRng;
+
+/// Check all of the proofs and signatures in the transaction.
+///
+/// Rather than checking each proof and signature on its own as part of its action's stateless
+/// checks, all of the proofs for each circuit are verified together as a batch, as are all of the
+/// signatures, which is considerably cheaper. Each batch is verified on its own blocking thread.
+#[tracing::instrument(skip(tx))]
+pub(super) async fn valid_proofs_and_signatures(tx: &Transaction) -> Result<()> {
+    let effect_hash = tx.transaction_body().effect_hash();
+    let anchor = tx.anchor;
+
+    let mut spend_proofs = BatchVerifier::new(&SPEND_PROOF_VERIFICATION_KEY);
+    let mut output_proofs = BatchVerifier::new(&OUTPUT_PROOF_VERIFICATION_KEY);
+    let mut swap_proofs = BatchVerifier::new(&SWAP_PROOF_VERIFICATION_KEY);
+    let mut swap_claim_proofs = BatchVerifier::new(&SWAPCLAIM_PROOF_VERIFICATION_KEY);
+    let mut delegator_vote_proofs = BatchVerifier::new(&DELEGATOR_VOTE_PROOF_VERIFICATION_KEY);
+    let mut undelegate_claim_proofs = BatchVerifier::new(&UNDELEGATECLAIM_PROOF_VERIFICATION_KEY);
+    let mut signatures = SignatureBatch::new();
+
+    for action in tx.actions() {
+        match action {
+            Action::Spend(spend) => {
+                signatures.queue(
+                    "spend auth signature",
+                    (
+                        VerificationKeyBytes::from(spend.body.rk),
+                        spend.auth_sig,
+                        &effect_hash,
+                    ),
+                );
+                spend.proof.queue(
+                    &mut spend_proofs,
+                    anchor,
+                    spend.body.balance_commitment,
+                    spend.body.nullifier,
+                    spend.body.rk,
+                );
+            }
+            Action::Output(output) => {
+                output.proof.queue(
+                    &mut output_proofs,
+                    output.body.balance_commitment,
+                    output.body.note_payload.note_commitment,
+                );
+            }
+            Action::Swap(swap) => {
+                swap.proof.queue(
+                    &mut swap_proofs,
+                    swap.balance_commitment(),
+                    swap.body.payload.commitment,
+                    swap.body.fee_commitment,
+                );
+            }
+            Action::SwapClaim(swap_claim) => {
+                swap_claim.proof.queue(
+                    &mut swap_claim_proofs,
+                    anchor,
+                    swap_claim.body.nullifier,
+                    swap_claim.body.fee.clone(),
+                    swap_claim.body.output_data,
+                    swap_claim.epoch_duration,
+                    swap_claim.body.output_1_commitment,
+                    swap_claim.body.output_2_commitment,
+                );
+            }
+            Action::DelegatorVote(vote) => {
+                signatures.queue(
+                    "delegator vote auth signature",
+                    (
+                        VerificationKeyBytes::from(vote.body.rk),
+                        vote.auth_sig,
+                        &effect_hash,
+                    ),
+                );
+                // The voted value is public, so its balance commitment uses a zero blinding factor.
+                vote.proof.queue(
+                    &mut delegator_vote_proofs,
+                    anchor,
+                    vote.body.value.commit(Fr::zero()),
+                    vote.body.nullifier,
+                    vote.body.rk,
+                    vote.body.start_position,
+                );
+            }
+            Action::UndelegateClaim(claim) => {
+                let unbonding_id = UnbondingToken::new(
+                    claim.body.validator_identity,
+                    claim.body.start_epoch_index,
+                    claim.body.end_epoch_index,
+                )
+                .id();
+                claim.proof.queue(
+                    &mut undelegate_claim_proofs,
+                    claim.body.balance_commitment,
+                    unbonding_id,
+                    claim.body.penalty,
+                );
+            }
+            Action::ValidatorVote(vote) => {
+                // Validator votes are signed using the governance key.
+                let body_bytes = vote.body.encode_to_vec();
+                signatures.queue(
+                    "validator vote signature",
+                    (
+                        VerificationKeyBytes::from(vote.body.governance_key.0),
+                        vote.auth_sig,
+                        &body_bytes,
+                    ),
+                );
+            }
+            Action::Unjail(unjail) => {
+                // Unjailing is signed using the validator's identity key.
+                let body_bytes = unjail.body.encode_to_vec();
+                signatures.queue(
+                    "unjail signature",
+                    (
+                        VerificationKeyBytes::from(unjail.body.identity_key.0),
+                        unjail.auth_sig,
+                        &body_bytes,
+                    ),
+                );
+            }
+            // The definition's signature is over the encoded validator, so it's checked when the
+            // definition is parsed, in its stateless checks.
+            Action::ValidatorDefinition(_) => {}
+            // These actions carry no proofs or signatures of their own.
+            Action::IBCAction(_)
+            | Action::ProposalSubmit(_)
+            | Action::ProposalWithdraw(_)
+            | Action::ProposalDepositClaim(_)
+            | Action::PositionOpen(_)
+            | Action::PositionClose(_)
+            | Action::PositionWithdraw(_)
+            | Action::PositionRewardClaim(_)
+            | Action::Delegate(_)
+            | Action::Undelegate(_)
+            | Action::Redelegate(_)
+            | Action::RedelegateClaim(_)
+            | Action::Ics20Withdrawal(_)
+            | Action::DaoSpend(_)
+            | Action::DaoOutput(_)
+            | Action::DaoDeposit(_) => {}
+        }
+    }
+
+    let auth_hash = tx.auth_hash();
+    tracing::debug!(bvk = ?tx.binding_verification_key(), ?auth_hash);
+    signatures.queue(
+        "binding signature",
+        (
+            VerificationKeyBytes::from(tx.binding_verification_key()),
+            *tx.binding_sig(),
+            &auth_hash.as_bytes(),
+        ),
+    );
+
+    let mut checks = Vec::new();
+    for (name, batch) in [
+        ("spend", spend_proofs),
+        ("output", output_proofs),
+        ("swap", swap_proofs),
+        ("swap claim", swap_claim_proofs),
+        ("delegator vote", delegator_vote_proofs),
+        ("undelegate claim", undelegate_claim_proofs),
+    ] {
+        if batch.is_empty() {
+            continue;
+        }
+        checks.push(tokio::task::spawn_blocking(move || {
+            batch
+                .verify(&mut OsRng)
+                .with_context(|| format!("a {name} proof did not verify"))
+        }));
+    }
+    checks.push(tokio::task::spawn_blocking(move || signatures.verify()));
+
+    for check in checks {
+        check.await??;
+    }
+
+    Ok(())
+}
+
+/// A batch of signatures to verify, recording what each one is so that a failure can be reported.
+struct SignatureBatch {
+    verifier: rdsa::batch::Verifier,
+    items: Vec<(&'static str, rdsa::batch::Item)>,
+}
+
+impl SignatureBatch {
+    fn new() -> Self {
+        Self {
+            verifier: rdsa::batch::Verifier::new(),
+            items: Vec::new(),
+        }
+    }
+
+    fn queue(&mut self, kind: &'static str, item: impl Into<rdsa::batch::Item>) {
+        let item = item.into();
+        self.verifier.queue(item.clone());
+        self.items.push((kind, item));
+    }
+
+    fn verify(self) -> Result<()> {
+        if self.verifier.verify(OsRng).is_ok() {
+            return Ok(());
+        }
+
+        // The batch failed, so check each signature to find the one that didn't verify.
+        for (kind, item) in self.items {
+            item.verify_single()
+                .with_context(|| format!("{kind} failed to verify"))?;
+        }
+
+        Err(anyhow::anyhow!("signature batch did not verify"))
+    }
+}
@@ -1,19 +1,7 @@
 use std::collections::BTreeSet;
 
-use anyhow::{Context, Result};
-use penumbra_transaction::{AuthorizingData, Transaction};
-
-#[tracing::instrument(skip(tx))]
-pub(super) fn valid_binding_signature(tx: &Transaction) -> Result<()> {
-    let auth_hash = tx.auth_hash();
-
-    tracing::debug!(bvk = ?tx.binding_verification_key(), ?auth_hash);
-
-    // Check binding signature.
-    tx.binding_verification_key()
-        .verify(auth_hash.as_bytes(), tx.binding_sig())
-        .context("binding signature failed to verify")
-}
+use anyhow::Result;
+use penumbra_transaction::Transaction;
 
 pub(super) fn no_duplicate_nullifiers(tx: &Transaction) -> Result<()> {
     // Disallow multiple `Spend`s with the same `Nullifier`.
@@ -30,5 +18,19 @@ pub(super) fn no_duplicate_nullifiers(tx: &Transaction) -> Result<()> {
         }
     }
 
+    // Likewise, disallow multiple `DelegatorVote`s on the same proposal with the same
+    // `Nullifier`, since they'd be checked against the same prior state, and so could each
+    // claim to be the first vote with that nullifier and mint voting receipt tokens.
+    let mut voted_nullifiers = BTreeSet::new();
+    for vote in tx.delegator_votes() {
+        if !voted_nullifiers.insert((vote.body.proposal, vote.body.nullifier)) {
+            return Err(anyhow::anyhow!(
+                "Duplicate nullifier {} for votes on proposal {} in transaction",
+                vote.body.nullifier,
+                vote.body.proposal
+            ));
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_chain::StateReadExt as _;
+use penumbra_proto::DomainType;
+use penumbra_storage::StateWrite;
+use tendermint::abci;
+
+/// Records events that are emitted as ABCI events and also persisted in nonconsensus storage,
+/// indexed by block height, so that clients can stream them.
+#[async_trait]
+pub trait EventStreamWrite: StateWrite {
+    /// Record an event, both as an ABCI event and in the list of events under `pending_key`,
+    /// which are stored at the end of the block by [`EventStreamWrite::store_events`].
+    fn record_event<E>(&mut self, pending_key: &'static str, event: E)
+    where
+        E: DomainType + Send + Sync + 'static,
+        for<'a> &'a E: Into<abci::Event>,
+    {
+        self.record((&event).into());
+
+        let mut pending: Vec<E> = self.object_get(pending_key).unwrap_or_default();
+        pending.push(event);
+        self.object_put(pending_key, pending);
+    }
+
+    /// Store the events recorded under `pending_key` in this block, at the keys given by
+    /// `event_key(height, index)`.
+    async fn store_events<E>(
+        &mut self,
+        pending_key: &'static str,
+        event_key: fn(u64, u64) -> String,
+    ) -> Result<()>
+    where
+        E: DomainType + Send + Sync + 'static,
+    {
+        let events: Vec<E> = self.object_get(pending_key).unwrap_or_default();
+        self.object_delete(pending_key);
+
+        let height = self.get_block_height().await?;
+        for (index, event) in events.into_iter().enumerate() {
+            self.nonconsensus_put_raw(
+                event_key(height, index as u64).into_bytes(),
+                event.encode_to_vec(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> EventStreamWrite for T {}
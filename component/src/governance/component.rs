@@ -43,6 +43,12 @@ impl Component for Governance {
                 .await
                 .expect("end epoch should never fail");
         }
+
+        // Finally, store this block's proposal events, including any changes in tallies
+        state
+            .store_proposal_events()
+            .await
+            .expect("storing proposal events should never fail");
     }
 }
 
@@ -136,6 +142,9 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
             }
         };
 
+        // Publish the final tally before the outcome, if it changed in this block
+        state.record_proposal_tally_if_changed(proposal_id).await?;
+
         // Update the proposal state to reflect the outcome
         state.put_proposal_state(proposal_id, proposal::State::Finished { outcome });
     }
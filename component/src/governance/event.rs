@@ -1 +1,165 @@
+use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType};
+use penumbra_transaction::proposal;
+use serde::{Deserialize, Serialize};
+use tendermint::abci::{Event, EventAttributeIndexExt};
 
+use super::tally::Tally;
+
+/// A change in the lifecycle of a proposal.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::ProposalEvent", into = "pb::ProposalEvent")]
+pub struct ProposalEvent {
+    /// The proposal the event concerns.
+    pub proposal_id: u64,
+    /// What happened to the proposal.
+    pub kind: ProposalEventKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum ProposalEventKind {
+    /// The proposal was submitted, and voting on it started.
+    Submitted {
+        title: String,
+        voting_start_height: u64,
+        voting_end_height: u64,
+    },
+    /// The tally of votes on the proposal changed.
+    Tally(Tally),
+    /// The proposal was withdrawn, finished voting, or had its deposit claimed.
+    State(proposal::State),
+}
+
+impl ProposalEvent {
+    pub fn submitted(
+        proposal_id: u64,
+        title: String,
+        voting_start_height: u64,
+        voting_end_height: u64,
+    ) -> Self {
+        Self {
+            proposal_id,
+            kind: ProposalEventKind::Submitted {
+                title,
+                voting_start_height,
+                voting_end_height,
+            },
+        }
+    }
+
+    pub fn tally(proposal_id: u64, tally: Tally) -> Self {
+        Self {
+            proposal_id,
+            kind: ProposalEventKind::Tally(tally),
+        }
+    }
+
+    pub fn state(proposal_id: u64, state: proposal::State) -> Self {
+        Self {
+            proposal_id,
+            kind: ProposalEventKind::State(state),
+        }
+    }
+}
+
+impl From<&ProposalEvent> for Event {
+    fn from(event: &ProposalEvent) -> Self {
+        let proposal_id = ("proposal_id", event.proposal_id.to_string()).index();
+        match &event.kind {
+            ProposalEventKind::Submitted {
+                title,
+                voting_start_height,
+                voting_end_height,
+            } => Event::new(
+                "proposal_submitted",
+                vec![
+                    proposal_id,
+                    ("title", title.clone()).index(),
+                    ("voting_start_height", voting_start_height.to_string()).index(),
+                    ("voting_end_height", voting_end_height.to_string()).index(),
+                ],
+            ),
+            ProposalEventKind::Tally(tally) => Event::new(
+                "proposal_tally",
+                vec![
+                    proposal_id,
+                    ("yes", tally.yes().to_string()).index(),
+                    ("no", tally.no().to_string()).index(),
+                    ("abstain", tally.abstain().to_string()).index(),
+                ],
+            ),
+            ProposalEventKind::State(state) => {
+                let state_name = match state {
+                    proposal::State::Voting => "voting",
+                    proposal::State::Withdrawn { .. } => "withdrawn",
+                    proposal::State::Finished { outcome }
+                    | proposal::State::Claimed { outcome } => match outcome {
+                        proposal::Outcome::Passed => "passed",
+                        proposal::Outcome::Failed { .. } => "failed",
+                        proposal::Outcome::Slashed { .. } => "slashed",
+                    },
+                };
+                Event::new(
+                    "proposal_state",
+                    vec![
+                        proposal_id,
+                        ("state", state_name.to_string()).index(),
+                        ("claimed", state.is_claimed().to_string()).index(),
+                    ],
+                )
+            }
+        }
+    }
+}
+
+impl DomainType for ProposalEvent {
+    type Proto = pb::ProposalEvent;
+}
+
+impl From<ProposalEvent> for pb::ProposalEvent {
+    fn from(event: ProposalEvent) -> Self {
+        let inner = match event.kind {
+            ProposalEventKind::Submitted {
+                title,
+                voting_start_height,
+                voting_end_height,
+            } => pb::proposal_event::Event::Submitted(pb::proposal_event::Submitted {
+                title,
+                voting_start_height,
+                voting_end_height,
+            }),
+            ProposalEventKind::Tally(tally) => pb::proposal_event::Event::Tally(tally.into()),
+            ProposalEventKind::State(state) => pb::proposal_event::Event::State(state.into()),
+        };
+        pb::ProposalEvent {
+            proposal_id: event.proposal_id,
+            event: Some(inner),
+        }
+    }
+}
+
+impl TryFrom<pb::ProposalEvent> for ProposalEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ProposalEvent) -> Result<Self, Self::Error> {
+        let kind = match msg
+            .event
+            .ok_or_else(|| anyhow::anyhow!("missing proposal event"))?
+        {
+            pb::proposal_event::Event::Submitted(pb::proposal_event::Submitted {
+                title,
+                voting_start_height,
+                voting_end_height,
+            }) => ProposalEventKind::Submitted {
+                title,
+                voting_start_height,
+                voting_end_height,
+            },
+            pb::proposal_event::Event::Tally(tally) => ProposalEventKind::Tally(tally.into()),
+            pb::proposal_event::Event::State(state) => ProposalEventKind::State(state.try_into()?),
+        };
+        Ok(Self {
+            proposal_id: msg.proposal_id,
+            kind,
+        })
+    }
+}
@@ -1,5 +1,5 @@
 mod component;
-pub(crate) mod event;
+pub mod event;
 mod metrics;
 mod view;
 
@@ -8,5 +8,6 @@ pub mod tally;
 
 pub use self::metrics::register_metrics;
 pub use component::Governance;
+pub use event::{ProposalEvent, ProposalEventKind};
 pub use tally::Tally;
 pub use view::{StateReadExt, StateWriteExt};
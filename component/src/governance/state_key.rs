@@ -45,6 +45,10 @@ pub fn voted_nullifier_lookup_for_proposal(proposal_id: u64, nullifier: &Nullifi
     format!("governance/proposal/{proposal_id:020}/voted_nullifiers/{nullifier}")
 }
 
+pub fn delegator_vote_by_nullifier(proposal_id: u64, nullifier: &Nullifier) -> String {
+    format!("governance/proposal/{proposal_id:020}/delegator_vote_by_nullifier/{nullifier}")
+}
+
 pub fn rate_data_at_proposal_start(proposal_id: u64, identity_key: IdentityKey) -> String {
     format!("governance/proposal/{proposal_id:020}/rate_data_at_start/{identity_key}")
 }
@@ -115,3 +119,24 @@ pub fn deliver_dao_transactions_at_height(block_height: u64) -> String {
 pub fn change_chain_params_at_height(block_height: u64) -> String {
     format!("governance/change_chain_params/{block_height}/")
 }
+
+/// Proposal events are kept in nonconsensus storage, since they can be recomputed from the
+/// chain's history but are needed to stream lifecycle changes to clients.
+pub fn proposal_event(block_height: u64, index: u64) -> String {
+    format!("governance/proposal_events/{block_height:020}/{index:020}")
+}
+
+pub fn proposal_events_at_height(block_height: u64) -> String {
+    // Note: this has to be the prefix of the `proposal_event` function above.
+    format!("governance/proposal_events/{block_height:020}/")
+}
+
+pub(crate) mod internal {
+    pub fn pending_proposal_events() -> &'static str {
+        "governance/stub/pending_proposal_events"
+    }
+
+    pub fn proposals_with_new_votes() -> &'static str {
+        "governance/stub/proposals_with_new_votes"
+    }
+}
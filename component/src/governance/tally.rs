@@ -1,4 +1,4 @@
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use serde::{Deserialize, Serialize};
 
@@ -62,11 +62,15 @@ impl DomainType for Tally {
 impl From<(Vote, u64)> for Tally {
     fn from((vote, power): (Vote, u64)) -> Self {
         let mut tally = Self::default();
-        *match vote {
-            Vote::Yes => &mut tally.yes,
-            Vote::No => &mut tally.no,
-            Vote::Abstain => &mut tally.abstain,
-        } = power;
+        match vote {
+            Vote::Yes => tally.yes = power,
+            Vote::No => tally.no = power,
+            Vote::Abstain => tally.abstain = power,
+            // A weighted vote splits its power proportionally, accounting for all of it
+            Vote::Weighted(weights) => {
+                (tally.yes, tally.no, tally.abstain) = weights.split(power);
+            }
+        }
         tally
     }
 }
@@ -97,6 +101,26 @@ impl AddAssign for Tally {
     }
 }
 
+impl Sub for Tally {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            yes: self.yes - rhs.yes,
+            no: self.no - rhs.no,
+            abstain: self.abstain - rhs.abstain,
+        }
+    }
+}
+
+impl SubAssign for Tally {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.yes -= rhs.yes;
+        self.no -= rhs.no;
+        self.abstain -= rhs.abstain;
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Outcome {
     Pass,
@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeMap, BTreeSet},
+    collections::{btree_map, BTreeMap, BTreeSet},
     str::FromStr,
 };
 
@@ -24,11 +24,12 @@ use tokio::task::JoinSet;
 use tracing::instrument;
 
 use crate::{
+    event_stream::EventStreamWrite as _,
     shielded_pool::{StateReadExt as _, StateWriteExt as _, SupplyRead},
     stake::{rate::RateData, validator, StateReadExt as _},
 };
 
-use super::{state_key, tally::Tally};
+use super::{event::ProposalEvent, state_key, tally::Tally};
 
 #[async_trait]
 pub trait StateReadExt: StateRead + crate::stake::StateReadExt {
@@ -123,26 +124,58 @@ pub trait StateReadExt: StateRead + crate::stake::StateReadExt {
             .sum())
     }
 
-    /// Check whether a nullifier was spent for a given proposal.
-    async fn check_nullifier_unvoted_for_proposal(
+    /// Check that a delegator vote claims to replace an earlier vote on a proposal exactly when
+    /// its nullifier has already been used to vote on the proposal.
+    ///
+    /// Only the first vote with a nullifier mints voting receipt tokens, so this is what stops a
+    /// nullifier from being used to mint them more than once.
+    async fn check_nullifier_vote_replacement(
         &self,
         proposal_id: u64,
         nullifier: &Nullifier,
+        replaces_previous: bool,
     ) -> Result<()> {
-        if let Some(height) = self
+        match (
+            self.nullifier_voted_on_proposal(proposal_id, nullifier)
+                .await?,
+            replaces_previous,
+        ) {
+            (Some(height), false) => Err(anyhow::anyhow!(
+                "nullifier {nullifier} was already used for voting on proposal {proposal_id} at height {height}, so a new vote must replace the earlier one",
+            )),
+            (None, true) => Err(anyhow::anyhow!(
+                "nullifier {nullifier} has not been used for voting on proposal {proposal_id}, so there is no earlier vote to replace",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the most recent vote cast on a proposal using a given nullifier, if any, as the tally
+    /// it contributes.
+    async fn delegator_vote_by_nullifier(
+        &self,
+        proposal_id: u64,
+        nullifier: &Nullifier,
+    ) -> Result<Option<Tally>> {
+        self.get(&state_key::delegator_vote_by_nullifier(
+            proposal_id,
+            nullifier,
+        ))
+        .await
+    }
+
+    /// Get the block height at which a nullifier was last used to vote on a proposal, if any.
+    async fn nullifier_voted_on_proposal(
+        &self,
+        proposal_id: u64,
+        nullifier: &Nullifier,
+    ) -> Result<Option<u64>> {
+        Ok(self
             .get_proto::<u64>(&state_key::voted_nullifier_lookup_for_proposal(
                 proposal_id,
                 nullifier,
             ))
-            .await?
-        {
-            // If the nullifier was already voted with, error:
-            return Err(anyhow::anyhow!(
-                "nullifier {nullifier} was already used for voting on proposal {proposal_id} at height {height}",
-            ));
-        }
-
-        Ok(())
+            .await?)
     }
 
     /// Get the [`RateData`] for a validator at the start height of a given proposal.
@@ -289,23 +322,6 @@ pub trait StateReadExt: StateRead + crate::stake::StateReadExt {
         Ok(())
     }
 
-    /// Check that the validator has not voted on the proposal.
-    async fn check_validator_has_not_voted(
-        &self,
-        proposal_id: u64,
-        identity_key: &IdentityKey,
-    ) -> Result<()> {
-        if let Some(_vote) = self.validator_vote(proposal_id, *identity_key).await? {
-            anyhow::bail!(
-                "validator {} has already voted on proposal {}",
-                identity_key,
-                proposal_id
-            );
-        }
-
-        Ok(())
-    }
-
     /// Check that the governance key matches the validator's identity key.
     async fn check_governance_key_matches_validator(
         &self,
@@ -653,6 +669,11 @@ pub trait StateWriteExt: StateWrite {
         // Set the state of the proposal
         self.put(state_key::proposal_state(proposal_id), state.clone());
 
+        // Proposals enter the voting state when they are submitted, which has its own event
+        if !state.is_voting() {
+            self.record_proposal_event(ProposalEvent::state(proposal_id, state.clone()));
+        }
+
         match &state {
             proposal::State::Voting | proposal::State::Withdrawn { .. } => {
                 // If we're setting the proposal to a non-finished state, track it in our list of
@@ -671,6 +692,53 @@ pub trait StateWriteExt: StateWrite {
     fn cast_validator_vote(&mut self, proposal_id: u64, identity_key: IdentityKey, vote: Vote) {
         // Record the vote
         self.put(state_key::validator_vote(proposal_id, identity_key), vote);
+        self.mark_proposal_votes_changed(proposal_id);
+    }
+
+    /// Record a proposal lifecycle event, both as an ABCI event and in the list of events to be
+    /// stored at the end of the block.
+    fn record_proposal_event(&mut self, event: ProposalEvent) {
+        self.record_event(state_key::internal::pending_proposal_events(), event);
+    }
+
+    /// Note that the tally of a proposal changed in this block, so it should be published at the
+    /// end of the block.
+    fn mark_proposal_votes_changed(&mut self, proposal_id: u64) {
+        let mut proposals: BTreeSet<u64> = self
+            .object_get(state_key::internal::proposals_with_new_votes())
+            .unwrap_or_default();
+        proposals.insert(proposal_id);
+        self.object_put(state_key::internal::proposals_with_new_votes(), proposals);
+    }
+
+    /// Record the current tally of a proposal as an event, if it changed in this block.
+    async fn record_proposal_tally_if_changed(&mut self, proposal_id: u64) -> Result<()> {
+        let mut proposals: BTreeSet<u64> = self
+            .object_get(state_key::internal::proposals_with_new_votes())
+            .unwrap_or_default();
+        if proposals.remove(&proposal_id) {
+            let tally = self.current_tally(proposal_id).await?;
+            self.record_proposal_event(ProposalEvent::tally(proposal_id, tally));
+            self.object_put(state_key::internal::proposals_with_new_votes(), proposals);
+        }
+        Ok(())
+    }
+
+    /// Publish the tallies of all proposals whose votes changed in this block, then store all the
+    /// proposal events of this block so that they can be streamed to clients.
+    async fn store_proposal_events(&mut self) -> Result<()> {
+        let proposals: BTreeSet<u64> = self
+            .object_get(state_key::internal::proposals_with_new_votes())
+            .unwrap_or_default();
+        for proposal_id in proposals {
+            self.record_proposal_tally_if_changed(proposal_id).await?;
+        }
+
+        self.store_events::<ProposalEvent>(
+            state_key::internal::pending_proposal_events(),
+            state_key::proposal_event,
+        )
+        .await
     }
 
     /// Set the proposal voting start block height for a proposal.
@@ -716,9 +784,32 @@ pub trait StateWriteExt: StateWrite {
         let power = u64::from(unbonded_amount);
         let tally: Tally = (vote, power).into();
 
+        // If this nullifier has voted on the proposal before, the new vote replaces the old one.
+        // If the old vote is still untallied, it will be overwritten below; otherwise, it has
+        // already been swept into the validator's delegator tally, so take it back out.
+        let untallied_key =
+            state_key::untallied_delegator_vote(proposal_id, identity_key, nullifier);
+        if let Some(previous) = self
+            .delegator_vote_by_nullifier(proposal_id, nullifier)
+            .await?
+        {
+            if self.get::<Tally>(&untallied_key).await?.is_none() {
+                let tallied_key = state_key::tallied_delegator_votes(proposal_id, identity_key);
+                let mut tallied = self.get::<Tally>(&tallied_key).await?.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "missing delegator tally for previously tallied vote on proposal {proposal_id}"
+                    )
+                })?;
+                tallied -= previous;
+                self.put(tallied_key, tallied);
+                self.mark_proposal_votes_changed(proposal_id);
+            }
+        }
+
         // Record the vote
+        self.put(untallied_key, tally);
         self.put(
-            state_key::untallied_delegator_vote(proposal_id, identity_key, nullifier),
+            state_key::delegator_vote_by_nullifier(proposal_id, nullifier),
             tally,
         );
 
@@ -758,23 +849,27 @@ pub trait StateWriteExt: StateWrite {
                 })?
                 .parse()?;
 
-            // Get the current tally for this validator
-            let mut current_tally = self
-                .get::<Tally>(&state_key::tallied_delegator_votes(
-                    proposal_id,
-                    identity_key,
-                ))
-                .await?
-                .unwrap_or_default();
-
-            // Add the new tally to the current tally
-            current_tally += tally;
-
-            // Remember the new tally
-            new_tallies
+            // Get the current tally for this validator, starting from the stored tally the first
+            // time the validator is seen in this sweep, so that earlier votes in the sweep aren't
+            // lost
+            let current_tally = match new_tallies
                 .entry(proposal_id)
                 .or_default()
-                .insert(identity_key, current_tally);
+                .entry(identity_key)
+            {
+                btree_map::Entry::Occupied(entry) => entry.into_mut(),
+                btree_map::Entry::Vacant(entry) => entry.insert(
+                    self.get::<Tally>(&state_key::tallied_delegator_votes(
+                        proposal_id,
+                        identity_key,
+                    ))
+                    .await?
+                    .unwrap_or_default(),
+                ),
+            };
+
+            // Add the new tally to the current tally
+            *current_tally += tally;
 
             // Remember to delete this key
             keys_to_delete.push(key);
@@ -790,6 +885,7 @@ pub trait StateWriteExt: StateWrite {
 
         // Actually record the new tallies in the state
         for (proposal_id, new_tallies_for_proposal) in new_tallies {
+            self.mark_proposal_votes_changed(proposal_id);
             for (identity_key, tally) in new_tallies_for_proposal {
                 tracing::debug!(
                     proposal_id,
@@ -922,3 +1018,52 @@ pub trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + StateReadExt> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::{rdsa::SigningKey, Fq};
+    use penumbra_storage::{StateDelta, TempStorage};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn replacement_delegator_vote_overrides_earlier_vote() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let proposal_id = 0;
+        let identity_key = IdentityKey((&SigningKey::new(OsRng)).into());
+        let nullifier = Nullifier(Fq::from(1u64));
+        let power = Amount::from(100u64);
+
+        // The first vote is swept into the validator's delegator tally.
+        state
+            .cast_delegator_vote(proposal_id, identity_key, Vote::Yes, &nullifier, power)
+            .await?;
+        state.tally_delegator_votes(None).await?;
+        let tally = state.tallied_delegator_votes(proposal_id).await?[&identity_key];
+        assert_eq!((tally.yes(), tally.no(), tally.abstain()), (100, 0, 0));
+
+        // Replacing an already-tallied vote takes it back out of the tally.
+        state
+            .cast_delegator_vote(proposal_id, identity_key, Vote::No, &nullifier, power)
+            .await?;
+        state.tally_delegator_votes(None).await?;
+        let tally = state.tallied_delegator_votes(proposal_id).await?[&identity_key];
+        assert_eq!((tally.yes(), tally.no(), tally.abstain()), (0, 100, 0));
+
+        // Replacing a vote that hasn't been tallied yet overwrites it.
+        state
+            .cast_delegator_vote(proposal_id, identity_key, Vote::Abstain, &nullifier, power)
+            .await?;
+        state
+            .cast_delegator_vote(proposal_id, identity_key, Vote::Yes, &nullifier, power)
+            .await?;
+        state.tally_delegator_votes(None).await?;
+        let tally = state.tallied_delegator_votes(proposal_id).await?[&identity_key];
+        assert_eq!((tally.yes(), tally.no(), tally.abstain()), (100, 0, 0));
+
+        Ok(())
+    }
+}
@@ -62,6 +62,10 @@ pub fn channel(channel_id: &ChannelId, port_id: &PortId) -> String {
     format!("channelEnds/ports/{port_id}/channels/{channel_id}")
 }
 
+pub fn all_channels() -> &'static str {
+    "channelEnds/ports/"
+}
+
 pub fn seq_recv(channel_id: &ChannelId, port_id: &PortId) -> String {
     format!("seqRecvs/ports/{port_id}/channels/{channel_id}/nextSequenceRecv")
 }
@@ -96,6 +100,17 @@ pub fn packet_commitment_by_port(
     format!("commitments/ports/{port_id}/channels/{channel_id}/packets/{sequence}")
 }
 
+pub fn packet_commitments_by_channel(port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!("commitments/ports/{port_id}/channels/{channel_id}/packets/")
+}
+
 pub fn ics20_value_balance(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
     format!("ics20-value-balance/{channel_id}/{asset_id}")
 }
+
+pub fn ics20_value_balances(channel_id: Option<&ChannelId>) -> String {
+    match channel_id {
+        Some(channel_id) => format!("ics20-value-balance/{channel_id}/"),
+        None => "ics20-value-balance/".to_string(),
+    }
+}
@@ -19,5 +19,8 @@ pub use self::metrics::register_metrics;
 pub use client::{
     validate_penumbra_client_state, ClientConnections, ClientCounter, VerifiedHeights,
 };
-pub use component::IBCComponent;
+pub use component::{
+    channel::StateReadExt as ChannelStateReadExt, client::StateReadExt as ClientStateReadExt,
+    connection::StateReadExt as ConnectionStateReadExt, state_key, IBCComponent,
+};
 pub use connection::{ConnectionCounter, SUPPORTED_VERSIONS};
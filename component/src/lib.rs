@@ -7,10 +7,12 @@ use penumbra_storage::StateWrite;
 use tendermint::abci;
 
 mod action_handler;
+mod event_stream;
 mod mock_client;
 mod temp_storage_ext;
 
 pub use action_handler::ActionHandler;
+pub use event_stream::EventStreamWrite;
 pub use mock_client::MockClient;
 pub use temp_storage_ext::TempStorageExt;
 
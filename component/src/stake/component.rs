@@ -5,7 +5,10 @@ use std::{
     pin::Pin,
 };
 
-use crate::{dao::view::StateWriteExt as _, stake::funding_stream::Recipient, Component};
+use crate::{
+    dao::view::StateWriteExt as _, event_stream::EventStreamWrite as _,
+    stake::funding_stream::Recipient, Component,
+};
 use ::metrics::{decrement_gauge, gauge, increment_gauge};
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
@@ -18,10 +21,10 @@ use penumbra_crypto::{
 };
 use penumbra_proto::{
     state::future::{DomainFuture, ProtoFuture},
-    StateReadProto, StateWriteProto,
+    DomainType, StateReadProto, StateWriteProto,
 };
 use penumbra_storage::{StateRead, StateWrite};
-use penumbra_transaction::action::{Delegate, Undelegate};
+use penumbra_transaction::action::{Delegate, Redelegate, Undelegate};
 use sha2::{Digest, Sha256};
 use tendermint::validator::Update;
 use tendermint::{
@@ -39,12 +42,12 @@ use crate::stake::{
     rate::{BaseRateData, RateData},
     state_key,
     validator::{self, Validator},
-    DelegationChanges, Uptime,
+    DelegationChanges, EvidenceKind, EvidenceRecord, Uptime,
 };
 
 use crate::shielded_pool::{NoteManager, SupplyRead, SupplyWrite};
 
-use super::{event, CurrentConsensusKeys};
+use super::{event, CurrentConsensusKeys, ValidatorEvent};
 
 // Max validator power is 1152921504606846975 (i64::MAX / 8)
 // https://github.com/tendermint/tendermint/blob/master/types/validator_set.go#L25
@@ -108,7 +111,17 @@ pub(crate) trait StakingImpl: StateWriteExt {
         // Delegating to an inner method here lets us create a span that has both states,
         // without having to manage span entry/exit in async code.
         self.set_validator_state_inner(identity_key, cur_state, new_state)
-            .await
+            .await?;
+
+        if cur_state != new_state {
+            self.record_validator_event(ValidatorEvent::state_change(
+                *identity_key,
+                cur_state,
+                new_state,
+            ));
+        }
+
+        Ok(())
     }
 
     // Inner function pretends to be the outer one, so we can include cur_state
@@ -224,10 +237,17 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 Ok(())
             }
             (Active, Jailed) => {
-                let penalty = self.get_chain_params().await?.slashing_penalty_downtime;
-
-                // Record the slashing penalty on this validator.
-                self.record_slashing_penalty(identity_key, penalty).await?;
+                // The slashing penalty for the validator's misbehavior is
+                // recorded by the caller, since it depends on why the validator
+                // was jailed.
+
+                // Record when the validator was jailed, so that we can tell
+                // when it has served its minimum jail duration.
+                let height = self.get_block_height().await?;
+                self.put_proto(
+                    state_key::jailed_at_height_by_validator(identity_key),
+                    height,
+                );
 
                 // The validator's delegation pool begins unbonding.  Jailed
                 // validators are not unbonded immediately, because they need to
@@ -282,6 +302,7 @@ pub(crate) trait StakingImpl: StateWriteExt {
         // and save the next rate data. ensure that non-Active validators maintain constant rates.
         let mut delegations_by_validator = BTreeMap::<IdentityKey, Vec<Delegate>>::new();
         let mut undelegations_by_validator = BTreeMap::<IdentityKey, Vec<Undelegate>>::new();
+        let mut redelegations = Vec::<Redelegate>::new();
         for height in epoch_to_end.start_height().value()..=epoch_to_end.end_height().value() {
             let changes = self.delegation_changes(height.try_into().unwrap()).await?;
             for d in changes.delegations {
@@ -296,12 +317,14 @@ pub(crate) trait StakingImpl: StateWriteExt {
                     .or_insert_with(Vec::new)
                     .push(u);
             }
+            redelegations.extend(changes.redelegations);
         }
         tracing::debug!(
             total_delegations = ?delegations_by_validator.values().map(|v| v.len())
                 .sum::<usize>(),
             total_undelegations = ?undelegations_by_validator.values().map(|v| v.len())
                 .sum::<usize>(),
+            total_redelegations = ?redelegations.len(),
         );
 
         let chain_params = self.get_chain_params().await?;
@@ -323,6 +346,25 @@ pub(crate) trait StakingImpl: StateWriteExt {
 
         let validator_list = self.validator_list().await?;
         for validator in &validator_list {
+            // Consensus key rotations take effect at the epoch boundary: once
+            // the definition's key is the one Tendermint knows the validator
+            // by, `build_tendermint_validator_updates` retires the old key.
+            let consensus_key = self
+                .validator_consensus_key(&validator.identity_key)
+                .await?;
+            if consensus_key != Some(validator.consensus_key) {
+                tracing::info!(
+                    identity_key = %validator.identity_key,
+                    old_consensus_key = ?consensus_key,
+                    new_consensus_key = ?validator.consensus_key,
+                    "rotating validator consensus key"
+                );
+                self.put(
+                    state_key::consensus_key_by_validator(&validator.identity_key),
+                    validator.consensus_key,
+                );
+            }
+
             // The old epoch's "next rate" is now the "current rate"...
             let old_next_rate = self
                 .next_validator_rate(&validator.identity_key)
@@ -351,15 +393,29 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 current_rate.next(&next_base_rate, funding_streams.as_ref(), &validator_state);
             assert!(next_rate.epoch_index == epoch_to_end.index + 2);
 
+            // Redelegations count as an undelegation from the source validator
+            // and a delegation to the destination validator.
             let total_delegations = delegations_by_validator
                 .get(&validator.identity_key)
                 .into_iter()
                 .flat_map(|ds| ds.iter().map(|d| u64::from(d.delegation_amount)))
+                .chain(
+                    redelegations
+                        .iter()
+                        .filter(|r| r.to_validator_identity == validator.identity_key)
+                        .map(|r| u64::from(r.to_delegation_amount)),
+                )
                 .sum::<u64>();
             let total_undelegations = undelegations_by_validator
                 .get(&validator.identity_key)
                 .into_iter()
                 .flat_map(|us| us.iter().map(|u| u64::from(u.delegation_amount)))
+                .chain(
+                    redelegations
+                        .iter()
+                        .filter(|r| r.from_validator_identity == validator.identity_key)
+                        .map(|r| u64::from(r.from_delegation_amount)),
+                )
                 .sum::<u64>();
             let delegation_delta = (total_delegations as i64) - (total_undelegations as i64);
 
@@ -450,6 +506,13 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 }
             }
 
+            // Once the ending epoch's commission has been paid out, its limit on commission
+            // increases no longer applies, so drop the validator's starting commission for it.
+            self.delete(state_key::commission_at_epoch_start(
+                &validator.identity_key,
+                epoch_to_end.index,
+            ));
+
             // rename to curr_rate so it lines up with next_rate (same # chars)
             let delegation_denom = DelegationToken::from(&validator.identity_key).denom();
             tracing::debug!(curr_rate = ?current_rate);
@@ -683,6 +746,10 @@ pub(crate) trait StakingImpl: StateWriteExt {
                     .try_into()
                     .unwrap();
 
+                // Note: Tendermint applies validator set changes with a delay,
+                // so for the first few blocks after a consensus key rotation,
+                // the validator signs with its old key and may be counted as
+                // having missed those blocks.
                 let voted = did_address_vote
                     .get(&addr)
                     .cloned()
@@ -702,6 +769,13 @@ pub(crate) trait StakingImpl: StateWriteExt {
 
                 uptime.mark_height_as_signed(height, voted).unwrap();
                 if uptime.num_missed_blocks() as u64 >= params.missed_blocks_maximum {
+                    self.record_slashing_penalty(&identity_key, params.slashing_penalty_downtime)
+                        .await?;
+                    self.record_validator_event(ValidatorEvent::slash(
+                        identity_key,
+                        params.slashing_penalty_downtime,
+                        None,
+                    ));
                     self.set_validator_state(&identity_key, validator::State::Jailed)
                         .await?;
                 } else {
@@ -815,10 +889,6 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 // The operator has enabled their validator, so set it to Inactive.
                 self.set_validator_state(id, Inactive).await?;
             }
-            (Jailed, true) => {
-                // Treat updates to jailed validators as unjail requests.
-                self.set_validator_state(id, Inactive).await?;
-            }
             (Active | Inactive | Jailed | Disabled, false) => {
                 // The operator has disabled their validator.
                 self.set_validator_state(id, Disabled).await?;
@@ -826,29 +896,125 @@ pub(crate) trait StakingImpl: StateWriteExt {
             (Active | Inactive, true) => {
                 // This validator update does not affect the validator's state.
             }
+            (Jailed, true) => {
+                // Jailed validators must be released with an `Unjail` action,
+                // once they have served their minimum jail duration.
+            }
             (Tombstoned, _) => {
                 // Ignore updates to tombstoned validators.
             }
         }
 
         // Update the consensus key lookup, in case the validator rotated their
-        // consensus key.
+        // consensus key.  The lookup is never cleared, so that evidence
+        // against a retired key can still be attributed to the validator.
+        // The rotation itself only takes effect at the next epoch boundary,
+        // so until then Tendermint still knows the validator by its old key.
         self.register_consensus_key(&validator.identity_key, &validator.consensus_key)
             .await;
 
+        // Remember the commission the validator charged at the start of the
+        // epoch, so that commission increases can be limited per epoch no
+        // matter how many times the validator redefines itself.
+        let epoch_index = self.get_current_epoch().await?.index;
+        if self
+            .validator_commission_at_epoch_start(id, epoch_index)
+            .await?
+            .is_none()
+        {
+            let prev_validator = self
+                .validator(id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("updated validator not found in JMT"))?;
+            self.put_proto(
+                state_key::commission_at_epoch_start(id, epoch_index),
+                prev_validator.funding_streams.total_rate_bps(),
+            );
+        }
+
         self.put(state_key::validators::by_id(id), validator);
 
         Ok(())
     }
 
+    /// Slashes a validator for misbehavior reported by Tendermint.
+    ///
+    /// The validator's rate is slashed by the misbehavior penalty, and, if it
+    /// is active, it is jailed, so that it stops participating in consensus
+    /// until it is explicitly unjailed.  Each piece of evidence is recorded, so
+    /// that slashing can be audited later.
+    #[instrument(skip(self, evidence), fields(height = %evidence.height, kind = ?evidence.kind))]
     async fn process_evidence(&mut self, evidence: &Evidence) -> Result<()> {
+        let Some(kind) = EvidenceKind::from_tendermint(evidence.kind) else {
+            tracing::warn!("ignoring evidence of unknown kind");
+            return Ok(());
+        };
+
         let validator = self
             .validator_by_tendermint_address(&evidence.validator.address)
             .await?
             .ok_or_else(|| anyhow::anyhow!("attempted to slash unknown validator"))?;
+        let identity_key = &validator.identity_key;
 
-        self.set_validator_state(&validator.identity_key, validator::State::Tombstoned)
-            .await
+        // Evidence is only actionable while the stake delegated at the time of
+        // the misbehavior could still be bonded: past the unbonding window,
+        // the delegators who were responsible may have left the pool.
+        let params = self.get_chain_params().await?;
+        let evidence_height = evidence.height.value();
+        let current_height = self.get_block_height().await?;
+        let unbonding_window = params.unbonding_epochs * params.epoch_duration;
+        if evidence_height.saturating_add(unbonding_window) < current_height {
+            tracing::warn!(
+                %identity_key,
+                evidence_height,
+                unbonding_window,
+                "ignoring evidence older than the unbonding window"
+            );
+            return Ok(());
+        }
+
+        // Tendermint may report the same misbehavior more than once (e.g., a
+        // duplicate vote in several rounds of the same height), but it should
+        // only be slashed once.
+        let evidence_key = state_key::evidence(identity_key, evidence_height, kind.as_str());
+        if self.get::<EvidenceRecord>(&evidence_key).await?.is_some() {
+            tracing::debug!(%identity_key, "ignoring evidence that was already processed");
+            return Ok(());
+        }
+
+        use validator::State::*;
+        let state = self
+            .validator_state(identity_key)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("slashed validator has no recorded state"))?;
+        if state == Tombstoned {
+            // Tombstoned validators have already been fully slashed.
+            tracing::debug!(%identity_key, "ignoring evidence against tombstoned validator");
+            return Ok(());
+        }
+
+        let penalty = params.slashing_penalty_misbehavior;
+        tracing::info!(%identity_key, %kind, %penalty, "slashing validator for misbehavior");
+        self.record_slashing_penalty(identity_key, penalty).await?;
+        if state == Active {
+            self.set_validator_state(identity_key, Jailed).await?;
+        }
+
+        let record = EvidenceRecord {
+            identity_key: identity_key.clone(),
+            kind,
+            evidence_height,
+            processed_height: current_height,
+            penalty,
+        };
+        self.record_validator_event(ValidatorEvent::slash(
+            *identity_key,
+            penalty,
+            Some((kind, evidence_height)),
+        ));
+        self.put(evidence_key, record);
+
+        Ok(())
     }
 }
 
@@ -948,6 +1114,8 @@ impl Component for Staking {
         }
 
         state.build_tendermint_validator_updates().await.unwrap();
+
+        state.store_validator_events().await.unwrap();
     }
 }
 
@@ -1028,23 +1196,16 @@ pub trait StateReadExt: StateRead {
         self.get(&state_key::validators::by_id(identity_key)).await
     }
 
+    /// Returns the consensus key Tendermint currently knows the validator by.
+    ///
+    /// If the validator has rotated its consensus key, this is the old key
+    /// until the rotation takes effect at the next epoch boundary; the new
+    /// key is in the validator definition.
     fn validator_consensus_key(
         &self,
         identity_key: &IdentityKey,
-    ) -> Pin<Box<dyn Future<Output = Result<Option<PublicKey>>> + Send + 'static>> {
-        // TODO: this is pulling out the whole proto, but only parsing
-        // the consensus key.  Alternatively, we could store the consensus
-        // keys in a separate index.
-        self.get_proto::<penumbra_proto::penumbra::core::stake::v1alpha1::Validator>(
-            &state_key::validators::by_id(identity_key),
-        )
-        .map_ok(|opt| {
-            opt.map(|v| {
-                tendermint::PublicKey::from_raw_ed25519(v.consensus_key.as_slice())
-                    .expect("validator consensus key must be valid ed25519 key")
-            })
-        })
-        .boxed()
+    ) -> DomainFuture<PublicKey, Self::GetRawFut> {
+        self.get(&state_key::consensus_key_by_validator(identity_key))
     }
 
     // Tendermint validators are referenced to us by their Tendermint consensus key,
@@ -1166,6 +1327,66 @@ pub trait StateReadExt: StateRead {
         self.get(&state_key::uptime_by_validator(identity_key))
     }
 
+    /// Returns the evidence of misbehavior processed against the given
+    /// validator, or against all validators if `None`, ordered by validator
+    /// and then by height.
+    async fn evidence_records(
+        &self,
+        identity_key: Option<&IdentityKey>,
+    ) -> Result<Vec<EvidenceRecord>> {
+        let prefix = match identity_key {
+            Some(id) => state_key::evidence_by_validator(id),
+            None => state_key::all_evidence().to_owned(),
+        };
+        self.prefix(&prefix)
+            .map_ok(|(_key, record)| record)
+            .try_collect()
+            .await
+    }
+
+    /// Returns the height at which the given validator was most recently jailed, if ever.
+    async fn validator_jailed_at_height(&self, identity_key: &IdentityKey) -> Result<Option<u64>> {
+        self.get_proto(&state_key::jailed_at_height_by_validator(identity_key))
+            .await
+    }
+
+    /// Returns the validator's total commission at the start of the given
+    /// epoch, if it has redefined itself during that epoch.
+    async fn validator_commission_at_epoch_start(
+        &self,
+        identity_key: &IdentityKey,
+        epoch_index: u64,
+    ) -> Result<Option<u64>> {
+        self.get_proto(&state_key::commission_at_epoch_start(
+            identity_key,
+            epoch_index,
+        ))
+        .await
+    }
+
+    /// Returns the validator's rate data in each epoch since it was defined,
+    /// oldest first.
+    async fn validator_rate_history(&self, identity_key: &IdentityKey) -> Result<Vec<RateData>> {
+        self.nonconsensus_prefix_raw(state_key::rate_history_prefix(identity_key).as_bytes())
+            .and_then(|(_key, bytes)| async move { RateData::decode(bytes.as_slice()) })
+            .try_collect()
+            .await
+    }
+
+    /// Returns the validator's rate data in the given epoch, if it is known.
+    async fn validator_rate_in_epoch(
+        &self,
+        identity_key: &IdentityKey,
+        epoch_index: u64,
+    ) -> Result<Option<RateData>> {
+        self.nonconsensus_get_raw(
+            state_key::rate_history_by_validator(identity_key, epoch_index).as_bytes(),
+        )
+        .await?
+        .map(|bytes| RateData::decode(bytes.as_slice()))
+        .transpose()
+    }
+
     async fn signed_blocks_window_len(&self) -> Result<u64> {
         Ok(self.get_chain_params().await?.signed_blocks_window_len)
     }
@@ -1222,6 +1443,13 @@ pub trait StateWriteExt: StateWrite {
         self.put_stub_delegation_changes(changes);
     }
 
+    fn stub_push_redelegation(&mut self, redelegation: Redelegate) {
+        self.record(event::redelegate(&redelegation));
+        let mut changes = self.stub_delegation_changes();
+        changes.redelegations.push(redelegation);
+        self.put_stub_delegation_changes(changes);
+    }
+
     #[instrument(skip(self))]
     async fn set_base_rates(&mut self, current: BaseRateData, next: BaseRateData) {
         tracing::debug!("setting base rates");
@@ -1253,13 +1481,34 @@ pub trait StateWriteExt: StateWrite {
         next_rates: RateData,
     ) {
         tracing::debug!("setting validator rates");
+        self.nonconsensus_put_raw(
+            state_key::rate_history_by_validator(identity_key, current_rates.epoch_index)
+                .into_bytes(),
+            current_rates.encode_to_vec(),
+        );
         self.put(
             state_key::current_rate_by_validator(identity_key),
             current_rates,
         );
+        self.record_validator_event(ValidatorEvent::rate_change(next_rates.clone()));
         self.put(state_key::next_rate_by_validator(identity_key), next_rates);
     }
 
+    /// Records a validator event, both as an ABCI event and for the validator
+    /// event stream, which is persisted at the end of the block.
+    fn record_validator_event(&mut self, event: ValidatorEvent) {
+        self.record_event(state_key::internal::pending_validator_events(), event);
+    }
+
+    /// Persists the validator events recorded in this block, indexed by height.
+    async fn store_validator_events(&mut self) -> Result<()> {
+        self.store_events::<ValidatorEvent>(
+            state_key::internal::pending_validator_events(),
+            state_key::validator_event,
+        )
+        .await
+    }
+
     async fn register_consensus_key(
         &mut self,
         identity_key: &IdentityKey,
@@ -1317,6 +1566,10 @@ pub trait StateWriteExt: StateWrite {
         self.put(state_key::validators::by_id(&id), validator.clone());
         self.register_consensus_key(&validator.identity_key, &validator.consensus_key)
             .await;
+        self.put(
+            state_key::consensus_key_by_validator(&id),
+            validator.consensus_key,
+        );
         self.register_denom(&DelegationToken::from(&id).denom())
             .await?;
 
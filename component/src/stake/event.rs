@@ -1,6 +1,12 @@
-use penumbra_transaction::action::{Delegate, Undelegate};
+use anyhow::anyhow;
+use penumbra_crypto::stake::{IdentityKey, Penalty};
+use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
+use penumbra_transaction::action::{Delegate, Redelegate, Undelegate};
+use serde::{Deserialize, Serialize};
 use tendermint::abci::{Event, EventAttributeIndexExt};
 
+use super::{rate::RateData, validator, EvidenceKind};
+
 pub fn delegate(delegate: &Delegate) -> Event {
     Event::new(
         "delegate",
@@ -20,3 +26,232 @@ pub fn undelegate(undelegate: &Undelegate) -> Event {
         ],
     )
 }
+
+pub fn redelegate(redelegate: &Redelegate) -> Event {
+    Event::new(
+        "redelegate",
+        [
+            (
+                "from_validator",
+                redelegate.from_validator_identity.to_string(),
+            )
+                .index(),
+            ("to_validator", redelegate.to_validator_identity.to_string()).index(),
+            ("amount", redelegate.unbonded_amount.to_string()).no_index(),
+        ],
+    )
+}
+
+/// A change to a validator, recorded so that clients can follow the validator set.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorEvent", into = "pb::ValidatorEvent")]
+pub struct ValidatorEvent {
+    /// The validator the event concerns.
+    pub identity_key: IdentityKey,
+    /// What happened to the validator.
+    pub kind: ValidatorEventKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum ValidatorEventKind {
+    /// The validator moved from one state to another.
+    StateChange {
+        old_state: validator::State,
+        new_state: validator::State,
+    },
+    /// The validator's rate for the next epoch was set.
+    RateChange(RateData),
+    /// The validator was slashed, either for misbehavior of the given kind at
+    /// the given height, or for downtime if there is none.
+    Slash {
+        penalty: Penalty,
+        evidence_kind: Option<EvidenceKind>,
+        evidence_height: Option<u64>,
+    },
+}
+
+impl ValidatorEvent {
+    pub fn state_change(
+        identity_key: IdentityKey,
+        old_state: validator::State,
+        new_state: validator::State,
+    ) -> Self {
+        Self {
+            identity_key,
+            kind: ValidatorEventKind::StateChange {
+                old_state,
+                new_state,
+            },
+        }
+    }
+
+    pub fn rate_change(rate_data: RateData) -> Self {
+        Self {
+            identity_key: rate_data.identity_key,
+            kind: ValidatorEventKind::RateChange(rate_data),
+        }
+    }
+
+    /// A slashing of the validator, for the misbehavior of the given kind at the
+    /// given height, or for downtime if `evidence` is `None`.
+    pub fn slash(
+        identity_key: IdentityKey,
+        penalty: Penalty,
+        evidence: Option<(EvidenceKind, u64)>,
+    ) -> Self {
+        Self {
+            identity_key,
+            kind: ValidatorEventKind::Slash {
+                penalty,
+                evidence_kind: evidence.map(|(kind, _)| kind),
+                evidence_height: evidence.map(|(_, height)| height),
+            },
+        }
+    }
+}
+
+impl From<&ValidatorEvent> for Event {
+    fn from(event: &ValidatorEvent) -> Self {
+        let validator = ("validator", event.identity_key.to_string()).index();
+        match &event.kind {
+            ValidatorEventKind::StateChange {
+                old_state,
+                new_state,
+            } => Event::new(
+                "validator_state_change",
+                vec![
+                    validator,
+                    ("old_state", old_state.to_string()).index(),
+                    ("new_state", new_state.to_string()).index(),
+                ],
+            ),
+            ValidatorEventKind::RateChange(rate_data) => Event::new(
+                "validator_rate_change",
+                vec![
+                    validator,
+                    ("epoch_index", rate_data.epoch_index.to_string()).index(),
+                    (
+                        "validator_reward_rate",
+                        rate_data.validator_reward_rate.to_string(),
+                    )
+                        .no_index(),
+                    (
+                        "validator_exchange_rate",
+                        rate_data.validator_exchange_rate.to_string(),
+                    )
+                        .no_index(),
+                ],
+            ),
+            ValidatorEventKind::Slash {
+                penalty,
+                evidence_kind,
+                evidence_height,
+            } => {
+                let mut attributes = vec![
+                    validator,
+                    (
+                        "kind",
+                        evidence_kind
+                            .map_or("downtime", |kind| kind.as_str())
+                            .to_string(),
+                    )
+                        .index(),
+                ];
+                if let Some(evidence_height) = evidence_height {
+                    attributes.push(("evidence_height", evidence_height.to_string()).no_index());
+                }
+                attributes.push(("penalty", penalty.to_string()).no_index());
+                Event::new("slash", attributes)
+            }
+        }
+    }
+}
+
+impl DomainType for ValidatorEvent {
+    type Proto = pb::ValidatorEvent;
+}
+
+impl From<ValidatorEvent> for pb::ValidatorEvent {
+    fn from(event: ValidatorEvent) -> Self {
+        let inner = match event.kind {
+            ValidatorEventKind::StateChange {
+                old_state,
+                new_state,
+            } => pb::validator_event::Event::StateChange(pb::validator_event::StateChange {
+                old_state: Some(old_state.into()),
+                new_state: Some(new_state.into()),
+            }),
+            ValidatorEventKind::RateChange(rate_data) => {
+                pb::validator_event::Event::RateChange(rate_data.into())
+            }
+            ValidatorEventKind::Slash {
+                penalty,
+                evidence_kind,
+                evidence_height,
+            } => pb::validator_event::Event::Slash(pb::validator_event::Slash {
+                penalty: Some(penalty.into()),
+                evidence_kind: evidence_kind
+                    .map_or(pb::evidence_record::EvidenceKind::Unspecified, Into::into)
+                    as i32,
+                evidence_height: evidence_height.unwrap_or_default(),
+            }),
+        };
+        pb::ValidatorEvent {
+            identity_key: Some(event.identity_key.into()),
+            event: Some(inner),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorEvent> for ValidatorEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ValidatorEvent) -> Result<Self, Self::Error> {
+        let kind = match msg
+            .event
+            .ok_or_else(|| anyhow!("missing validator event"))?
+        {
+            pb::validator_event::Event::StateChange(pb::validator_event::StateChange {
+                old_state,
+                new_state,
+            }) => ValidatorEventKind::StateChange {
+                old_state: old_state
+                    .ok_or_else(|| anyhow!("missing old state in validator event"))?
+                    .try_into()?,
+                new_state: new_state
+                    .ok_or_else(|| anyhow!("missing new state in validator event"))?
+                    .try_into()?,
+            },
+            pb::validator_event::Event::RateChange(rate_data) => {
+                ValidatorEventKind::RateChange(rate_data.try_into()?)
+            }
+            pb::validator_event::Event::Slash(pb::validator_event::Slash {
+                penalty,
+                evidence_kind,
+                evidence_height,
+            }) => {
+                let evidence_kind = match pb::evidence_record::EvidenceKind::from_i32(evidence_kind)
+                    .ok_or_else(|| anyhow!("invalid evidence kind"))?
+                {
+                    pb::evidence_record::EvidenceKind::Unspecified => None,
+                    kind => Some(kind.try_into()?),
+                };
+                ValidatorEventKind::Slash {
+                    penalty: penalty
+                        .ok_or_else(|| anyhow!("missing penalty in validator event"))?
+                        .try_into()?,
+                    // Downtime slashing has no evidence, and so no evidence height.
+                    evidence_height: evidence_kind.map(|_| evidence_height),
+                    evidence_kind,
+                }
+            }
+        };
+        Ok(Self {
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow!("missing identity key in validator event"))?
+                .try_into()?,
+            kind,
+        })
+    }
+}
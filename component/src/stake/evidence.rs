@@ -0,0 +1,122 @@
+use anyhow::anyhow;
+use penumbra_crypto::stake::{IdentityKey, Penalty};
+use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+use tendermint::abci::types::EvidenceKind as TendermintEvidenceKind;
+
+/// The kinds of validator misbehavior reported by Tendermint that we slash for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvidenceKind {
+    /// The validator signed conflicting votes at the same height and round.
+    DuplicateVote,
+    /// The validator took part in an attack on a light client, by signing a
+    /// conflicting header.
+    LightClientAttack,
+}
+
+impl EvidenceKind {
+    /// Classifies Tendermint's evidence kind, returning `None` for kinds we don't
+    /// know how to handle.
+    pub fn from_tendermint(kind: TendermintEvidenceKind) -> Option<Self> {
+        match kind {
+            TendermintEvidenceKind::DuplicateVote => Some(EvidenceKind::DuplicateVote),
+            TendermintEvidenceKind::LightClientAttack => Some(EvidenceKind::LightClientAttack),
+            TendermintEvidenceKind::Unknown => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvidenceKind::DuplicateVote => "duplicate_vote",
+            EvidenceKind::LightClientAttack => "light_client_attack",
+        }
+    }
+}
+
+impl From<EvidenceKind> for pb::evidence_record::EvidenceKind {
+    fn from(kind: EvidenceKind) -> Self {
+        match kind {
+            EvidenceKind::DuplicateVote => pb::evidence_record::EvidenceKind::DuplicateVote,
+            EvidenceKind::LightClientAttack => pb::evidence_record::EvidenceKind::LightClientAttack,
+        }
+    }
+}
+
+impl TryFrom<pb::evidence_record::EvidenceKind> for EvidenceKind {
+    type Error = anyhow::Error;
+
+    fn try_from(kind: pb::evidence_record::EvidenceKind) -> Result<Self, Self::Error> {
+        match kind {
+            pb::evidence_record::EvidenceKind::DuplicateVote => Ok(EvidenceKind::DuplicateVote),
+            pb::evidence_record::EvidenceKind::LightClientAttack => {
+                Ok(EvidenceKind::LightClientAttack)
+            }
+            pb::evidence_record::EvidenceKind::Unspecified => {
+                Err(anyhow!("unspecified evidence kind"))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for EvidenceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A record of evidence of validator misbehavior processed by the chain, and
+/// the slashing penalty it caused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::EvidenceRecord", into = "pb::EvidenceRecord")]
+pub struct EvidenceRecord {
+    /// The validator that misbehaved.
+    pub identity_key: IdentityKey,
+    /// The kind of misbehavior.
+    pub kind: EvidenceKind,
+    /// The height at which the misbehavior occurred.
+    pub evidence_height: u64,
+    /// The height at which the evidence was processed.
+    pub processed_height: u64,
+    /// The slashing penalty applied to the validator.
+    pub penalty: Penalty,
+}
+
+impl DomainType for EvidenceRecord {
+    type Proto = pb::EvidenceRecord;
+}
+
+impl From<EvidenceRecord> for pb::EvidenceRecord {
+    fn from(record: EvidenceRecord) -> Self {
+        pb::EvidenceRecord {
+            identity_key: Some(record.identity_key.into()),
+            kind: pb::evidence_record::EvidenceKind::from(record.kind) as i32,
+            evidence_height: record.evidence_height,
+            processed_height: record.processed_height,
+            penalty: Some(record.penalty.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::EvidenceRecord> for EvidenceRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::EvidenceRecord) -> Result<Self, Self::Error> {
+        let kind = pb::evidence_record::EvidenceKind::from_i32(msg.kind)
+            .ok_or_else(|| anyhow!("invalid evidence kind"))?
+            .try_into()?;
+
+        Ok(EvidenceRecord {
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow!("missing identity key in evidence record"))?
+                .try_into()?,
+            kind,
+            evidence_height: msg.evidence_height,
+            processed_height: msg.processed_height,
+            penalty: msg
+                .penalty
+                .ok_or_else(|| anyhow!("missing penalty in evidence record"))?
+                .try_into()?,
+        })
+    }
+}
@@ -5,6 +5,7 @@ use penumbra_crypto::stake::IdentityKey;
 mod changes;
 mod current_consensus_keys;
 mod event;
+mod evidence;
 mod funding_stream;
 mod metrics;
 mod uptime;
@@ -18,5 +19,7 @@ pub use self::metrics::register_metrics;
 pub use changes::DelegationChanges;
 pub use component::StateReadExt;
 pub use current_consensus_keys::CurrentConsensusKeys;
+pub use event::{ValidatorEvent, ValidatorEventKind};
+pub use evidence::{EvidenceKind, EvidenceRecord};
 pub use funding_stream::{FundingStream, FundingStreams};
 pub use uptime::Uptime;
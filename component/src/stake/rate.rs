@@ -4,7 +4,7 @@ use penumbra_crypto::{stake::Penalty, Amount};
 use penumbra_proto::{
     client::v1alpha1::NextValidatorRateResponse, core::stake::v1alpha1 as pb, DomainType,
 };
-use penumbra_transaction::action::{Delegate, Undelegate};
+use penumbra_transaction::action::{Delegate, Redelegate, Undelegate};
 use serde::{Deserialize, Serialize};
 
 use crate::stake::{validator::State, FundingStream, IdentityKey};
@@ -166,6 +166,27 @@ impl RateData {
             validator_identity: self.identity_key.clone(),
         }
     }
+
+    /// Uses this `RateData` and the destination validator's `to_rate_data` to
+    /// build a `Redelegate` transaction action that moves `delegation_amount`
+    /// of this validator's delegation tokens to the destination validator.
+    pub fn build_redelegate(
+        &self,
+        to_rate_data: &RateData,
+        delegation_amount: Amount,
+        end_epoch_index: u64,
+    ) -> Redelegate {
+        let unbonded_amount = self.unbonded_amount(delegation_amount.into());
+        Redelegate {
+            from_validator_identity: self.identity_key.clone(),
+            to_validator_identity: to_rate_data.identity_key.clone(),
+            epoch_index: self.epoch_index,
+            end_epoch_index,
+            from_delegation_amount: delegation_amount,
+            unbonded_amount: unbonded_amount.into(),
+            to_delegation_amount: to_rate_data.delegation_amount(unbonded_amount).into(),
+        }
+    }
 }
 
 /// Describes the base reward and exchange rates in some epoch.
@@ -45,6 +45,20 @@ pub fn next_rate_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_rate/next/{id}")
 }
 
+/// The validator's rate data in the given epoch.
+///
+/// Rate history is kept in nonconsensus storage, since it's only used to
+/// answer client queries.
+pub fn rate_history_by_validator(id: &IdentityKey, epoch: u64) -> String {
+    // Load-bearing format string: we need to pad with 0s to ensure that
+    // the lex order agrees with the numeric order on epochs.
+    format!("staking/validator_rate/history/{id}/{epoch:010}")
+}
+
+pub fn rate_history_prefix(id: &IdentityKey) -> String {
+    format!("staking/validator_rate/history/{id}/")
+}
+
 pub fn power_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_power/{id}")
 }
@@ -53,6 +67,17 @@ pub fn bonding_state_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_bonding_state/{id}")
 }
 
+pub fn jailed_at_height_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_jailed_at_height/{id}")
+}
+
+/// The validator's total commission at the start of the given epoch, recorded
+/// the first time it redefines itself during the epoch, and deleted when the
+/// epoch ends.
+pub fn commission_at_epoch_start(id: &IdentityKey, epoch: u64) -> String {
+    format!("staking/validator_commission/{id}/{epoch:010}")
+}
+
 pub fn uptime_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_uptime/{id}")
 }
@@ -61,6 +86,29 @@ pub fn slashed_validators(height: u64) -> String {
     format!("staking/slashed_validators/{height}")
 }
 
+/// The record of evidence of the given kind against `id`, for misbehavior at `height`.
+pub fn evidence(id: &IdentityKey, height: u64, kind: &str) -> String {
+    // Load-bearing format string: we need to pad with 0s to ensure that
+    // the lex order agrees with the numeric order on heights.
+    format!("staking/evidence/{id}/{height:020}/{kind}")
+}
+
+pub fn evidence_by_validator(id: &IdentityKey) -> String {
+    format!("staking/evidence/{id}/")
+}
+
+pub fn all_evidence() -> &'static str {
+    "staking/evidence/"
+}
+
+/// The consensus key Tendermint knows the validator by.
+///
+/// This lags behind the consensus key in the validator definition: rotations
+/// take effect at the next epoch boundary.
+pub fn consensus_key_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_consensus_key/{id}")
+}
+
 pub fn validator_id_by_consensus_key(pk: &PublicKey) -> String {
     format!("staking/validator_id_by_consensus_key/{}", pk.to_hex())
 }
@@ -80,7 +128,20 @@ pub fn current_consensus_keys() -> &'static str {
     "staking/current_consensus_keys"
 }
 
+pub fn validator_event(block_height: u64, index: u64) -> String {
+    format!("staking/validator_events/{block_height:020}/{index:020}")
+}
+
+pub fn validator_events_at_height(block_height: u64) -> String {
+    // Note: this has to be the prefix of the `validator_event` function above.
+    format!("staking/validator_events/{block_height:020}/")
+}
+
 pub(super) mod internal {
+    pub fn pending_validator_events() -> &'static str {
+        "staking/stub/pending_validator_events"
+    }
+
     pub fn stub_delegation_changes() -> &'static str {
         "staking/delegation_changes"
     }
@@ -64,7 +64,12 @@ impl AllocVar<Amount, Fq> for AmountVar {
         let amount: Amount = *f()?.borrow();
         match mode {
             AllocationMode::Constant => unimplemented!(),
-            AllocationMode::Input => unimplemented!(),
+            AllocationMode::Input => {
+                let inner_amount_var = FqVar::new_input(cs, || Ok(Fq::from(amount)))?;
+                Ok(Self {
+                    amount: inner_amount_var,
+                })
+            }
             AllocationMode::Witness => {
                 let inner_amount_var = FqVar::new_witness(cs, || Ok(Fq::from(amount)))?;
                 Ok(Self {
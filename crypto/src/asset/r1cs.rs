@@ -22,8 +22,18 @@ impl AllocVar<crate::asset::Id, Fq> for AssetIdVar {
         let cs = ns.cs();
         let asset_id: crate::asset::Id = *f()?.borrow();
         match mode {
-            AllocationMode::Constant => unimplemented!(),
-            AllocationMode::Input => unimplemented!(),
+            AllocationMode::Constant => {
+                let inner_asset_id_var = FqVar::new_constant(cs, asset_id.0)?;
+                Ok(Self {
+                    asset_id: inner_asset_id_var,
+                })
+            }
+            AllocationMode::Input => {
+                let inner_asset_id_var = FqVar::new_input(cs, || Ok(asset_id.0))?;
+                Ok(Self {
+                    asset_id: inner_asset_id_var,
+                })
+            }
             AllocationMode::Witness => {
                 let inner_asset_id_var = FqVar::new_witness(cs, || Ok(asset_id.0))?;
                 Ok(Self {
@@ -1,7 +1,7 @@
 pub mod execution;
 pub mod lp;
 pub mod swap;
-pub use swap::BatchSwapOutputData;
+pub use swap::{BatchSwapOutputData, BatchSwapOutputDataVar};
 
 mod trading_pair;
 pub use trading_pair::{DirectedTradingPair, TradingPair};
@@ -1,7 +1,9 @@
 use anyhow::{anyhow, Result};
-use ark_ff::PrimeField;
+use ark_ff::{PrimeField, ToConstraintField};
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::SynthesisError;
 
-use decaf377::Fq;
+use decaf377::{r1cs::FqVar, Fq};
 use once_cell::sync::Lazy;
 use penumbra_proto::{
     client::v1alpha1::BatchSwapOutputDataResponse, core::dex::v1alpha1 as pb, DomainType,
@@ -17,6 +19,8 @@ pub use ciphertext::SwapCiphertext;
 pub use payload::SwapPayload;
 pub use plaintext::{SwapPlaintext, SwapPlaintextVar};
 
+use crate::asset::AssetIdVar;
+
 // Swap ciphertext byte length.
 pub const SWAP_CIPHERTEXT_BYTES: usize = 272;
 // Swap plaintext byte length.
@@ -66,6 +70,64 @@ impl BatchSwapOutputData {
     }
 }
 
+impl ToConstraintField<Fq> for BatchSwapOutputData {
+    fn to_field_elements(&self) -> Option<Vec<Fq>> {
+        // The order here must match the order in which `BatchSwapOutputDataVar`
+        // allocates its variables.
+        Some(vec![
+            Fq::from(self.delta_1),
+            Fq::from(self.delta_2),
+            Fq::from(self.lambda_1),
+            Fq::from(self.lambda_2),
+            Fq::from(self.height),
+            self.trading_pair.asset_1().0,
+            self.trading_pair.asset_2().0,
+            Fq::from(self.success as u64),
+        ])
+    }
+}
+
+pub struct BatchSwapOutputDataVar {
+    pub delta_1: FqVar,
+    pub delta_2: FqVar,
+    pub lambda_1: FqVar,
+    pub lambda_2: FqVar,
+    pub height: FqVar,
+    pub trading_pair_asset_1: AssetIdVar,
+    pub trading_pair_asset_2: AssetIdVar,
+    pub success: Boolean<Fq>,
+}
+
+impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
+    fn new_variable<T: std::borrow::Borrow<BatchSwapOutputData>>(
+        cs: impl Into<ark_relations::r1cs::Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: ark_r1cs_std::prelude::AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let output_data: BatchSwapOutputData = *f()?.borrow();
+        match mode {
+            AllocationMode::Constant => unimplemented!(),
+            AllocationMode::Input => Ok(Self {
+                delta_1: FqVar::new_input(cs.clone(), || Ok(Fq::from(output_data.delta_1)))?,
+                delta_2: FqVar::new_input(cs.clone(), || Ok(Fq::from(output_data.delta_2)))?,
+                lambda_1: FqVar::new_input(cs.clone(), || Ok(Fq::from(output_data.lambda_1)))?,
+                lambda_2: FqVar::new_input(cs.clone(), || Ok(Fq::from(output_data.lambda_2)))?,
+                height: FqVar::new_input(cs.clone(), || Ok(Fq::from(output_data.height)))?,
+                trading_pair_asset_1: AssetIdVar::new_input(cs.clone(), || {
+                    Ok(output_data.trading_pair.asset_1())
+                })?,
+                trading_pair_asset_2: AssetIdVar::new_input(cs.clone(), || {
+                    Ok(output_data.trading_pair.asset_2())
+                })?,
+                success: Boolean::new_input(cs, || Ok(output_data.success))?,
+            }),
+            AllocationMode::Witness => unimplemented!(),
+        }
+    }
+}
+
 impl DomainType for BatchSwapOutputData {
     type Proto = pb::BatchSwapOutputData;
 }
@@ -1,13 +1,25 @@
+mod batch;
+mod delegator_vote;
 mod gadgets;
 mod output;
+mod phase1;
+mod phase2;
 mod spend;
 mod swap;
+mod swap_claim;
 mod traits;
+mod undelegate_claim;
 
+pub use batch::BatchVerifier;
+pub use delegator_vote::{DelegatorVoteCircuit, DelegatorVoteProof};
 pub use output::{OutputCircuit, OutputProof};
+pub use phase1::Phase1Parameters;
+pub use phase2::{Contribution, Phase2Parameters};
 pub use spend::{SpendCircuit, SpendProof};
 pub use swap::{SwapCircuit, SwapProof};
+pub use swap_claim::{SwapClaimCircuit, SwapClaimProof};
 pub use traits::{ParameterSetup, VerifyingKeyExt};
+pub use undelegate_claim::{UndelegateClaimCircuit, UndelegateClaimProof};
 
 /// The length of our Groth16 proofs in bytes.
 pub const GROTH16_PROOF_LENGTH_BYTES: usize = 192;
@@ -19,19 +31,20 @@ mod tests {
     use super::*;
     use crate::{
         asset,
-        dex::{swap::SwapPlaintext, TradingPair},
+        dex::{swap::SwapPlaintext, BatchSwapOutputData, TradingPair},
         keys::{SeedPhrase, SpendKey},
+        stake::{IdentityKey, Penalty, UnbondingToken},
         transaction::Fee,
         Address, Amount, Balance, Rseed,
     };
-    use ark_groth16::{Groth16, ProvingKey, VerifyingKey};
+    use ark_groth16::Groth16;
     use ark_r1cs_std::prelude::*;
     use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
     use ark_snark::SNARK;
     use decaf377::{r1cs::FqVar, Bls12_377, Fq, Fr};
     use proptest::prelude::*;
 
-    use decaf377_rdsa::{SpendAuth, VerificationKey};
+    use decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey};
     use penumbra_proto::core::crypto::v1alpha1 as pb;
     use penumbra_tct as tct;
     use rand_core::OsRng;
@@ -39,7 +52,7 @@ mod tests {
 
     use crate::{note, Note, Value};
 
-    use ark_ff::PrimeField;
+    use ark_ff::{One, PrimeField, UniformRand, Zero};
 
     fn fq_strategy() -> BoxedStrategy<Fq> {
         any::<[u8; 32]>()
@@ -115,6 +128,272 @@ mod tests {
         }
     }
 
+    proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    fn swap_claim_proof_happy_path(seed_phrase_randomness in any::<[u8; 32]>(), value1_amount in 2..200u64, lambda_2 in 1..2000u64) {
+        let (pk, vk) = SwapClaimCircuit::generate_prepared_test_parameters();
+
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_recipient = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_recipient = sk_recipient.full_viewing_key();
+        let ivk_recipient = fvk_recipient.incoming();
+        let (claim_address, _dtk_d) = ivk_recipient.payment_address(0u32.into());
+        let nk = *sk_recipient.nullifier_key();
+
+        let gm = asset::REGISTRY.parse_unit("gm");
+        let gn = asset::REGISTRY.parse_unit("gn");
+        let trading_pair = TradingPair::new(gm.id(), gn.id());
+
+        let delta_1_i = Amount::from(value1_amount);
+        let delta_2_i = Amount::from(0u64);
+        let fee = Fee::default();
+
+        let swap_plaintext =
+        SwapPlaintext::new(&mut rng, trading_pair, delta_1_i, delta_2_i, fee, claim_address);
+        let swap_commitment = swap_plaintext.swap_commitment();
+
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, swap_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(swap_commitment).unwrap();
+        let position = state_commitment_proof.position();
+        let nullifier = fvk_recipient.derive_nullifier(position, &swap_commitment);
+
+        // This swap is the only one in the batch, so it receives the whole output.
+        let output_data = BatchSwapOutputData {
+            delta_1: value1_amount,
+            delta_2: 0,
+            lambda_1: 0,
+            lambda_2,
+            height: 0,
+            trading_pair: swap_plaintext.trading_pair,
+            success: true,
+        };
+        let (output_1_note, output_2_note) = swap_plaintext.output_notes(&output_data);
+        let note_commitment_1 = output_1_note.commit();
+        let note_commitment_2 = output_2_note.commit();
+
+        let proof = SwapClaimProof::prove(
+            &mut rng,
+            &pk,
+            swap_plaintext.clone(),
+            state_commitment_proof,
+            nk,
+            output_1_note.note_blinding(),
+            output_2_note.note_blinding(),
+            anchor,
+            nullifier,
+            swap_plaintext.claim_fee.clone(),
+            output_data,
+            20,
+            note_commitment_1,
+            note_commitment_2,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(
+            &vk,
+            anchor,
+            nullifier,
+            swap_plaintext.claim_fee,
+            output_data,
+            20,
+            note_commitment_1,
+            note_commitment_2,
+        );
+
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `DelegatorVoteProof` verification succeeds.
+    fn delegator_vote_proof_verification_success(seed_phrase_randomness in any::<[u8; 32]>(), spend_auth_randomizer in fr_strategy(), value_amount in 2..2000000000u64, num_commitments in 1..2000u64) {
+        let (pk, vk) = DelegatorVoteCircuit::generate_prepared_test_parameters();
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (sender, _dtk_d) = ivk_sender.payment_address(0u32.into());
+
+        let value_to_send = Value {
+            amount: value_amount.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        };
+
+        let note = Note::generate(&mut rng, &sender, value_to_send);
+        let note_commitment = note.commit();
+        let rsk = sk_sender.spend_auth_key().randomize(&spend_auth_randomizer);
+        let nk = *sk_sender.nullifier_key();
+        let ak: VerificationKey<SpendAuth> = sk_sender.spend_auth_key().into();
+        let mut sct = tct::Tree::new();
+
+        for _ in 0..num_commitments {
+            let random_note_commitment = Note::generate(&mut rng, &sender, value_to_send).commit();
+            sct.insert(tct::Witness::Keep, random_note_commitment).unwrap();
+        }
+
+        sct.insert(tct::Witness::Keep, note_commitment).unwrap();
+        sct.end_block().unwrap();
+        let start_position = sct.position().unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(note_commitment).unwrap();
+        let balance_commitment = value_to_send.commit(Fr::from(0u64));
+        let rk: VerificationKey<SpendAuth> = rsk.into();
+        let nf = nk.derive_nullifier(state_commitment_proof.position(), &note_commitment);
+
+        let proof = DelegatorVoteProof::prove(
+            &mut rng,
+            &pk,
+            state_commitment_proof,
+            note,
+            spend_auth_randomizer,
+            ak,
+            nk,
+            anchor,
+            balance_commitment,
+            nf,
+            rk,
+            start_position,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, anchor, balance_commitment, nf, rk, start_position);
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `DelegatorVoteProof` verification fails when checked against a
+    /// different start position than the one it was proven for.
+    fn delegator_vote_proof_verification_start_position_failure(seed_phrase_randomness in any::<[u8; 32]>(), spend_auth_randomizer in fr_strategy(), value_amount in 2..200u64) {
+        let (pk, vk) = DelegatorVoteCircuit::generate_prepared_test_parameters();
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (sender, _dtk_d) = ivk_sender.payment_address(0u32.into());
+
+        let value_to_send = Value {
+            amount: value_amount.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        };
+
+        let note = Note::generate(&mut rng, &sender, value_to_send);
+        let note_commitment = note.commit();
+        let rsk = sk_sender.spend_auth_key().randomize(&spend_auth_randomizer);
+        let nk = *sk_sender.nullifier_key();
+        let ak: VerificationKey<SpendAuth> = sk_sender.spend_auth_key().into();
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, note_commitment).unwrap();
+        sct.end_block().unwrap();
+        let start_position = sct.position().unwrap();
+        sct.end_block().unwrap();
+        let incorrect_start_position = sct.position().unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(note_commitment).unwrap();
+        let balance_commitment = value_to_send.commit(Fr::from(0u64));
+        let rk: VerificationKey<SpendAuth> = rsk.into();
+        let nf = nk.derive_nullifier(state_commitment_proof.position(), &note_commitment);
+
+        let proof = DelegatorVoteProof::prove(
+            &mut rng,
+            &pk,
+            state_commitment_proof,
+            note,
+            spend_auth_randomizer,
+            ak,
+            nk,
+            anchor,
+            balance_commitment,
+            nf,
+            rk,
+            start_position,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, anchor, balance_commitment, nf, rk, incorrect_start_position);
+        assert!(proof_result.is_err());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `UndelegateClaimProof` verification succeeds.
+    fn undelegate_claim_proof_happy_path(unbonding_amount in 0..2000000000u64, balance_blinding in fr_strategy(), penalty in 0..100000000u64) {
+        let (pk, vk) = UndelegateClaimCircuit::generate_prepared_test_parameters();
+        let mut rng = OsRng;
+
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(rng).into());
+        let unbonding_id = UnbondingToken::new(identity_key, 0, 1).id();
+        let penalty = Penalty(penalty);
+        let unbonding_amount = Amount::from(unbonding_amount);
+        let balance_commitment = penalty
+            .balance_for_claim(unbonding_id, unbonding_amount)
+            .commit(balance_blinding);
+
+        let proof = UndelegateClaimProof::prove(
+            &mut rng,
+            &pk,
+            unbonding_amount,
+            balance_blinding,
+            balance_commitment,
+            unbonding_id,
+            penalty,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, balance_commitment, unbonding_id, penalty);
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `UndelegateClaimProof` verification fails when checked against a
+    /// different penalty than the one it was proven for.
+    fn undelegate_claim_proof_verification_penalty_failure(unbonding_amount in 1..2000000000u64, balance_blinding in fr_strategy(), penalty in 0..50000000u64) {
+        let (pk, vk) = UndelegateClaimCircuit::generate_prepared_test_parameters();
+        let mut rng = OsRng;
+
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(rng).into());
+        let unbonding_id = UnbondingToken::new(identity_key, 0, 1).id();
+        let penalty = Penalty(penalty);
+        let incorrect_penalty = Penalty(penalty.0 + 50000000);
+        let unbonding_amount = Amount::from(unbonding_amount);
+        let balance_commitment = penalty
+            .balance_for_claim(unbonding_id, unbonding_amount)
+            .commit(balance_blinding);
+
+        let proof = UndelegateClaimProof::prove(
+            &mut rng,
+            &pk,
+            unbonding_amount,
+            balance_blinding,
+            balance_commitment,
+            unbonding_id,
+            penalty,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, balance_commitment, unbonding_id, incorrect_penalty);
+        assert!(proof_result.is_err());
+    }
+    }
+
     proptest! {
     #![proptest_config(ProptestConfig::with_cases(2))]
     #[test]
@@ -156,6 +435,61 @@ mod tests {
         }
     }
 
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+        #[test]
+        fn output_proof_batch_verification(seed_phrase_randomness in any::<[u8; 32]>(), v_blinding in fr_strategy(), value_amount in 2..200u64) {
+            let (pk, vk) = OutputCircuit::generate_prepared_test_parameters();
+
+            let mut rng = OsRng;
+
+            let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+            let sk_recipient = SpendKey::from_seed_phrase(seed_phrase, 0);
+            let fvk_recipient = sk_recipient.full_viewing_key();
+            let ivk_recipient = fvk_recipient.incoming();
+            let (dest, _dtk_d) = ivk_recipient.payment_address(0u32.into());
+
+            let value_to_send = Value {
+                amount: value_amount.into(),
+                asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+            };
+
+            let mut outputs = Vec::new();
+            for _ in 0..3 {
+                let note = Note::generate(&mut rng, &dest, value_to_send);
+                let note_commitment = note.commit();
+                let balance_commitment = (-Balance::from(value_to_send)).commit(v_blinding);
+
+                let proof = OutputProof::prove(
+                    &mut rng,
+                    &pk,
+                    note,
+                    v_blinding,
+                    balance_commitment,
+                    note_commitment,
+                )
+                .expect("can create proof");
+                outputs.push((proof, balance_commitment, note_commitment));
+            }
+
+            let mut batch = BatchVerifier::new(&vk);
+            for (proof, balance_commitment, note_commitment) in &outputs {
+                proof.queue(&mut batch, *balance_commitment, *note_commitment);
+            }
+            assert_eq!(batch.len(), 3);
+            assert!(batch.verify(&mut rng).is_ok());
+
+            // Swapping the note commitments of two outputs should cause the batch to fail, and the
+            // first mismatched proof should be reported.
+            let mut batch = BatchVerifier::new(&vk);
+            outputs[0].0.queue(&mut batch, outputs[0].1, outputs[1].2);
+            outputs[1].0.queue(&mut batch, outputs[1].1, outputs[0].2);
+            outputs[2].0.queue(&mut batch, outputs[2].1, outputs[2].2);
+            let err = batch.verify(&mut rng).expect_err("batch should not verify");
+            assert_eq!(err.to_string(), "proof 0 in batch did not verify");
+        }
+    }
+
     proptest! {
     #![proptest_config(ProptestConfig::with_cases(2))]
     #[test]
@@ -677,7 +1011,7 @@ mod tests {
     }
 
     impl ParameterSetup for MerkleProofCircuit {
-        fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        fn example_circuit() -> Self {
             let seed_phrase = SeedPhrase::from_randomness([b'f'; 32]);
             let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
             let fvk_sender = sk_sender.full_viewing_key();
@@ -695,13 +1029,10 @@ mod tests {
             sct.insert(tct::Witness::Keep, note_commitment).unwrap();
             let anchor = sct.root();
             let state_commitment_proof = sct.witness(note_commitment).unwrap();
-            let circuit = MerkleProofCircuit {
+            MerkleProofCircuit {
                 state_commitment_proof,
                 anchor,
-            };
-            let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
-                .expect("can perform circuit specific setup");
-            (pk, vk)
+            }
         }
     }
 
@@ -789,4 +1120,131 @@ mod tests {
             assert!(proof_result.is_ok());
         }
     }
+
+    #[test]
+    fn phase2_contributions_produce_working_parameters() {
+        let mut rng = OsRng;
+        let (pk, _vk) = OutputCircuit::generate_test_parameters();
+        let initial = Phase2Parameters::new(pk).expect("can start ceremony");
+
+        let mut first = initial.clone();
+        let first_hash = first.contribute(&mut rng).expect("can contribute");
+        assert_eq!(
+            first.verify(&initial).expect("can verify"),
+            vec![first_hash]
+        );
+
+        let mut second = first.clone();
+        let second_hash = second.contribute(&mut rng).expect("can contribute");
+        assert_eq!(
+            second.verify(&first).expect("can verify"),
+            vec![second_hash]
+        );
+
+        // The whole transcript verifies after a round trip through serialization.
+        let mut bytes = Vec::new();
+        second.write(&mut bytes).expect("can serialize parameters");
+        let second = Phase2Parameters::read(&bytes[..]).expect("can deserialize parameters");
+        assert_eq!(
+            second.verify(&initial).expect("can verify"),
+            vec![first_hash, second_hash]
+        );
+
+        // Parameters can't be verified against later parameters, or another ceremony.
+        assert!(first.verify(&second).is_err());
+        let (other_pk, _other_vk) = OutputCircuit::generate_test_parameters();
+        let other = Phase2Parameters::new(other_pk).expect("can start ceremony");
+        assert!(second.verify(&other).is_err());
+
+        // Proofs made with the resulting parameters verify.
+        let seed_phrase = SeedPhrase::from_randomness([1u8; 32]);
+        let sk_recipient = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let (dest, _dtk_d) = sk_recipient
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+        let value_to_send = Value {
+            amount: 10u64.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        };
+        let note = Note::generate(&mut rng, &dest, value_to_send);
+        let note_commitment = note.commit();
+        let v_blinding = Fr::rand(&mut rng);
+        let balance_commitment = (-Balance::from(value_to_send)).commit(v_blinding);
+
+        let proof = OutputProof::prove(
+            &mut rng,
+            second.proving_key(),
+            note,
+            v_blinding,
+            balance_commitment,
+            note_commitment,
+        )
+        .expect("can create proof");
+        let vk = second.verifying_key().clone().into();
+        assert!(proof
+            .verify(&vk, balance_commitment, note_commitment)
+            .is_ok());
+    }
+
+    /// Knowledge of `x` such that `x^3 + x + 5 = y`.
+    struct CubeCircuit {
+        x: Fq,
+        y: Fq,
+    }
+
+    impl ConstraintSynthesizer<Fq> for CubeCircuit {
+        fn generate_constraints(
+            self,
+            cs: ConstraintSystemRef<Fq>,
+        ) -> ark_relations::r1cs::Result<()> {
+            let x = FqVar::new_witness(cs.clone(), || Ok(self.x))?;
+            let y = FqVar::new_input(cs, || Ok(self.y))?;
+            let cube = &x * &x * &x;
+            (cube + &x + FqVar::constant(Fq::from(5u64))).enforce_equal(&y)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn phase1_derived_parameters_produce_working_proofs() {
+        let mut rng = OsRng;
+        let phase1 = Phase1Parameters::insecure_for_testing(&mut rng, 8);
+        phase1.verify().expect("powers of tau are consistent");
+
+        // The powers survive a round trip through serialization.
+        let mut bytes = Vec::new();
+        phase1
+            .write(&mut bytes)
+            .expect("can serialize powers of tau");
+        let phase1 = Phase1Parameters::read(&bytes[..]).expect("can deserialize powers of tau");
+        phase1.verify().expect("powers of tau are consistent");
+
+        let example = || CubeCircuit {
+            x: Fq::zero(),
+            y: Fq::from(5u64),
+        };
+        let pk = phase1
+            .circuit_parameters(example())
+            .expect("can derive circuit parameters");
+        let initial = Phase2Parameters::new(pk).expect("can start ceremony");
+        let mut params = initial.clone();
+        let hash = params.contribute(&mut rng).expect("can contribute");
+        assert_eq!(params.verify(&initial).expect("can verify"), vec![hash]);
+
+        let x = Fq::rand(&mut rng);
+        let y = x * x * x + x + Fq::from(5u64);
+        let proof = Groth16::prove(params.proving_key(), CubeCircuit { x, y }, &mut rng)
+            .expect("can create proof");
+        let pvk = params.verifying_key().clone().into();
+        assert!(Groth16::verify_with_processed_vk(&pvk, &[y], &proof).expect("can verify proof"));
+        assert!(
+            !Groth16::verify_with_processed_vk(&pvk, &[y + Fq::one()], &proof)
+                .expect("can verify proof")
+        );
+
+        // Powers of too low a degree can't be used for the circuit.
+        let too_small = Phase1Parameters::insecure_for_testing(&mut rng, 2);
+        assert!(too_small.circuit_parameters(example()).is_err());
+    }
 }
@@ -0,0 +1,111 @@
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_groth16::{prepare_inputs, Groth16, PreparedVerifyingKey, Proof};
+use ark_snark::SNARK;
+use decaf377::{Bls12_377, Fq};
+use rand::{CryptoRng, Rng};
+
+use super::VerifyingKeyExt;
+
+type G1Projective = <Bls12_377 as PairingEngine>::G1Projective;
+
+/// Verifies a batch of Groth16 proofs for the same circuit at once.
+///
+/// Each proof is weighted by a random scalar, so the whole batch can be checked with a single
+/// multi-pairing rather than with a separate pairing check per proof. If the batch fails to
+/// verify, each proof is checked individually to identify the one that failed.
+pub struct BatchVerifier<'a> {
+    vk: &'a PreparedVerifyingKey<Bls12_377>,
+    items: Vec<(Proof<Bls12_377>, Vec<Fq>)>,
+}
+
+impl<'a> BatchVerifier<'a> {
+    /// Create an empty batch of proofs to verify against `vk`.
+    pub fn new(vk: &'a PreparedVerifyingKey<Bls12_377>) -> Self {
+        Self {
+            vk,
+            items: Vec::new(),
+        }
+    }
+
+    /// Add a proof and its public inputs to the batch.
+    pub(super) fn queue(&mut self, proof: Proof<Bls12_377>, public_inputs: Vec<Fq>) {
+        self.items.push((proof, public_inputs));
+    }
+
+    /// The number of proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verify every proof in the batch.
+    ///
+    /// On failure, the error names the position (in queue order) of the first proof that
+    /// doesn't verify.
+    #[tracing::instrument(level = "debug", skip(self, rng), fields(len = self.items.len(), vk = ?self.vk.debug_id()))]
+    pub fn verify<R: CryptoRng + Rng>(self, rng: &mut R) -> anyhow::Result<()> {
+        if self.items.is_empty() {
+            return Ok(());
+        }
+
+        let start = std::time::Instant::now();
+        let batch_result = self.verify_batch(rng)?;
+        tracing::debug!(?batch_result, elapsed = ?start.elapsed());
+        if batch_result {
+            return Ok(());
+        }
+
+        // The batch failed, so fall back to checking each proof to find the culprit.
+        for (index, (proof, public_inputs)) in self.items.iter().enumerate() {
+            let proof_result = Groth16::verify_with_processed_vk(self.vk, public_inputs, proof)
+                .map_err(|err| anyhow::anyhow!(err))?;
+            if !proof_result {
+                anyhow::bail!("proof {} in batch did not verify", index);
+            }
+        }
+
+        // Every proof verified on its own, so the batch can only have failed because of an
+        // unlucky choice of randomizers, which happens with negligible probability.
+        Err(anyhow::anyhow!("proof batch did not verify"))
+    }
+
+    /// Check the randomized combination of all of the proofs' verification equations.
+    ///
+    /// For randomizers `r_i`, this checks that
+    /// `prod_i e(r_i A_i, B_i) * e(sum_i r_i PI_i, -gamma) * e(sum_i r_i C_i, -delta)` equals
+    /// `e(alpha, beta)^(sum_i r_i)`, where `PI_i` is the prepared public input of the `i`th proof.
+    fn verify_batch<R: CryptoRng + Rng>(&self, rng: &mut R) -> anyhow::Result<bool> {
+        let mut pairs = Vec::with_capacity(self.items.len() + 2);
+        let mut randomizer_sum = Fq::zero();
+        let mut prepared_inputs = G1Projective::zero();
+        let mut c = G1Projective::zero();
+
+        for (proof, public_inputs) in &self.items {
+            // 128-bit randomizers are enough for the batch to be sound.
+            let randomizer = Fq::from(rng.gen::<u128>());
+            randomizer_sum += randomizer;
+
+            let a = proof.a.mul(randomizer.into_repr()).into_affine();
+            pairs.push((a.into(), proof.b.into()));
+
+            let inputs =
+                prepare_inputs(self.vk, public_inputs).map_err(|err| anyhow::anyhow!(err))?;
+            prepared_inputs += inputs.mul(randomizer.into_repr());
+            c += proof.c.mul(randomizer.into_repr());
+        }
+
+        pairs.push((
+            prepared_inputs.into_affine().into(),
+            self.vk.gamma_g2_neg_pc.clone(),
+        ));
+        pairs.push((c.into_affine().into(), self.vk.delta_g2_neg_pc.clone()));
+
+        let lhs = Bls12_377::product_of_pairings(&pairs);
+        let rhs = self.vk.alpha_g1_beta_g2.pow(randomizer_sum.into_repr());
+        Ok(lhs == rhs)
+    }
+}
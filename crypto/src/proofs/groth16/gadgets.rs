@@ -1,6 +1,11 @@
+use std::cmp::Ordering;
+
 use ark_r1cs_std::prelude::*;
 use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
-use decaf377::{r1cs::ElementVar, Fq};
+use decaf377::{
+    r1cs::{ElementVar, FqVar},
+    FieldExt, Fq,
+};
 
 /// Check the element is not identity.
 pub(crate) fn element_not_identity(
@@ -13,3 +18,56 @@ pub(crate) fn element_not_identity(
     identity.conditional_enforce_not_equal(&element, enforce)?;
     Ok(())
 }
+
+/// Check the field element fits in `num_bits` bits.
+pub(crate) fn enforce_bit_length(value: &FqVar, num_bits: usize) -> Result<(), SynthesisError> {
+    let bits = value.to_bits_le()?;
+    for bit in &bits[num_bits..] {
+        bit.enforce_equal(&Boolean::FALSE)?;
+    }
+    Ok(())
+}
+
+/// Compute `numerator / denominator`, rounded down, or zero if the denominator is zero.
+///
+/// Both the numerator and denominator must fit in 128 bits.
+pub(crate) fn div_floor(
+    cs: ConstraintSystemRef<Fq>,
+    numerator: &FqVar,
+    denominator: &FqVar,
+) -> Result<FqVar, SynthesisError> {
+    // Witness the quotient and remainder, computed out of circuit.
+    let division = || -> Result<(u128, u128), SynthesisError> {
+        let numerator = fq_to_u128(numerator.value()?);
+        let denominator = fq_to_u128(denominator.value()?);
+        Ok((
+            numerator.checked_div(denominator).unwrap_or(0),
+            numerator.checked_rem(denominator).unwrap_or(numerator),
+        ))
+    };
+    let quotient = FqVar::new_witness(cs.clone(), || Ok(Fq::from(division()?.0)))?;
+    let remainder = FqVar::new_witness(cs, || Ok(Fq::from(division()?.1)))?;
+
+    // Bounding the quotient and remainder means `quotient * denominator + remainder`
+    // can't wrap around the field modulus.
+    enforce_bit_length(&quotient, 128)?;
+    enforce_bit_length(&remainder, 128)?;
+    numerator.enforce_equal(&(&quotient * denominator + &remainder))?;
+
+    // The remainder must be less than the denominator, unless the denominator is zero, in which
+    // case the result is zero regardless of the quotient.
+    let denominator_is_zero = denominator.is_zero()?;
+    remainder
+        .is_cmp(denominator, Ordering::Less, false)?
+        .conditional_enforce_equal(&Boolean::TRUE, &denominator_is_zero.not())?;
+
+    FqVar::conditionally_select(&denominator_is_zero, &FqVar::zero(), &quotient)
+}
+
+fn fq_to_u128(value: Fq) -> u128 {
+    u128::from_le_bytes(
+        value.to_bytes()[0..16]
+            .try_into()
+            .expect("slice is 16 bytes"),
+    )
+}
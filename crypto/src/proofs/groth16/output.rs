@@ -8,17 +8,16 @@ use decaf377_fmd as fmd;
 use decaf377_ka as ka;
 
 use ark_ff::ToConstraintField;
-use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
 use ark_r1cs_std::prelude::*;
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
 use ark_snark::SNARK;
 use penumbra_proto::{core::crypto::v1alpha1 as pb, DomainType};
 use rand::{CryptoRng, Rng};
-use rand_core::OsRng;
 
 use crate::balance::BalanceVar;
 use crate::proofs::groth16::{
-    gadgets, ParameterSetup, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
+    gadgets, BatchVerifier, ParameterSetup, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
 };
 use crate::{
     balance, balance::commitment::BalanceCommitmentVar, keys::Diversifier, note, Address, Note,
@@ -82,7 +81,7 @@ impl ConstraintSynthesizer<Fq> for OutputCircuit {
 }
 
 impl ParameterSetup for OutputCircuit {
-    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+    fn example_circuit() -> Self {
         let diversifier_bytes = [1u8; 16];
         let pk_d_bytes = decaf377::basepoint().vartime_compress().0;
         let clue_key_bytes = [1; 32];
@@ -100,15 +99,12 @@ impl ParameterSetup for OutputCircuit {
         )
         .expect("can make a note");
         let v_blinding = Fr::from(1);
-        let circuit = OutputCircuit {
+        OutputCircuit {
             note: note.clone(),
             note_commitment: note.commit(),
             v_blinding,
             balance_commitment: balance::Commitment(decaf377::basepoint()),
-        };
-        let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
-            .expect("can perform circuit specific setup");
-        (pk, vk)
+        }
     }
 }
 
@@ -149,9 +145,7 @@ impl OutputProof {
         balance_commitment: balance::Commitment,
         note_commitment: note::Commitment,
     ) -> anyhow::Result<()> {
-        let mut public_inputs = Vec::new();
-        public_inputs.extend(note_commitment.0.to_field_elements().unwrap());
-        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        let public_inputs = Self::public_inputs(balance_commitment, note_commitment);
 
         tracing::trace!(?public_inputs);
         let start = std::time::Instant::now();
@@ -163,6 +157,30 @@ impl OutputProof {
             .then_some(())
             .ok_or_else(|| anyhow::anyhow!("output proof did not verify"))
     }
+
+    /// Queue the proof to be verified as part of the provided batch, using the provided public
+    /// inputs.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier<'_>,
+        balance_commitment: balance::Commitment,
+        note_commitment: note::Commitment,
+    ) {
+        batch.queue(
+            self.0.clone(),
+            Self::public_inputs(balance_commitment, note_commitment),
+        );
+    }
+
+    fn public_inputs(
+        balance_commitment: balance::Commitment,
+        note_commitment: note::Commitment,
+    ) -> Vec<Fq> {
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(note_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        public_inputs
+    }
 }
 
 impl DomainType for OutputProof {
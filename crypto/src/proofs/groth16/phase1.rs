@@ -0,0 +1,298 @@
+//! The output of a phase 1 ("powers of tau") ceremony, from which the initial parameters for any
+//! circuit of bounded size can be derived.
+//!
+//! Nobody learns `tau`, `alpha` or `beta` as long as a single participant in the phase 1 ceremony
+//! was honest, and deriving a circuit's parameters requires no further secrets: `gamma` and
+//! `delta` start out as the generator, and `delta` is then re-randomized by the phase 2 ceremony.
+//! This follows Bowe, Gabizon and Miers, "Scalable Multi-party Computation for zk-SNARK
+//! Parameters in the Random Beacon Model".
+
+use std::io::{Read, Write};
+
+use anyhow::{anyhow, ensure, Result};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, Zero};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, OptimizationGoal, SynthesisMode,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use decaf377::Bls12_377;
+use rand_core::OsRng;
+
+use super::phase2::{merge_pairs, same_ratio, G1Affine, G1Projective, G2Affine, G2Projective};
+use crate::Fq;
+
+/// Powers of a secret `tau`, in the form published by a phase 1 ceremony of degree `n`.
+#[derive(Clone)]
+pub struct Phase1Parameters {
+    /// `tau^i` in G1, for `i` in `0..2n - 1`.
+    tau_g1: Vec<G1Affine>,
+    /// `tau^i` in G2, for `i` in `0..n`.
+    tau_g2: Vec<G2Affine>,
+    /// `alpha * tau^i` in G1, for `i` in `0..n`.
+    alpha_tau_g1: Vec<G1Affine>,
+    /// `beta * tau^i` in G1, for `i` in `0..n`.
+    beta_tau_g1: Vec<G1Affine>,
+    /// `beta` in G2.
+    beta_g2: G2Affine,
+}
+
+impl Phase1Parameters {
+    /// The largest evaluation domain these powers can be used for.
+    pub fn degree(&self) -> usize {
+        self.tau_g2.len()
+    }
+
+    /// Check that the points are consistent powers of the same `tau`, multiplied by the same
+    /// `alpha` and `beta`.
+    pub fn verify(&self) -> Result<()> {
+        let n = self.degree();
+        ensure!(n >= 2, "powers of tau have degree less than 2");
+        ensure!(
+            self.tau_g1.len() == 2 * n - 1
+                && self.alpha_tau_g1.len() == n
+                && self.beta_tau_g1.len() == n,
+            "powers of tau have inconsistent lengths"
+        );
+        ensure!(
+            self.tau_g1[0] == G1Affine::prime_subgroup_generator()
+                && self.tau_g2[0] == G2Affine::prime_subgroup_generator(),
+            "powers of tau do not start from the generator"
+        );
+        ensure!(
+            !self.tau_g1[1].is_zero()
+                && !self.tau_g2[1].is_zero()
+                && !self.alpha_tau_g1[0].is_zero()
+                && !self.beta_tau_g1[0].is_zero()
+                && !self.beta_g2.is_zero(),
+            "powers of tau contain the identity"
+        );
+
+        let tau_in_g1 = (self.tau_g1[0], self.tau_g1[1]);
+        let tau_in_g2 = (self.tau_g2[0], self.tau_g2[1]);
+        // Each point must be `tau` times the one before it.
+        let (before, after) = merge_pairs(&mut OsRng, &self.tau_g1[..2 * n - 2], &self.tau_g1[1..]);
+        ensure!(
+            same_ratio((before, after), tau_in_g2),
+            "powers of tau in G1 are inconsistent"
+        );
+        let (before, after) = merge_pairs(&mut OsRng, &self.tau_g2[..n - 1], &self.tau_g2[1..]);
+        ensure!(
+            same_ratio(tau_in_g1, (before, after)),
+            "powers of tau in G2 are inconsistent"
+        );
+        let (before, after) = merge_pairs(
+            &mut OsRng,
+            &self.alpha_tau_g1[..n - 1],
+            &self.alpha_tau_g1[1..],
+        );
+        ensure!(
+            same_ratio((before, after), tau_in_g2),
+            "powers of tau times alpha are inconsistent"
+        );
+        let (before, after) = merge_pairs(
+            &mut OsRng,
+            &self.beta_tau_g1[..n - 1],
+            &self.beta_tau_g1[1..],
+        );
+        ensure!(
+            same_ratio((before, after), tau_in_g2),
+            "powers of tau times beta are inconsistent"
+        );
+        ensure!(
+            same_ratio(
+                (self.tau_g1[0], self.beta_tau_g1[0]),
+                (self.tau_g2[0], self.beta_g2)
+            ),
+            "beta in G1 does not match beta in G2"
+        );
+
+        Ok(())
+    }
+
+    /// Derive the initial parameters for `circuit`, before any phase 2 contributions.
+    ///
+    /// This mirrors `ark_groth16::generate_parameters`, but evaluates the circuit's polynomials at
+    /// `tau` in the exponent, using the published powers rather than a known `tau`.
+    pub fn circuit_parameters<C: ConstraintSynthesizer<Fq>>(
+        &self,
+        circuit: C,
+    ) -> Result<ProvingKey<Bls12_377>> {
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        cs.set_optimization_goal(OptimizationGoal::Constraints);
+        cs.set_mode(SynthesisMode::Setup);
+        circuit
+            .generate_constraints(cs.clone())
+            .map_err(|err| anyhow!(err))?;
+        cs.finalize();
+        let matrices = cs
+            .to_matrices()
+            .ok_or_else(|| anyhow!("could not construct constraint matrices"))?;
+
+        let num_instance_variables = matrices.num_instance_variables;
+        let num_variables = num_instance_variables + matrices.num_witness_variables;
+        let domain =
+            GeneralEvaluationDomain::<Fq>::new(matrices.num_constraints + num_instance_variables)
+                .ok_or_else(|| anyhow!("circuit is too large for an evaluation domain"))?;
+        let n = domain.size();
+        ensure!(
+            n <= self.degree(),
+            "circuit needs powers of tau of degree {}, but only {} are available",
+            n,
+            self.degree()
+        );
+
+        // The Lagrange basis polynomials of the domain, evaluated at `tau` in the exponent.
+        let lagrange_g1 = lagrange_basis(&domain, &self.tau_g1);
+        let lagrange_g2 = lagrange_basis(&domain, &self.tau_g2);
+        let lagrange_alpha_g1 = lagrange_basis(&domain, &self.alpha_tau_g1);
+        let lagrange_beta_g1 = lagrange_basis(&domain, &self.beta_tau_g1);
+
+        // For each variable, its QAP polynomials `u`, `v` and `w` at `tau`, as `u` and `v` in G1,
+        // `v` in G2, and `beta * u + alpha * v + w` in G1.
+        let mut a_query = vec![G1Projective::zero(); num_variables];
+        let mut b_g1_query = vec![G1Projective::zero(); num_variables];
+        let mut b_g2_query = vec![G2Projective::zero(); num_variables];
+        let mut abc_query = vec![G1Projective::zero(); num_variables];
+        // Each instance variable also gets a constraint of its own, as in `R1CStoQAP`, which
+        // keeps the instance polynomials linearly independent.
+        let instance_rows =
+            matrices.num_constraints..matrices.num_constraints + num_instance_variables;
+        for (index, row) in instance_rows.enumerate() {
+            a_query[index] += lagrange_g1[row];
+            abc_query[index] += lagrange_beta_g1[row];
+        }
+        for (row, ((a, b), c)) in matrices
+            .a
+            .iter()
+            .zip(&matrices.b)
+            .zip(&matrices.c)
+            .enumerate()
+        {
+            for &(coefficient, index) in a {
+                add_multiple(&mut a_query[index], lagrange_g1[row], coefficient);
+                add_multiple(&mut abc_query[index], lagrange_beta_g1[row], coefficient);
+            }
+            for &(coefficient, index) in b {
+                add_multiple(&mut b_g1_query[index], lagrange_g1[row], coefficient);
+                add_multiple(&mut b_g2_query[index], lagrange_g2[row], coefficient);
+                add_multiple(&mut abc_query[index], lagrange_alpha_g1[row], coefficient);
+            }
+            for &(coefficient, index) in c {
+                add_multiple(&mut abc_query[index], lagrange_g1[row], coefficient);
+            }
+        }
+
+        // `tau^i * t(tau)` for the vanishing polynomial `t(X) = X^n - 1`.
+        let h_query = (0..n - 1)
+            .map(|i| self.tau_g1[n + i].into_projective() - self.tau_g1[i].into_projective())
+            .collect::<Vec<_>>();
+
+        let abc_query = G1Projective::batch_normalization_into_affine(&abc_query);
+        let vk = VerifyingKey {
+            alpha_g1: self.alpha_tau_g1[0],
+            beta_g2: self.beta_g2,
+            gamma_g2: G2Affine::prime_subgroup_generator(),
+            delta_g2: G2Affine::prime_subgroup_generator(),
+            gamma_abc_g1: abc_query[..num_instance_variables].to_vec(),
+        };
+        Ok(ProvingKey {
+            vk,
+            beta_g1: self.beta_tau_g1[0],
+            delta_g1: G1Affine::prime_subgroup_generator(),
+            a_query: G1Projective::batch_normalization_into_affine(&a_query),
+            b_g1_query: G1Projective::batch_normalization_into_affine(&b_g1_query),
+            b_g2_query: G2Projective::batch_normalization_into_affine(&b_g2_query),
+            h_query: G1Projective::batch_normalization_into_affine(&h_query),
+            l_query: abc_query[num_instance_variables..].to_vec(),
+        })
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        self.tau_g1.serialize(&mut writer)?;
+        self.tau_g2.serialize(&mut writer)?;
+        self.alpha_tau_g1.serialize(&mut writer)?;
+        self.beta_tau_g1.serialize(&mut writer)?;
+        self.beta_g2.serialize(&mut writer)?;
+        Ok(())
+    }
+
+    /// Read parameters, checking that every point is valid.
+    ///
+    /// This does not check that the points are consistent: use [`Phase1Parameters::verify`] for
+    /// that.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self> {
+        Ok(Self {
+            tau_g1: Vec::deserialize(&mut reader)?,
+            tau_g2: Vec::deserialize(&mut reader)?,
+            alpha_tau_g1: Vec::deserialize(&mut reader)?,
+            beta_tau_g1: Vec::deserialize(&mut reader)?,
+            beta_g2: G2Affine::deserialize(&mut reader)?,
+        })
+    }
+
+    /// Compute powers of tau from locally sampled secrets, which must only be used for testing.
+    #[cfg(test)]
+    pub(super) fn insecure_for_testing<R: rand::Rng>(rng: &mut R, degree: usize) -> Self {
+        use ark_ff::{PrimeField, UniformRand};
+
+        let (tau, alpha, beta) = (Fq::rand(rng), Fq::rand(rng), Fq::rand(rng));
+        let powers = (0..2 * degree - 1)
+            .scan(Fq::one(), |power, _| {
+                let current = *power;
+                *power *= tau;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+        let g1 = |scalars: &[Fq], factor: Fq| {
+            let points = scalars
+                .iter()
+                .map(|s| G1Affine::prime_subgroup_generator().mul((*s * factor).into_repr()))
+                .collect::<Vec<_>>();
+            G1Projective::batch_normalization_into_affine(&points)
+        };
+        let tau_g2 = powers[..degree]
+            .iter()
+            .map(|s| G2Affine::prime_subgroup_generator().mul(s.into_repr()))
+            .collect::<Vec<_>>();
+
+        Self {
+            tau_g1: g1(&powers, Fq::one()),
+            tau_g2: G2Projective::batch_normalization_into_affine(&tau_g2),
+            alpha_tau_g1: g1(&powers[..degree], alpha),
+            beta_tau_g1: g1(&powers[..degree], beta),
+            beta_g2: G2Affine::prime_subgroup_generator()
+                .mul(beta.into_repr())
+                .into_affine(),
+        }
+    }
+}
+
+/// Convert the first `domain.size()` powers of tau into the Lagrange basis of the domain, so that
+/// the `i`th point is the `i`th Lagrange basis polynomial evaluated at `tau`.
+fn lagrange_basis<G: AffineCurve<ScalarField = Fq>>(
+    domain: &GeneralEvaluationDomain<Fq>,
+    powers: &[G],
+) -> Vec<G::Projective> {
+    let mut points = powers[..domain.size()]
+        .iter()
+        .map(|p| p.into_projective())
+        .collect::<Vec<_>>();
+    domain.ifft_in_place(&mut points);
+    points
+}
+
+/// Add `coefficient * point` to `sum`, avoiding the multiplication for the common coefficients.
+fn add_multiple<G: ProjectiveCurve<ScalarField = Fq>>(sum: &mut G, point: G, coefficient: Fq) {
+    if coefficient.is_one() {
+        *sum += point;
+    } else if (-coefficient).is_one() {
+        *sum -= point;
+    } else {
+        let mut point = point;
+        point *= coefficient;
+        *sum += point;
+    }
+}
@@ -0,0 +1,347 @@
+//! A phase 2 multi-party computation for Groth16 parameters.
+//!
+//! Starting from some initial parameters for a circuit, each participant multiplies `delta` by a
+//! secret scalar (and divides the `h` and `l` queries by it), publishing a [`Contribution`] which
+//! proves knowledge of that scalar relative to the transcript of all earlier contributions. As
+//! long as a single participant destroys their scalar, nobody knows the final `delta`.
+//!
+//! This follows the construction of Bowe, Gabizon and Miers, "Scalable Multi-party Computation
+//! for zk-SNARK Parameters in the Random Beacon Model".
+
+use std::io::{Read, Write};
+
+use anyhow::{ensure, Result};
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, UniformRand, Zero};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use decaf377::Bls12_377;
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use rand_core::OsRng;
+
+use crate::Fq;
+
+pub(super) type G1Affine = <Bls12_377 as PairingEngine>::G1Affine;
+pub(super) type G1Projective = <Bls12_377 as PairingEngine>::G1Projective;
+pub(super) type G2Affine = <Bls12_377 as PairingEngine>::G2Affine;
+pub(super) type G2Projective = <Bls12_377 as PairingEngine>::G2Projective;
+
+/// A hash of ceremony data: the initial parameters, a contribution, or the transcript.
+pub type Hash = [u8; 64];
+
+fn hasher() -> blake2b_simd::State {
+    blake2b_simd::Params::new()
+        .hash_length(64)
+        .personal(b"penumbra_phase2")
+        .to_state()
+}
+
+/// The public record of one participant's contribution to the parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Contribution {
+    /// `delta` in G1 after this contribution.
+    delta_after: G1Affine,
+    /// A random point chosen by the participant.
+    s: G1Affine,
+    /// `s` multiplied by the participant's secret scalar.
+    s_delta: G1Affine,
+    /// The point derived from `s`, `s_delta` and the transcript, multiplied by the secret scalar.
+    r_delta: G2Affine,
+    /// The hash of the transcript this contribution extends.
+    transcript: Hash,
+}
+
+impl Contribution {
+    /// The hash of this contribution, which the participant should publish so that anyone can
+    /// check it was included in the final parameters.
+    pub fn hash(&self) -> Result<Hash> {
+        let mut state = hasher();
+        self.write(&mut state)?;
+        Ok(*state.finalize().as_array())
+    }
+
+    fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        self.delta_after.serialize(&mut writer)?;
+        self.s.serialize(&mut writer)?;
+        self.s_delta.serialize(&mut writer)?;
+        self.r_delta.serialize(&mut writer)?;
+        writer.write_all(&self.transcript)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(mut reader: R) -> Result<Self> {
+        let delta_after = G1Affine::deserialize(&mut reader)?;
+        let s = G1Affine::deserialize(&mut reader)?;
+        let s_delta = G1Affine::deserialize(&mut reader)?;
+        let r_delta = G2Affine::deserialize(&mut reader)?;
+        let mut transcript = [0u8; 64];
+        reader.read_exact(&mut transcript)?;
+        Ok(Self {
+            delta_after,
+            s,
+            s_delta,
+            r_delta,
+            transcript,
+        })
+    }
+}
+
+/// Groth16 parameters for a circuit, along with the contributions made to them so far.
+#[derive(Clone)]
+pub struct Phase2Parameters {
+    pk: ProvingKey<Bls12_377>,
+    /// The hash of the parameters before any contributions, which starts the transcript.
+    initial_hash: Hash,
+    contributions: Vec<Contribution>,
+}
+
+impl Phase2Parameters {
+    /// Start a ceremony from the provided initial parameters.
+    pub fn new(pk: ProvingKey<Bls12_377>) -> Result<Self> {
+        let mut state = hasher();
+        pk.serialize(&mut state)?;
+        Ok(Self {
+            pk,
+            initial_hash: *state.finalize().as_array(),
+            contributions: Vec::new(),
+        })
+    }
+
+    pub fn proving_key(&self) -> &ProvingKey<Bls12_377> {
+        &self.pk
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey<Bls12_377> {
+        &self.pk.vk
+    }
+
+    /// The hash of the initial parameters of the ceremony.
+    pub fn initial_hash(&self) -> Hash {
+        self.initial_hash
+    }
+
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+
+    /// Make a contribution to the parameters using randomness from `rng`, returning the hash of
+    /// the contribution.
+    ///
+    /// The secret scalar is discarded once the contribution is made.
+    pub fn contribute<R: CryptoRng + Rng>(&mut self, rng: &mut R) -> Result<Hash> {
+        let transcript = self.transcript_hash()?;
+
+        let delta = Fq::rand(rng);
+        let delta_inverse = delta
+            .inverse()
+            .ok_or_else(|| anyhow::anyhow!("sampled a zero contribution"))?;
+
+        // Prove knowledge of `delta`, bound to the transcript.
+        let s = G1Projective::rand(rng).into_affine();
+        let s_delta = s.mul(delta.into_repr()).into_affine();
+        let r_delta = hash_to_g2(&s, &s_delta, &transcript)?
+            .mul(delta.into_repr())
+            .into_affine();
+
+        self.pk.delta_g1 = self.pk.delta_g1.mul(delta.into_repr()).into_affine();
+        self.pk.vk.delta_g2 = self.pk.vk.delta_g2.mul(delta.into_repr()).into_affine();
+        scale(&mut self.pk.h_query, delta_inverse);
+        scale(&mut self.pk.l_query, delta_inverse);
+
+        let contribution = Contribution {
+            delta_after: self.pk.delta_g1,
+            s,
+            s_delta,
+            r_delta,
+            transcript,
+        };
+        let hash = contribution.hash()?;
+        self.contributions.push(contribution);
+        Ok(hash)
+    }
+
+    /// Verify that these parameters were derived from `previous` by a sequence of valid
+    /// contributions, returning the hashes of those contributions in order.
+    ///
+    /// `previous` may be the initial parameters of the ceremony, to verify the whole transcript,
+    /// or any later parameters, to verify only the contributions made since.
+    pub fn verify(&self, previous: &Self) -> Result<Vec<Hash>> {
+        ensure!(
+            self.initial_hash == previous.initial_hash,
+            "parameters are from a different ceremony"
+        );
+        ensure!(
+            self.contributions.starts_with(&previous.contributions),
+            "parameters do not extend the previous transcript"
+        );
+
+        // Contributions only change delta and the queries divided by it.
+        let (pk, previous_pk) = (&self.pk, &previous.pk);
+        ensure!(
+            pk.vk.alpha_g1 == previous_pk.vk.alpha_g1
+                && pk.vk.beta_g2 == previous_pk.vk.beta_g2
+                && pk.vk.gamma_g2 == previous_pk.vk.gamma_g2
+                && pk.vk.gamma_abc_g1 == previous_pk.vk.gamma_abc_g1
+                && pk.beta_g1 == previous_pk.beta_g1
+                && pk.a_query == previous_pk.a_query
+                && pk.b_g1_query == previous_pk.b_g1_query
+                && pk.b_g2_query == previous_pk.b_g2_query
+                && pk.h_query.len() == previous_pk.h_query.len()
+                && pk.l_query.len() == previous_pk.l_query.len(),
+            "contributions changed parameters other than delta"
+        );
+
+        let mut transcript = hasher();
+        transcript.update(&self.initial_hash);
+        let mut delta = previous_pk.delta_g1;
+        let mut hashes = Vec::new();
+        for (index, contribution) in self.contributions.iter().enumerate() {
+            if index >= previous.contributions.len() {
+                ensure!(
+                    contribution.transcript == *transcript.finalize().as_array(),
+                    "contribution {} does not extend the transcript",
+                    index
+                );
+                ensure!(
+                    !contribution.delta_after.is_zero()
+                        && !contribution.s.is_zero()
+                        && !contribution.s_delta.is_zero(),
+                    "contribution {} contains the identity",
+                    index
+                );
+                let r = hash_to_g2(
+                    &contribution.s,
+                    &contribution.s_delta,
+                    &contribution.transcript,
+                )?;
+                ensure!(
+                    same_ratio(
+                        (contribution.s, contribution.s_delta),
+                        (r, contribution.r_delta)
+                    ),
+                    "contribution {} has an invalid proof of knowledge",
+                    index
+                );
+                ensure!(
+                    same_ratio((delta, contribution.delta_after), (r, contribution.r_delta)),
+                    "contribution {} does not match its proof of knowledge",
+                    index
+                );
+                delta = contribution.delta_after;
+                hashes.push(contribution.hash()?);
+            }
+            contribution.write(&mut transcript)?;
+        }
+
+        ensure!(
+            pk.delta_g1 == delta,
+            "delta does not match the last contribution"
+        );
+        ensure!(
+            same_ratio(
+                (G1Affine::prime_subgroup_generator(), pk.delta_g1),
+                (G2Affine::prime_subgroup_generator(), pk.vk.delta_g2)
+            ),
+            "delta in G1 does not match delta in G2"
+        );
+
+        // The h and l queries must have been divided by the same factor delta was multiplied by.
+        let (h_before, h_after) = merge_pairs(&mut OsRng, &previous_pk.h_query, &pk.h_query);
+        ensure!(
+            same_ratio(
+                (h_before, h_after),
+                (pk.vk.delta_g2, previous_pk.vk.delta_g2)
+            ),
+            "h query does not match delta"
+        );
+        let (l_before, l_after) = merge_pairs(&mut OsRng, &previous_pk.l_query, &pk.l_query);
+        ensure!(
+            same_ratio(
+                (l_before, l_after),
+                (pk.vk.delta_g2, previous_pk.vk.delta_g2)
+            ),
+            "l query does not match delta"
+        );
+
+        Ok(hashes)
+    }
+
+    /// The hash of the initial parameters and every contribution so far.
+    fn transcript_hash(&self) -> Result<Hash> {
+        let mut transcript = hasher();
+        transcript.update(&self.initial_hash);
+        for contribution in &self.contributions {
+            contribution.write(&mut transcript)?;
+        }
+        Ok(*transcript.finalize().as_array())
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(&self.initial_hash)?;
+        self.pk.serialize(&mut writer)?;
+        (self.contributions.len() as u64).serialize(&mut writer)?;
+        for contribution in &self.contributions {
+            contribution.write(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Read parameters, checking that every point is valid.
+    ///
+    /// This does not check the contributions: use [`Phase2Parameters::verify`] for that.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self> {
+        let mut initial_hash = [0u8; 64];
+        reader.read_exact(&mut initial_hash)?;
+        let pk = ProvingKey::deserialize(&mut reader)?;
+        let len = u64::deserialize(&mut reader)?;
+        let contributions = (0..len)
+            .map(|_| Contribution::read(&mut reader))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            pk,
+            initial_hash,
+            contributions,
+        })
+    }
+}
+
+/// Derive a point in G2 from a contribution's proof of knowledge and the transcript it extends,
+/// whose discrete logarithm is unknown to the participant.
+fn hash_to_g2(s: &G1Affine, s_delta: &G1Affine, transcript: &Hash) -> Result<G2Affine> {
+    let mut state = hasher();
+    s.serialize(&mut state)?;
+    s_delta.serialize(&mut state)?;
+    state.update(transcript);
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&state.finalize().as_bytes()[..32]);
+    Ok(G2Projective::rand(&mut ChaCha20Rng::from_seed(seed)).into_affine())
+}
+
+/// Check that `g1.1 = x * g1.0` and `g2.1 = x * g2.0` for the same `x`.
+pub(super) fn same_ratio(g1: (G1Affine, G1Affine), g2: (G2Affine, G2Affine)) -> bool {
+    Bls12_377::pairing(g1.0, g2.1) == Bls12_377::pairing(g1.1, g2.0)
+}
+
+/// Combine two equal-length lists of points using the same random coefficients, so that checking
+/// the ratio of the combinations checks the ratio of every pair of points, with high probability.
+pub(super) fn merge_pairs<G: AffineCurve<ScalarField = Fq>, R: CryptoRng + Rng>(
+    rng: &mut R,
+    before: &[G],
+    after: &[G],
+) -> (G, G) {
+    let coefficients = (0..before.len())
+        .map(|_| Fq::from(rng.gen::<u128>()).into_repr())
+        .collect::<Vec<_>>();
+    (
+        VariableBaseMSM::multi_scalar_mul(before, &coefficients).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(after, &coefficients).into_affine(),
+    )
+}
+
+/// Multiply every point in `points` by `scalar`.
+fn scale(points: &mut [G1Affine], scalar: Fq) {
+    let scalar = scalar.into_repr();
+    let scaled = points.iter().map(|p| p.mul(scalar)).collect::<Vec<_>>();
+    points.copy_from_slice(&G1Projective::batch_normalization_into_affine(&scaled));
+}
@@ -9,7 +9,7 @@ use decaf377::FieldExt;
 use decaf377::{r1cs::FqVar, Bls12_377, Fq, Fr};
 
 use ark_ff::ToConstraintField;
-use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
 use ark_r1cs_std::prelude::AllocVar;
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
 use ark_snark::SNARK;
@@ -17,9 +17,8 @@ use decaf377_rdsa::{SpendAuth, VerificationKey};
 use penumbra_proto::{core::crypto::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
 use rand::{CryptoRng, Rng};
-use rand_core::OsRng;
 
-use crate::proofs::groth16::{gadgets, ParameterSetup, VerifyingKeyExt};
+use crate::proofs::groth16::{gadgets, BatchVerifier, ParameterSetup, VerifyingKeyExt};
 use crate::{
     balance,
     balance::commitment::BalanceCommitmentVar,
@@ -139,7 +138,7 @@ impl ConstraintSynthesizer<Fq> for SpendCircuit {
 }
 
 impl ParameterSetup for SpendCircuit {
-    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+    fn example_circuit() -> Self {
         let seed_phrase = SeedPhrase::from_randomness([b'f'; 32]);
         let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
         let fvk_sender = sk_sender.full_viewing_key();
@@ -165,7 +164,7 @@ impl ParameterSetup for SpendCircuit {
         let anchor = sct.root();
         let state_commitment_proof = sct.witness(note_commitment).unwrap();
 
-        let circuit = SpendCircuit {
+        SpendCircuit {
             state_commitment_proof,
             note,
             v_blinding,
@@ -176,10 +175,7 @@ impl ParameterSetup for SpendCircuit {
             balance_commitment: balance::Commitment(decaf377::basepoint()),
             nullifier,
             rk,
-        };
-        let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
-            .expect("can perform circuit specific setup");
-        (pk, vk)
+        }
     }
 }
 
@@ -230,14 +226,7 @@ impl SpendProof {
         nullifier: Nullifier,
         rk: VerificationKey<SpendAuth>,
     ) -> anyhow::Result<()> {
-        let mut public_inputs = Vec::new();
-        public_inputs.extend(Fq::from(anchor.0).to_field_elements().unwrap());
-        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
-        public_inputs.extend(nullifier.0.to_field_elements().unwrap());
-        let element_rk = decaf377::Encoding(rk.to_bytes())
-            .vartime_decompress()
-            .expect("expect only valid element points");
-        public_inputs.extend(element_rk.to_field_elements().unwrap());
+        let public_inputs = Self::public_inputs(anchor, balance_commitment, nullifier, rk);
 
         tracing::trace!(?public_inputs);
         let start = std::time::Instant::now();
@@ -249,6 +238,39 @@ impl SpendProof {
             .then_some(())
             .ok_or_else(|| anyhow::anyhow!("spend proof did not verify"))
     }
+
+    /// Queue the proof to be verified as part of the provided batch, using the provided public
+    /// inputs.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier<'_>,
+        anchor: tct::Root,
+        balance_commitment: balance::Commitment,
+        nullifier: Nullifier,
+        rk: VerificationKey<SpendAuth>,
+    ) {
+        batch.queue(
+            self.0.clone(),
+            Self::public_inputs(anchor, balance_commitment, nullifier, rk),
+        );
+    }
+
+    fn public_inputs(
+        anchor: tct::Root,
+        balance_commitment: balance::Commitment,
+        nullifier: Nullifier,
+        rk: VerificationKey<SpendAuth>,
+    ) -> Vec<Fq> {
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(Fq::from(anchor.0).to_field_elements().unwrap());
+        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(nullifier.0.to_field_elements().unwrap());
+        let element_rk = decaf377::Encoding(rk.to_bytes())
+            .vartime_decompress()
+            .expect("expect only valid element points");
+        public_inputs.extend(element_rk.to_field_elements().unwrap());
+        public_inputs
+    }
 }
 
 impl DomainType for SpendProof {
@@ -1,5 +1,5 @@
 use ark_ff::ToConstraintField;
-use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
 use ark_r1cs_std::prelude::*;
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
@@ -9,7 +9,6 @@ use decaf377_fmd as fmd;
 use penumbra_proto::{core::crypto::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
 use rand::{CryptoRng, Rng};
-use rand_core::OsRng;
 
 use crate::{
     asset,
@@ -25,7 +24,7 @@ use crate::{
     Address, Fq, Fr, Rseed, Value,
 };
 
-use super::{ParameterSetup, GROTH16_PROOF_LENGTH_BYTES};
+use super::{BatchVerifier, ParameterSetup, GROTH16_PROOF_LENGTH_BYTES};
 
 pub struct SwapCircuit {
     /// The swap plaintext.
@@ -81,7 +80,7 @@ impl ConstraintSynthesizer<Fq> for SwapCircuit {
 }
 
 impl ParameterSetup for SwapCircuit {
-    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+    fn example_circuit() -> Self {
         let trading_pair = TradingPair {
             asset_1: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
             asset_2: asset::REGISTRY.parse_denom("nala").unwrap().id(),
@@ -108,16 +107,13 @@ impl ParameterSetup for SwapCircuit {
             rseed: Rseed([1u8; 32]),
         };
 
-        let circuit = SwapCircuit {
+        SwapCircuit {
             swap_plaintext: swap_plaintext.clone(),
             fee_blinding: Fr::from(1),
             swap_commitment: swap_plaintext.swap_commitment(),
             fee_commitment: balance::Commitment(decaf377::basepoint()),
             balance_commitment: balance::Commitment(decaf377::basepoint()),
-        };
-        let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
-            .expect("can perform circuit specific setup");
-        (pk, vk)
+        }
     }
 }
 
@@ -164,10 +160,8 @@ impl SwapProof {
         swap_commitment: tct::Commitment,
         fee_commitment: balance::Commitment,
     ) -> anyhow::Result<()> {
-        let mut public_inputs = Vec::new();
-        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
-        public_inputs.extend(swap_commitment.0.to_field_elements().unwrap());
-        public_inputs.extend(fee_commitment.0.to_field_elements().unwrap());
+        let public_inputs =
+            Self::public_inputs(balance_commitment, swap_commitment, fee_commitment);
 
         tracing::trace!(?public_inputs);
         let start = std::time::Instant::now();
@@ -178,6 +172,33 @@ impl SwapProof {
             .then_some(())
             .ok_or_else(|| anyhow::anyhow!("proof did not verify"))
     }
+
+    /// Queue the proof to be verified as part of the provided batch, using the provided public
+    /// inputs.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier<'_>,
+        balance_commitment: balance::Commitment,
+        swap_commitment: tct::Commitment,
+        fee_commitment: balance::Commitment,
+    ) {
+        batch.queue(
+            self.0.clone(),
+            Self::public_inputs(balance_commitment, swap_commitment, fee_commitment),
+        );
+    }
+
+    fn public_inputs(
+        balance_commitment: balance::Commitment,
+        swap_commitment: tct::Commitment,
+        fee_commitment: balance::Commitment,
+    ) -> Vec<Fq> {
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(swap_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(fee_commitment.0.to_field_elements().unwrap());
+        public_inputs
+    }
 }
 
 impl DomainType for SwapProof {
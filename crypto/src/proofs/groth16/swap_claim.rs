@@ -0,0 +1,393 @@
+use ark_ff::ToConstraintField;
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use decaf377::{r1cs::FqVar, Bls12_377, Fq};
+use penumbra_proto::{core::crypto::v1alpha1 as pb, DomainType};
+use penumbra_tct as tct;
+use rand::{CryptoRng, Rng};
+
+use crate::{
+    asset::{self, AmountVar},
+    dex::{
+        swap::{SwapPlaintext, SwapPlaintextVar},
+        BatchSwapOutputData, BatchSwapOutputDataVar, TradingPair,
+    },
+    keys::{NullifierKey, NullifierKeyVar, SeedPhrase, SpendKey},
+    note::{self, NoteVar, StateCommitmentVar},
+    nullifier::NullifierVar,
+    transaction::Fee,
+    value::ValueVar,
+    Nullifier, Rseed, Value,
+};
+
+use super::{gadgets, BatchVerifier, ParameterSetup, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+
+/// Groth16 proof for claiming the outputs of a swap.
+#[derive(Clone, Debug)]
+pub struct SwapClaimCircuit {
+    // Witnesses
+    /// The swap being claimed.
+    swap_plaintext: SwapPlaintext,
+    /// Inclusion proof for the swap commitment.
+    state_commitment_proof: tct::Proof,
+    /// The nullifier deriving key.
+    nk: NullifierKey,
+    /// The blinding factor of the first output note.
+    note_blinding_1: Fq,
+    /// The blinding factor of the second output note.
+    note_blinding_2: Fq,
+
+    // Public inputs
+    /// The merkle root of the state commitment tree.
+    pub anchor: tct::Root,
+    /// The nullifier of the swap commitment.
+    pub nullifier: Nullifier,
+    /// The fee paid for the claim.
+    pub claim_fee: Fee,
+    /// The output data of the batch the swap was included in.
+    pub output_data: BatchSwapOutputData,
+    /// The epoch duration of the chain, used to find the height of the swap.
+    pub epoch_duration: u64,
+    /// The note commitment of the first output note.
+    pub note_commitment_1: note::Commitment,
+    /// The note commitment of the second output note.
+    pub note_commitment_2: note::Commitment,
+}
+
+impl ConstraintSynthesizer<Fq> for SwapClaimCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> ark_relations::r1cs::Result<()> {
+        // Witnesses
+        let swap_plaintext_var =
+            SwapPlaintextVar::new_witness(cs.clone(), || Ok(self.swap_plaintext.clone()))?;
+        let claimed_swap_commitment = StateCommitmentVar::new_witness(cs.clone(), || {
+            Ok(self.state_commitment_proof.commitment())
+        })?;
+        let position_var = tct::r1cs::PositionVar::new_witness(cs.clone(), || {
+            Ok(self.state_commitment_proof.position())
+        })?;
+        let merkle_path_var =
+            tct::r1cs::MerkleAuthPathVar::new(cs.clone(), self.state_commitment_proof)?;
+        let nk_var = NullifierKeyVar::new_witness(cs.clone(), || Ok(self.nk))?;
+        let note_blinding_1_var = FqVar::new_witness(cs.clone(), || Ok(self.note_blinding_1))?;
+        let note_blinding_2_var = FqVar::new_witness(cs.clone(), || Ok(self.note_blinding_2))?;
+
+        // Public inputs
+        let anchor_var = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.anchor)))?;
+        let claimed_nullifier_var = NullifierVar::new_input(cs.clone(), || Ok(self.nullifier))?;
+        let claimed_fee_var = ValueVar::new_input(cs.clone(), || Ok(self.claim_fee.0))?;
+        let output_data_var =
+            BatchSwapOutputDataVar::new_input(cs.clone(), || Ok(self.output_data))?;
+        let epoch_duration_var =
+            FqVar::new_input(cs.clone(), || Ok(Fq::from(self.epoch_duration)))?;
+        let claimed_note_commitment_1 =
+            StateCommitmentVar::new_input(cs.clone(), || Ok(self.note_commitment_1))?;
+        let claimed_note_commitment_2 =
+            StateCommitmentVar::new_input(cs.clone(), || Ok(self.note_commitment_2))?;
+
+        // Swap commitment integrity.
+        let swap_commitment = swap_plaintext_var.commit()?;
+        claimed_swap_commitment.enforce_equal(&swap_commitment)?;
+
+        // Merkle auth path verification against the provided anchor.
+        merkle_path_var.verify(
+            cs.clone(),
+            &Boolean::TRUE,
+            position_var.inner.clone(),
+            anchor_var,
+            claimed_swap_commitment.inner(),
+        )?;
+
+        // Nullifier integrity.
+        let nullifier_var = nk_var.derive_nullifier(&position_var, &claimed_swap_commitment)?;
+        nullifier_var.enforce_equal(&claimed_nullifier_var)?;
+
+        // Fee consistency.
+        claimed_fee_var
+            .amount()
+            .enforce_equal(&swap_plaintext_var.claim_fee.amount())?;
+        claimed_fee_var
+            .asset_id()
+            .enforce_equal(&swap_plaintext_var.claim_fee.asset_id())?;
+
+        // The swap must have been included in the block the output data is for. The position
+        // packs the epoch into bits 32..48 and the block within the epoch into bits 16..32.
+        let position_bits = position_var.inner.to_bits_le()?;
+        let block = Boolean::le_bits_to_fp_var(&position_bits[16..32])?;
+        let epoch = Boolean::le_bits_to_fp_var(&position_bits[32..48])?;
+        let swap_height = epoch * &epoch_duration_var + block;
+        swap_height.enforce_equal(&output_data_var.height)?;
+
+        // The output data must be for the swap's trading pair.
+        output_data_var
+            .trading_pair_asset_1
+            .asset_id
+            .enforce_equal(&swap_plaintext_var.trading_pair_asset_1.asset_id)?;
+        output_data_var
+            .trading_pair_asset_2
+            .asset_id
+            .enforce_equal(&swap_plaintext_var.trading_pair_asset_2.asset_id)?;
+
+        // Output amounts integrity. If the batch succeeded, each output is the swap's pro rata
+        // share of the batch output of that asset; otherwise the inputs are returned as is.
+        let delta_1_i = swap_plaintext_var.delta_1_i.amount.clone();
+        let delta_2_i = swap_plaintext_var.delta_2_i.amount.clone();
+        gadgets::enforce_bit_length(&delta_1_i, 64)?;
+        gadgets::enforce_bit_length(&delta_2_i, 64)?;
+        let pro_rata_1 = gadgets::div_floor(
+            cs.clone(),
+            &(&delta_2_i * &output_data_var.lambda_1),
+            &output_data_var.delta_2,
+        )?;
+        let pro_rata_2 = gadgets::div_floor(
+            cs,
+            &(&delta_1_i * &output_data_var.lambda_2),
+            &output_data_var.delta_1,
+        )?;
+        let lambda_1_i =
+            FqVar::conditionally_select(&output_data_var.success, &pro_rata_1, &delta_1_i)?;
+        let lambda_2_i =
+            FqVar::conditionally_select(&output_data_var.success, &pro_rata_2, &delta_2_i)?;
+
+        // Output note integrity. Both outputs are sent to the swap's claim address.
+        let output_1_note = NoteVar {
+            value: ValueVar {
+                amount: AmountVar { amount: lambda_1_i },
+                asset_id: swap_plaintext_var.trading_pair_asset_1.clone(),
+            },
+            note_blinding: note_blinding_1_var,
+            address: swap_plaintext_var.claim_address.clone(),
+        };
+        let output_2_note = NoteVar {
+            value: ValueVar {
+                amount: AmountVar { amount: lambda_2_i },
+                asset_id: swap_plaintext_var.trading_pair_asset_2.clone(),
+            },
+            note_blinding: note_blinding_2_var,
+            address: swap_plaintext_var.claim_address.clone(),
+        };
+        output_1_note
+            .commit()?
+            .enforce_equal(&claimed_note_commitment_1)?;
+        output_2_note
+            .commit()?
+            .enforce_equal(&claimed_note_commitment_2)?;
+
+        Ok(())
+    }
+}
+
+impl ParameterSetup for SwapClaimCircuit {
+    fn example_circuit() -> Self {
+        let trading_pair = TradingPair {
+            asset_1: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+            asset_2: asset::REGISTRY.parse_denom("nala").unwrap().id(),
+        };
+        let seed_phrase = SeedPhrase::from_randomness([b'f'; 32]);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (claim_address, _dtk_d) = ivk_sender.payment_address(0u32.into());
+        let nk = *sk_sender.nullifier_key();
+
+        let swap_plaintext = SwapPlaintext {
+            trading_pair,
+            delta_1_i: 100000u64.into(),
+            delta_2_i: 1u64.into(),
+            claim_fee: Fee(Value {
+                amount: 3u64.into(),
+                asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+            }),
+            claim_address,
+            rseed: Rseed([1u8; 32]),
+        };
+        let mut sct = tct::Tree::new();
+        let swap_commitment = swap_plaintext.swap_commitment();
+        sct.insert(tct::Witness::Keep, swap_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(swap_commitment).unwrap();
+
+        let output_data = BatchSwapOutputData {
+            delta_1: 100000,
+            delta_2: 1,
+            lambda_1: 2,
+            lambda_2: 50000,
+            height: 0,
+            trading_pair,
+            success: true,
+        };
+        let (output_1_note, output_2_note) = swap_plaintext.output_notes(&output_data);
+
+        SwapClaimCircuit {
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            note_blinding_1: output_1_note.note_blinding(),
+            note_blinding_2: output_2_note.note_blinding(),
+            anchor,
+            nullifier: Nullifier(Fq::from(1)),
+            claim_fee: Fee::default(),
+            output_data,
+            epoch_duration: 10,
+            note_commitment_1: output_1_note.commit(),
+            note_commitment_2: output_2_note.commit(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SwapClaimProof(Proof<Bls12_377>);
+
+impl SwapClaimProof {
+    #![allow(clippy::too_many_arguments)]
+    pub fn prove<R: CryptoRng + Rng>(
+        rng: &mut R,
+        pk: &ProvingKey<Bls12_377>,
+        swap_plaintext: SwapPlaintext,
+        state_commitment_proof: tct::Proof,
+        nk: NullifierKey,
+        note_blinding_1: Fq,
+        note_blinding_2: Fq,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        claim_fee: Fee,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+    ) -> anyhow::Result<Self> {
+        let circuit = SwapClaimCircuit {
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            note_blinding_1,
+            note_blinding_2,
+            anchor,
+            nullifier,
+            claim_fee,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+        };
+        let proof = Groth16::prove(pk, circuit, rng).map_err(|err| anyhow::anyhow!(err))?;
+        Ok(Self(proof))
+    }
+
+    /// Called to verify the proof using the provided public inputs.
+    ///
+    /// The public inputs are:
+    /// * anchor,
+    /// * nullifier,
+    /// * claim fee,
+    /// * batch swap output data,
+    /// * epoch duration,
+    /// * the note commitments of both outputs.
+    // For debugging proof verification failures,
+    // to check that the proof data and verification keys are consistent.
+    #[tracing::instrument(level="debug", skip(self, vk), fields(self = ?base64::encode(&self.clone().encode_to_vec()), vk = ?vk.debug_id()))]
+    pub fn verify(
+        &self,
+        vk: &PreparedVerifyingKey<Bls12_377>,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        claim_fee: Fee,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+    ) -> anyhow::Result<()> {
+        let public_inputs = Self::public_inputs(
+            anchor,
+            nullifier,
+            claim_fee,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+        );
+
+        tracing::trace!(?public_inputs);
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::verify_with_processed_vk(vk, public_inputs.as_slice(), &self.0)
+            .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("swap claim proof did not verify"))
+    }
+
+    /// Queue the proof to be verified as part of the provided batch, using the provided public
+    /// inputs.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier<'_>,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        claim_fee: Fee,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+    ) {
+        batch.queue(
+            self.0.clone(),
+            Self::public_inputs(
+                anchor,
+                nullifier,
+                claim_fee,
+                output_data,
+                epoch_duration,
+                note_commitment_1,
+                note_commitment_2,
+            ),
+        );
+    }
+
+    fn public_inputs(
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        claim_fee: Fee,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+    ) -> Vec<Fq> {
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(Fq::from(anchor.0).to_field_elements().unwrap());
+        public_inputs.extend(nullifier.0.to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(claim_fee.amount()).to_field_elements().unwrap());
+        public_inputs.extend(claim_fee.asset_id().0.to_field_elements().unwrap());
+        public_inputs.extend(output_data.to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(epoch_duration).to_field_elements().unwrap());
+        public_inputs.extend(note_commitment_1.0.to_field_elements().unwrap());
+        public_inputs.extend(note_commitment_2.0.to_field_elements().unwrap());
+        public_inputs
+    }
+}
+
+impl DomainType for SwapClaimProof {
+    type Proto = pb::ZkSwapClaimProof;
+}
+
+impl From<SwapClaimProof> for pb::ZkSwapClaimProof {
+    fn from(proof: SwapClaimProof) -> Self {
+        let mut proof_bytes = [0u8; GROTH16_PROOF_LENGTH_BYTES];
+        Proof::serialize(&proof.0, &mut proof_bytes[..]).expect("can serialize Proof");
+        pb::ZkSwapClaimProof {
+            inner: proof_bytes.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ZkSwapClaimProof> for SwapClaimProof {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::ZkSwapClaimProof) -> Result<Self, Self::Error> {
+        Ok(SwapClaimProof(
+            Proof::deserialize(&proto.inner[..]).map_err(|e| anyhow::anyhow!(e))?,
+        ))
+    }
+}
@@ -1,10 +1,21 @@
-use ark_groth16::{PreparedVerifyingKey, ProvingKey, VerifyingKey};
+use ark_groth16::{Groth16, PreparedVerifyingKey, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::ConstraintSynthesizer;
 use ark_serialize::CanonicalSerialize;
-use decaf377::Bls12_377;
+use ark_snark::SNARK;
+use decaf377::{Bls12_377, Fq};
+use rand_core::OsRng;
 
 /// Must be implemented to generate proving and verification keys for a circuit.
-pub trait ParameterSetup {
-    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>);
+pub trait ParameterSetup: ConstraintSynthesizer<Fq> + Sized {
+    /// An instance of the circuit with an arbitrary witness, which is synthesized only to
+    /// determine the circuit's constraints.
+    fn example_circuit() -> Self;
+
+    /// Generate parameters with locally sampled toxic waste, which must only be used for testing.
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        Groth16::circuit_specific_setup(Self::example_circuit(), &mut OsRng)
+            .expect("can perform circuit specific setup")
+    }
 
     fn generate_prepared_test_parameters(
     ) -> (ProvingKey<Bls12_377>, PreparedVerifyingKey<Bls12_377>) {
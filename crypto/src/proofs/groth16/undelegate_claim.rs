@@ -0,0 +1,204 @@
+use ark_ff::ToConstraintField;
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use decaf377::{r1cs::FqVar, Bls12_377, FieldExt};
+use decaf377_rdsa::{SigningKey, SpendAuth};
+use penumbra_proto::{core::crypto::v1alpha1 as pb, DomainType};
+use rand::{CryptoRng, Rng};
+use rand_core::OsRng;
+
+use crate::{
+    asset::{self, AmountVar, AssetIdVar},
+    balance::{self, commitment::BalanceCommitmentVar, BalanceVar},
+    stake::{IdentityKey, Penalty, UnbondingToken},
+    value::ValueVar,
+    Amount, Fq, Fr, STAKING_TOKEN_ASSET_ID,
+};
+
+use super::{gadgets, BatchVerifier, ParameterSetup, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+
+/// Groth16 proof for claiming unbonding tokens as staking tokens, after applying the penalty
+/// accrued while unbonding.
+#[derive(Clone, Debug)]
+pub struct UndelegateClaimCircuit {
+    // Witnesses
+    /// The amount of unbonding tokens being claimed.
+    unbonding_amount: Amount,
+    /// The blinding factor for the balance commitment.
+    balance_blinding: Fr,
+
+    // Public inputs
+    /// balance commitment of the claim.
+    pub balance_commitment: balance::Commitment,
+    /// the asset ID of the unbonding tokens being claimed.
+    pub unbonding_id: asset::Id,
+    /// the penalty applied to the unbonding tokens.
+    pub penalty: Penalty,
+}
+
+impl ConstraintSynthesizer<Fq> for UndelegateClaimCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> ark_relations::r1cs::Result<()> {
+        // Witnesses
+        let unbonding_amount_var =
+            AmountVar::new_witness(cs.clone(), || Ok(self.unbonding_amount))?;
+        let balance_blinding_var =
+            UInt8::new_witness_vec(cs.clone(), &self.balance_blinding.to_bytes())?;
+
+        // Inputs
+        let claimed_balance_commitment =
+            BalanceCommitmentVar::new_input(cs.clone(), || Ok(self.balance_commitment))?;
+        let unbonding_id_var = AssetIdVar::new_input(cs.clone(), || Ok(self.unbonding_id))?;
+        let penalty_var = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.penalty.0)))?;
+
+        // The penalty is in bps^2, so it is applied by scaling the unbonding amount by
+        // `(10^8 - penalty) / 10^8`, rounding down. Bounding the unbonding amount to 64 bits and
+        // the scaling factor to 27 bits (which also rules out penalties above 10^8) keeps the
+        // product within the 128 bits `div_floor` expects.
+        let one = FqVar::new_constant(cs.clone(), Fq::from(1_0000_0000u64))?;
+        let penalty_factor = &one - &penalty_var;
+        gadgets::enforce_bit_length(&unbonding_amount_var.amount, 64)?;
+        gadgets::enforce_bit_length(&penalty_factor, 27)?;
+        let unbonded_amount = gadgets::div_floor(
+            cs.clone(),
+            &(&unbonding_amount_var.amount * &penalty_factor),
+            &one,
+        )?;
+
+        // Balance commitment integrity check: the claim consumes the unbonding tokens and
+        // produces the penalized amount of staking tokens.
+        let unbonding_balance = BalanceVar::from_negative_value_var(ValueVar {
+            amount: unbonding_amount_var,
+            asset_id: unbonding_id_var,
+        });
+        let staking_balance = BalanceVar::from_positive_value_var(ValueVar {
+            amount: AmountVar {
+                amount: unbonded_amount,
+            },
+            asset_id: AssetIdVar::new_constant(cs, *STAKING_TOKEN_ASSET_ID)?,
+        });
+        let transparent_blinding_var = UInt8::constant_vec(&[0u8; 32]);
+        let balance_commitment = unbonding_balance.commit(balance_blinding_var)?
+            + staking_balance.commit(transparent_blinding_var)?;
+        claimed_balance_commitment.enforce_equal(&balance_commitment)?;
+
+        Ok(())
+    }
+}
+
+impl ParameterSetup for UndelegateClaimCircuit {
+    fn example_circuit() -> Self {
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let unbonding_id = UnbondingToken::new(identity_key, 0, 1).id();
+
+        UndelegateClaimCircuit {
+            unbonding_amount: 1u64.into(),
+            balance_blinding: Fr::from(1),
+            balance_commitment: balance::Commitment(decaf377::basepoint()),
+            unbonding_id,
+            penalty: Penalty(1),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UndelegateClaimProof(Proof<Bls12_377>);
+
+impl UndelegateClaimProof {
+    pub fn prove<R: CryptoRng + Rng>(
+        rng: &mut R,
+        pk: &ProvingKey<Bls12_377>,
+        unbonding_amount: Amount,
+        balance_blinding: Fr,
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> anyhow::Result<Self> {
+        let circuit = UndelegateClaimCircuit {
+            unbonding_amount,
+            balance_blinding,
+            balance_commitment,
+            unbonding_id,
+            penalty,
+        };
+        let proof = Groth16::prove(pk, circuit, rng).map_err(|err| anyhow::anyhow!(err))?;
+        Ok(Self(proof))
+    }
+
+    /// Called to verify the proof using the provided public inputs.
+    // For debugging proof verification failures,
+    // to check that the proof data and verification keys are consistent.
+    #[tracing::instrument(level="debug", skip(self, vk), fields(self = ?base64::encode(&self.clone().encode_to_vec()), vk = ?vk.debug_id()))]
+    pub fn verify(
+        &self,
+        vk: &PreparedVerifyingKey<Bls12_377>,
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> anyhow::Result<()> {
+        let public_inputs = Self::public_inputs(balance_commitment, unbonding_id, penalty);
+
+        tracing::trace!(?public_inputs);
+        let start = std::time::Instant::now();
+        let proof_result =
+            Groth16::verify_with_processed_vk(&vk, public_inputs.as_slice(), &self.0)
+                .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("undelegate claim proof did not verify"))
+    }
+
+    /// Queue the proof to be verified as part of the provided batch, using the provided public
+    /// inputs.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier<'_>,
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) {
+        batch.queue(
+            self.0.clone(),
+            Self::public_inputs(balance_commitment, unbonding_id, penalty),
+        );
+    }
+
+    fn public_inputs(
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> Vec<Fq> {
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(unbonding_id.0.to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(penalty.0).to_field_elements().unwrap());
+        public_inputs
+    }
+}
+
+impl DomainType for UndelegateClaimProof {
+    type Proto = pb::ZkUndelegateClaimProof;
+}
+
+impl From<UndelegateClaimProof> for pb::ZkUndelegateClaimProof {
+    fn from(proof: UndelegateClaimProof) -> Self {
+        let mut proof_bytes = [0u8; GROTH16_PROOF_LENGTH_BYTES];
+        Proof::serialize(&proof.0, &mut proof_bytes[..]).expect("can serialize Proof");
+        pb::ZkUndelegateClaimProof {
+            inner: proof_bytes.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ZkUndelegateClaimProof> for UndelegateClaimProof {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::ZkUndelegateClaimProof) -> Result<Self, Self::Error> {
+        Ok(UndelegateClaimProof(
+            Proof::deserialize(&proto.inner[..]).map_err(|e| anyhow::anyhow!(e))?,
+        ))
+    }
+}
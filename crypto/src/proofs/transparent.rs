@@ -1,7 +1,6 @@
 //! Transparent proofs for `MVP1` of the Penumbra system.
 
-use anyhow::{anyhow, ensure, Error, Ok, Result};
-use ark_ff::Zero;
+use anyhow::{anyhow, Error, Ok, Result};
 use decaf377_rdsa::{SpendAuth, VerificationKey};
 use std::convert::{TryFrom, TryInto};
 
@@ -12,15 +11,7 @@ use penumbra_proto::{
 use penumbra_tct as tct;
 
 use super::transparent_gadgets as gadgets;
-use crate::{
-    asset, balance,
-    dex::{swap::SwapPlaintext, BatchSwapOutputData},
-    keys::{self, NullifierKey},
-    note,
-    stake::Penalty,
-    transaction::Fee,
-    Amount, Balance, Fq, Fr, Note, Nullifier, Value,
-};
+use crate::{asset, balance, keys, Balance, Fq, Fr, Note, Nullifier};
 
 /// Transparent proof for spending existing notes.
 ///
@@ -95,65 +86,12 @@ impl SpendProof {
     }
 }
 
-/// Transparent proof for delegator voting.
-///
-/// Internally, this is the same data as a transparent spend proof, but with additional verification
-/// conditions.
-#[derive(Clone, Debug)]
-pub struct DelegatorVoteProof {
-    pub spend_proof: SpendProof,
-}
-
-impl DelegatorVoteProof {
-    pub fn verify(
-        &self,
-        anchor: tct::Root,
-        start_position: tct::Position,
-        value: Value,
-        nullifier: Nullifier,
-        rk: VerificationKey<SpendAuth>,
-    ) -> anyhow::Result<()> {
-        // Additionally, check that the start position has a zero commitment index, since this is
-        // the only sensible start time for a vote.
-        if start_position.commitment() != 0 {
-            return Err(anyhow!(
-                "vote proof start position has non-zero commitment index"
-            ));
-        }
-
-        // Additionally, check that the position of the spend proof is before the start
-        // start_height, which ensures that the note being voted with was created before voting
-        // started.
-        let vote_proof_position = self.spend_proof.state_commitment_proof.position();
-        if vote_proof_position >= start_position {
-            return Err(anyhow!(
-                "vote proof from epoch {}, block {} is not before start position of voting at epoch {}, block {}",
-                vote_proof_position.epoch(),
-                vote_proof_position.block(),
-                start_position.epoch(),
-                start_position.block(),
-            ));
-        }
-
-        // Check that the spend proof is valid, for the public value committed with the zero
-        // blinding factor, since it's not blinded.
-        self.spend_proof
-            .verify(anchor, value.commit(Fr::zero()), nullifier, rk)?;
-
-        Ok(())
-    }
-}
-
 // Conversions
 
 impl DomainType for SpendProof {
     type Proto = transparent_proofs::SpendProof;
 }
 
-impl DomainType for DelegatorVoteProof {
-    type Proto = transparent_proofs::SpendProof;
-}
-
 impl From<SpendProof> for transparent_proofs::SpendProof {
     fn from(msg: SpendProof) -> Self {
         let ak_bytes: [u8; 32] = msg.ak.into();
@@ -169,22 +107,6 @@ impl From<SpendProof> for transparent_proofs::SpendProof {
     }
 }
 
-impl From<DelegatorVoteProof> for transparent_proofs::SpendProof {
-    fn from(msg: DelegatorVoteProof) -> Self {
-        msg.spend_proof.into()
-    }
-}
-
-impl TryFrom<transparent_proofs::SpendProof> for DelegatorVoteProof {
-    type Error = Error;
-
-    fn try_from(proto: transparent_proofs::SpendProof) -> anyhow::Result<Self, Self::Error> {
-        Ok(DelegatorVoteProof {
-            spend_proof: proto.try_into()?,
-        })
-    }
-}
-
 impl TryFrom<transparent_proofs::SpendProof> for SpendProof {
     type Error = Error;
 
@@ -250,269 +172,6 @@ impl TryFrom<&[u8]> for SpendProof {
     }
 }
 
-impl From<DelegatorVoteProof> for Vec<u8> {
-    fn from(delegator_vote_proof: DelegatorVoteProof) -> Vec<u8> {
-        let protobuf_serialized_proof: transparent_proofs::SpendProof = delegator_vote_proof.into();
-        protobuf_serialized_proof.encode_to_vec()
-    }
-}
-
-impl TryFrom<&[u8]> for DelegatorVoteProof {
-    type Error = Error;
-
-    fn try_from(bytes: &[u8]) -> Result<DelegatorVoteProof, Self::Error> {
-        let protobuf_serialized_proof = transparent_proofs::SpendProof::decode(bytes)
-            .map_err(|_| anyhow!("proto malformed"))?;
-        protobuf_serialized_proof
-            .try_into()
-            .map_err(|_| anyhow!("proto malformed"))
-    }
-}
-
-/// Transparent proof for claiming swapped assets.
-///
-/// SwapClaim consumes an existing Swap NFT so they are most similar to Spend operations,
-/// however the note commitment proof needs to be for a specific block due to clearing prices
-/// only being valid for particular blocks (i.e. the exchange rates of assets change over time).
-///
-/// This structure keeps track of the auxiliary (private) inputs.
-#[derive(Clone, Debug)]
-pub struct SwapClaimProof {
-    // The swap being claimed
-    pub swap_plaintext: SwapPlaintext,
-    // Inclusion proof for the swap commitment
-    pub swap_commitment_proof: tct::Proof,
-    // The nullifier deriving key for the Swap NFT note.
-    pub nk: keys::NullifierKey,
-    // Describes output amounts
-    pub lambda_1_i: u64,
-    pub lambda_2_i: u64,
-}
-
-impl SwapClaimProof {
-    /// Called to verify the proof using the provided public inputs.
-    ///
-    #[allow(clippy::too_many_arguments)]
-    pub fn verify(
-        &self,
-        anchor: tct::Root,
-        nullifier: Nullifier,
-        output_data: BatchSwapOutputData,
-        epoch_duration: u64,
-        note_commitment_1: note::Commitment,
-        note_commitment_2: note::Commitment,
-        fee: Fee,
-    ) -> anyhow::Result<()> {
-        // Swap commitment integrity
-        let swap_commitment = self.swap_plaintext.swap_commitment();
-        ensure!(
-            swap_commitment == self.swap_commitment_proof.commitment(),
-            "swap commitment mismatch"
-        );
-
-        // Merkle path integrity. Ensure the provided note commitment is in the TCT.
-        self.swap_commitment_proof
-            .verify(anchor)
-            .map_err(|_| anyhow!("merkle root mismatch"))?;
-
-        // Swap commitment nullifier integrity. Ensure the nullifier is correctly formed.
-        gadgets::nullifier_integrity(
-            nullifier,
-            self.nk,
-            self.swap_commitment_proof.position(),
-            self.swap_commitment_proof.commitment(),
-        )?;
-
-        // Validate the swap commitment's height matches the output data's height.
-        let position = self.swap_commitment_proof.position();
-        let block = position.block();
-        let epoch = position.epoch();
-        let note_commitment_block_height: u64 =
-            epoch_duration * u64::from(epoch) + u64::from(block);
-        ensure!(
-            note_commitment_block_height == output_data.height,
-            "note commitment was not for clearing price height"
-        );
-
-        // Validate that the output data's trading pair matches the note commitment's trading pair.
-        ensure!(
-            output_data.trading_pair == self.swap_plaintext.trading_pair,
-            "trading pair mismatch"
-        );
-
-        // Fee consistency check
-        ensure!(fee == self.swap_plaintext.claim_fee, "fee mismatch");
-
-        // Output amounts integrity
-        let (lambda_1_i, lambda_2_i) = output_data
-            // TODO: Amount conversion ?
-            .pro_rata_outputs((
-                self.swap_plaintext.delta_1_i.try_into()?,
-                self.swap_plaintext.delta_2_i.try_into()?,
-            ));
-        ensure!(self.lambda_1_i == lambda_1_i, "lambda_1_i mismatch");
-        ensure!(self.lambda_2_i == lambda_2_i, "lambda_2_i mismatch");
-
-        // Output note integrity
-        let (output_rseed_1, output_rseed_2) = self.swap_plaintext.output_rseeds();
-        let output_1_commitment = note::commitment_from_address(
-            self.swap_plaintext.claim_address,
-            Value {
-                amount: self.lambda_1_i.into(),
-                asset_id: self.swap_plaintext.trading_pair.asset_1(),
-            },
-            output_rseed_1.derive_note_blinding(),
-        )?;
-        let output_2_commitment = note::commitment_from_address(
-            self.swap_plaintext.claim_address,
-            Value {
-                amount: self.lambda_2_i.into(),
-                asset_id: self.swap_plaintext.trading_pair.asset_2(),
-            },
-            output_rseed_2.derive_note_blinding(),
-        )?;
-
-        ensure!(
-            output_1_commitment == note_commitment_1,
-            "output 1 commitment mismatch"
-        );
-        ensure!(
-            output_2_commitment == note_commitment_2,
-            "output 2 commitment mismatch"
-        );
-
-        Ok(())
-    }
-}
-
-impl From<SwapClaimProof> for Vec<u8> {
-    fn from(swap_proof: SwapClaimProof) -> Vec<u8> {
-        let protobuf_serialized_proof: transparent_proofs::SwapClaimProof = swap_proof.into();
-        protobuf_serialized_proof.encode_to_vec()
-    }
-}
-
-impl TryFrom<&[u8]> for SwapClaimProof {
-    type Error = Error;
-
-    fn try_from(bytes: &[u8]) -> Result<SwapClaimProof, Self::Error> {
-        let protobuf_serialized_proof = transparent_proofs::SwapClaimProof::decode(bytes)
-            .map_err(|_| anyhow!("proto malformed"))?;
-        protobuf_serialized_proof
-            .try_into()
-            .map_err(|_| anyhow!("proto malformed"))
-    }
-}
-
-impl DomainType for SwapClaimProof {
-    type Proto = transparent_proofs::SwapClaimProof;
-}
-
-impl From<SwapClaimProof> for transparent_proofs::SwapClaimProof {
-    fn from(msg: SwapClaimProof) -> Self {
-        Self {
-            swap_commitment_proof: Some(msg.swap_commitment_proof.into()),
-            swap_plaintext: Some(msg.swap_plaintext.into()),
-            nk: msg.nk.0.to_bytes().to_vec(),
-            lambda_1_i: msg.lambda_1_i,
-            lambda_2_i: msg.lambda_2_i,
-        }
-    }
-}
-
-impl TryFrom<transparent_proofs::SwapClaimProof> for SwapClaimProof {
-    type Error = Error;
-
-    fn try_from(proto: transparent_proofs::SwapClaimProof) -> anyhow::Result<Self, Self::Error> {
-        let swap_commitment_proof = proto
-            .swap_commitment_proof
-            .ok_or_else(|| anyhow!("missing swap commitment proof"))?
-            .try_into()?;
-        let swap_plaintext = proto
-            .swap_plaintext
-            .ok_or_else(|| anyhow!("missing swap plaintext"))?
-            .try_into()?;
-        let nk = NullifierKey(
-            Fq::from_bytes(proto.nk.try_into().map_err(|_| anyhow!("invalid nk"))?)
-                .map_err(|_| anyhow!("invalid nk"))?,
-        );
-        let lambda_1_i = proto.lambda_1_i;
-        let lambda_2_i = proto.lambda_2_i;
-
-        Ok(Self {
-            swap_commitment_proof,
-            swap_plaintext,
-            nk,
-            lambda_1_i,
-            lambda_2_i,
-        })
-    }
-}
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UndelegateClaimProof {
-    unbonding_amount: Amount,
-    balance_blinding: Fr,
-}
-
-impl UndelegateClaimProof {
-    pub fn new(unbonding_amount: Amount, balance_blinding: Fr) -> Self {
-        Self {
-            unbonding_amount,
-            balance_blinding,
-        }
-    }
-
-    pub fn verify(
-        &self,
-        balance_commitment: balance::Commitment,
-        unbonding_id: asset::Id,
-        penalty: Penalty,
-    ) -> anyhow::Result<()> {
-        let expected_balance = penalty.balance_for_claim(unbonding_id, self.unbonding_amount);
-        let expected_commitment = expected_balance.commit(self.balance_blinding);
-        ensure!(
-            expected_commitment == balance_commitment,
-            "balance commitment mismatch"
-        );
-        Ok(())
-    }
-}
-
-impl DomainType for UndelegateClaimProof {
-    type Proto = transparent_proofs::UndelegateClaimProof;
-}
-
-impl From<UndelegateClaimProof> for transparent_proofs::UndelegateClaimProof {
-    fn from(claim_proof: UndelegateClaimProof) -> Self {
-        transparent_proofs::UndelegateClaimProof {
-            unbonding_amount: Some(claim_proof.unbonding_amount.into()),
-            balance_blinding: claim_proof.balance_blinding.to_bytes().into(),
-        }
-    }
-}
-
-impl TryFrom<transparent_proofs::UndelegateClaimProof> for UndelegateClaimProof {
-    type Error = Error;
-
-    fn try_from(proto: transparent_proofs::UndelegateClaimProof) -> Result<Self, Self::Error> {
-        let unbonding_amount = proto
-            .unbonding_amount
-            .ok_or_else(|| anyhow!("proto malformed"))?
-            .try_into()
-            .map_err(|_| anyhow!("proto malformed"))?;
-        let balance_blinding_bytes: [u8; 32] = proto.balance_blinding[..]
-            .try_into()
-            .map_err(|_| anyhow!("proto malformed"))?;
-        let balance_blinding = Fr::from_bytes(balance_blinding_bytes)?;
-
-        Ok(UndelegateClaimProof {
-            unbonding_amount,
-            balance_blinding,
-        })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use ark_ff::UniformRand;
@@ -554,7 +213,6 @@ mod tests {
 
         let proof = SpendProof {
             state_commitment_proof,
-            note,
             v_blinding,
             spend_auth_randomizer,
             ak,
@@ -598,7 +256,6 @@ mod tests {
 
         let proof = SpendProof {
             state_commitment_proof,
-            note,
             v_blinding,
             spend_auth_randomizer,
             ak,
@@ -646,7 +303,6 @@ mod tests {
 
         let proof = SpendProof {
             state_commitment_proof,
-            note,
             v_blinding,
             spend_auth_randomizer,
             ak,
@@ -692,7 +348,6 @@ mod tests {
 
         let proof = SpendProof {
             state_commitment_proof,
-            note,
             v_blinding,
             spend_auth_randomizer,
             ak,
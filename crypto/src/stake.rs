@@ -1,9 +1,11 @@
 mod delegation_token;
 mod identity_key;
 mod penalty;
+mod redelegation_token;
 mod unbonding_token;
 
 pub use delegation_token::DelegationToken;
 pub use identity_key::IdentityKey;
 pub use penalty::Penalty;
+pub use redelegation_token::RedelegationToken;
 pub use unbonding_token::UnbondingToken;
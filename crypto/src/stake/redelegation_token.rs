@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use regex::Regex;
+
+use super::IdentityKey;
+use crate::asset;
+
+/// Redelegation tokens represent stake that has been redelegated from one
+/// validator to another, and is still subject to slashing of the validator it
+/// was redelegated from.
+///
+/// Redelegation tokens are denominated in units of the destination validator's
+/// delegation tokens, and are parameterized by the source and destination
+/// validator identities, the epoch in which the redelegation was performed, and
+/// the epoch at which unbonding from the source validator would have ended.
+pub struct RedelegationToken {
+    from_validator_identity: IdentityKey,
+    to_validator_identity: IdentityKey,
+    start_epoch_index: u64,
+    end_epoch_index: u64,
+    base_denom: asset::Denom,
+}
+
+impl RedelegationToken {
+    pub fn new(
+        from_validator_identity: IdentityKey,
+        to_validator_identity: IdentityKey,
+        start_epoch_index: u64,
+        end_epoch_index: u64,
+    ) -> Self {
+        // This format string needs to be in sync with the asset registry
+        let base_denom = asset::REGISTRY
+            .parse_denom(&format!(
+                "uredelegation_epoch_{start_epoch_index}_until_{end_epoch_index}_from_{from_validator_identity}_to_{to_validator_identity}"
+            ))
+            .expect("base denom format is valid");
+        RedelegationToken {
+            from_validator_identity,
+            to_validator_identity,
+            base_denom,
+            start_epoch_index,
+            end_epoch_index,
+        }
+    }
+
+    /// Get the base denomination for this redelegation token.
+    pub fn denom(&self) -> asset::Denom {
+        self.base_denom.clone()
+    }
+
+    /// Get the default display denomination for this redelegation token.
+    pub fn default_unit(&self) -> asset::Unit {
+        self.base_denom.default_unit()
+    }
+
+    /// Get the asset ID for this redelegation token.
+    pub fn id(&self) -> asset::Id {
+        self.base_denom.id()
+    }
+
+    /// Get the identity key of the validator the stake was redelegated from.
+    pub fn from_validator(&self) -> IdentityKey {
+        self.from_validator_identity.clone()
+    }
+
+    /// Get the identity key of the validator the stake was redelegated to.
+    pub fn to_validator(&self) -> IdentityKey {
+        self.to_validator_identity.clone()
+    }
+
+    pub fn start_epoch_index(&self) -> u64 {
+        self.start_epoch_index
+    }
+
+    pub fn end_epoch_index(&self) -> u64 {
+        self.end_epoch_index
+    }
+}
+
+impl TryFrom<asset::Denom> for RedelegationToken {
+    type Error = anyhow::Error;
+
+    fn try_from(base_denom: asset::Denom) -> Result<Self, Self::Error> {
+        let base_string = base_denom.to_string();
+
+        // Note: this regex must be in sync with both asset::REGISTRY
+        // and VALIDATOR_IDENTITY_BECH32_PREFIX
+        // The data capture group is used by asset::REGISTRY
+        let captures =
+            Regex::new("^uredelegation_(?P<data>epoch_(?P<start>[0-9]+)_until_(?P<end>[0-9]+)_from_(?P<from>penumbravalid1[a-zA-HJ-NP-Z0-9]+)_to_(?P<to>penumbravalid1[a-zA-HJ-NP-Z0-9]+))$")
+                .expect("regex is valid")
+                .captures(base_string.as_ref())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "base denom {} is not a redelegation token",
+                        base_denom.to_string()
+                    )
+                })?;
+
+        let from_validator_identity = captures
+            .name("from")
+            .expect("from is a named capture")
+            .as_str()
+            .parse()?;
+        let to_validator_identity = captures
+            .name("to")
+            .expect("to is a named capture")
+            .as_str()
+            .parse()?;
+
+        let start_epoch_index = captures
+            .name("start")
+            .expect("start is a named capture")
+            .as_str()
+            .parse()?;
+        let end_epoch_index = captures
+            .name("end")
+            .expect("end is a named capture")
+            .as_str()
+            .parse()?;
+
+        Ok(Self {
+            base_denom,
+            from_validator_identity,
+            to_validator_identity,
+            start_epoch_index,
+            end_epoch_index,
+        })
+    }
+}
+
+impl FromStr for RedelegationToken {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        asset::REGISTRY
+            .parse_denom(s)
+            .ok_or_else(|| anyhow::anyhow!("could not parse {} as base denomination", s))?
+            .try_into()
+    }
+}
+
+impl std::fmt::Display for RedelegationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.base_denom.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for RedelegationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.base_denom.fmt(f)
+    }
+}
+
+impl PartialEq for RedelegationToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.base_denom.eq(&other.base_denom)
+    }
+}
+
+impl Eq for RedelegationToken {}
+
+impl std::hash::Hash for RedelegationToken {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.base_denom.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rdsa::{SigningKey, SpendAuth};
+
+    use super::*;
+
+    #[test]
+    fn redelegation_token_denomination_round_trip() {
+        use rand_core::OsRng;
+
+        let from = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let to = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let start = 782;
+        let end = 789;
+
+        let token = RedelegationToken::new(from, to, start, end);
+
+        let denom = token.to_string();
+        assert_eq!(
+            denom,
+            format!("uredelegation_epoch_{start}_until_{end}_from_{from}_to_{to}")
+        );
+        let token2 = RedelegationToken::from_str(&denom).unwrap();
+        let denom2 = token2.to_string();
+
+        assert_eq!(denom, denom2);
+        assert_eq!(token, token2);
+        assert_eq!(token2.from_validator(), from);
+        assert_eq!(token2.to_validator(), to);
+    }
+}
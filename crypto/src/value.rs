@@ -86,7 +86,14 @@ impl AllocVar<Value, Fq> for ValueVar {
         let inner: Value = *f()?.borrow();
         match mode {
             AllocationMode::Constant => unimplemented!(),
-            AllocationMode::Input => unimplemented!(),
+            AllocationMode::Input => {
+                let amount_var = asset::AmountVar::new_input(cs.clone(), || Ok(inner.amount))?;
+                let asset_id_var = asset::AssetIdVar::new_input(cs, || Ok(inner.asset_id))?;
+                Ok(Self {
+                    amount: amount_var,
+                    asset_id: asset_id_var,
+                })
+            }
             AllocationMode::Witness => {
                 let amount_var = asset::AmountVar::new_witness(cs.clone(), || Ok(inner.amount))?;
                 let asset_id_var = asset::AssetIdVar::new_witness(cs, || Ok(inner.asset_id))?;
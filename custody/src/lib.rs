@@ -2,7 +2,9 @@
 //!
 //! This crate currently focuses on the [`soft_kms`] implementation, a basic
 //! software key management system that can perform basic policy-based
-//! authorization or blind signing.
+//! authorization or blind signing, and the [`threshold`] implementation,
+//! which splits spend authority between several custodians so that a
+//! threshold of them must agree to sign.
 
 #[macro_use]
 extern crate serde_with;
@@ -13,6 +15,7 @@ mod request;
 
 pub mod policy;
 pub mod soft_kms;
+pub mod threshold;
 
 pub use client::CustodyClient;
 pub use pre_auth::PreAuthorization;
@@ -0,0 +1,360 @@
+//! Threshold spend authorization, where `t` of `n` custodians jointly sign a transaction.
+//!
+//! The spend authorization key is split into `n` shares so that any `t` custodians can produce
+//! ordinary decaf377-rdsa spend authorization signatures, using a FROST-style two-round signing
+//! protocol, while fewer than `t` custodians learn nothing about the key. Nobody needs to hold the
+//! whole key once it has been split.
+//!
+//! The key is split by a trusted dealer (see [`split`] and [`generate`]), not by distributed key
+//! generation, so the dealer sees the whole key and must erase it after dealing out the shares.
+//!
+//! Signing a transaction takes two rounds, coordinated by the [`ThresholdKms`]:
+//!
+//! 1. Each custodian checks the request against its own policies, then commits to fresh nonces for
+//!    each signature the transaction needs, sending back a [`Round1`] message.
+//! 2. Given the commitments from every participating custodian, each custodian computes its share
+//!    of each signature, sending back a [`Round2`] message.
+//!
+//! The coordinator then checks each custodian's shares and combines them into the
+//! [`AuthorizationData`](penumbra_transaction::AuthorizationData) for the transaction. The
+//! coordinator only sees public data, so it need not be trusted with anything besides liveness.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use ark_ff::{Field, PrimeField};
+use decaf377::Element;
+use penumbra_crypto::{
+    rdsa::{SpendAuth, VerificationKey},
+    FullViewingKey, One, Zero,
+};
+use penumbra_transaction::{plan::TransactionPlan, EffectHash};
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+mod coordinator;
+mod encoding;
+mod keygen;
+mod sign;
+
+pub use coordinator::{aggregate, Custodian, ThresholdKms};
+pub use keygen::{generate, split};
+pub use sign::{NonceCommitment, Round1, Round2, Signer};
+
+use encoding::{ElementHex, ScalarHex};
+
+/// The public key material for a threshold spend authority, shared by every custodian and the
+/// coordinator.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupKey {
+    /// The number of custodians needed to sign.
+    pub threshold: u32,
+    /// The full viewing key whose spend authorization key is split among the custodians.
+    #[serde_as(as = "DisplayFromStr")]
+    pub fvk: FullViewingKey,
+    /// The public counterpart of each custodian's key share, by custodian index.
+    #[serde_as(as = "BTreeMap<DisplayFromStr, ElementHex>")]
+    verification_shares: BTreeMap<u32, Element>,
+}
+
+/// A single custodian's share of a threshold spend authority.
+#[serde_as]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct KeyShare {
+    /// The custodian's index, starting from 1.
+    pub index: u32,
+    #[serde_as(as = "ScalarHex")]
+    signing_share: decaf377::Fr,
+    /// The public key material for the whole group.
+    pub group: GroupKey,
+}
+
+/// Everything about a signing session which every participant derives for itself from the
+/// transaction plan and the first-round commitments.
+struct Session {
+    effect_hash: EffectHash,
+    signatures: Vec<SignatureSession>,
+    /// The Lagrange coefficient for each participating custodian.
+    lagrange: BTreeMap<u32, decaf377::Fr>,
+}
+
+/// The values needed to compute or check the shares of a single signature.
+struct SignatureSession {
+    randomizer: decaf377::Fr,
+    rk: VerificationKey<SpendAuth>,
+    binding_factors: BTreeMap<u32, decaf377::Fr>,
+    group_commitment: Element,
+    challenge: decaf377::Fr,
+}
+
+/// The effect hash to sign and the randomizer for each signature the plan needs, with the spend
+/// signatures first and the delegator vote signatures after them, as in
+/// [`AuthorizationData`](penumbra_transaction::AuthorizationData).
+fn signing_inputs(group: &GroupKey, plan: &TransactionPlan) -> (EffectHash, Vec<decaf377::Fr>) {
+    let randomizers = plan
+        .spend_plans()
+        .map(|plan| plan.randomizer)
+        .chain(plan.delegator_vote_plans().map(|plan| plan.randomizer))
+        .collect();
+    (plan.effect_hash(&group.fvk), randomizers)
+}
+
+impl Session {
+    fn new(
+        group: &GroupKey,
+        effect_hash: EffectHash,
+        randomizers: Vec<decaf377::Fr>,
+        commitments: &[Round1],
+    ) -> Result<Self> {
+        let mut by_index = BTreeMap::new();
+        for round1 in commitments {
+            if !group.verification_shares.contains_key(&round1.index) {
+                return Err(anyhow!("unknown custodian {}", round1.index));
+            }
+            if round1.commitments.len() != randomizers.len() {
+                return Err(anyhow!(
+                    "custodian {} committed to {} signatures, but {} are needed",
+                    round1.index,
+                    round1.commitments.len(),
+                    randomizers.len()
+                ));
+            }
+            if by_index.insert(round1.index, round1).is_some() {
+                return Err(anyhow!("custodian {} committed twice", round1.index));
+            }
+        }
+        if by_index.len() < group.threshold as usize {
+            return Err(anyhow!(
+                "{} custodians committed, but {} are needed to sign",
+                by_index.len(),
+                group.threshold
+            ));
+        }
+
+        let signatures = randomizers
+            .into_iter()
+            .enumerate()
+            .map(|(j, randomizer)| {
+                let rk = group.fvk.spend_verification_key().randomize(&randomizer);
+
+                // Bind each custodian's nonces to this signature and to everyone else's
+                // commitments, so that commitments can't be mixed and matched between sessions.
+                let mut prefix = blake2b_simd::Params::new()
+                    .personal(b"Penumbra_FrostBF")
+                    .to_state();
+                prefix.update(effect_hash.as_bytes());
+                prefix.update(&(j as u64).to_le_bytes());
+                prefix.update(&rk.to_bytes());
+                for (index, round1) in &by_index {
+                    let commitment = &round1.commitments[j];
+                    prefix.update(&index.to_le_bytes());
+                    prefix.update(&commitment.hiding.vartime_compress().0);
+                    prefix.update(&commitment.binding.vartime_compress().0);
+                }
+
+                let mut binding_factors = BTreeMap::new();
+                let mut group_commitment = Element::default();
+                for (index, round1) in &by_index {
+                    let mut state = prefix.clone();
+                    state.update(&index.to_le_bytes());
+                    let binding_factor =
+                        decaf377::Fr::from_le_bytes_mod_order(state.finalize().as_bytes());
+
+                    let commitment = &round1.commitments[j];
+                    group_commitment += commitment.hiding + binding_factor * commitment.binding;
+                    binding_factors.insert(*index, binding_factor);
+                }
+
+                let challenge = challenge(&group_commitment, &rk, effect_hash.as_ref());
+                SignatureSession {
+                    randomizer,
+                    rk,
+                    binding_factors,
+                    group_commitment,
+                    challenge,
+                }
+            })
+            .collect();
+
+        let indices = by_index.keys().copied().collect::<Vec<_>>();
+        let lagrange = indices
+            .iter()
+            .map(|&index| (index, lagrange_coefficient(index, &indices)))
+            .collect();
+
+        Ok(Self {
+            effect_hash,
+            signatures,
+            lagrange,
+        })
+    }
+}
+
+/// The decaf377-rdsa challenge for a signature with nonce commitment `r` under `vk`.
+fn challenge(r: &Element, vk: &VerificationKey<SpendAuth>, msg: &[u8]) -> decaf377::Fr {
+    let hash = blake2b_simd::Params::new()
+        .personal(b"decaf377-rdsa---")
+        .to_state()
+        .update(&r.vartime_compress().0)
+        .update(&vk.to_bytes())
+        .update(msg)
+        .finalize();
+    decaf377::Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+/// The Lagrange coefficient for interpolating the value at zero from the share at `index`, given
+/// the shares at `indices`.
+fn lagrange_coefficient(index: u32, indices: &[u32]) -> decaf377::Fr {
+    let x_i = decaf377::Fr::from(u64::from(index));
+    let mut numerator = decaf377::Fr::one();
+    let mut denominator = decaf377::Fr::one();
+    for &other in indices {
+        if other == index {
+            continue;
+        }
+        let x_j = decaf377::Fr::from(u64::from(other));
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    numerator
+        * denominator
+            .inverse()
+            .expect("custodian indices are distinct, so the denominator is nonzero")
+}
+
+/// Evaluate the polynomial with the given coefficients (lowest degree first) at `x`.
+fn evaluate(coefficients: &[decaf377::Fr], x: decaf377::Fr) -> decaf377::Fr {
+    coefficients
+        .iter()
+        .rev()
+        .fold(decaf377::Fr::zero(), |acc, coefficient| {
+            acc * x + coefficient
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::{
+        keys::{SeedPhrase, SpendKey},
+        Note, Value, STAKING_TOKEN_ASSET_ID,
+    };
+    use penumbra_transaction::plan::SpendPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::AuthorizeRequest;
+
+    fn request(sk: &SpendKey) -> AuthorizeRequest {
+        let fvk = sk.full_viewing_key();
+        let (address, _dtk) = fvk.incoming().payment_address(0u32.into());
+        let note = |amount: u64| {
+            Note::generate(
+                &mut OsRng,
+                &address,
+                Value {
+                    amount: amount.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+            )
+        };
+
+        AuthorizeRequest {
+            plan: TransactionPlan {
+                chain_id: "penumbra-test".to_string(),
+                actions: vec![
+                    SpendPlan::new(&mut OsRng, note(10000), 0u64.into()).into(),
+                    SpendPlan::new(&mut OsRng, note(20000), 1u64.into()).into(),
+                ],
+                ..Default::default()
+            },
+            account_group_id: fvk.account_group_id(),
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn threshold_signatures_verify() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let shares = split(&sk, 2, 3, OsRng).unwrap();
+        let group = shares[0].group.clone();
+        let signers = shares
+            .into_iter()
+            .map(|share| Signer::new(share, Vec::new()))
+            .collect::<Vec<_>>();
+        let request = request(&sk);
+
+        // Any two of the three custodians can sign.
+        for (a, b) in [(0, 1), (0, 2), (1, 2)] {
+            let participants = [&signers[a], &signers[b]];
+            let commitments = participants
+                .iter()
+                .map(|signer| signer.commit(&request, OsRng).unwrap())
+                .collect::<Vec<_>>();
+            let shares = participants
+                .iter()
+                .map(|signer| signer.sign(&request, &commitments).unwrap())
+                .collect::<Vec<_>>();
+
+            let auth_data = aggregate(&group, &request.plan, &commitments, &shares).unwrap();
+            assert_eq!(auth_data.spend_auths.len(), 2);
+            for (spend, sig) in request.plan.spend_plans().zip(&auth_data.spend_auths) {
+                let rk = sk.full_viewing_key().spend_verification_key();
+                rk.randomize(&spend.randomizer)
+                    .verify(auth_data.effect_hash.as_ref(), sig)
+                    .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_shares_are_rejected() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let shares = split(&sk, 2, 3, OsRng).unwrap();
+        let group = shares[0].group.clone();
+        let signers = shares
+            .into_iter()
+            .take(2)
+            .map(|share| Signer::new(share, Vec::new()))
+            .collect::<Vec<_>>();
+        let request = request(&sk);
+
+        let commitments = signers
+            .iter()
+            .map(|signer| signer.commit(&request, OsRng).unwrap())
+            .collect::<Vec<_>>();
+        let mut shares = signers
+            .iter()
+            .map(|signer| signer.sign(&request, &commitments).unwrap())
+            .collect::<Vec<_>>();
+        shares[1].shares[0] += decaf377::Fr::one();
+
+        let error = aggregate(&group, &request.plan, &commitments, &shares).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&format!("custodian {}", shares[1].index)));
+    }
+
+    #[test]
+    fn threshold_signing_needs_enough_custodians() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let shares = split(&sk, 2, 3, OsRng).unwrap();
+        let signer = Signer::new(shares[0].clone(), Vec::new());
+        let request = request(&sk);
+
+        let commitments = vec![signer.commit(&request, OsRng).unwrap()];
+        assert!(signer.sign(&request, &commitments).is_err());
+    }
+
+    #[test]
+    fn nonces_are_used_once() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let shares = split(&sk, 1, 1, OsRng).unwrap();
+        let signer = Signer::new(shares[0].clone(), Vec::new());
+        let request = request(&sk);
+
+        let commitments = vec![signer.commit(&request, OsRng).unwrap()];
+        signer.sign(&request, &commitments).unwrap();
+        assert!(signer.sign(&request, &commitments).is_err());
+    }
+}
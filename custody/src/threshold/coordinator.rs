@@ -0,0 +1,201 @@
+//! The coordinator's side of the two-round signing protocol.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
+use decaf377::FieldExt;
+use futures::{future, stream::FuturesUnordered, StreamExt};
+use penumbra_crypto::rdsa::{Signature, SpendAuth};
+use penumbra_proto::custody::v1alpha1::{self as pb, AuthorizeResponse};
+use penumbra_transaction::{plan::TransactionPlan, AuthorizationData};
+use rand_core::OsRng;
+use tonic::{async_trait, Request, Response, Status};
+
+use super::{signing_inputs, GroupKey, Round1, Round2, Session, Signer};
+use crate::AuthorizeRequest;
+
+/// Combine the custodians' signature shares into the [`AuthorizationData`] for `plan`.
+///
+/// Each custodian's shares are checked against its commitments and verification share before
+/// being combined, so that a misbehaving custodian is identified rather than producing an invalid
+/// signature.
+pub fn aggregate(
+    group: &GroupKey,
+    plan: &TransactionPlan,
+    commitments: &[Round1],
+    shares: &[Round2],
+) -> Result<AuthorizationData> {
+    let (effect_hash, randomizers) = signing_inputs(group, plan);
+    let num_spends = plan.spend_plans().count();
+    let session = Session::new(group, effect_hash, randomizers, commitments)?;
+
+    let mut shares_by_index = BTreeMap::new();
+    for round2 in shares {
+        if shares_by_index.insert(round2.index, round2).is_some() {
+            return Err(anyhow!("custodian {} sent shares twice", round2.index));
+        }
+    }
+    let commitments_by_index = commitments
+        .iter()
+        .map(|round1| (round1.index, round1))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut signatures = Vec::with_capacity(session.signatures.len());
+    for (j, signature) in session.signatures.iter().enumerate() {
+        let mut s = decaf377::Fr::zero();
+        for (index, lagrange) in &session.lagrange {
+            let round2 = shares_by_index
+                .get(index)
+                .ok_or_else(|| anyhow!("custodian {index} committed but sent no shares"))?;
+            let z = *round2.shares.get(j).ok_or_else(|| {
+                anyhow!(
+                    "custodian {index} sent {} shares, but {} are needed",
+                    round2.shares.len(),
+                    session.signatures.len()
+                )
+            })?;
+
+            let commitment = &commitments_by_index[index].commitments[j];
+            let expected = commitment.hiding
+                + signature.binding_factors[index] * commitment.binding
+                + (signature.challenge * lagrange) * group.verification_shares[index];
+            if z * decaf377::basepoint() != expected {
+                return Err(anyhow!("custodian {index} sent an invalid signature share"));
+            }
+            s += z;
+        }
+        // The shares sign for the unrandomized key, so account for the randomizer here.
+        s += signature.challenge * signature.randomizer;
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&signature.group_commitment.vartime_compress().0);
+        bytes[32..].copy_from_slice(&s.to_bytes());
+        let signature_bytes = Signature::<SpendAuth>::from(bytes);
+        signature
+            .rk
+            .verify(session.effect_hash.as_ref(), &signature_bytes)
+            .map_err(|_| anyhow!("combined signature {j} failed to verify"))?;
+        signatures.push(signature_bytes);
+    }
+
+    let delegator_vote_auths = signatures.split_off(num_spends);
+    Ok(AuthorizationData {
+        effect_hash: session.effect_hash,
+        spend_auths: signatures,
+        delegator_vote_auths,
+    })
+}
+
+/// A custodian holding one share of a threshold spend authority, which the [`ThresholdKms`] can
+/// ask to take part in signing.
+///
+/// This is implemented by [`Signer`] for custodians in the same process; custodians elsewhere can
+/// be reached by implementing it over whatever transport carries the [`Round1`] and [`Round2`]
+/// messages.
+#[async_trait]
+pub trait Custodian: Send + Sync {
+    /// Check the request, and commit to nonces for each signature it needs.
+    async fn commit(&self, request: &AuthorizeRequest) -> Result<Round1>;
+
+    /// Compute this custodian's signature shares, given every participant's commitments.
+    async fn sign(&self, request: &AuthorizeRequest, commitments: &[Round1]) -> Result<Round2>;
+}
+
+#[async_trait]
+impl Custodian for Signer {
+    async fn commit(&self, request: &AuthorizeRequest) -> Result<Round1> {
+        Signer::commit(self, request, OsRng)
+    }
+
+    async fn sign(&self, request: &AuthorizeRequest, commitments: &[Round1]) -> Result<Round2> {
+        Signer::sign(self, request, commitments)
+    }
+}
+
+/// A custody service which authorizes transactions by coordinating a threshold of custodians,
+/// none of which holds the whole spend authorization key.
+pub struct ThresholdKms {
+    group: GroupKey,
+    custodians: Vec<Arc<dyn Custodian>>,
+}
+
+impl ThresholdKms {
+    /// Coordinate signing between the given custodians, which all hold shares of `group`.
+    pub fn new(group: GroupKey, custodians: Vec<Arc<dyn Custodian>>) -> Self {
+        Self { group, custodians }
+    }
+
+    /// Attempt to authorize the requested [`TransactionPlan`].
+    ///
+    /// Every custodian is asked to take part, and the first `threshold` of them to agree go on to
+    /// sign; custodians which refuse the request or are unavailable are skipped.
+    #[tracing::instrument(skip(self, request), name = "threshold_sign")]
+    pub async fn sign(&self, request: &AuthorizeRequest) -> Result<AuthorizationData> {
+        tracing::debug!(?request.plan);
+
+        let threshold = self.group.threshold as usize;
+        let mut pending = self
+            .custodians
+            .iter()
+            .map(|custodian| async move {
+                custodian
+                    .commit(request)
+                    .await
+                    .map(|round1| (custodian, round1))
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut participants = Vec::with_capacity(threshold);
+        let mut commitments = Vec::with_capacity(threshold);
+        while participants.len() < threshold {
+            match pending.next().await {
+                Some(Ok((custodian, round1))) => {
+                    participants.push(custodian);
+                    commitments.push(round1);
+                }
+                Some(Err(e)) => tracing::warn!(error = %e, "custodian declined to commit"),
+                None => {
+                    return Err(anyhow!(
+                        "only {} custodians agreed to sign, but {threshold} are needed",
+                        participants.len()
+                    ))
+                }
+            }
+        }
+        drop(pending);
+
+        let shares = future::try_join_all(
+            participants
+                .iter()
+                .map(|custodian| custodian.sign(request, &commitments)),
+        )
+        .await?;
+
+        aggregate(&self.group, &request.plan, &commitments, &shares)
+    }
+}
+
+#[async_trait]
+impl pb::custody_protocol_service_server::CustodyProtocolService for ThresholdKms {
+    async fn authorize(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<AuthorizeResponse>, Status> {
+        let request = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        let authorization_data = self
+            .sign(&request)
+            .await
+            .map_err(|e| Status::unauthenticated(format!("{e:#}")))?;
+
+        let authorization_response = AuthorizeResponse {
+            data: Some(authorization_data.into()),
+        };
+
+        Ok(Response::new(authorization_response))
+    }
+}
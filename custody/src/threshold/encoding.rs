@@ -0,0 +1,45 @@
+//! Hex encodings of group elements and scalars, for use with `serde_with`.
+
+use decaf377::{Element, Encoding, FieldExt, Fr};
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// Encodes a group [`Element`] as the hex of its compressed encoding.
+pub struct ElementHex;
+
+/// Encodes a scalar [`Fr`] as the hex of its little-endian bytes.
+pub struct ScalarHex;
+
+impl SerializeAs<Element> for ElementHex {
+    fn serialize_as<S: Serializer>(element: &Element, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(element.vartime_compress().0))
+    }
+}
+
+impl<'de> DeserializeAs<'de, Element> for ElementHex {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Element, D::Error> {
+        Encoding(bytes_32(deserializer)?)
+            .vartime_decompress()
+            .map_err(|_| D::Error::custom("invalid group element encoding"))
+    }
+}
+
+impl SerializeAs<Fr> for ScalarHex {
+    fn serialize_as<S: Serializer>(scalar: &Fr, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(scalar.to_bytes()))
+    }
+}
+
+impl<'de> DeserializeAs<'de, Fr> for ScalarHex {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Fr, D::Error> {
+        Fr::from_bytes(bytes_32(deserializer)?)
+            .map_err(|_| D::Error::custom("invalid scalar encoding"))
+    }
+}
+
+fn bytes_32<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+    let bytes = hex::decode(String::deserialize(deserializer)?).map_err(D::Error::custom)?;
+    bytes
+        .try_into()
+        .map_err(|_| D::Error::custom("expected 32 bytes"))
+}
@@ -0,0 +1,80 @@
+//! Key generation for threshold spend authorities, using a trusted dealer.
+//!
+//! There is no distributed key generation: the dealer holds the whole spend key while splitting
+//! it, so it can spend the funds alone, and the threshold only protects them once the dealer has
+//! erased every copy of the key (and of the seed it came from). Only run this on a machine every
+//! custodian trusts, and erase the key once the shares have been handed out.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use ark_ff::UniformRand;
+use decaf377::FieldExt;
+use penumbra_crypto::keys::{SpendKey, SpendKeyBytes, SPENDKEY_LEN_BYTES};
+use rand_core::{CryptoRng, RngCore};
+
+use super::{evaluate, GroupKey, KeyShare};
+
+/// Split the spend authorization key of an existing `spend_key` into `n` shares, any `threshold`
+/// of which can sign for it.
+///
+/// This acts as a trusted dealer: whoever runs it sees the whole spend key, and must erase it once
+/// the shares have been handed out to the custodians. Only the spend authorization key is
+/// split; every custodian learns the full viewing key.
+pub fn split<R: RngCore + CryptoRng>(
+    spend_key: &SpendKey,
+    threshold: u32,
+    n: u32,
+    mut rng: R,
+) -> Result<Vec<KeyShare>> {
+    if threshold == 0 || threshold > n {
+        return Err(anyhow!(
+            "threshold must be between 1 and the number of custodians ({n}), not {threshold}"
+        ));
+    }
+
+    let secret = decaf377::Fr::from_bytes(spend_key.spend_auth_key().to_bytes())
+        .map_err(|_| anyhow!("spend authorization key is not a valid scalar"))?;
+
+    // A random polynomial of degree `threshold - 1` whose value at zero is the secret.
+    let coefficients = std::iter::once(secret)
+        .chain((1..threshold).map(|_| decaf377::Fr::rand(&mut rng)))
+        .collect::<Vec<_>>();
+
+    let signing_shares = (1..=n)
+        .map(|index| (index, evaluate(&coefficients, u64::from(index).into())))
+        .collect::<BTreeMap<_, _>>();
+
+    let group = GroupKey {
+        threshold,
+        fvk: spend_key.full_viewing_key().clone(),
+        verification_shares: signing_shares
+            .iter()
+            .map(|(index, share)| (*index, *share * decaf377::basepoint()))
+            .collect(),
+    };
+
+    Ok(signing_shares
+        .into_iter()
+        .map(|(index, signing_share)| KeyShare {
+            index,
+            signing_share,
+            group: group.clone(),
+        })
+        .collect())
+}
+
+/// Generate a fresh spend key, split into `n` shares, any `threshold` of which can sign for it.
+///
+/// As with [`split`], the caller acts as a trusted dealer: the whole key passes through its memory,
+/// so it must be trusted by every custodian.
+pub fn generate<R: RngCore + CryptoRng>(
+    threshold: u32,
+    n: u32,
+    mut rng: R,
+) -> Result<Vec<KeyShare>> {
+    let mut seed = [0u8; SPENDKEY_LEN_BYTES];
+    rng.fill_bytes(&mut seed);
+    let spend_key = SpendKey::from(SpendKeyBytes(seed));
+    split(&spend_key, threshold, n, rng)
+}
@@ -0,0 +1,203 @@
+//! The custodian's side of the two-round signing protocol.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+use anyhow::{anyhow, Result};
+use ark_ff::UniformRand;
+use decaf377::Element;
+use penumbra_transaction::EffectHash;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use super::{signing_inputs, ElementHex, KeyShare, ScalarHex, Session};
+use crate::{
+    policy::{AuthPolicy, Policy},
+    AuthorizeRequest,
+};
+
+/// A custodian's commitment to the pair of nonces it will use for one signature.
+#[serde_as]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceCommitment {
+    #[serde_as(as = "ElementHex")]
+    pub hiding: Element,
+    #[serde_as(as = "ElementHex")]
+    pub binding: Element,
+}
+
+/// The first-round message from a custodian, committing to nonces for each signature a
+/// transaction needs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Round1 {
+    /// The index of the custodian sending the message.
+    pub index: u32,
+    /// One commitment for each signature, in the order of the signatures in the
+    /// [`AuthorizationData`](penumbra_transaction::AuthorizationData).
+    pub commitments: Vec<NonceCommitment>,
+}
+
+/// The second-round message from a custodian, containing its share of each signature.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Round2 {
+    /// The index of the custodian sending the message.
+    pub index: u32,
+    /// One share for each signature, in the same order as the commitments.
+    #[serde_as(as = "Vec<ScalarHex>")]
+    pub shares: Vec<decaf377::Fr>,
+}
+
+/// The secret nonces behind a [`NonceCommitment`].
+struct Nonces {
+    hiding: decaf377::Fr,
+    binding: decaf377::Fr,
+}
+
+impl Nonces {
+    fn commitment(&self) -> NonceCommitment {
+        NonceCommitment {
+            hiding: self.hiding * decaf377::basepoint(),
+            binding: self.binding * decaf377::basepoint(),
+        }
+    }
+}
+
+/// A single custodian, holding one share of a threshold spend authority.
+///
+/// Before taking part in signing a transaction, the custodian checks the request against its own
+/// authorization policies, so each custodian can enforce its own rules independently.
+pub struct Signer {
+    share: KeyShare,
+    auth_policy: Vec<AuthPolicy>,
+    /// The nonces committed to in the first round, by effect hash, awaiting the second round.
+    nonces: Mutex<BTreeMap<[u8; 64], Vec<Nonces>>>,
+}
+
+impl Signer {
+    /// Create a custodian from its key share, which will only sign requests allowed by all of the
+    /// given policies.
+    pub fn new(share: KeyShare, auth_policy: Vec<AuthPolicy>) -> Self {
+        Self {
+            share,
+            auth_policy,
+            nonces: Default::default(),
+        }
+    }
+
+    /// The index of this custodian's key share.
+    pub fn index(&self) -> u32 {
+        self.share.index
+    }
+
+    fn check(&self, request: &AuthorizeRequest) -> Result<()> {
+        if request.account_group_id != self.share.group.fvk.account_group_id() {
+            return Err(anyhow!(
+                "request is for a different account group than this custodian's key share"
+            ));
+        }
+        for policy in &self.auth_policy {
+            policy.check(request)?;
+        }
+        Ok(())
+    }
+
+    /// Run the first round of signing, committing to fresh nonces for each signature the request
+    /// needs.
+    ///
+    /// The nonces are remembered until [`Signer::sign`] is called for the same transaction; calling
+    /// this again for the same transaction replaces them.
+    pub fn commit<R: RngCore + CryptoRng>(
+        &self,
+        request: &AuthorizeRequest,
+        rng: R,
+    ) -> Result<Round1> {
+        self.check(request)?;
+        let (effect_hash, randomizers) = signing_inputs(&self.share.group, &request.plan);
+        Ok(self.commit_inner(&effect_hash, randomizers.len(), rng))
+    }
+
+    fn commit_inner<R: RngCore + CryptoRng>(
+        &self,
+        effect_hash: &EffectHash,
+        count: usize,
+        mut rng: R,
+    ) -> Round1 {
+        let nonces = (0..count)
+            .map(|_| Nonces {
+                hiding: decaf377::Fr::rand(&mut rng),
+                binding: decaf377::Fr::rand(&mut rng),
+            })
+            .collect::<Vec<_>>();
+        let commitments = nonces.iter().map(Nonces::commitment).collect();
+
+        self.nonces
+            .lock()
+            .expect("nonce lock is not poisoned")
+            .insert(*effect_hash.as_bytes(), nonces);
+
+        Round1 {
+            index: self.share.index,
+            commitments,
+        }
+    }
+
+    /// Run the second round of signing, computing this custodian's share of each signature, given
+    /// the first-round commitments of every participating custodian (including this one).
+    ///
+    /// The nonces committed to in the first round are discarded whether or not this succeeds, so
+    /// they are never used for more than one signature.
+    pub fn sign(&self, request: &AuthorizeRequest, commitments: &[Round1]) -> Result<Round2> {
+        self.check(request)?;
+        let (effect_hash, randomizers) = signing_inputs(&self.share.group, &request.plan);
+        self.sign_inner(effect_hash, randomizers, commitments)
+    }
+
+    fn sign_inner(
+        &self,
+        effect_hash: EffectHash,
+        randomizers: Vec<decaf377::Fr>,
+        commitments: &[Round1],
+    ) -> Result<Round2> {
+        let nonces = self
+            .nonces
+            .lock()
+            .expect("nonce lock is not poisoned")
+            .remove(effect_hash.as_bytes())
+            .ok_or_else(|| anyhow!("no first-round commitments were made for this transaction"))?;
+
+        let own = commitments
+            .iter()
+            .find(|round1| round1.index == self.share.index)
+            .ok_or_else(|| anyhow!("this custodian's commitments are missing"))?;
+        if !own
+            .commitments
+            .iter()
+            .copied()
+            .eq(nonces.iter().map(Nonces::commitment))
+        {
+            return Err(anyhow!(
+                "this custodian's commitments don't match its nonces"
+            ));
+        }
+
+        let session = Session::new(&self.share.group, effect_hash, randomizers, commitments)?;
+        let lagrange = session.lagrange[&self.share.index];
+
+        let shares = session
+            .signatures
+            .iter()
+            .zip(nonces)
+            .map(|(signature, nonces)| {
+                let binding_factor = signature.binding_factors[&self.share.index];
+                nonces.hiding
+                    + nonces.binding * binding_factor
+                    + lagrange * self.share.signing_share * signature.challenge
+            })
+            .collect();
+
+        Ok(Round2 {
+            index: self.share.index,
+            shares,
+        })
+    }
+}
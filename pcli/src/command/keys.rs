@@ -3,6 +3,7 @@ use std::str::FromStr;
 use anyhow::{anyhow, Result};
 use directories::ProjectDirs;
 use penumbra_crypto::keys::SeedPhrase;
+use penumbra_wallet::passphrase;
 use rand_core::OsRng;
 use sha2::{Digest, Sha256};
 
@@ -17,7 +18,21 @@ pub enum KeysCmd {
     #[clap(subcommand)]
     Export(ExportCmd),
     /// Generate a new seed phrase and import its corresponding key.
-    Generate,
+    Generate {
+        /// Store the wallet in plaintext, rather than encrypting it with a passphrase.
+        ///
+        /// Plaintext wallets don't store the seed phrase, so can't add accounts.
+        #[clap(long)]
+        unencrypted: bool,
+    },
+    /// Encrypt a plaintext wallet file with a passphrase.
+    ///
+    /// Plaintext wallets don't store the seed phrase, so the encrypted wallet won't be able to add
+    /// accounts either; to add accounts, import the seed phrase again with a passphrase.
+    Encrypt,
+    /// Manage the accounts derived from the wallet's seed phrase.
+    #[clap(subcommand)]
+    Account(AccountCmd),
     /// Delete the entire wallet permanently.
     Delete,
 }
@@ -28,28 +43,44 @@ pub enum ImportCmd {
     Phrase {
         /// A 24 word phrase in quotes.
         seed_phrase: String,
+        /// Store the wallet in plaintext, rather than encrypting it with a passphrase.
+        ///
+        /// Plaintext wallets don't store the seed phrase, so can't add accounts.
+        #[clap(long)]
+        unencrypted: bool,
     },
 }
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ExportCmd {
-    /// Export the full viewing key for the wallet.
+    /// Export the full viewing key for the selected account.
     FullViewingKey,
 }
 
+#[derive(Debug, clap::Subcommand)]
+pub enum AccountCmd {
+    /// Derive a new account from the wallet's seed phrase.
+    Add {
+        /// A name for the new account, used to select it with `--account`.
+        label: String,
+    },
+    /// List the accounts in the wallet.
+    List,
+}
+
 impl KeysCmd {
     /// Determine if this command requires a network sync before it executes.
     pub fn offline(&self) -> bool {
         true
     }
 
-    fn archive_wallet(&self, wallet: &KeyStore) -> Result<()> {
+    fn archive_wallet(&self, wallet: &KeyStore, passphrase: Option<&str>) -> Result<()> {
         // Archive the newly generated state
         let archive_dir = ProjectDirs::from("zone", "penumbra", "penumbra-testnet-archive")
             .expect("can access penumbra-testnet-archive dir");
 
         // Create the directory <data dir>/penumbra-testnet-archive/<chain id>/<spend key hash prefix>/
-        let spend_key_hash = Sha256::digest(&wallet.spend_key.to_bytes().0);
+        let spend_key_hash = Sha256::digest(&wallet.default_account().spend_key.to_bytes().0);
         let wallet_archive_dir = archive_dir
             .data_dir()
             .join(hex::encode(&spend_key_hash[0..8]));
@@ -59,14 +90,19 @@ impl KeysCmd {
         // Save the wallet file in the archive directory
         let archive_path = wallet_archive_dir.join(crate::CUSTODY_FILE_NAME);
         println!("Saving backup wallet to {}", archive_path.display());
-        wallet.save(archive_path)?;
+        wallet.save(archive_path, passphrase)?;
         Ok(())
     }
 
-    pub fn exec(&self, data_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
+    pub fn exec(
+        &self,
+        data_dir: impl AsRef<camino::Utf8Path>,
+        account: Option<&str>,
+    ) -> Result<()> {
         let data_dir = data_dir.as_ref();
         match self {
-            KeysCmd::Generate => {
+            KeysCmd::Generate { unencrypted } => {
+                let passphrase = new_wallet_passphrase(*unencrypted)?;
                 let seed_phrase = SeedPhrase::generate(OsRng);
 
                 // xxx: Something better should be done here, this is in danger of being
@@ -74,17 +110,66 @@ impl KeysCmd {
                 println!("YOUR PRIVATE SEED PHRASE: {seed_phrase}\nDO NOT SHARE WITH ANYONE!");
 
                 let wallet = KeyStore::from_seed_phrase(seed_phrase);
-                wallet.save(data_dir.join(crate::CUSTODY_FILE_NAME))?;
-                self.archive_wallet(&wallet)?;
+                wallet.save(
+                    data_dir.join(crate::CUSTODY_FILE_NAME),
+                    passphrase.as_deref(),
+                )?;
+                self.archive_wallet(&wallet, passphrase.as_deref())?;
             }
-            KeysCmd::Import(ImportCmd::Phrase { seed_phrase }) => {
+            KeysCmd::Import(ImportCmd::Phrase {
+                seed_phrase,
+                unencrypted,
+            }) => {
                 let wallet = KeyStore::from_seed_phrase(SeedPhrase::from_str(seed_phrase)?);
-                wallet.save(data_dir.join(crate::CUSTODY_FILE_NAME))?;
-                self.archive_wallet(&wallet)?;
+                let passphrase = new_wallet_passphrase(*unencrypted)?;
+                wallet.save(
+                    data_dir.join(crate::CUSTODY_FILE_NAME),
+                    passphrase.as_deref(),
+                )?;
+                self.archive_wallet(&wallet, passphrase.as_deref())?;
+            }
+            KeysCmd::Encrypt => {
+                let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
+                if KeyStore::is_encrypted(&wallet_path)? {
+                    return Err(anyhow!("Wallet file at {wallet_path} is already encrypted"));
+                }
+                let wallet = KeyStore::load(&wallet_path, None)?;
+                let passphrase = passphrase::choose()?;
+                wallet.update(&wallet_path, Some(&passphrase))?;
+                println!("Encrypted wallet file at {wallet_path}");
+                println!(
+                    "Backups made before encrypting the wallet are still in plaintext, and should be deleted."
+                );
             }
             KeysCmd::Export(ExportCmd::FullViewingKey) => {
-                let wallet = KeyStore::load(data_dir.join(crate::CUSTODY_FILE_NAME))?;
-                println!("{}", wallet.spend_key.full_viewing_key());
+                let wallet = load_wallet(data_dir)?;
+                let account = match account {
+                    Some(name) => wallet.account(name)?,
+                    None => wallet.default_account(),
+                };
+                println!("{}", account.spend_key.full_viewing_key());
+            }
+            KeysCmd::Account(AccountCmd::Add { label }) => {
+                let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
+                let passphrase = passphrase::unlock(&wallet_path)?;
+                let mut wallet = KeyStore::load(&wallet_path, passphrase.as_deref())?;
+                let account = wallet.add_account(label.clone())?;
+                println!(
+                    "Added account {:?} at index {}",
+                    account.label, account.index
+                );
+                wallet.update(&wallet_path, passphrase.as_deref())?;
+            }
+            KeysCmd::Account(AccountCmd::List) => {
+                let wallet = load_wallet(data_dir)?;
+                for account in wallet.accounts() {
+                    println!(
+                        "{}\t{}\t{}",
+                        account.index,
+                        account.label,
+                        account.spend_key.full_viewing_key().account_group_id()
+                    );
+                }
             }
             KeysCmd::Delete => {
                 let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
@@ -108,3 +193,20 @@ impl KeysCmd {
         Ok(())
     }
 }
+
+/// Load the wallet in the data directory, unlocking it if it's encrypted.
+fn load_wallet(data_dir: &camino::Utf8Path) -> Result<KeyStore> {
+    let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
+    let passphrase = passphrase::unlock(&wallet_path)?;
+    KeyStore::load(&wallet_path, passphrase.as_deref())
+}
+
+/// Choose the passphrase for a new wallet file, unless it's to be stored `unencrypted`.
+fn new_wallet_passphrase(unencrypted: bool) -> Result<Option<String>> {
+    if unencrypted {
+        println!("WARNING: the wallet will be stored unencrypted.");
+        Ok(None)
+    } else {
+        passphrase::choose().map(Some)
+    }
+}
@@ -12,6 +12,8 @@ mod governance;
 use governance::GovernanceCmd;
 mod dao;
 use dao::DaoCmd;
+mod ibc;
+use self::ibc::IbcCmd;
 mod validator;
 pub(super) use validator::ValidatorCmd;
 
@@ -56,6 +58,9 @@ pub enum QueryCmd {
     /// Queries information about the decentralized exchange.
     #[clap(subcommand)]
     Dex(DexCmd),
+    /// Queries information about IBC clients, connections, channels and packets.
+    #[clap(subcommand)]
+    Ibc(IbcCmd),
 }
 
 impl QueryCmd {
@@ -85,13 +90,18 @@ impl QueryCmd {
             return dao.exec(app).await;
         }
 
+        if let QueryCmd::Ibc(ibc) = self {
+            return ibc.exec(app).await;
+        }
+
         let key = match self {
             QueryCmd::Tx(_)
             | QueryCmd::Chain(_)
             | QueryCmd::Validator(_)
             | QueryCmd::Dex(_)
             | QueryCmd::Governance(_)
-            | QueryCmd::Dao(_) => {
+            | QueryCmd::Dao(_)
+            | QueryCmd::Ibc(_) => {
                 unreachable!("query handled in guard");
             }
             QueryCmd::ShieldedPool(p) => p.key().clone(),
@@ -123,7 +133,8 @@ impl QueryCmd {
             | QueryCmd::Validator { .. }
             | QueryCmd::Dex { .. }
             | QueryCmd::Governance { .. }
-            | QueryCmd::Dao { .. } => {
+            | QueryCmd::Dao { .. }
+            | QueryCmd::Ibc { .. } => {
                 unreachable!("query is special cased")
             }
         }
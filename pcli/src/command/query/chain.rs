@@ -74,6 +74,18 @@ impl ChainCmd {
                 "Missed Blocks Max",
                 &format!("{}", params.missed_blocks_maximum),
             ])
+            .add_row(vec![
+                "Min Jail Duration (blocks)",
+                &format!("{}", params.min_jail_duration),
+            ])
+            .add_row(vec![
+                "Max Validator Commission (bps)",
+                &format!("{}", params.max_validator_commission_bps),
+            ])
+            .add_row(vec![
+                "Max Commission Increase per Epoch (bps)",
+                &format!("{}", params.max_commission_increase_bps_per_epoch),
+            ])
             .add_row(vec!["IBC Enabled", &format!("{}", params.ibc_enabled)])
             .add_row(vec![
                 "Inbound ICS-20 Enabled",
@@ -6,9 +6,11 @@ use std::{
 
 use anyhow::{Context, Result};
 use futures::{StreamExt, TryStreamExt};
-use penumbra_component::governance::{self, state_key::*};
+use penumbra_component::governance::{self, state_key::*, ProposalEvent, ProposalEventKind};
 use penumbra_crypto::stake::IdentityKey;
-use penumbra_proto::client::v1alpha1::{PrefixValueRequest, PrefixValueResponse};
+use penumbra_proto::client::v1alpha1::{
+    PrefixValueRequest, PrefixValueResponse, ProposalEventsRequest, ProposalEventsResponse,
+};
 use penumbra_transaction::{
     proposal::{self, Proposal},
     vote::Vote,
@@ -35,6 +37,13 @@ pub enum GovernanceCmd {
         #[clap(subcommand)]
         query: PerProposalCmd,
     },
+    /// Watch for proposals being submitted, voted on, and concluded, printing each event as it
+    /// happens.
+    Watch {
+        /// Replay the events since this block height before watching for new ones.
+        #[clap(long)]
+        since: Option<u64>,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -93,6 +102,53 @@ impl GovernanceCmd {
                     )?;
                 }
             }
+            GovernanceCmd::Watch { since } => {
+                let mut events = client
+                    .proposal_events(ProposalEventsRequest {
+                        chain_id: app.view().chain_params().await?.chain_id,
+                        start_height: since.unwrap_or_default(),
+                    })
+                    .await?
+                    .into_inner();
+
+                while let Some(ProposalEventsResponse { height, event }) = events.message().await? {
+                    let ProposalEvent { proposal_id, kind } = event
+                        .context("missing event in proposal event response")?
+                        .try_into()?;
+                    let description = match kind {
+                        ProposalEventKind::Submitted {
+                            title,
+                            voting_end_height,
+                            ..
+                        } => format!(
+                            "submitted \"{title}\", voting until height {voting_end_height}"
+                        ),
+                        ProposalEventKind::Tally(tally) => format!(
+                            "tally is now {} yes, {} no, {} abstain",
+                            tally.yes(),
+                            tally.no(),
+                            tally.abstain()
+                        ),
+                        ProposalEventKind::State(proposal::State::Withdrawn { reason }) => {
+                            format!("withdrawn: {reason}")
+                        }
+                        ProposalEventKind::State(proposal::State::Claimed { .. }) => {
+                            "deposit claimed".to_string()
+                        }
+                        ProposalEventKind::State(state) if state.is_passed() => {
+                            "passed".to_string()
+                        }
+                        ProposalEventKind::State(state) if state.is_slashed() => {
+                            "slashed".to_string()
+                        }
+                        ProposalEventKind::State(state) if state.is_failed() => {
+                            "failed".to_string()
+                        }
+                        ProposalEventKind::State(state) => format!("{state:?}"),
+                    };
+                    println!("[height {height}] #{proposal_id} {description}");
+                }
+            }
             GovernanceCmd::Proposal { proposal_id, query } => match query {
                 Definition => {
                     let proposal: Proposal =
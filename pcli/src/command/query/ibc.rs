@@ -0,0 +1,242 @@
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use futures::TryStreamExt;
+use ibc::{
+    clients::ics07_tendermint::client_state::ClientState as TendermintClientState,
+    core::ics02_client::client_state::ClientState as _,
+};
+use ibc_proto::ibc::core::{channel::v1 as channel, connection::v1 as connection};
+use penumbra_crypto::Value;
+use penumbra_proto::client::v1alpha1::{
+    IbcChannelSequencesRequest, IbcChannelsRequest, IbcClientsRequest, IbcConnectionsRequest,
+    IbcPacketCommitmentsRequest, Ics20EscrowRequest,
+};
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum IbcCmd {
+    /// List the IBC light clients tracked by the chain.
+    Clients,
+    /// List the IBC connections known to the chain.
+    Connections,
+    /// List the IBC channels known to the chain.
+    Channels,
+    /// List the outstanding packet commitments on a channel.
+    ///
+    /// These are packets sent by this chain that have been neither acknowledged nor timed out.
+    PacketCommitments {
+        /// The channel to inspect, e.g. `channel-0`.
+        channel: String,
+        /// The port the channel is bound to.
+        #[clap(long, default_value = "transfer")]
+        port: String,
+    },
+    /// Display the send, receive and acknowledgement sequence numbers of a channel.
+    ///
+    /// The chain doesn't store packet acknowledgements themselves: use `packet-commitments` to see
+    /// which sent packets are still waiting to be acknowledged.
+    Sequences {
+        /// The channel to inspect, e.g. `channel-0`.
+        channel: String,
+        /// The port the channel is bound to.
+        #[clap(long, default_value = "transfer")]
+        port: String,
+    },
+    /// Display the value escrowed by outbound ICS-20 transfers.
+    Escrow {
+        /// Only display the escrow for this channel.
+        channel: Option<String>,
+    },
+}
+
+impl IbcCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let chain_id = app.view().chain_params().await?.chain_id;
+        let mut client = app.specific_client().await?;
+
+        match self {
+            IbcCmd::Clients => {
+                let clients: Vec<_> = client
+                    .ibc_clients(IbcClientsRequest { chain_id })
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec![
+                    "Client ID",
+                    "Counterparty Chain ID",
+                    "Latest Height",
+                    "Frozen",
+                ]);
+                for rsp in clients {
+                    let identified = rsp
+                        .client_state
+                        .context("missing client state in response")?;
+                    let client_state = TendermintClientState::try_from(
+                        identified
+                            .client_state
+                            .context("missing client state in response")?,
+                    )?;
+                    table.add_row(vec![
+                        identified.client_id,
+                        client_state.chain_id().to_string(),
+                        client_state.latest_height().to_string(),
+                        client_state.is_frozen().to_string(),
+                    ]);
+                }
+                println!("{table}");
+            }
+            IbcCmd::Connections => {
+                let connections: Vec<_> = client
+                    .ibc_connections(IbcConnectionsRequest { chain_id })
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec![
+                    "Connection ID",
+                    "State",
+                    "Client ID",
+                    "Counterparty Client ID",
+                    "Counterparty Connection ID",
+                ]);
+                for rsp in connections {
+                    let conn = rsp.connection.context("missing connection in response")?;
+                    let counterparty = conn.counterparty.unwrap_or_default();
+                    table.add_row(vec![
+                        conn.id,
+                        connection::State::from_i32(conn.state)
+                            .map(|state| format!("{state:?}"))
+                            .unwrap_or_else(|| "Unknown".to_string()),
+                        conn.client_id,
+                        counterparty.client_id,
+                        counterparty.connection_id,
+                    ]);
+                }
+                println!("{table}");
+            }
+            IbcCmd::Channels => {
+                let channels: Vec<_> = client
+                    .ibc_channels(IbcChannelsRequest { chain_id })
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec![
+                    "Port ID",
+                    "Channel ID",
+                    "State",
+                    "Connection",
+                    "Counterparty Port ID",
+                    "Counterparty Channel ID",
+                ]);
+                for rsp in channels {
+                    let chan = rsp.channel.context("missing channel in response")?;
+                    let counterparty = chan.counterparty.unwrap_or_default();
+                    table.add_row(vec![
+                        chan.port_id,
+                        chan.channel_id,
+                        channel::State::from_i32(chan.state)
+                            .map(|state| format!("{state:?}"))
+                            .unwrap_or_else(|| "Unknown".to_string()),
+                        chan.connection_hops.join(","),
+                        counterparty.port_id,
+                        counterparty.channel_id,
+                    ]);
+                }
+                println!("{table}");
+            }
+            IbcCmd::PacketCommitments { channel, port } => {
+                let commitments: Vec<_> = client
+                    .ibc_packet_commitments(IbcPacketCommitmentsRequest {
+                        chain_id,
+                        port_id: port.clone(),
+                        channel_id: channel.clone(),
+                    })
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+                println!(
+                    "{} outstanding packet commitments on {port}/{channel}",
+                    commitments.len()
+                );
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Sequence", "Commitment"]);
+                for rsp in commitments {
+                    let commitment = rsp.commitment.context("missing commitment in response")?;
+                    table.add_row(vec![
+                        commitment.sequence.to_string(),
+                        hex::encode(commitment.data),
+                    ]);
+                }
+                println!("{table}");
+            }
+            IbcCmd::Sequences { channel, port } => {
+                let sequences = client
+                    .ibc_channel_sequences(IbcChannelSequencesRequest {
+                        chain_id,
+                        port_id: port.clone(),
+                        channel_id: channel.clone(),
+                    })
+                    .await?
+                    .into_inner();
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table
+                    .set_header(vec!["", ""])
+                    .add_row(vec![
+                        "Next Sequence Send".to_string(),
+                        sequences.next_sequence_send.to_string(),
+                    ])
+                    .add_row(vec![
+                        "Next Sequence Recv".to_string(),
+                        sequences.next_sequence_recv.to_string(),
+                    ])
+                    .add_row(vec![
+                        "Next Sequence Ack".to_string(),
+                        sequences.next_sequence_ack.to_string(),
+                    ]);
+                println!("{table}");
+            }
+            IbcCmd::Escrow { channel } => {
+                let balances: Vec<_> = client
+                    .ics20_escrow(Ics20EscrowRequest {
+                        chain_id,
+                        channel_id: channel.clone().unwrap_or_default(),
+                    })
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+                let asset_cache = app.view().assets().await?;
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Channel ID", "Escrowed"]);
+                for rsp in balances {
+                    let value: Value =
+                        rsp.value.context("missing value in response")?.try_into()?;
+                    table.add_row(vec![rsp.channel_id, value.format(&asset_cache)]);
+                }
+                println!("{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -3,9 +3,13 @@ use std::{fs::File, io::Write};
 use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
-use penumbra_component::stake::validator::{self, ValidatorToml};
+use penumbra_component::stake::{
+    rate::RateData,
+    validator::{self, ValidatorToml},
+    Uptime,
+};
 use penumbra_crypto::stake::IdentityKey;
-use penumbra_proto::client::v1alpha1::ValidatorInfoRequest;
+use penumbra_proto::client::v1alpha1::{ValidatorInfoRequest, ValidatorUptimeRequest};
 
 use crate::App;
 
@@ -29,6 +33,14 @@ pub enum ValidatorCmd {
         /// The identity key of the validator to fetch.
         identity_key: String,
     },
+    /// Show a validator's recent signing performance and rate history.
+    Uptime {
+        /// The identity key of the validator to query.
+        identity_key: String,
+        /// The number of most recent epochs of rate history to show.
+        #[clap(long, default_value = "10")]
+        epochs: u64,
+    },
 }
 
 impl ValidatorCmd {
@@ -203,6 +215,102 @@ impl ValidatorCmd {
                     println!("{}", toml::to_string_pretty(&validator)?);
                 }
             }
+            ValidatorCmd::Uptime {
+                identity_key,
+                epochs,
+            } => {
+                let identity_key = identity_key.parse::<IdentityKey>()?;
+
+                let mut client = app.specific_client().await?;
+                let response = client
+                    .validator_uptime(ValidatorUptimeRequest {
+                        chain_id: app.view().chain_params().await?.chain_id,
+                        identity_key: Some(identity_key.clone().into()),
+                        rate_history_epochs: *epochs,
+                    })
+                    .await?
+                    .into_inner();
+
+                let state: validator::State = response
+                    .state
+                    .ok_or_else(|| anyhow::anyhow!("missing validator state"))?
+                    .try_into()?;
+                let uptime = response.uptime.map(Uptime::try_from).transpose()?;
+                let rate_history = response
+                    .rate_history
+                    .into_iter()
+                    .map(RateData::try_from)
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.add_row(vec!["Validator".to_string(), identity_key.to_string()]);
+                table.add_row(vec!["State".to_string(), state.to_string()]);
+                if let Some(uptime) = uptime {
+                    let window_len = uptime.window_len() as u64;
+                    let missed_blocks = response.missed_blocks;
+                    let signing_rate = if window_len == 0 {
+                        100.0
+                    } else {
+                        100.0 * (window_len - missed_blocks) as f64 / window_len as f64
+                    };
+                    table.add_row(vec![
+                        "Signing Window".to_string(),
+                        format!(
+                            "{window_len} blocks, as of height {}",
+                            uptime.as_of_block_height()
+                        ),
+                    ]);
+                    table.add_row(vec![
+                        "Missed Blocks".to_string(),
+                        format!("{missed_blocks} / {window_len}"),
+                    ]);
+                    table.add_row(vec![
+                        "Signing Rate".to_string(),
+                        format!("{signing_rate:.2}%"),
+                    ]);
+                    // Only active validators can be jailed for missing blocks.
+                    let jail_risk = if state != validator::State::Active {
+                        "none (validator is not active)".to_string()
+                    } else if response.missed_blocks_maximum == 0 {
+                        // There's no share of the maximum to report when no missed
+                        // blocks are tolerated at all.
+                        "high (no missed blocks are tolerated)".to_string()
+                    } else {
+                        let remaining =
+                            response.missed_blocks_maximum.saturating_sub(missed_blocks);
+                        let usage = missed_blocks as f64 / response.missed_blocks_maximum as f64;
+                        let level = if usage >= 0.75 {
+                            "high"
+                        } else if usage >= 0.25 {
+                            "medium"
+                        } else {
+                            "low"
+                        };
+                        format!("{level} ({remaining} more missed blocks until jailed)")
+                    };
+                    table.add_row(vec!["Jail Risk".to_string(), jail_risk]);
+                } else {
+                    table.add_row(vec![
+                        "Signing Rate".to_string(),
+                        "not tracked (validator has not been active)".to_string(),
+                    ]);
+                }
+                println!("{table}");
+
+                let mut rates = Table::new();
+                rates.load_preset(presets::NOTHING);
+                rates.set_header(vec!["Epoch", "Reward Rate", "Exchange Rate"]);
+                for rate_data in rate_history.iter().rev() {
+                    rates.add_row(vec![
+                        rate_data.epoch_index.to_string(),
+                        // Rates are represented in units of 1e-8.
+                        format!("{:.6}%", rate_data.validator_reward_rate as f64 / 1e6),
+                        format!("{:.8}", rate_data.validator_exchange_rate as f64 / 1e8),
+                    ]);
+                }
+                println!("{rates}");
+            }
         }
 
         Ok(())
@@ -2,13 +2,21 @@ use std::{
     collections::BTreeMap,
     fs::File,
     io::{Read, Write},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::{anyhow, Context, Result};
-use ark_ff::UniformRand;
-use decaf377::Fr;
-use penumbra_chain::Epoch;
-use penumbra_component::stake::rate::RateData;
+use ibc::{
+    clients::ics07_tendermint::client_state::ClientState as TendermintClientState,
+    core::{
+        ics02_client::client_state::ClientState as _,
+        ics03_connection::connection::ConnectionEnd,
+        ics04_channel::channel::ChannelEnd,
+        ics24_host::identifier::{ChannelId, PortId},
+    },
+};
+use penumbra_component::{ibc::state_key as ibc_state_key, stake::rate::RateData};
 use penumbra_crypto::{
     asset,
     dex::{
@@ -17,17 +25,18 @@ use penumbra_crypto::{
     },
     keys::AddressIndex,
     memo::MemoPlaintext,
-    stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken},
+    stake::{DelegationToken, IdentityKey},
     transaction::Fee,
     Amount, Value, STAKING_TOKEN_ASSET_ID,
 };
 use penumbra_proto::client::v1alpha1::{
-    ProposalInfoRequest, ProposalInfoResponse, ProposalRateDataRequest, ValidatorPenaltyRequest,
+    ProposalInfoRequest, ProposalInfoResponse, ProposalRateDataRequest,
 };
 use penumbra_transaction::{
-    plan::{SwapClaimPlan, UndelegateClaimPlan},
+    action::Ics20Withdrawal,
+    plan::SwapClaimPlan,
     proposal::ProposalToml,
-    vote::Vote,
+    vote::{Vote, VoteWeights},
 };
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
@@ -63,6 +72,34 @@ pub enum TxCmd {
         #[clap(long)]
         memo: Option<String>,
     },
+    /// Withdraw funds from Penumbra to an address on a counterparty chain, over IBC.
+    #[clap(display_order = 150)]
+    Withdraw {
+        /// The address on the counterparty chain to send the withdrawn funds to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// The value to withdraw, written as a typed value 1.87penumbra, 12cubes, etc.
+        value: String,
+        /// The IBC channel on Penumbra to withdraw over, e.g. `channel-0`.
+        #[clap(long, display_order = 150)]
+        channel: String,
+        /// The height on the counterparty chain after which the withdrawal times out.
+        ///
+        /// Defaults to 1000 blocks past the latest counterparty height known to Penumbra.
+        #[clap(long, display_order = 160)]
+        timeout_height: Option<u64>,
+        /// The time (in nanoseconds since the Unix epoch) after which the withdrawal times out.
+        ///
+        /// Defaults to two days from now.
+        #[clap(long, display_order = 170)]
+        timeout_timestamp: Option<u64>,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0", display_order = 200)]
+        fee: u64,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
     Delegate {
@@ -90,6 +127,25 @@ pub enum TxCmd {
         #[clap(long, default_value = "0", display_order = 300)]
         source: u32,
     },
+    /// Move stake from one validator's delegation pool to another's, without waiting for it to
+    /// unbond.
+    ///
+    /// The redelegated stake remains liable for slashing of the original validator until the end
+    /// of the unbonding period, after which it can be claimed with `redelegate-claim`.
+    #[clap(display_order = 200)]
+    Redelegate {
+        /// The identity key of the validator to redelegate to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// The amount of delegation tokens to redelegate.
+        amount: String,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0", display_order = 200)]
+        fee: u64,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
     /// Claim any undelegations that have finished unbonding.
     #[clap(display_order = 200)]
     UndelegateClaim {
@@ -97,6 +153,13 @@ pub enum TxCmd {
         #[clap(long, default_value = "0")]
         fee: u64,
     },
+    /// Claim any redelegations that are no longer liable for slashing of the original validator.
+    #[clap(display_order = 200)]
+    RedelegateClaim {
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+    },
     /// Swap tokens of one denomination for another using the DEX.
     ///
     /// Swaps are batched and executed at the market-clearing price.
@@ -122,6 +185,8 @@ pub enum TxCmd {
         source: u32,
     },
     /// Vote on a governance proposal in your role as a delegator (see also: `pcli validator vote`).
+    ///
+    /// Voting again on a proposal that is still open replaces your previous vote.
     #[clap(display_order = 400)]
     Vote {
         /// The transaction fee (paid in upenumbra).
@@ -131,6 +196,9 @@ pub enum TxCmd {
         /// account.
         #[clap(long, default_value = "0", global = true, display_order = 300)]
         source: u32,
+        /// Optional. A rationale for the vote, recorded in the transaction's encrypted memo.
+        #[clap(long, global = true, display_order = 400)]
+        reason: Option<String>,
         #[clap(subcommand)]
         vote: VoteCmd,
     },
@@ -188,15 +256,47 @@ pub enum VoteCmd {
         #[clap(long = "on")]
         proposal_id: u64,
     },
+    /// Split voting power between options, e.g. `--yes 60 --no 40`.
+    ///
+    /// The percentages must add up to 100.
+    #[clap(display_order = 400)]
+    Split {
+        /// The proposal ID to vote on.
+        #[clap(long = "on")]
+        proposal_id: u64,
+        /// The percentage of voting power in favor of the proposal.
+        #[clap(long, default_value = "0", parse(try_from_str = VoteWeights::parse_percentage))]
+        yes: u16,
+        /// The percentage of voting power against the proposal.
+        #[clap(long, default_value = "0", parse(try_from_str = VoteWeights::parse_percentage))]
+        no: u16,
+        /// The percentage of voting power abstaining from the proposal.
+        #[clap(long, default_value = "0", parse(try_from_str = VoteWeights::parse_percentage))]
+        abstain: u16,
+    },
 }
 
-impl From<VoteCmd> for (u64, Vote) {
-    fn from(cmd: VoteCmd) -> (u64, Vote) {
-        match cmd {
+impl TryFrom<VoteCmd> for (u64, Vote) {
+    type Error = anyhow::Error;
+
+    fn try_from(cmd: VoteCmd) -> Result<(u64, Vote)> {
+        Ok(match cmd {
             VoteCmd::Yes { proposal_id } => (proposal_id, Vote::Yes),
             VoteCmd::No { proposal_id } => (proposal_id, Vote::No),
             VoteCmd::Abstain { proposal_id } => (proposal_id, Vote::Abstain),
-        }
+            VoteCmd::Split {
+                proposal_id,
+                yes,
+                no,
+                abstain,
+            } => (
+                proposal_id,
+                Vote::Weighted(
+                    VoteWeights::new(yes, no, abstain)
+                        .context("split vote percentages must add up to 100")?,
+                ),
+            ),
+        })
     }
 }
 
@@ -205,11 +305,14 @@ impl TxCmd {
     pub fn offline(&self) -> bool {
         match self {
             TxCmd::Send { .. } => false,
+            TxCmd::Withdraw { .. } => false,
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
+            TxCmd::Redelegate { .. } => false,
             TxCmd::UndelegateClaim { .. } => false,
+            TxCmd::RedelegateClaim { .. } => false,
             TxCmd::Vote { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
             TxCmd::DaoDeposit { .. } => false,
@@ -259,6 +362,90 @@ impl TxCmd {
                 .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Withdraw {
+                to,
+                value,
+                channel,
+                timeout_height,
+                timeout_timestamp,
+                fee,
+                source,
+            } => {
+                let value = value.parse::<Value>()?;
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let denom = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&value.asset_id)
+                    .ok_or_else(|| anyhow!("unknown asset id {}", value.asset_id))?
+                    .clone();
+
+                let source_channel = ChannelId::from_str(channel)
+                    .with_context(|| format!("invalid channel id {channel}"))?;
+                let source_port = PortId::transfer();
+
+                // Follow the channel to the client tracking the counterparty chain, so we can fill
+                // in its chain ID and pick a sensible default timeout height.
+                let mut client = app.specific_client().await?;
+                let channel_end: ChannelEnd = client
+                    .key_domain(ibc_state_key::channel(&source_channel, &source_port))
+                    .await
+                    .with_context(|| format!("could not find channel {source_channel}"))?;
+                let connection_id = channel_end
+                    .connection_hops
+                    .first()
+                    .ok_or_else(|| anyhow!("channel {} has no connection", source_channel))?;
+                let connection: ConnectionEnd = client
+                    .key_domain(ibc_state_key::connections::by_connection_id(connection_id))
+                    .await
+                    .with_context(|| format!("could not find connection {connection_id}"))?;
+                let client_state: TendermintClientState = client
+                    .key_domain(ibc_state_key::client_state(connection.client_id()))
+                    .await
+                    .with_context(|| format!("could not find client {}", connection.client_id()))?;
+
+                let timeout_height = timeout_height
+                    .unwrap_or_else(|| client_state.latest_height().revision_height() + 1000);
+                let timeout_time = match timeout_timestamp {
+                    Some(timestamp) => *timestamp,
+                    None => {
+                        let two_days = Duration::from_secs(2 * 24 * 60 * 60);
+                        (SystemTime::now().duration_since(UNIX_EPOCH)? + two_days).as_nanos() as u64
+                    }
+                };
+
+                // Any funds returned to us by a timeout should go to an ephemeral address, so that
+                // the withdrawal can't be linked to our other activity.
+                let (return_address, _dtk) =
+                    app.fvk.ephemeral_address(OsRng, AddressIndex::new(*source));
+
+                let withdrawal = Ics20Withdrawal {
+                    destination_chain_id: client_state.chain_id.to_string(),
+                    denom,
+                    amount: value.amount,
+                    destination_chain_address: to.clone(),
+                    return_address,
+                    timeout_height,
+                    timeout_time,
+                    source_port,
+                    source_channel,
+                };
+                withdrawal.validate()?;
+
+                let plan = Planner::new(OsRng)
+                    .ics20_withdrawal(withdrawal)
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        app.fvk.account_group_id(),
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't plan withdrawal")?;
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::DaoDeposit {
                 fee,
                 values,
@@ -494,96 +681,128 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Redelegate {
+                to,
+                amount,
+                fee,
+                source,
+            } => {
+                let delegation_value @ Value {
+                    amount: _,
+                    asset_id,
+                } = amount.parse::<Value>()?;
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let delegation_token: DelegationToken = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&asset_id)
+                    .ok_or_else(|| anyhow::anyhow!("unknown asset id {}", asset_id))?
+                    .clone()
+                    .try_into()
+                    .context("could not parse supplied denomination as a delegation token")?;
+
+                let from = delegation_token.validator();
+                let to = to.parse::<IdentityKey>()?;
+                if from == to {
+                    return Err(anyhow!("cannot redelegate to the same validator"));
+                }
+
+                let mut client = app.specific_client().await?;
+                let from_rate_data: RateData = client
+                    .next_validator_rate(tonic::Request::new(from.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                let to_rate_data: RateData = client
+                    .next_validator_rate(tonic::Request::new(to.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+
+                let params = app.view.as_mut().unwrap().chain_params().await?;
+
+                // The redelegated stake stays liable for slashing of the source validator until
+                // undelegating from it would have completed.
+                let end_epoch_index = from_rate_data.epoch_index + params.unbonding_epochs;
+
+                let mut planner = Planner::new(OsRng);
+
+                let plan = planner
+                    .fee(fee)
+                    .redelegate(
+                        delegation_value.amount,
+                        from_rate_data,
+                        to_rate_data,
+                        end_epoch_index,
+                    )
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        app.fvk.account_group_id(),
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't build redelegate plan")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::UndelegateClaim { fee } => {
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
                 let account_group_id = app.fvk.account_group_id(); // this should be optional? or saved in the client statefully?
 
-                let mut specific_client = app.specific_client().await?;
-                let view: &mut dyn ViewClient = app.view.as_mut().unwrap();
-
-                let params = view.chain_params().await?;
-                let current_height = view.status(account_group_id).await?.sync_height;
-                let current_epoch = Epoch::from_height(current_height, params.epoch_duration);
-                let asset_cache = view.assets().await?;
+                // Report the undelegations that aren't ready to be claimed yet.
+                for unbonding in app
+                    .view()
+                    .unbondings(account_group_id, false)
+                    .await?
+                    .into_iter()
+                    .filter(|unbonding| !unbonding.claimable)
+                {
+                    println!(
+                        "skipping {} because it is not yet ready to be claimed",
+                        unbonding.token().default_unit(),
+                    );
+                }
 
-                // Query the view client for the list of undelegations that are ready to be claimed.
-                // We want to claim them into the same address index that currently holds the tokens.
-                let notes = view
-                    .unspent_notes_by_address_and_asset(account_group_id)
-                    .await?;
+                // Claim each undelegation that's ready, into the same address
+                // index that currently holds the unbonding tokens.
+                println!("claiming undelegations that are ready...");
+                let claimed = penumbra_wallet::claim_undelegations(
+                    &app.fvk,
+                    app.view.as_mut().unwrap(),
+                    &mut app.custody,
+                    OsRng,
+                    fee,
+                )
+                .await?;
+                for (token, id) in claimed {
+                    println!("claimed {} in transaction {}", token.default_unit(), id);
+                }
+            }
+            TxCmd::RedelegateClaim { fee } => {
+                let fee = Fee::from_staking_token_amount((*fee).into());
 
-                for (address_index, notes_by_asset) in notes.into_iter() {
-                    for (token, notes) in notes_by_asset
-                        .into_iter()
-                        .filter_map(|(asset_id, notes)| {
-                            // Filter for notes that are unbonding tokens.
-                            let denom = asset_cache.get(&asset_id).unwrap().clone();
-                            match UnbondingToken::try_from(denom) {
-                                Ok(token) => Some((token, notes)),
-                                Err(_) => None,
-                            }
-                        })
-                        .filter_map(|(token, notes)| {
-                            // Filter for notes that are ready to be claimed.
-                            if token.end_epoch_index() <= current_epoch.index {
-                                Some((token, notes))
-                            } else {
-                                println!(
-                                    "skipping {} because it is not yet ready to be claimed",
-                                    token.denom().default_unit(),
-                                );
-                                None
-                            }
-                        })
-                    {
-                        println!("claiming {}", token.denom().default_unit());
-                        let validator_identity = token.validator();
-                        let start_epoch_index = token.start_epoch_index();
-                        let end_epoch_index = token.end_epoch_index();
-
-                        let penalty: Penalty = specific_client
-                            .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
-                                chain_id: params.chain_id.to_string(),
-                                identity_key: Some(validator_identity.into()),
-                                start_epoch_index,
-                                end_epoch_index,
-                            }))
-                            .await?
-                            .into_inner()
-                            .penalty
-                            .ok_or_else(|| {
-                                anyhow::anyhow!(
-                                    "no penalty returned for validator {}",
-                                    validator_identity
-                                )
-                            })?
-                            .try_into()?;
-
-                        let mut planner = Planner::new(OsRng);
-                        let unbonding_amount = notes.iter().map(|n| n.note.amount()).sum();
-                        for note in notes {
-                            planner.spend(note.note, note.position);
-                        }
-
-                        let plan = planner
-                            .undelegate_claim(UndelegateClaimPlan {
-                                validator_identity,
-                                start_epoch_index,
-                                end_epoch_index,
-                                penalty,
-                                unbonding_amount,
-                                balance_blinding: Fr::rand(&mut OsRng),
-                            })
-                            .fee(fee.clone())
-                            .plan(
-                                app.view.as_mut().unwrap(),
-                                app.fvk.account_group_id(),
-                                address_index,
-                            )
-                            .await?;
-                        app.build_and_submit_transaction(plan).await?;
+                // Claim each redelegation that's ready, into the same address
+                // index that currently holds the redelegation tokens.
+                let specific_client = app.specific_client().await?;
+                let plans = plan::redelegate_claims(
+                    app.fvk.account_group_id(),
+                    app.view.as_mut().unwrap(),
+                    OsRng,
+                    fee,
+                    specific_client,
+                )
+                .await?;
+                if plans.is_empty() {
+                    println!("no redelegations are ready to be claimed");
+                }
+                for plan in plans {
+                    for claim in plan.redelegate_claims() {
+                        println!("claiming {}", claim.redelegation_token().default_unit());
                     }
+                    app.build_and_submit_transaction(plan).await?;
                 }
             }
             TxCmd::Proposal(ProposalCmd::Submit { file, fee, source }) => {
@@ -696,8 +915,13 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Vote { vote, fee, source } => {
-                let (proposal_id, vote): (u64, Vote) = (*vote).into();
+            TxCmd::Vote {
+                vote,
+                fee,
+                source,
+                reason,
+            } => {
+                let (proposal_id, vote): (u64, Vote) = (*vote).try_into()?;
 
                 // Before we vote on the proposal, we have to gather some information about it so
                 // that we can prepare our vote:
@@ -743,7 +967,19 @@ impl TxCmd {
 
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
-                let plan = Planner::new(OsRng)
+                let mut planner = Planner::new(OsRng);
+                if let Some(reason) = reason {
+                    let memo_ephemeral_address = app
+                        .fvk
+                        .ephemeral_address(OsRng, AddressIndex::new(*source))
+                        .0;
+                    planner.memo(MemoPlaintext {
+                        sender: memo_ephemeral_address,
+                        text: reason.clone(),
+                    })?;
+                }
+
+                let plan = planner
                     .delegator_vote(
                         proposal_id,
                         start_block_height,
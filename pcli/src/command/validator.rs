@@ -5,13 +5,15 @@ use std::{
 
 use anyhow::{Context, Result};
 use penumbra_component::stake::{
-    validator,
+    state_key, validator,
     validator::{Validator, ValidatorToml},
     FundingStream, FundingStreams,
 };
 use penumbra_crypto::{keys::AddressIndex, stake::IdentityKey, transaction::Fee, GovernanceKey};
 use penumbra_proto::{core::stake::v1alpha1::Validator as ProtoValidator, DomainType, Message};
-use penumbra_transaction::action::{ValidatorVote, ValidatorVoteBody, Vote};
+use penumbra_transaction::action::{
+    Unjail, UnjailBody, ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, Vote,
+};
 use penumbra_wallet::plan;
 use rand_core::OsRng;
 use serde_json::Value;
@@ -30,6 +32,8 @@ pub enum ValidatorCmd {
     #[clap(subcommand)]
     Definition(DefinitionCmd),
     /// Cast a vote on a proposal in your capacity as a validator (see also: `pcli tx vote`).
+    ///
+    /// Voting again on a proposal that is still open replaces your previous vote.
     Vote {
         /// The transaction fee (paid in upenumbra).
         #[clap(long, default_value = "0", global = true, display_order = 200)]
@@ -37,10 +41,22 @@ pub enum ValidatorCmd {
         /// Optional. Only spend funds originally received by the given account.
         #[clap(long, default_value = "0", global = true, display_order = 300)]
         source: u32,
+        /// Optional. A justification for the vote, published alongside it.
+        #[clap(long, default_value = "", global = true, display_order = 400)]
+        reason: String,
         /// The vote to cast.
         #[clap(subcommand)]
         vote: super::tx::VoteCmd,
     },
+    /// Release your validator from jail, once it has been jailed for the minimum jail duration.
+    Unjail {
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Optional. Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -90,12 +106,13 @@ impl ValidatorCmd {
                 DefinitionCmd::Template { .. } | DefinitionCmd::Fetch { .. },
             ) => true,
             ValidatorCmd::Vote { .. } => false,
+            ValidatorCmd::Unjail { .. } => false,
         }
     }
 
     // TODO: move use of sk into custody service
     pub async fn exec(&self, app: &mut App) -> Result<()> {
-        let sk = app.wallet.spend_key.clone();
+        let sk = app.account.spend_key.clone();
         let fvk = sk.full_viewing_key().clone();
         match self {
             ValidatorCmd::Identity { base64 } => {
@@ -157,13 +174,18 @@ impl ValidatorCmd {
                 // never appear on-chain.
                 println!("Uploaded validator definition");
             }
-            ValidatorCmd::Vote { fee, source, vote } => {
+            ValidatorCmd::Vote {
+                fee,
+                source,
+                vote,
+                reason,
+            } => {
                 // TODO: support submitting a separate governance key.
                 let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
                 // Currently this is always just copied from the identity key
                 let governance_key = GovernanceKey(identity_key.0);
 
-                let (proposal, vote): (u64, Vote) = (*vote).into();
+                let (proposal, vote): (u64, Vote) = (*vote).try_into()?;
 
                 // Construct the vote body
                 let body = ValidatorVoteBody {
@@ -171,6 +193,7 @@ impl ValidatorCmd {
                     vote,
                     identity_key,
                     governance_key,
+                    reason: ValidatorVoteReason(reason.clone()),
                 };
 
                 // TODO: support signing with a separate governance key
@@ -200,6 +223,45 @@ impl ValidatorCmd {
 
                 println!("Cast validator vote");
             }
+            ValidatorCmd::Unjail { fee, source } => {
+                let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
+
+                // The unjailing is bound to the height at which the validator was jailed.
+                let jailed_at_height: u64 = app
+                    .specific_client()
+                    .await?
+                    .key_proto(state_key::jailed_at_height_by_validator(&identity_key))
+                    .await
+                    .context("could not find the height at which the validator was jailed")?;
+
+                let body = UnjailBody {
+                    identity_key,
+                    jailed_at_height,
+                };
+
+                // Generate an authorizing signature with the identity key for the unjail body
+                let body_bytes = body.encode_to_vec();
+                let auth_sig = sk.spend_auth_key().sign(OsRng, &body_bytes);
+
+                let unjail = Unjail { body, auth_sig };
+
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let account_group_id = app.fvk.account_group_id();
+
+                let plan = plan::unjail(
+                    account_group_id,
+                    app.view.as_mut().unwrap(),
+                    OsRng,
+                    unjail,
+                    fee,
+                    AddressIndex::new(*source),
+                )
+                .await?;
+                app.build_and_submit_transaction(plan).await?;
+
+                println!("Released validator from jail");
+            }
             ValidatorCmd::Definition(DefinitionCmd::Template {
                 file,
                 tendermint_validator_keyfile,
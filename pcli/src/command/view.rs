@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+use camino::Utf8Path;
 use penumbra_crypto::FullViewingKey;
 use penumbra_proto::client::v1alpha1::oblivious_query_service_client::ObliviousQueryServiceClient;
 use penumbra_view::ViewClient;
@@ -15,6 +16,8 @@ pub mod transaction_hashes;
 use transaction_hashes::TransactionHashesCmd;
 mod tx;
 use tx::TxCmd;
+mod voting_power;
+use voting_power::VotingPowerCmd;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ViewCmd {
@@ -36,6 +39,8 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// View the voting power of your staked notes on a proposal.
+    VotingPower(VotingPowerCmd),
 }
 
 impl ViewCmd {
@@ -48,6 +53,7 @@ impl ViewCmd {
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::VotingPower(voting_power_cmd) => voting_power_cmd.offline(),
         }
     }
 
@@ -56,6 +62,7 @@ impl ViewCmd {
         full_viewing_key: &FullViewingKey,
         view_client: Option<&mut impl ViewClient>,
         oblivious_client: &mut ObliviousQueryServiceClient<Channel>,
+        data_path: &Utf8Path,
     ) -> Result<()> {
         match self {
             ViewCmd::Tx(tx_cmd) => {
@@ -81,9 +88,19 @@ impl ViewCmd {
                     .exec(full_viewing_key, view_client.unwrap())
                     .await?;
             }
+            ViewCmd::VotingPower(voting_power_cmd) => {
+                voting_power_cmd
+                    .exec(full_viewing_key, view_client.unwrap())
+                    .await?;
+            }
             ViewCmd::Staked(staked_cmd) => {
                 staked_cmd
-                    .exec(full_viewing_key, view_client.unwrap(), oblivious_client)
+                    .exec(
+                        full_viewing_key,
+                        view_client.unwrap(),
+                        oblivious_client,
+                        data_path,
+                    )
                     .await?;
             }
         }
@@ -98,21 +115,38 @@ pub struct Reset;
 impl Reset {
     pub fn exec(&self, data_path: impl AsRef<camino::Utf8Path>) -> Result<()> {
         tracing::info!("resetting client state");
-        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
-        if view_path.is_file() {
-            std::fs::remove_file(&view_path)?;
-            println!("Deleted view data at {view_path}");
-        } else if view_path.exists() {
-            return Err(anyhow::anyhow!(
-                "Expected view data at {} but found something that is not a file; refusing to delete it",
-                view_path
-            ));
-        } else {
+        let data_path = data_path.as_ref();
+
+        // Each account in the wallet has its own view data, all of which should be reset.
+        let mut view_paths = Vec::new();
+        for entry in data_path.read_dir_utf8()? {
+            let path = entry?.into_path();
+            let is_view_data = path.file_name().map_or(false, |name| {
+                name == crate::VIEW_FILE_NAME
+                    || (name.starts_with("pcli-view-") && name.ends_with(".sqlite"))
+            });
+            if is_view_data {
+                view_paths.push(path);
+            }
+        }
+
+        if view_paths.is_empty() {
             return Err(anyhow::anyhow!(
-                "No view data exists at {}, so it cannot be deleted",
-                view_path
+                "No view data exists in {}, so it cannot be deleted",
+                data_path
             ));
         }
+        for view_path in view_paths {
+            if view_path.is_file() {
+                std::fs::remove_file(&view_path)?;
+                println!("Deleted view data at {view_path}");
+            } else {
+                return Err(anyhow::anyhow!(
+                    "Expected view data at {} but found something that is not a file; refusing to delete it",
+                    view_path
+                ));
+            }
+        }
 
         Ok(())
     }
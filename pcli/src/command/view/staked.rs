@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use camino::Utf8Path;
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_component::stake::validator;
@@ -11,8 +12,17 @@ use penumbra_proto::client::v1alpha1::{
 use penumbra_view::ViewClient;
 use tonic::transport::Channel;
 
+/// The file recording the commission of each validator we're delegated to, as
+/// of the last time we looked, so that we can tell when it's raised.
+const COMMISSIONS_FILE_NAME: &str = "validator-commissions.json";
+
 #[derive(Debug, clap::Parser)]
-pub struct StakedCmd {}
+pub struct StakedCmd {
+    /// Also show the per-epoch history of each delegation, with the rewards
+    /// earned and slashing losses incurred in each epoch.
+    #[clap(long)]
+    pub history: bool,
+}
 
 impl StakedCmd {
     pub fn offline(&self) -> bool {
@@ -24,6 +34,7 @@ impl StakedCmd {
         full_viewing_key: &FullViewingKey,
         view_client: &mut impl ViewClient,
         oblivious_client: &mut ObliviousQueryServiceClient<Channel>,
+        data_path: &Utf8Path,
     ) -> Result<()> {
         let client = oblivious_client;
 
@@ -48,6 +59,15 @@ impl StakedCmd {
             .await?;
         let mut total = 0u64;
 
+        let commissions_path = data_path.join(COMMISSIONS_FILE_NAME);
+        let seen_commissions: BTreeMap<String, u64> = if commissions_path.exists() {
+            serde_json::from_slice(&std::fs::read(&commissions_path)?)
+                .with_context(|| format!("could not parse {commissions_path}"))?
+        } else {
+            BTreeMap::new()
+        };
+        let mut commissions = BTreeMap::new();
+
         let mut table = Table::new();
         table.load_preset(presets::NOTHING);
         table.set_header(vec!["Name", "Value", "Exch. Rate", "Tokens"]);
@@ -90,6 +110,20 @@ impl StakedCmd {
 
             let rate = info.rate_data.validator_exchange_rate as f64 / 1_0000_0000.0;
 
+            // Warn about validators that have raised their commission since we
+            // last looked.
+            let identity_key = info.validator.identity_key.to_string();
+            let commission_bps = info.validator.funding_streams.total_rate_bps();
+            if let Some(&seen_commission_bps) = seen_commissions.get(&identity_key) {
+                if commission_bps > seen_commission_bps {
+                    eprintln!(
+                        "warning: validator {} raised its commission from {}bps to {}bps",
+                        info.validator.name, seen_commission_bps, commission_bps,
+                    );
+                }
+            }
+            commissions.insert(identity_key, commission_bps);
+
             table.add_row(vec![
                 info.validator.name.clone(),
                 unbonded.format(&asset_cache),
@@ -133,6 +167,65 @@ impl StakedCmd {
         ]);
         println!("{table}");
 
+        std::fs::write(&commissions_path, serde_json::to_vec_pretty(&commissions)?)
+            .with_context(|| format!("could not write {commissions_path}"))?;
+
+        if self.history {
+            let history = view_client
+                .delegation_history(account_group_id, None)
+                .await?;
+
+            let mut history_by_validator = BTreeMap::<_, Vec<_>>::new();
+            for record in history {
+                history_by_validator
+                    .entry(record.rate_data.identity_key)
+                    .or_default()
+                    .push(record);
+            }
+
+            for (identity_key, records) in history_by_validator {
+                let name = validators
+                    .iter()
+                    .find(|v| v.validator.identity_key == identity_key)
+                    .map(|v| v.validator.name.clone())
+                    .unwrap_or_else(|| identity_key.to_string());
+                let delegation_token = DelegationToken::new(identity_key);
+
+                let staking_value = |amount: penumbra_crypto::Amount| {
+                    Value {
+                        amount,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                    .format(&asset_cache)
+                };
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Epoch", "Tokens", "Value", "Rewards", "Slashed"]);
+                for record in records {
+                    table.add_row(vec![
+                        record.rate_data.epoch_index.to_string(),
+                        Value {
+                            amount: record.delegation_amount,
+                            asset_id: delegation_token.id(),
+                        }
+                        .format(&asset_cache),
+                        staking_value(record.unbonded_amount),
+                        record
+                            .reward_amount
+                            .map(staking_value)
+                            .unwrap_or_else(|| "?".to_string()),
+                        record
+                            .slashed_amount
+                            .map(staking_value)
+                            .unwrap_or_else(|| "?".to_string()),
+                    ]);
+                }
+
+                println!("\n{name}:\n{table}");
+            }
+        }
+
         Ok(())
     }
 }
@@ -273,6 +273,15 @@ impl TxCmd {
                     penumbra_transaction::ActionView::UndelegateClaim(_) => {
                         ["Undelegation Claim".to_string(), "".to_string()]
                     }
+                    penumbra_transaction::ActionView::Redelegate(_) => {
+                        ["Redelegation".to_string(), "".to_string()]
+                    }
+                    penumbra_transaction::ActionView::RedelegateClaim(_) => {
+                        ["Redelegation Claim".to_string(), "".to_string()]
+                    }
+                    penumbra_transaction::ActionView::Unjail(_) => {
+                        ["Unjail Validator".to_string(), "".to_string()]
+                    }
                     penumbra_transaction::ActionView::ValidatorDefinition(_) => {
                         ["Upload Validator Definition".to_string(), "".to_string()]
                     }
@@ -0,0 +1,70 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_crypto::{FullViewingKey, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_view::ViewClient;
+
+#[derive(Debug, clap::Parser)]
+pub struct VotingPowerCmd {
+    /// The proposal to show voting power for.
+    pub proposal_id: u64,
+}
+
+impl VotingPowerCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(
+        &self,
+        full_viewing_key: &FullViewingKey,
+        view_client: &mut impl ViewClient,
+    ) -> Result<()> {
+        let asset_cache = view_client.assets().await?;
+        let records = view_client
+            .voting_power(full_viewing_key.account_group_id(), self.proposal_id)
+            .await?;
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec!["Account", "Delegation", "Voting Power", "Voted"]);
+
+        let mut total = 0u64;
+        let mut unvoted = 0u64;
+        for record in records {
+            let power = u64::from(record.voting_power);
+            total += power;
+            if !record.voted {
+                unvoted += power;
+            }
+
+            let delegation = Value {
+                amount: record.note_record.note.amount(),
+                asset_id: record.note_record.note.asset_id(),
+            };
+            table.add_row(vec![
+                format!("{}", record.note_record.address_index.account),
+                delegation.format(&asset_cache),
+                staking_value(power).format(&asset_cache),
+                if record.voted { "yes" } else { "no" }.to_string(),
+            ]);
+        }
+
+        table.add_row(vec![
+            "Total".to_string(),
+            "".to_string(),
+            staking_value(total).format(&asset_cache),
+            format!("{} unvoted", staking_value(unvoted).format(&asset_cache)),
+        ]);
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
+
+fn staking_value(amount: u64) -> Value {
+    Value {
+        amount: amount.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    }
+}
@@ -20,7 +20,7 @@ mod opt;
 mod warning;
 
 use opt::Opt;
-use penumbra_wallet::KeyStore;
+use penumbra_wallet::{Account, KeyStore};
 
 use box_grpc_svc::BoxGrpcService;
 use command::*;
@@ -28,6 +28,17 @@ use command::*;
 const CUSTODY_FILE_NAME: &str = "custody.json";
 const VIEW_FILE_NAME: &str = "pcli-view.sqlite";
 
+/// The name of the view data file for the account derived at `index`.
+///
+/// The default account keeps using [`VIEW_FILE_NAME`], so existing view data is reused.
+fn view_file_name(index: u64) -> String {
+    if index == 0 {
+        VIEW_FILE_NAME.to_string()
+    } else {
+        format!("pcli-view-{index}.sqlite")
+    }
+}
+
 #[derive(Debug)]
 pub struct App {
     /// view will be `None` when a command indicates that it can be run offline via
@@ -37,6 +48,8 @@ pub struct App {
     pub custody: CustodyProtocolServiceClient<BoxGrpcService>,
     pub fvk: FullViewingKey,
     pub wallet: KeyStore,
+    /// The account selected with `--account`, whose keys `fvk` and `custody` use.
+    pub account: Account,
     pub pd_url: Url,
 }
 
@@ -104,7 +117,7 @@ async fn main() -> Result<()> {
     // create the client state, so handle it specially here so that we can have
     // common code for the other subcommands.
     if let Command::Keys(keys_cmd) = &opt.cmd {
-        keys_cmd.exec(opt.data_path.as_path())?;
+        keys_cmd.exec(opt.data_path.as_path(), opt.account.as_deref())?;
         return Ok(());
     }
 
@@ -121,6 +134,7 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    let data_path = opt.data_path.clone();
     let (mut app, cmd) = opt.into_app().await?;
 
     if !cmd.offline() {
@@ -139,7 +153,12 @@ async fn main() -> Result<()> {
             let mut oblivious_client = app.oblivious_client().await?;
 
             view_cmd
-                .exec(&app.fvk, app.view.as_mut(), &mut oblivious_client)
+                .exec(
+                    &app.fvk,
+                    app.view.as_mut(),
+                    &mut oblivious_client,
+                    data_path.as_path(),
+                )
                 .await?
         }
         Command::Validator(cmd) => cmd.exec(&mut app).await?,
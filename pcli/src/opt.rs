@@ -6,7 +6,6 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
-use penumbra_crypto::FullViewingKey;
 use penumbra_custody::soft_kms::SoftKms;
 use penumbra_proto::{
     custody::v1alpha1::{
@@ -18,8 +17,8 @@ use penumbra_proto::{
         view_protocol_service_server::ViewProtocolServiceServer,
     },
 };
-use penumbra_view::ViewService;
-use penumbra_wallet::KeyStore;
+use penumbra_view::{MultiViewService, ViewService};
+use penumbra_wallet::{passphrase, Account, KeyStore};
 use std::net::SocketAddr;
 use tracing_subscriber::EnvFilter;
 use url::Url;
@@ -51,6 +50,9 @@ pub struct Opt {
     /// The path to the wallet file itself (overrides `--data-path` if it is set).
     #[clap(long, env = "PENUMBRA_CUSTODY_PATH")]
     pub custody_path: Option<Utf8PathBuf>,
+    /// The account in the wallet to use, by label or index [default: the first account].
+    #[clap(long, env = "PENUMBRA_ACCOUNT")]
+    pub account: Option<String>,
     /// If set, use a remote view service instead of local synchronization.
     #[clap(short, long, env = "PENUMBRA_VIEW_ADDRESS")]
     view_address: Option<SocketAddr>,
@@ -87,17 +89,23 @@ impl Opt {
             legacy::migrate(&legacy_wallet_path, custody_path.as_path())?;
         }
 
-        // Build the custody service...
-        let wallet = KeyStore::load(custody_path)?;
-        let soft_kms = SoftKms::new(wallet.spend_key.clone().into());
+        // Build the custody service for the selected account...
+        let passphrase = passphrase::unlock(&custody_path)?;
+        let wallet = KeyStore::load(&custody_path, passphrase.as_deref())?;
+        let account = match &self.account {
+            Some(name) => wallet.account(name)?,
+            None => wallet.default_account(),
+        }
+        .clone();
+        let soft_kms = SoftKms::new(account.spend_key.clone().into());
         let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
         let custody = CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc));
 
-        let fvk = wallet.spend_key.full_viewing_key().clone();
+        let fvk = account.spend_key.full_viewing_key().clone();
 
         // ...and the view service...
         let view = if !self.cmd.offline() {
-            Some(self.view_client(&fvk).await?)
+            Some(self.view_client(&wallet, &account).await?)
         } else {
             None
         };
@@ -114,15 +122,20 @@ impl Opt {
             custody,
             fvk,
             wallet,
+            account,
             pd_url,
         };
         Ok((app, self.cmd))
     }
 
     /// Constructs a [`ViewProtocolServiceClient`] based on the command-line options.
+    ///
+    /// A local view service syncs every account in the wallet, answering requests which don't
+    /// name an account for the selected `account`.
     async fn view_client(
         &self,
-        fvk: &FullViewingKey,
+        wallet: &KeyStore,
+        account: &Account,
     ) -> Result<ViewProtocolServiceClient<BoxGrpcService>> {
         let svc = if let Some(address) = self.view_address {
             // Use a remote view service.
@@ -131,16 +144,33 @@ impl Opt {
             let ep = tonic::transport::Endpoint::new(format!("http://{address}"))?;
             box_grpc_svc::connect(ep).await?
         } else {
-            // Use an in-memory view service.
-            let path = self.data_path.join(crate::VIEW_FILE_NAME);
-            tracing::info!(%path, "using local view service");
+            // Use an in-memory view service, with the selected account first so that it's the
+            // default.
+            let accounts = std::iter::once(account).chain(
+                wallet
+                    .accounts()
+                    .iter()
+                    .filter(|other| other.index != account.index),
+            );
+
+            let mut services = Vec::new();
+            for account in accounts {
+                let path = self.data_path.join(crate::view_file_name(account.index));
+                tracing::info!(%path, label = %account.label, "using local view service");
 
-            let svc =
-                ViewService::load_or_initialize(path, fvk, self.node.to_string(), self.pd_port)
-                    .await?;
+                services.push(
+                    ViewService::load_or_initialize(
+                        path,
+                        account.spend_key.full_viewing_key(),
+                        self.node.to_string(),
+                        self.pd_port,
+                    )
+                    .await?,
+                );
+            }
 
             // Now build the view and custody clients, doing gRPC with ourselves
-            let svc = ViewProtocolServiceServer::new(svc);
+            let svc = ViewProtocolServiceServer::new(MultiViewService::new(services)?);
             box_grpc_svc::local(svc)
         };
 
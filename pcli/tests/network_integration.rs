@@ -52,6 +52,7 @@ fn load_wallet_into_tmpdir() -> TempDir {
             "import",
             "phrase",
             SEED_PHRASE,
+            "--unencrypted",
         ])
         .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
     setup_cmd
@@ -2,6 +2,7 @@
 //! proving/verification key.
 
 use ark_ff::UniformRand;
+use ark_serialize::CanonicalSerialize;
 use decaf377::Fr;
 use penumbra_crypto::{
     asset,
@@ -168,3 +169,22 @@ fn output_proof_parameters_vs_current_output_circuit() {
 
     assert!(proof_result.is_ok());
 }
+
+#[test]
+fn proving_key_must_match_verification_key() {
+    let mut pk_params = Vec::new();
+    OUTPUT_PROOF_PROVING_KEY
+        .serialize(&mut pk_params)
+        .expect("can serialize ProvingKey");
+
+    // The output proving key can't be loaded in place of the spend proving key.
+    let err = SPEND_PROOF_PROVING_KEY
+        .load(&pk_params)
+        .expect_err("mismatched proving key is rejected");
+    assert!(err
+        .to_string()
+        .contains("does not match the verification key"));
+
+    // Nor can garbage.
+    assert!(SWAP_PROOF_PROVING_KEY.load(&[0u8; 1000]).is_err());
+}
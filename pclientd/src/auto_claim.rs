@@ -0,0 +1,42 @@
+//! Automatically claims undelegations once they finish unbonding.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use penumbra_crypto::{transaction::Fee, FullViewingKey};
+use penumbra_proto::{
+    custody::v1alpha1::custody_protocol_service_client::CustodyProtocolServiceClient,
+    view::v1alpha1::view_protocol_service_client::ViewProtocolServiceClient,
+};
+use rand_core::OsRng;
+
+/// Checks for claimable undelegations every `interval`, claiming any that are
+/// found using the view and custody services served at `endpoint`.
+///
+/// Errors are logged rather than returned, so that a transient failure doesn't
+/// stop later claims.
+pub async fn run(fvk: FullViewingKey, endpoint: String, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(e) = claim_undelegations(&fvk, &endpoint).await {
+            tracing::warn!(?e, "failed to claim undelegations");
+        }
+    }
+}
+
+async fn claim_undelegations(fvk: &FullViewingKey, endpoint: &str) -> Result<()> {
+    let mut view = ViewProtocolServiceClient::connect(endpoint.to_owned()).await?;
+    let mut custody = CustodyProtocolServiceClient::connect(endpoint.to_owned()).await?;
+
+    penumbra_wallet::claim_undelegations(
+        fvk,
+        &mut view,
+        &mut custody,
+        OsRng,
+        Fee::from_staking_token_amount(0u64.into()),
+    )
+    .await?;
+
+    Ok(())
+}
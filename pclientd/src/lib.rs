@@ -4,7 +4,7 @@ use std::path::Path;
 use anyhow::Result;
 use camino::Utf8PathBuf;
 use clap::Parser;
-use penumbra_crypto::keys::{SeedPhrase, SpendKey};
+use penumbra_crypto::keys::SeedPhrase;
 use penumbra_crypto::FullViewingKey;
 use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
@@ -15,6 +15,7 @@ use penumbra_proto::{
     view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer,
 };
 use penumbra_view::{Storage, ViewService};
+use penumbra_wallet::{passphrase, KeyStore};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
@@ -24,15 +25,34 @@ use std::io::{self, BufRead, Write};
 use std::str::FromStr;
 use tonic::transport::Server;
 
+mod auto_claim;
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PclientdConfig {
-    /// Optional KMS config for custody mode
+    /// Optional KMS config for custody mode, with the spend key stored in plaintext.
+    ///
+    /// Prefer `custody`, which keeps the spend key in an encrypted key store instead; `pclientd
+    /// encrypt` moves the spend key out of this config.
     pub kms_config: Option<soft_kms::Config>,
+    /// Optional config for custody mode, with the spend key stored in an encrypted key store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custody: Option<CustodyConfig>,
     /// FVK for both view and custody modes
     pub fvk: FullViewingKey,
 }
 
+/// Config for custody mode, where the spend key is kept in a (normally encrypted) key store
+/// rather than in the config file itself.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustodyConfig {
+    /// The path to the key store, relative to the pclientd home directory.
+    pub key_store: Utf8PathBuf,
+    /// The policies used to authorize transactions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auth_policy: Vec<AuthPolicy>,
+}
+
 impl PclientdConfig {
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
         let contents = std::fs::read_to_string(path)?;
@@ -90,9 +110,18 @@ pub enum Command {
         /// Bind the view gRPC server to this port.
         #[clap(long, default_value = "8081")]
         view_port: u16,
+        /// In custody mode, automatically claim undelegations once they finish
+        /// unbonding, checking for claimable undelegations every this many seconds.
+        #[clap(long)]
+        auto_claim_interval: Option<u64>,
     },
+    /// Move a plaintext spend key out of the config file into an encrypted key store.
+    Encrypt,
 }
 
+/// The name of the key store file holding the spend key in custody mode.
+const CUSTODY_FILE_NAME: &str = "custody.json";
+
 impl Opt {
     fn config_path(&self) -> Utf8PathBuf {
         let mut path = self.home.clone();
@@ -125,6 +154,32 @@ impl Opt {
         Ok(Storage::initialize(&self.sqlite_path(), fvk.clone(), params).await?)
     }
 
+    /// Get the KMS config for custody mode, unlocking the key store if necessary, or `None` to
+    /// run in view mode.
+    fn load_kms_config(&self, config: &PclientdConfig) -> Result<Option<soft_kms::Config>> {
+        match (&config.kms_config, &config.custody) {
+            (Some(_), Some(_)) => Err(anyhow::anyhow!(
+                "config sets both `kms_config` and `custody`, but only one spend key can be used"
+            )),
+            (Some(kms_config), None) => {
+                tracing::warn!(
+                    "spend key is stored in plaintext in the config file; run `pclientd encrypt` to encrypt it"
+                );
+                Ok(Some(kms_config.clone()))
+            }
+            (None, Some(custody)) => {
+                let key_store_path = self.home.join(&custody.key_store);
+                let passphrase = passphrase::unlock(&key_store_path)?;
+                let key_store = KeyStore::load(&key_store_path, passphrase.as_deref())?;
+                Ok(Some(soft_kms::Config {
+                    spend_key: key_store.default_account().spend_key.clone(),
+                    auth_policy: custody.auth_policy.clone(),
+                }))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
     async fn load_or_init_sqlite(&self, fvk: &FullViewingKey) -> Result<Storage> {
         if self.sqlite_path().exists() {
             Ok(Storage::load(self.sqlite_path()).await?)
@@ -169,12 +224,11 @@ impl Opt {
 
                 // Create config file
 
-                let kms_config: Option<soft_kms::Config> = match seed_phrase {
+                let custody: Option<CustodyConfig> = match seed_phrase {
                     Some(seed_phrase) => {
-                        let spend_key = SpendKey::from_seed_phrase(
-                            SeedPhrase::from_str(seed_phrase.as_str())?,
-                            0,
-                        );
+                        let key_store =
+                            KeyStore::from_seed_phrase(SeedPhrase::from_str(seed_phrase.as_str())?);
+                        let spend_key = key_store.default_account().spend_key.clone();
 
                         let pak = ed25519_consensus::SigningKey::new(rand_core::OsRng);
                         let pvk = pak.verification_key();
@@ -194,8 +248,13 @@ impl Opt {
                                 allowed_signers: vec![pvk],
                             }),
                         ];
-                        Some(soft_kms::Config {
-                            spend_key,
+
+                        // Keep the spend key out of the config file, in an encrypted key store.
+                        let passphrase = passphrase::choose()?;
+                        key_store.save(opt.home.join(CUSTODY_FILE_NAME), Some(&passphrase))?;
+
+                        Some(CustodyConfig {
+                            key_store: CUSTODY_FILE_NAME.into(),
                             auth_policy,
                         })
                     }
@@ -203,7 +262,8 @@ impl Opt {
                 };
 
                 let client_config = PclientdConfig {
-                    kms_config,
+                    kms_config: None,
+                    custody,
                     fvk: FullViewingKey::from_str(full_viewing_key.as_ref())?,
                 };
 
@@ -219,10 +279,16 @@ impl Opt {
 
                 Ok(())
             }
-            Command::Start { host, view_port } => {
+            Command::Start {
+                host,
+                view_port,
+                auto_claim_interval,
+            } => {
                 tracing::info!(?opt.home, ?host, ?view_port, ?opt.node, ?opt.pd_port, "starting pclientd");
 
                 let config = PclientdConfig::load(opt.config_path())?;
+                // Unlock the spend key (if any) before starting to sync.
+                let kms_config = opt.load_kms_config(&config)?;
                 let storage = opt.load_or_init_sqlite(&config.fvk).await?;
 
                 println!(
@@ -232,7 +298,7 @@ impl Opt {
 
                 let service = ViewService::new(storage, opt.node, opt.pd_port).await?;
 
-                match config.kms_config {
+                match kms_config {
                     None => {
                         // No key management config: start in view mode
 
@@ -263,6 +329,17 @@ impl Opt {
 
                         let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
 
+                        if let Some(interval) = auto_claim_interval {
+                            println!(
+                                "Automatically claiming undelegations every {interval} seconds."
+                            );
+                            tokio::spawn(auto_claim::run(
+                                config.fvk.clone(),
+                                format!("http://{host}:{view_port}"),
+                                std::time::Duration::from_secs(*interval),
+                            ));
+                        }
+
                         tokio::spawn(
                             Server::builder()
                                 .accept_http1(true)
@@ -280,6 +357,33 @@ impl Opt {
                     }
                 }
 
+                Ok(())
+            }
+            Command::Encrypt => {
+                let mut config = PclientdConfig::load(opt.config_path())?;
+                let kms_config = config.kms_config.take().ok_or_else(|| {
+                    anyhow::anyhow!("config has no plaintext spend key to encrypt")
+                })?;
+                if config.custody.is_some() {
+                    return Err(anyhow::anyhow!(
+                        "config already has a custody key store, refusing to replace it"
+                    ));
+                }
+
+                let passphrase = passphrase::choose()?;
+                KeyStore::from_spend_key(kms_config.spend_key)
+                    .save(opt.home.join(CUSTODY_FILE_NAME), Some(&passphrase))?;
+                config.custody = Some(CustodyConfig {
+                    key_store: CUSTODY_FILE_NAME.into(),
+                    auth_policy: kms_config.auth_policy,
+                });
+                config.save(opt.config_path())?;
+
+                println!(
+                    "Moved spend key into encrypted key store at {}",
+                    opt.home.join(CUSTODY_FILE_NAME)
+                );
+
                 Ok(())
             }
         }
@@ -40,6 +40,7 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
         }),
+        custody: None,
     };
 
     let mut config_file_path = data_dir.path().to_owned();
@@ -135,16 +136,25 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .data
         .ok_or_else(|| anyhow::anyhow!("AuthorizeResponse missing data"))?;
 
-    // 5.3. Have pclientd build and sign the planned transaction.
-    let tx = view_client
+    // 5.3. Have pclientd build and sign the planned transaction, which streams progress on each
+    // proof before the transaction itself.
+    let mut build_stream = view_client
         .witness_and_build(WitnessAndBuildRequest {
             transaction_plan: Some(plan),
             authorization_data: Some(auth_data),
         })
         .await?
-        .into_inner()
-        .transaction
-        .ok_or_else(|| anyhow::anyhow!("WitnessAndBuildResponse missing transaction"))?;
+        .into_inner();
+    let mut tx = None;
+    while let Some(msg) = build_stream.message().await? {
+        if let Some(progress) = msg.progress {
+            tracing::debug!(?progress);
+        }
+        if msg.transaction.is_some() {
+            tx = msg.transaction;
+        }
+    }
+    let tx = tx.ok_or_else(|| anyhow::anyhow!("WitnessAndBuildResponse missing transaction"))?;
 
     // 5.4. Have pclientd broadcast and await confirmation of the built transaction.
     let tx_id = view_client
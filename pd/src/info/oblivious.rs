@@ -13,6 +13,7 @@ use penumbra_proto::{
         oblivious_query_service_server::ObliviousQueryService, AssetListRequest, AssetListResponse,
         ChainParametersRequest, ChainParametersResponse, CompactBlockRangeRequest,
         CompactBlockRangeResponse, ValidatorInfoRequest, ValidatorInfoResponse,
+        ValidatorRatesRequest, ValidatorRatesResponse,
     },
     DomainType,
 };
@@ -57,6 +58,9 @@ impl ObliviousQueryService for Info {
     type ValidatorInfoStream =
         Pin<Box<dyn futures::Stream<Item = Result<ValidatorInfoResponse, tonic::Status>> + Send>>;
 
+    type ValidatorRatesStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ValidatorRatesResponse, tonic::Status>> + Send>>;
+
     #[instrument(skip(self, request))]
     async fn chain_parameters(
         &self,
@@ -142,6 +146,53 @@ impl ObliviousQueryService for Info {
         ))
     }
 
+    #[instrument(skip(self, request), fields(epoch_index = request.get_ref().epoch_index))]
+    async fn validator_rates(
+        &self,
+        request: tonic::Request<ValidatorRatesRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorRatesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let validators = state
+            .validator_identity_list()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error listing validators: {e}")))?;
+
+        let epoch_index = request.get_ref().epoch_index;
+        let s = try_stream! {
+            for identity_key in validators {
+                // Validators defined after the epoch have no rate data for it.
+                let Some(rate_data) = state.validator_rate_in_epoch(&identity_key, epoch_index).await? else {
+                    continue;
+                };
+                // The penalty recorded in the previous epoch was applied to
+                // form this epoch's rate.
+                let penalty = match epoch_index.checked_sub(1) {
+                    Some(previous_epoch) => state
+                        .penalty_in_epoch(&identity_key, previous_epoch)
+                        .await?
+                        .unwrap_or_default(),
+                    None => Default::default(),
+                };
+                yield ValidatorRatesResponse {
+                    rate_data: Some(rate_data.into()),
+                    penalty: Some(penalty.into()),
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error getting validator rates: {e}"))
+            })
+            .boxed(),
+        ))
+    }
+
     #[instrument(
         skip(self, request),
         fields(
@@ -1,29 +1,57 @@
 use std::pin::Pin;
+use std::str::FromStr;
 
 use async_stream::try_stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use ibc::core::{
+    ics02_client::{client_state::ClientState as _, client_type::ClientType},
+    ics03_connection::connection::IdentifiedConnectionEnd,
+    ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd},
+    ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId},
+};
+use ibc_proto::ibc::core::{channel::v1::PacketState, client::v1::IdentifiedClientState};
 use penumbra_chain::AppHashRead;
 use penumbra_chain::StateReadExt as _;
-use penumbra_component::governance::StateReadExt as _;
+use penumbra_component::governance::{
+    state_key as governance_state_key, ProposalEvent, StateReadExt as _,
+};
+use penumbra_component::ibc::{
+    state_key as ibc_state_key, ChannelStateReadExt as _, ClientStateReadExt as _,
+    ConnectionStateReadExt as _,
+};
 use penumbra_component::shielded_pool::{StateReadExt as _, SupplyRead as _};
 use penumbra_component::stake::rate::RateData;
-use penumbra_component::stake::StateReadExt as _;
+use penumbra_component::stake::{state_key as stake_state_key, StateReadExt as _, ValidatorEvent};
 use penumbra_component::stubdex::StateReadExt as _;
 use penumbra_crypto::asset::{self, Asset};
+use penumbra_crypto::{stake::IdentityKey, Amount, Nullifier, Value};
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
         specific_query_service_server::SpecificQueryService, AssetInfoRequest, AssetInfoResponse,
-        BatchSwapOutputDataRequest, KeyValueRequest, KeyValueResponse, ProposalInfoRequest,
-        ProposalInfoResponse, ProposalRateDataRequest, ProposalRateDataResponse,
-        StubCpmmReservesRequest, ValidatorStatusRequest,
+        BatchSwapOutputDataRequest, KeyValueRequest, KeyValueResponse, ProposalEventsRequest,
+        ProposalEventsResponse, ProposalInfoRequest, ProposalInfoResponse,
+        ProposalNullifierStatusRequest, ProposalNullifierStatusResponse, ProposalRateDataRequest,
+        ProposalRateDataResponse, StubCpmmReservesRequest, ValidatorStatusRequest,
     },
-    StateReadProto as _,
+    DomainType, StateReadProto as _,
 };
 
-use penumbra_storage::StateRead;
+use penumbra_storage::{StateRead, Storage};
 use proto::client::v1alpha1::BatchSwapOutputDataResponse;
+use proto::client::v1alpha1::IbcChannelSequencesRequest;
+use proto::client::v1alpha1::IbcChannelSequencesResponse;
+use proto::client::v1alpha1::IbcChannelsRequest;
+use proto::client::v1alpha1::IbcChannelsResponse;
+use proto::client::v1alpha1::IbcClientsRequest;
+use proto::client::v1alpha1::IbcClientsResponse;
+use proto::client::v1alpha1::IbcConnectionsRequest;
+use proto::client::v1alpha1::IbcConnectionsResponse;
+use proto::client::v1alpha1::IbcPacketCommitmentsRequest;
+use proto::client::v1alpha1::IbcPacketCommitmentsResponse;
+use proto::client::v1alpha1::Ics20EscrowRequest;
+use proto::client::v1alpha1::Ics20EscrowResponse;
 use proto::client::v1alpha1::NextValidatorRateRequest;
 use proto::client::v1alpha1::NextValidatorRateResponse;
 use proto::client::v1alpha1::PrefixValueRequest;
@@ -31,9 +59,15 @@ use proto::client::v1alpha1::PrefixValueResponse;
 use proto::client::v1alpha1::StubCpmmReservesResponse;
 use proto::client::v1alpha1::TransactionByNoteRequest;
 use proto::client::v1alpha1::TransactionByNoteResponse;
+use proto::client::v1alpha1::ValidatorEventsRequest;
+use proto::client::v1alpha1::ValidatorEventsResponse;
+use proto::client::v1alpha1::ValidatorEvidenceRequest;
+use proto::client::v1alpha1::ValidatorEvidenceResponse;
 use proto::client::v1alpha1::ValidatorPenaltyRequest;
 use proto::client::v1alpha1::ValidatorPenaltyResponse;
 use proto::client::v1alpha1::ValidatorStatusResponse;
+use proto::client::v1alpha1::ValidatorUptimeRequest;
+use proto::client::v1alpha1::ValidatorUptimeResponse;
 use tonic::Status;
 use tracing::instrument;
 
@@ -131,6 +165,61 @@ impl SpecificQueryService for Info {
         }))
     }
 
+    #[instrument(skip(self, request))]
+    async fn validator_uptime(
+        &self,
+        request: tonic::Request<ValidatorUptimeRequest>,
+    ) -> Result<tonic::Response<ValidatorUptimeResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let request = request.into_inner();
+        let id = request
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let validator_state = state
+            .validator_state(&id)
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting validator state: {e}")))?
+            .ok_or_else(|| Status::not_found("validator not found"))?;
+        let uptime = state
+            .validator_uptime(&id)
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting validator uptime: {e}")))?;
+        let missed_blocks_maximum = state
+            .missed_blocks_maximum()
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting chain parameters: {e}")))?;
+
+        let mut rate_history = state
+            .validator_rate_history(&id)
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting rate history: {e}")))?;
+        if request.rate_history_epochs != 0 {
+            let skip = rate_history
+                .len()
+                .saturating_sub(request.rate_history_epochs as usize);
+            rate_history.drain(..skip);
+        }
+
+        Ok(tonic::Response::new(ValidatorUptimeResponse {
+            state: Some(validator_state.into()),
+            missed_blocks: uptime
+                .as_ref()
+                .map(|uptime| uptime.num_missed_blocks() as u64)
+                .unwrap_or_default(),
+            uptime: uptime.map(Into::into),
+            missed_blocks_maximum,
+            rate_history: rate_history.into_iter().map(Into::into).collect(),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn next_validator_rate(
         &self,
@@ -310,34 +399,162 @@ impl SpecificQueryService for Info {
         let proposal_id = request.into_inner().proposal_id;
 
         use penumbra_component::governance::state_key;
-        let stream_iter = state
+        let rate_data: Vec<(String, RateData)> = state
             .prefix(&state_key::all_rate_data_at_proposal_start(proposal_id))
-            .next()
+            .try_collect()
             .await
-            .into_iter();
-        let s = try_stream! {
-            for item in stream_iter
-                .map(|item| item.map_err(|e| tonic::Status::internal(e.to_string()))) {
-                    yield item
-                }
-        };
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error getting prefix value from storage: {e}"))
+            })?;
 
         Ok(tonic::Response::new(
-            s.map_ok(|i: Result<(String, RateData), tonic::Status>| {
-                let (_key, rate_data) = i.unwrap();
-                ProposalRateDataResponse {
+            futures::stream::iter(rate_data.into_iter().map(|(_key, rate_data)| {
+                Ok(ProposalRateDataResponse {
                     rate_data: Some(rate_data.into()),
-                }
-            })
-            .map_err(|e: anyhow::Error| {
-                tonic::Status::unavailable(format!("error getting prefix value from storage: {e}"))
-            })
+                })
+            }))
             // TODO: how do we instrument a Stream
             //.instrument(Span::current())
             .boxed(),
         ))
     }
 
+    #[instrument(skip(self, request))]
+    async fn proposal_nullifier_status(
+        &self,
+        request: tonic::Request<ProposalNullifierStatusRequest>,
+    ) -> Result<tonic::Response<ProposalNullifierStatusResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+        let nullifier: Nullifier = request
+            .nullifier
+            .ok_or_else(|| tonic::Status::invalid_argument("missing nullifier"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("could not parse nullifier: {e}"))
+            })?;
+
+        let voted = state
+            .nullifier_voted_on_proposal(request.proposal_id, &nullifier)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .is_some();
+
+        Ok(tonic::Response::new(ProposalNullifierStatusResponse {
+            voted,
+        }))
+    }
+
+    type ProposalEventsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ProposalEventsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn proposal_events(
+        &self,
+        request: tonic::Request<ProposalEventsRequest>,
+    ) -> Result<tonic::Response<Self::ProposalEventsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let start_height = request.into_inner().start_height;
+
+        let s = events_by_height::<ProposalEvent>(
+            &self.storage,
+            start_height,
+            governance_state_key::proposal_events_at_height,
+        )
+        .map_ok(|(height, event)| ProposalEventsResponse {
+            height,
+            event: Some(event.into()),
+        });
+
+        Ok(tonic::Response::new(s.boxed()))
+    }
+
+    type ValidatorEventsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ValidatorEventsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn validator_events(
+        &self,
+        request: tonic::Request<ValidatorEventsRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorEventsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+        let identity_keys = request
+            .identity_keys
+            .into_iter()
+            .map(IdentityKey::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("could not parse identity key: {e}"))
+            })?;
+
+        let s = events_by_height::<ValidatorEvent>(
+            &self.storage,
+            request.start_height,
+            stake_state_key::validator_events_at_height,
+        )
+        // An empty filter matches every validator.
+        .try_filter(move |(_height, event)| {
+            futures::future::ready(
+                identity_keys.is_empty() || identity_keys.contains(&event.identity_key),
+            )
+        })
+        .map_ok(|(height, event)| ValidatorEventsResponse {
+            height,
+            event: Some(event.into()),
+        });
+
+        Ok(tonic::Response::new(s.boxed()))
+    }
+
+    type ValidatorEvidenceStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValidatorEvidenceResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn validator_evidence(
+        &self,
+        request: tonic::Request<ValidatorEvidenceRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorEvidenceStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let identity_key = request
+            .into_inner()
+            .identity_key
+            .map(IdentityKey::try_from)
+            .transpose()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid identity key: {e}")))?;
+
+        let records = state
+            .evidence_records(identity_key.as_ref())
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting evidence: {e}")))?;
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(records.into_iter().map(|record| {
+                Ok(ValidatorEvidenceResponse {
+                    record: Some(record.into()),
+                })
+            }))
+            .boxed(),
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn key_value(
         &self,
@@ -426,4 +643,362 @@ impl SpecificQueryService for Info {
             .boxed(),
         ))
     }
+
+    type IbcClientsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<IbcClientsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn ibc_clients(
+        &self,
+        request: tonic::Request<IbcClientsRequest>,
+    ) -> Result<tonic::Response<Self::IbcClientsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let counter = state.client_counter().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting client counter: {e}"))
+        })?;
+
+        // Client identifiers are allocated sequentially, and only Tendermint light
+        // clients are supported, so we can enumerate them from the counter.
+        let mut clients = Vec::new();
+        for n in 0..counter.0 {
+            let client_id = ClientId::new(ClientType::new("07-tendermint".to_string()), n)
+                .map_err(|e| tonic::Status::internal(e.to_string()))?;
+            let client_state = state
+                .get_client_state(&client_id)
+                .await
+                .map_err(|e| tonic::Status::unavailable(format!("{client_id}: {e}")))?;
+            let latest_height = client_state.latest_height();
+            let consensus_state = state
+                .get_verified_consensus_state(latest_height, client_id.clone())
+                .await
+                .ok();
+
+            clients.push(Ok(IbcClientsResponse {
+                client_state: Some(IdentifiedClientState {
+                    client_id: client_id.to_string(),
+                    client_state: Some(client_state.into()),
+                }),
+                latest_consensus_state: consensus_state.map(|consensus_state| {
+                    ibc_proto::ibc::core::client::v1::ConsensusStateWithHeight {
+                        height: Some(latest_height.into()),
+                        consensus_state: Some(consensus_state.into()),
+                    }
+                }),
+            }));
+        }
+
+        Ok(tonic::Response::new(futures::stream::iter(clients).boxed()))
+    }
+
+    type IbcConnectionsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<IbcConnectionsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn ibc_connections(
+        &self,
+        request: tonic::Request<IbcConnectionsRequest>,
+    ) -> Result<tonic::Response<Self::IbcConnectionsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let counter = state.get_connection_counter().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting connection counter: {e}"))
+        })?;
+
+        let mut connections = Vec::new();
+        for n in 0..counter.0 {
+            let connection_id = ConnectionId::new(n);
+            let connection_end = state
+                .get_connection(&connection_id)
+                .await
+                .map_err(|e| tonic::Status::unavailable(format!("{connection_id}: {e}")))?;
+            if let Some(connection_end) = connection_end {
+                connections.push(Ok(IbcConnectionsResponse {
+                    connection: Some(
+                        IdentifiedConnectionEnd::new(connection_id, connection_end).into(),
+                    ),
+                }));
+            }
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(connections).boxed(),
+        ))
+    }
+
+    type IbcChannelsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<IbcChannelsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn ibc_channels(
+        &self,
+        request: tonic::Request<IbcChannelsRequest>,
+    ) -> Result<tonic::Response<Self::IbcChannelsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let channel_ends: Vec<(String, ChannelEnd)> = state
+            .prefix(ibc_state_key::all_channels())
+            .try_collect()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting channels: {e}")))?;
+
+        let channels = channel_ends
+            .into_iter()
+            .map(|(key, channel_end)| {
+                // Keys have the form channelEnds/ports/{port_id}/channels/{channel_id}.
+                let (port_id, channel_id) = match key.split('/').collect::<Vec<_>>()[..] {
+                    [_, "ports", port_id, "channels", channel_id] => (
+                        PortId::from_str(port_id)
+                            .map_err(|e| tonic::Status::internal(e.to_string()))?,
+                        ChannelId::from_str(channel_id)
+                            .map_err(|e| tonic::Status::internal(e.to_string()))?,
+                    ),
+                    _ => {
+                        return Err(tonic::Status::internal(format!(
+                            "unexpected channel key {key}"
+                        )))
+                    }
+                };
+                Ok(IbcChannelsResponse {
+                    channel: Some(
+                        IdentifiedChannelEnd::new(port_id, channel_id, channel_end).into(),
+                    ),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(channels).boxed(),
+        ))
+    }
+
+    type IbcPacketCommitmentsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<IbcPacketCommitmentsResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn ibc_packet_commitments(
+        &self,
+        request: tonic::Request<IbcPacketCommitmentsRequest>,
+    ) -> Result<tonic::Response<Self::IbcPacketCommitmentsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid port id: {e}")))?;
+        let channel_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid channel id: {e}")))?;
+
+        let prefix = ibc_state_key::packet_commitments_by_channel(&port_id, &channel_id);
+        let commitments: Vec<(String, Vec<u8>)> = state
+            .prefix_proto(&prefix)
+            .try_collect()
+            .await
+            .map_err(|e| {
+            tonic::Status::unavailable(format!("error getting packet commitments: {e}"))
+        })?;
+
+        let commitments = commitments
+            .into_iter()
+            // Commitments are cleared by writing an empty value once the packet is
+            // acknowledged or timed out.
+            .filter(|(_key, commitment)| !commitment.is_empty())
+            .map(|(key, commitment)| {
+                let sequence = key
+                    .strip_prefix(&prefix)
+                    .and_then(|sequence| sequence.parse::<u64>().ok())
+                    .ok_or_else(|| {
+                        tonic::Status::internal(format!("unexpected packet commitment key {key}"))
+                    })?;
+                Ok(IbcPacketCommitmentsResponse {
+                    commitment: Some(PacketState {
+                        port_id: port_id.to_string(),
+                        channel_id: channel_id.to_string(),
+                        sequence,
+                        data: commitment,
+                    }),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(commitments).boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn ibc_channel_sequences(
+        &self,
+        request: tonic::Request<IbcChannelSequencesRequest>,
+    ) -> Result<tonic::Response<IbcChannelSequencesResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid port id: {e}")))?;
+        let channel_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid channel id: {e}")))?;
+
+        if state
+            .get_channel(&channel_id, &port_id)
+            .await
+            .map_err(|e| tonic::Status::unavailable(e.to_string()))?
+            .is_none()
+        {
+            return Err(tonic::Status::not_found(format!(
+                "channel {channel_id} on port {port_id} not found"
+            )));
+        }
+
+        let next_sequence_send = state
+            .get_send_sequence(&channel_id, &port_id)
+            .await
+            .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
+        let next_sequence_recv = state
+            .get_recv_sequence(&channel_id, &port_id)
+            .await
+            .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
+        let next_sequence_ack = state
+            .get_ack_sequence(&channel_id, &port_id)
+            .await
+            .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
+
+        Ok(tonic::Response::new(IbcChannelSequencesResponse {
+            next_sequence_send,
+            next_sequence_recv,
+            next_sequence_ack,
+        }))
+    }
+
+    type Ics20EscrowStream =
+        Pin<Box<dyn futures::Stream<Item = Result<Ics20EscrowResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn ics20_escrow(
+        &self,
+        request: tonic::Request<Ics20EscrowRequest>,
+    ) -> Result<tonic::Response<Self::Ics20EscrowStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+
+        let channel_id =
+            if request.channel_id.is_empty() {
+                None
+            } else {
+                Some(ChannelId::from_str(&request.channel_id).map_err(|e| {
+                    tonic::Status::invalid_argument(format!("invalid channel id: {e}"))
+                })?)
+            };
+
+        let prefix = ibc_state_key::ics20_value_balances(channel_id.as_ref());
+        let balances: Vec<(String, Amount)> = state
+            .prefix(&prefix)
+            .try_collect()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting escrow: {e}")))?;
+
+        let balances = balances
+            .into_iter()
+            .map(|(key, amount)| {
+                // Keys have the form ics20-value-balance/{channel_id}/{asset_id}.
+                let (channel_id, asset_id) = match key.split('/').collect::<Vec<_>>()[..] {
+                    [_, channel_id, asset_id] => (
+                        channel_id.to_string(),
+                        asset::Id::from_str(asset_id)
+                            .map_err(|e| tonic::Status::internal(e.to_string()))?,
+                    ),
+                    _ => {
+                        return Err(tonic::Status::internal(format!(
+                            "unexpected escrow key {key}"
+                        )))
+                    }
+                };
+                Ok(Ics20EscrowResponse {
+                    channel_id,
+                    value: Some(Value { amount, asset_id }.into()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(balances).boxed(),
+        ))
+    }
+}
+
+/// Streams the events stored in nonconsensus storage under `prefix(height)`, block by block,
+/// starting from `start_height` (or from the next block, if it is zero) and following new blocks
+/// as they are committed.
+fn events_by_height<E>(
+    storage: &Storage,
+    start_height: u64,
+    prefix: fn(u64) -> String,
+) -> impl futures::Stream<Item = Result<(u64, E), tonic::Status>> + Send + 'static
+where
+    E: DomainType + Send + 'static,
+{
+    // Subscribe before reading the current height, so no blocks are missed in between.
+    let mut state_rx = storage.subscribe();
+    let state = storage.latest_snapshot();
+
+    try_stream! {
+        let current_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting block height: {e}")))?;
+
+        // If no start height was requested, only stream events from new blocks.
+        let mut next_height = if start_height == 0 {
+            current_height + 1
+        } else {
+            start_height
+        };
+
+        loop {
+            let snapshot = state_rx.borrow_and_update().clone();
+            while next_height <= snapshot.version() {
+                let events: Vec<(Vec<u8>, Vec<u8>)> = snapshot
+                    .nonconsensus_prefix_raw(prefix(next_height).as_bytes())
+                    .try_collect()
+                    .await
+                    .map_err(|e| tonic::Status::unavailable(format!("error getting events: {e}")))?;
+                for (_key, bytes) in events {
+                    let event = E::decode(bytes.as_slice())
+                        .map_err(|e| tonic::Status::internal(format!("invalid event: {e}")))?;
+                    yield (next_height, event);
+                }
+                next_height += 1;
+            }
+
+            // Wait for the next block to be committed.
+            state_rx
+                .changed()
+                .await
+                .map_err(|e| tonic::Status::unavailable(format!("storage closed: {e}")))?;
+        }
+    }
 }
@@ -232,9 +232,7 @@ pub fn write_configs(
     validator_spend_key_file_path.push("validator_custody.json");
     tracing::info!(validator_spend_key_file_path = %validator_spend_key_file_path.display(), "writing validator custody file");
     let mut validator_spend_key_file = File::create(validator_spend_key_file_path)?;
-    let validator_wallet = KeyStore {
-        spend_key: vk.validator_spend_key.clone().into(),
-    };
+    let validator_wallet = KeyStore::from_spend_key(vk.validator_spend_key.clone().into());
     validator_spend_key_file
         .write_all(serde_json::to_string_pretty(&validator_wallet)?.as_bytes())?;
 
@@ -3,11 +3,17 @@ fn main() {
         "src/gen/output_pk.bin",
         "src/gen/spend_pk.bin",
         "src/gen/swap_pk.bin",
+        "src/gen/swapclaim_pk.bin",
+        "src/gen/delegatorvote_pk.bin",
+        "src/gen/undelegateclaim_pk.bin",
     ];
     let verification_parameter_files = [
         "src/gen/output_vk.param",
         "src/gen/spend_vk.param",
         "src/gen/swap_vk.param",
+        "src/gen/swapclaim_vk.param",
+        "src/gen/delegatorvote_vk.param",
+        "src/gen/undelegateclaim_vk.param",
     ];
     for file in proving_parameter_files
         .into_iter()
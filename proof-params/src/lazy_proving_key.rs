@@ -0,0 +1,98 @@
+use std::{ops::Deref, path::Path};
+
+use anyhow::{ensure, Context, Result};
+use ark_groth16::{PreparedVerifyingKey, ProvingKey};
+use ark_serialize::CanonicalDeserialize;
+use decaf377::Bls12_377;
+use once_cell::sync::{Lazy, OnceCell};
+use penumbra_crypto::proofs::groth16::VerifyingKeyExt;
+
+/// A proving key which can be loaded at runtime, rather than being embedded in the binary.
+///
+/// Keys loaded at runtime are checked against the verification key embedded in the binary, so
+/// that a corrupted or substituted proving key is rejected before it's used. If no key has been
+/// loaded when the key is first used, the embedded proving key is used if the `proving-keys`
+/// feature is enabled, and otherwise dereferencing the key panics.
+pub struct LazyProvingKey {
+    /// The name of the circuit, as used in the names of the parameter files.
+    name: &'static str,
+    vk: &'static Lazy<PreparedVerifyingKey<Bls12_377>>,
+    inner: OnceCell<ProvingKey<Bls12_377>>,
+}
+
+impl LazyProvingKey {
+    pub(crate) const fn new(
+        name: &'static str,
+        vk: &'static Lazy<PreparedVerifyingKey<Bls12_377>>,
+    ) -> Self {
+        Self {
+            name,
+            vk,
+            inner: OnceCell::new(),
+        }
+    }
+
+    /// Load the proving key from its serialized bytes.
+    ///
+    /// This fails if the proving key doesn't match the embedded verification key, or if a proving
+    /// key has already been loaded or used.
+    pub fn load(&self, pk_params: &[u8]) -> Result<()> {
+        // Unlike the embedded keys, these bytes come from an untrusted source, so they need to be
+        // checked as they're deserialized.
+        let pk = ProvingKey::deserialize(pk_params)
+            .map_err(|e| anyhow::anyhow!(e))
+            .with_context(|| format!("could not deserialize {} proving key", self.name))?;
+
+        let expected = self.vk.debug_id();
+        let actual = PreparedVerifyingKey::from(pk.vk.clone()).debug_id();
+        ensure!(
+            expected == actual,
+            "{} proving key does not match the verification key: expected {}, found {}",
+            self.name,
+            expected,
+            actual
+        );
+
+        self.inner
+            .set(pk)
+            .map_err(|_| anyhow::anyhow!("{} proving key is already loaded", self.name))
+    }
+
+    /// Load the proving key from the `<name>_pk.bin` file in the provided directory.
+    pub fn load_from_dir(&self, dir: &Path) -> Result<()> {
+        let location = dir.join(format!("{}_pk.bin", self.name));
+        let pk_params = std::fs::read(&location)
+            .with_context(|| format!("could not read {}", location.display()))?;
+        self.load(&pk_params)
+    }
+
+    /// Whether a proving key has been loaded (or the embedded one used).
+    pub fn is_loaded(&self) -> bool {
+        self.inner.get().is_some()
+    }
+
+    /// Get the proving key, falling back to the embedded one if none has been loaded.
+    pub fn try_get(&self) -> Result<&ProvingKey<Bls12_377>> {
+        #[cfg(feature = "proving-keys")]
+        {
+            Ok(self
+                .inner
+                .get_or_init(|| crate::proving_keys::embedded_proving_parameters(self.name)))
+        }
+
+        #[cfg(not(feature = "proving-keys"))]
+        {
+            self.inner
+                .get()
+                .ok_or_else(|| anyhow::anyhow!("{} proving key has not been loaded", self.name))
+        }
+    }
+}
+
+impl Deref for LazyProvingKey {
+    type Target = ProvingKey<Bls12_377>;
+
+    fn deref(&self) -> &Self::Target {
+        self.try_get().expect("proving key is available")
+    }
+}
@@ -1,38 +1,82 @@
-use ark_groth16::{PreparedVerifyingKey, ProvingKey, VerifyingKey};
+use std::path::Path;
+
+use anyhow::Result;
+use ark_groth16::{PreparedVerifyingKey, VerifyingKey};
 use ark_serialize::CanonicalDeserialize;
 use decaf377::Bls12_377;
 use once_cell::sync::Lazy;
 
+mod lazy_proving_key;
 #[cfg(feature = "proving-keys")]
 mod proving_keys;
 
+pub use lazy_proving_key::LazyProvingKey;
+
 /// Proving key for the spend proof.
-#[cfg(feature = "proving-keys")]
-pub static SPEND_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
-    Lazy::new(proving_keys::spend_proving_parameters);
+pub static SPEND_PROOF_PROVING_KEY: LazyProvingKey =
+    LazyProvingKey::new("spend", &SPEND_PROOF_VERIFICATION_KEY);
 
 /// Verifying key for the spend proof.
 pub static SPEND_PROOF_VERIFICATION_KEY: Lazy<PreparedVerifyingKey<Bls12_377>> =
     Lazy::new(|| spend_verification_parameters().into());
 
 /// Proving key for the output proof.
-#[cfg(feature = "proving-keys")]
-pub static OUTPUT_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
-    Lazy::new(proving_keys::output_proving_parameters);
+pub static OUTPUT_PROOF_PROVING_KEY: LazyProvingKey =
+    LazyProvingKey::new("output", &OUTPUT_PROOF_VERIFICATION_KEY);
 
 /// Proving key for the spend proof.
 pub static OUTPUT_PROOF_VERIFICATION_KEY: Lazy<PreparedVerifyingKey<Bls12_377>> =
     Lazy::new(|| output_verification_parameters().into());
 
-#[cfg(feature = "proving-keys")]
 /// Proving key for the swap proof.
-pub static SWAP_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
-    Lazy::new(proving_keys::swap_proving_parameters);
+pub static SWAP_PROOF_PROVING_KEY: LazyProvingKey =
+    LazyProvingKey::new("swap", &SWAP_PROOF_VERIFICATION_KEY);
 
 /// Verification key for the swap proof.
 pub static SWAP_PROOF_VERIFICATION_KEY: Lazy<PreparedVerifyingKey<Bls12_377>> =
     Lazy::new(|| swap_verification_parameters().into());
 
+/// Proving key for the swap claim proof.
+pub static SWAPCLAIM_PROOF_PROVING_KEY: LazyProvingKey =
+    LazyProvingKey::new("swapclaim", &SWAPCLAIM_PROOF_VERIFICATION_KEY);
+
+/// Verification key for the swap claim proof.
+pub static SWAPCLAIM_PROOF_VERIFICATION_KEY: Lazy<PreparedVerifyingKey<Bls12_377>> =
+    Lazy::new(|| swapclaim_verification_parameters().into());
+
+/// Proving key for the delegator vote proof.
+pub static DELEGATOR_VOTE_PROOF_PROVING_KEY: LazyProvingKey =
+    LazyProvingKey::new("delegatorvote", &DELEGATOR_VOTE_PROOF_VERIFICATION_KEY);
+
+/// Verification key for the delegator vote proof.
+pub static DELEGATOR_VOTE_PROOF_VERIFICATION_KEY: Lazy<PreparedVerifyingKey<Bls12_377>> =
+    Lazy::new(|| delegator_vote_verification_parameters().into());
+
+/// Proving key for the undelegate claim proof.
+pub static UNDELEGATECLAIM_PROOF_PROVING_KEY: LazyProvingKey =
+    LazyProvingKey::new("undelegateclaim", &UNDELEGATECLAIM_PROOF_VERIFICATION_KEY);
+
+/// Verification key for the undelegate claim proof.
+pub static UNDELEGATECLAIM_PROOF_VERIFICATION_KEY: Lazy<PreparedVerifyingKey<Bls12_377>> =
+    Lazy::new(|| undelegateclaim_verification_parameters().into());
+
+/// Load all of the proving keys from the `<name>_pk.bin` files in the provided directory.
+///
+/// This must be called before any of the proving keys are used.
+pub fn load_proving_keys(dir: &Path) -> Result<()> {
+    for pk in [
+        &SPEND_PROOF_PROVING_KEY,
+        &OUTPUT_PROOF_PROVING_KEY,
+        &SWAP_PROOF_PROVING_KEY,
+        &SWAPCLAIM_PROOF_PROVING_KEY,
+        &DELEGATOR_VOTE_PROOF_PROVING_KEY,
+        &UNDELEGATECLAIM_PROOF_PROVING_KEY,
+    ] {
+        pk.load_from_dir(dir)?;
+    }
+    Ok(())
+}
+
 // Note: Here we are using `CanonicalDeserialize::deserialize_unchecked` as the
 // parameters are being loaded from a trusted source (our source code).
 
@@ -50,3 +94,18 @@ fn swap_verification_parameters() -> VerifyingKey<Bls12_377> {
     let vk_params = include_bytes!("gen/swap_vk.param");
     VerifyingKey::deserialize_unchecked(&vk_params[..]).expect("can deserialize VerifyingKey")
 }
+
+fn swapclaim_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/swapclaim_vk.param");
+    VerifyingKey::deserialize_unchecked(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
+
+fn delegator_vote_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/delegatorvote_vk.param");
+    VerifyingKey::deserialize_unchecked(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
+
+fn undelegateclaim_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/undelegateclaim_vk.param");
+    VerifyingKey::deserialize_unchecked(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
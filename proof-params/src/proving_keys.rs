@@ -18,6 +18,34 @@ pub fn swap_proving_parameters() -> ProvingKey<Bls12_377> {
     load_proving_parameters(pk_params)
 }
 
+pub fn swapclaim_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/swapclaim_pk.bin");
+    load_proving_parameters(pk_params)
+}
+
+pub fn delegator_vote_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/delegatorvote_pk.bin");
+    load_proving_parameters(pk_params)
+}
+
+pub fn undelegateclaim_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/undelegateclaim_pk.bin");
+    load_proving_parameters(pk_params)
+}
+
+/// The embedded proving key for the circuit with the provided name.
+pub fn embedded_proving_parameters(name: &str) -> ProvingKey<Bls12_377> {
+    match name {
+        "output" => output_proving_parameters(),
+        "spend" => spend_proving_parameters(),
+        "swap" => swap_proving_parameters(),
+        "swapclaim" => swapclaim_proving_parameters(),
+        "delegatorvote" => delegator_vote_proving_parameters(),
+        "undelegateclaim" => undelegateclaim_proving_parameters(),
+        _ => panic!("no embedded proving key for {name}"),
+    }
+}
+
 /// Given a byte slice, deserialize it into a proving key.
 pub fn load_proving_parameters(pk_params: &[u8]) -> ProvingKey<Bls12_377> {
     // If the system does not have Git LFS installed, then the files will
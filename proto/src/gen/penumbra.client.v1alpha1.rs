@@ -59,6 +59,25 @@ pub struct ValidatorInfoResponse {
         super::super::core::stake::v1alpha1::ValidatorInfo,
     >,
 }
+/// Requests the rates of all validators in an epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRatesRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub epoch_index: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRatesResponse {
+    #[prost(message, optional, tag = "1")]
+    pub rate_data: ::core::option::Option<super::super::core::stake::v1alpha1::RateData>,
+    /// The slashing penalty applied to the validator's rate at the start of the epoch.
+    #[prost(message, optional, tag = "2")]
+    pub penalty: ::core::option::Option<super::super::core::stake::v1alpha1::Penalty>,
+}
 /// Lists all assets in Asset Registry
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -136,6 +155,45 @@ pub struct ValidatorPenaltyResponse {
     #[prost(message, optional, tag = "1")]
     pub penalty: ::core::option::Option<super::super::core::stake::v1alpha1::Penalty>,
 }
+/// Requests a validator's recent signing performance and rate history.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorUptimeRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The number of most recent epochs of rate history to return (0 for all).
+    #[prost(uint64, tag = "3")]
+    pub rate_history_epochs: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorUptimeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub state: ::core::option::Option<
+        super::super::core::stake::v1alpha1::ValidatorState,
+    >,
+    /// The validator's signing record over the signed blocks window.
+    ///
+    /// This is only present for validators that have been active.
+    #[prost(message, optional, tag = "2")]
+    pub uptime: ::core::option::Option<super::super::core::stake::v1alpha1::Uptime>,
+    /// The number of blocks the validator has missed signing in the window.
+    #[prost(uint64, tag = "3")]
+    pub missed_blocks: u64,
+    /// The number of missed blocks in the window at which the validator is jailed.
+    #[prost(uint64, tag = "4")]
+    pub missed_blocks_maximum: u64,
+    /// The validator's rate data in each epoch, oldest first.
+    #[prost(message, repeated, tag = "5")]
+    pub rate_history: ::prost::alloc::vec::Vec<
+        super::super::core::stake::v1alpha1::RateData,
+    >,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NextValidatorRateRequest {
@@ -251,6 +309,218 @@ pub struct ProposalRateDataResponse {
     #[prost(message, optional, tag = "1")]
     pub rate_data: ::core::option::Option<super::super::core::stake::v1alpha1::RateData>,
 }
+/// Requests whether a nullifier has voted on a proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalNullifierStatusRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The proposal id to request information on.
+    #[prost(uint64, tag = "2")]
+    pub proposal_id: u64,
+    /// The nullifier to check.
+    #[prost(message, optional, tag = "3")]
+    pub nullifier: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Nullifier,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalNullifierStatusResponse {
+    /// Whether the nullifier has been used to vote on the proposal.
+    #[prost(bool, tag = "1")]
+    pub voted: bool,
+}
+/// Requests a stream of proposal lifecycle events.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEventsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// If nonzero, first replay the events since this height, before streaming new events.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+}
+/// A single proposal event, and the height at which it occurred.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEventsResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, optional, tag = "2")]
+    pub event: ::core::option::Option<
+        super::super::core::governance::v1alpha1::ProposalEvent,
+    >,
+}
+/// Requests a stream of validator events.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorEventsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// If nonzero, first replay the events since this height, before streaming new events.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// If nonempty, only return events concerning these validators.
+    #[prost(message, repeated, tag = "3")]
+    pub identity_keys: ::prost::alloc::vec::Vec<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+}
+/// A single validator event, and the height at which it occurred.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorEventsResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, optional, tag = "2")]
+    pub event: ::core::option::Option<
+        super::super::core::stake::v1alpha1::ValidatorEvent,
+    >,
+}
+/// Requests the evidence of validator misbehavior processed by the chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorEvidenceRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// If set, only return evidence against this validator.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorEvidenceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub record: ::core::option::Option<
+        super::super::core::stake::v1alpha1::EvidenceRecord,
+    >,
+}
+/// Requests all IBC light clients tracked by the chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcClientsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// A single IBC light client.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcClientsResponse {
+    /// The client's identifier and its current client state.
+    #[prost(message, optional, tag = "1")]
+    pub client_state: ::core::option::Option<
+        ::ibc_proto::ibc::core::client::v1::IdentifiedClientState,
+    >,
+    /// The verified consensus state at the client's latest height.
+    #[prost(message, optional, tag = "2")]
+    pub latest_consensus_state: ::core::option::Option<
+        ::ibc_proto::ibc::core::client::v1::ConsensusStateWithHeight,
+    >,
+}
+/// Requests all IBC connections known to the chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcConnectionsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcConnectionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub connection: ::core::option::Option<
+        ::ibc_proto::ibc::core::connection::v1::IdentifiedConnection,
+    >,
+}
+/// Requests all IBC channel ends known to the chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcChannelsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcChannelsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub channel: ::core::option::Option<
+        ::ibc_proto::ibc::core::channel::v1::IdentifiedChannel,
+    >,
+}
+/// Requests the outstanding packet commitments on a channel, i.e. the packets
+/// sent by this chain that have been neither acknowledged nor timed out.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcPacketCommitmentsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub channel_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcPacketCommitmentsResponse {
+    /// The packet's sequence number, with `data` set to the packet commitment.
+    #[prost(message, optional, tag = "1")]
+    pub commitment: ::core::option::Option<
+        ::ibc_proto::ibc::core::channel::v1::PacketState,
+    >,
+}
+/// Requests the send, receive and acknowledgement sequence counters of a channel.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcChannelSequencesRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub channel_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcChannelSequencesResponse {
+    #[prost(uint64, tag = "1")]
+    pub next_sequence_send: u64,
+    #[prost(uint64, tag = "2")]
+    pub next_sequence_recv: u64,
+    #[prost(uint64, tag = "3")]
+    pub next_sequence_ack: u64,
+}
+/// Requests the value escrowed by ICS-20 transfers out of this chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20EscrowRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// If set, only return the escrow for this channel.
+    #[prost(string, tag = "2")]
+    pub channel_id: ::prost::alloc::string::String,
+}
+/// The amount of a single asset escrowed on a single channel.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20EscrowResponse {
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<super::super::core::crypto::v1alpha1::Value>,
+}
 /// Performs a key-value query, either by key or by key hash.
 ///
 /// Proofs are only supported by key.
@@ -646,6 +916,29 @@ pub mod oblivious_query_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Returns the rates of all validators in a past or current epoch.
+        pub async fn validator_rates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorRatesRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorRatesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.ObliviousQueryService/ValidatorRates",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -780,6 +1073,26 @@ pub mod specific_query_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Reports a validator's recent signing performance and rate history.
+        pub async fn validator_uptime(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorUptimeRequest>,
+        ) -> Result<tonic::Response<super::ValidatorUptimeResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorUptime",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         pub async fn next_validator_rate(
             &mut self,
             request: impl tonic::IntoRequest<super::NextValidatorRateRequest>,
@@ -897,12 +1210,14 @@ pub mod specific_query_service_client {
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
-        /// General-purpose key-value state query API, that can be used to query
-        /// arbitrary keys in the JMT storage.
-        pub async fn key_value(
+        /// Reports whether a nullifier has been used to vote on a proposal.
+        pub async fn proposal_nullifier_status(
             &mut self,
-            request: impl tonic::IntoRequest<super::KeyValueRequest>,
-        ) -> Result<tonic::Response<super::KeyValueResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::ProposalNullifierStatusRequest>,
+        ) -> Result<
+            tonic::Response<super::ProposalNullifierStatusResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -914,17 +1229,16 @@ pub mod specific_query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.client.v1alpha1.SpecificQueryService/KeyValue",
+                "/penumbra.client.v1alpha1.SpecificQueryService/ProposalNullifierStatus",
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
-        /// General-purpose prefixed key-value state query API, that can be used to query
-        /// arbitrary prefixes in the JMT storage.
-        pub async fn prefix_value(
+        /// Streams proposal lifecycle events as they occur.
+        pub async fn proposal_events(
             &mut self,
-            request: impl tonic::IntoRequest<super::PrefixValueRequest>,
+            request: impl tonic::IntoRequest<super::ProposalEventsRequest>,
         ) -> Result<
-            tonic::Response<tonic::codec::Streaming<super::PrefixValueResponse>>,
+            tonic::Response<tonic::codec::Streaming<super::ProposalEventsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -938,48 +1252,271 @@ pub mod specific_query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.client.v1alpha1.SpecificQueryService/PrefixValue",
+                "/penumbra.client.v1alpha1.SpecificQueryService/ProposalEvents",
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
-    }
-}
-/// Generated client implementations.
-#[cfg(feature = "rpc")]
-pub mod tendermint_proxy_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// Defines the gRPC query service for proxying requests to an upstream Tendermint RPC.
-    #[derive(Debug, Clone)]
-    pub struct TendermintProxyServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl TendermintProxyServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: std::convert::TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> TendermintProxyServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
+        /// Streams changes to validators: state transitions, rate changes and slashing.
+        pub async fn validator_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorEventsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorEventsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorEvents",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// Lists the evidence of validator misbehavior processed by the chain.
+        pub async fn validator_evidence(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorEvidenceRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorEvidenceResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorEvidence",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// IBC inspection queries, decoding the IBC component's state for debugging relayers.
+        pub async fn ibc_clients(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IbcClientsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::IbcClientsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcClients",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn ibc_connections(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IbcConnectionsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::IbcConnectionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcConnections",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn ibc_channels(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IbcChannelsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::IbcChannelsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcChannels",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn ibc_packet_commitments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IbcPacketCommitmentsRequest>,
+        ) -> Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::IbcPacketCommitmentsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcPacketCommitments",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn ibc_channel_sequences(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IbcChannelSequencesRequest>,
+        ) -> Result<tonic::Response<super::IbcChannelSequencesResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcChannelSequences",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn ics20_escrow(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Ics20EscrowRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::Ics20EscrowResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/Ics20Escrow",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// General-purpose key-value state query API, that can be used to query
+        /// arbitrary keys in the JMT storage.
+        pub async fn key_value(
+            &mut self,
+            request: impl tonic::IntoRequest<super::KeyValueRequest>,
+        ) -> Result<tonic::Response<super::KeyValueResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/KeyValue",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// General-purpose prefixed key-value state query API, that can be used to query
+        /// arbitrary prefixes in the JMT storage.
+        pub async fn prefix_value(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PrefixValueRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::PrefixValueResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/PrefixValue",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod tendermint_proxy_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Defines the gRPC query service for proxying requests to an upstream Tendermint RPC.
+    #[derive(Debug, Clone)]
+    pub struct TendermintProxyServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TendermintProxyServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TendermintProxyServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
         }
         pub fn with_interceptor<F>(
             inner: T,
@@ -1177,6 +1714,17 @@ pub mod oblivious_query_service_server {
             &self,
             request: tonic::Request<super::AssetListRequest>,
         ) -> Result<tonic::Response<super::AssetListResponse>, tonic::Status>;
+        /// Server streaming response type for the ValidatorRates method.
+        type ValidatorRatesStream: futures_core::Stream<
+                Item = Result<super::ValidatorRatesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Returns the rates of all validators in a past or current epoch.
+        async fn validator_rates(
+            &self,
+            request: tonic::Request<super::ValidatorRatesRequest>,
+        ) -> Result<tonic::Response<Self::ValidatorRatesStream>, tonic::Status>;
     }
     /// Methods for accessing chain state that are "oblivious" in the sense that they
     /// do not request specific portions of the chain state that could reveal private
@@ -1404,33 +1952,74 @@ pub mod oblivious_query_service_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
-            }
-        }
-    }
-    impl<T: ObliviousQueryService> Clone for ObliviousQueryServiceServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-            }
-        }
-    }
-    impl<T: ObliviousQueryService> Clone for _Inner<T> {
-        fn clone(&self) -> Self {
+                "/penumbra.client.v1alpha1.ObliviousQueryService/ValidatorRates" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorRatesSvc<T: ObliviousQueryService>(pub Arc<T>);
+                    impl<
+                        T: ObliviousQueryService,
+                    > tonic::server::ServerStreamingService<super::ValidatorRatesRequest>
+                    for ValidatorRatesSvc<T> {
+                        type Response = super::ValidatorRatesResponse;
+                        type ResponseStream = T::ValidatorRatesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorRatesRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).validator_rates(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorRatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ObliviousQueryService> Clone for ObliviousQueryServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: ObliviousQueryService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
             Self(self.0.clone())
         }
     }
@@ -1464,6 +2053,11 @@ pub mod specific_query_service_server {
             &self,
             request: tonic::Request<super::ValidatorPenaltyRequest>,
         ) -> Result<tonic::Response<super::ValidatorPenaltyResponse>, tonic::Status>;
+        /// Reports a validator's recent signing performance and rate history.
+        async fn validator_uptime(
+            &self,
+            request: tonic::Request<super::ValidatorUptimeRequest>,
+        ) -> Result<tonic::Response<super::ValidatorUptimeResponse>, tonic::Status>;
         async fn next_validator_rate(
             &self,
             request: tonic::Request<super::NextValidatorRateRequest>,
@@ -1494,6 +2088,102 @@ pub mod specific_query_service_server {
             &self,
             request: tonic::Request<super::ProposalRateDataRequest>,
         ) -> Result<tonic::Response<Self::ProposalRateDataStream>, tonic::Status>;
+        /// Reports whether a nullifier has been used to vote on a proposal.
+        async fn proposal_nullifier_status(
+            &self,
+            request: tonic::Request<super::ProposalNullifierStatusRequest>,
+        ) -> Result<
+            tonic::Response<super::ProposalNullifierStatusResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ProposalEvents method.
+        type ProposalEventsStream: futures_core::Stream<
+                Item = Result<super::ProposalEventsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Streams proposal lifecycle events as they occur.
+        async fn proposal_events(
+            &self,
+            request: tonic::Request<super::ProposalEventsRequest>,
+        ) -> Result<tonic::Response<Self::ProposalEventsStream>, tonic::Status>;
+        /// Server streaming response type for the ValidatorEvents method.
+        type ValidatorEventsStream: futures_core::Stream<
+                Item = Result<super::ValidatorEventsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Streams changes to validators: state transitions, rate changes and slashing.
+        async fn validator_events(
+            &self,
+            request: tonic::Request<super::ValidatorEventsRequest>,
+        ) -> Result<tonic::Response<Self::ValidatorEventsStream>, tonic::Status>;
+        /// Server streaming response type for the ValidatorEvidence method.
+        type ValidatorEvidenceStream: futures_core::Stream<
+                Item = Result<super::ValidatorEvidenceResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Lists the evidence of validator misbehavior processed by the chain.
+        async fn validator_evidence(
+            &self,
+            request: tonic::Request<super::ValidatorEvidenceRequest>,
+        ) -> Result<tonic::Response<Self::ValidatorEvidenceStream>, tonic::Status>;
+        /// Server streaming response type for the IbcClients method.
+        type IbcClientsStream: futures_core::Stream<
+                Item = Result<super::IbcClientsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// IBC inspection queries, decoding the IBC component's state for debugging relayers.
+        async fn ibc_clients(
+            &self,
+            request: tonic::Request<super::IbcClientsRequest>,
+        ) -> Result<tonic::Response<Self::IbcClientsStream>, tonic::Status>;
+        /// Server streaming response type for the IbcConnections method.
+        type IbcConnectionsStream: futures_core::Stream<
+                Item = Result<super::IbcConnectionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn ibc_connections(
+            &self,
+            request: tonic::Request<super::IbcConnectionsRequest>,
+        ) -> Result<tonic::Response<Self::IbcConnectionsStream>, tonic::Status>;
+        /// Server streaming response type for the IbcChannels method.
+        type IbcChannelsStream: futures_core::Stream<
+                Item = Result<super::IbcChannelsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn ibc_channels(
+            &self,
+            request: tonic::Request<super::IbcChannelsRequest>,
+        ) -> Result<tonic::Response<Self::IbcChannelsStream>, tonic::Status>;
+        /// Server streaming response type for the IbcPacketCommitments method.
+        type IbcPacketCommitmentsStream: futures_core::Stream<
+                Item = Result<super::IbcPacketCommitmentsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn ibc_packet_commitments(
+            &self,
+            request: tonic::Request<super::IbcPacketCommitmentsRequest>,
+        ) -> Result<tonic::Response<Self::IbcPacketCommitmentsStream>, tonic::Status>;
+        async fn ibc_channel_sequences(
+            &self,
+            request: tonic::Request<super::IbcChannelSequencesRequest>,
+        ) -> Result<tonic::Response<super::IbcChannelSequencesResponse>, tonic::Status>;
+        /// Server streaming response type for the Ics20Escrow method.
+        type Ics20EscrowStream: futures_core::Stream<
+                Item = Result<super::Ics20EscrowResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn ics20_escrow(
+            &self,
+            request: tonic::Request<super::Ics20EscrowRequest>,
+        ) -> Result<tonic::Response<Self::Ics20EscrowStream>, tonic::Status>;
         /// General-purpose key-value state query API, that can be used to query
         /// arbitrary keys in the JMT storage.
         async fn key_value(
@@ -1698,6 +2388,46 @@ pub mod specific_query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorUptime" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorUptimeSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::ValidatorUptimeRequest>
+                    for ValidatorUptimeSvc<T> {
+                        type Response = super::ValidatorUptimeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorUptimeRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).validator_uptime(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorUptimeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.client.v1alpha1.SpecificQueryService/NextValidatorRate" => {
                     #[allow(non_camel_case_types)]
                     struct NextValidatorRateSvc<T: SpecificQueryService>(pub Arc<T>);
@@ -1938,6 +2668,419 @@ pub mod specific_query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.SpecificQueryService/ProposalNullifierStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalNullifierStatusSvc<T: SpecificQueryService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::ProposalNullifierStatusRequest>
+                    for ProposalNullifierStatusSvc<T> {
+                        type Response = super::ProposalNullifierStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ProposalNullifierStatusRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).proposal_nullifier_status(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ProposalNullifierStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/ProposalEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalEventsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::ProposalEventsRequest>
+                    for ProposalEventsSvc<T> {
+                        type Response = super::ProposalEventsResponse;
+                        type ResponseStream = T::ProposalEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProposalEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).proposal_events(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ProposalEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorEventsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::ValidatorEventsRequest,
+                    > for ValidatorEventsSvc<T> {
+                        type Response = super::ValidatorEventsResponse;
+                        type ResponseStream = T::ValidatorEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).validator_events(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorEvidence" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorEvidenceSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::ValidatorEvidenceRequest,
+                    > for ValidatorEvidenceSvc<T> {
+                        type Response = super::ValidatorEvidenceResponse;
+                        type ResponseStream = T::ValidatorEvidenceStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorEvidenceRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).validator_evidence(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorEvidenceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcClients" => {
+                    #[allow(non_camel_case_types)]
+                    struct IbcClientsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::IbcClientsRequest>
+                    for IbcClientsSvc<T> {
+                        type Response = super::IbcClientsResponse;
+                        type ResponseStream = T::IbcClientsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IbcClientsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).ibc_clients(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IbcClientsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcConnections" => {
+                    #[allow(non_camel_case_types)]
+                    struct IbcConnectionsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::IbcConnectionsRequest>
+                    for IbcConnectionsSvc<T> {
+                        type Response = super::IbcConnectionsResponse;
+                        type ResponseStream = T::IbcConnectionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IbcConnectionsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).ibc_connections(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IbcConnectionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcChannels" => {
+                    #[allow(non_camel_case_types)]
+                    struct IbcChannelsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::IbcChannelsRequest>
+                    for IbcChannelsSvc<T> {
+                        type Response = super::IbcChannelsResponse;
+                        type ResponseStream = T::IbcChannelsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IbcChannelsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).ibc_channels(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IbcChannelsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcPacketCommitments" => {
+                    #[allow(non_camel_case_types)]
+                    struct IbcPacketCommitmentsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::IbcPacketCommitmentsRequest,
+                    > for IbcPacketCommitmentsSvc<T> {
+                        type Response = super::IbcPacketCommitmentsResponse;
+                        type ResponseStream = T::IbcPacketCommitmentsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IbcPacketCommitmentsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).ibc_packet_commitments(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IbcPacketCommitmentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/IbcChannelSequences" => {
+                    #[allow(non_camel_case_types)]
+                    struct IbcChannelSequencesSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::IbcChannelSequencesRequest>
+                    for IbcChannelSequencesSvc<T> {
+                        type Response = super::IbcChannelSequencesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IbcChannelSequencesRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).ibc_channel_sequences(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IbcChannelSequencesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/Ics20Escrow" => {
+                    #[allow(non_camel_case_types)]
+                    struct Ics20EscrowSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::Ics20EscrowRequest>
+                    for Ics20EscrowSvc<T> {
+                        type Response = super::Ics20EscrowResponse;
+                        type ResponseStream = T::Ics20EscrowStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Ics20EscrowRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).ics20_escrow(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = Ics20EscrowSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.client.v1alpha1.SpecificQueryService/KeyValue" => {
                     #[allow(non_camel_case_types)]
                     struct KeyValueSvc<T: SpecificQueryService>(pub Arc<T>);
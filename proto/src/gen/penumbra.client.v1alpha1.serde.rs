@@ -2139,7 +2139,7 @@ impl<'de> serde::Deserialize<'de> for GetTxResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.GetTxResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for KeyValueRequest {
+impl serde::Serialize for IbcChannelSequencesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2150,26 +2150,26 @@ impl serde::Serialize for KeyValueRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if !self.key.is_empty() {
+        if !self.port_id.is_empty() {
             len += 1;
         }
-        if self.proof {
+        if !self.channel_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcChannelSequencesRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if !self.key.is_empty() {
-            struct_ser.serialize_field("key", &self.key)?;
+        if !self.port_id.is_empty() {
+            struct_ser.serialize_field("portId", &self.port_id)?;
         }
-        if self.proof {
-            struct_ser.serialize_field("proof", &self.proof)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for KeyValueRequest {
+impl<'de> serde::Deserialize<'de> for IbcChannelSequencesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2178,15 +2178,17 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "key",
-            "proof",
+            "port_id",
+            "portId",
+            "channel_id",
+            "channelId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            Key,
-            Proof,
+            PortId,
+            ChannelId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2209,8 +2211,8 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "key" => Ok(GeneratedField::Key),
-                            "proof" => Ok(GeneratedField::Proof),
+                            "portId" | "port_id" => Ok(GeneratedField::PortId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2220,19 +2222,19 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = KeyValueRequest;
+            type Value = IbcChannelSequencesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.KeyValueRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcChannelSequencesRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<KeyValueRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcChannelSequencesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut key__ = None;
-                let mut proof__ = None;
+                let mut port_id__ = None;
+                let mut channel_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -2241,31 +2243,31 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::Key => {
-                            if key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("key"));
+                        GeneratedField::PortId => {
+                            if port_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("portId"));
                             }
-                            key__ = Some(map.next_value()?);
+                            port_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
                             }
-                            proof__ = Some(map.next_value()?);
+                            channel_id__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(KeyValueRequest {
+                Ok(IbcChannelSequencesRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    key: key__.unwrap_or_default(),
-                    proof: proof__.unwrap_or_default(),
+                    port_id: port_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcChannelSequencesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for KeyValueResponse {
+impl serde::Serialize for IbcChannelSequencesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2273,37 +2275,48 @@ impl serde::Serialize for KeyValueResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.value.is_empty() {
+        if self.next_sequence_send != 0 {
             len += 1;
         }
-        if self.proof.is_some() {
+        if self.next_sequence_recv != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueResponse", len)?;
-        if !self.value.is_empty() {
-            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        if self.next_sequence_ack != 0 {
+            len += 1;
         }
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcChannelSequencesResponse", len)?;
+        if self.next_sequence_send != 0 {
+            struct_ser.serialize_field("nextSequenceSend", ToString::to_string(&self.next_sequence_send).as_str())?;
+        }
+        if self.next_sequence_recv != 0 {
+            struct_ser.serialize_field("nextSequenceRecv", ToString::to_string(&self.next_sequence_recv).as_str())?;
+        }
+        if self.next_sequence_ack != 0 {
+            struct_ser.serialize_field("nextSequenceAck", ToString::to_string(&self.next_sequence_ack).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for KeyValueResponse {
+impl<'de> serde::Deserialize<'de> for IbcChannelSequencesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "proof",
+            "next_sequence_send",
+            "nextSequenceSend",
+            "next_sequence_recv",
+            "nextSequenceRecv",
+            "next_sequence_ack",
+            "nextSequenceAck",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            Proof,
+            NextSequenceSend,
+            NextSequenceRecv,
+            NextSequenceAck,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2325,8 +2338,9 @@ impl<'de> serde::Deserialize<'de> for KeyValueResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "proof" => Ok(GeneratedField::Proof),
+                            "nextSequenceSend" | "next_sequence_send" => Ok(GeneratedField::NextSequenceSend),
+                            "nextSequenceRecv" | "next_sequence_recv" => Ok(GeneratedField::NextSequenceRecv),
+                            "nextSequenceAck" | "next_sequence_ack" => Ok(GeneratedField::NextSequenceAck),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2336,46 +2350,58 @@ impl<'de> serde::Deserialize<'de> for KeyValueResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = KeyValueResponse;
+            type Value = IbcChannelSequencesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.KeyValueResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcChannelSequencesResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<KeyValueResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcChannelSequencesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut proof__ = None;
+                let mut next_sequence_send__ = None;
+                let mut next_sequence_recv__ = None;
+                let mut next_sequence_ack__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::NextSequenceSend => {
+                            if next_sequence_send__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextSequenceSend"));
                             }
-                            value__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            next_sequence_send__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::NextSequenceRecv => {
+                            if next_sequence_recv__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextSequenceRecv"));
                             }
-                            proof__ = map.next_value()?;
+                            next_sequence_recv__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NextSequenceAck => {
+                            if next_sequence_ack__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextSequenceAck"));
+                            }
+                            next_sequence_ack__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(KeyValueResponse {
-                    value: value__.unwrap_or_default(),
-                    proof: proof__,
+                Ok(IbcChannelSequencesResponse {
+                    next_sequence_send: next_sequence_send__.unwrap_or_default(),
+                    next_sequence_recv: next_sequence_recv__.unwrap_or_default(),
+                    next_sequence_ack: next_sequence_ack__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcChannelSequencesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NextValidatorRateRequest {
+impl serde::Serialize for IbcChannelsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2386,20 +2412,14 @@ impl serde::Serialize for NextValidatorRateRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.identity_key.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.NextValidatorRateRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcChannelsRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NextValidatorRateRequest {
+impl<'de> serde::Deserialize<'de> for IbcChannelsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2408,14 +2428,11 @@ impl<'de> serde::Deserialize<'de> for NextValidatorRateRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "identity_key",
-            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            IdentityKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2438,7 +2455,6 @@ impl<'de> serde::Deserialize<'de> for NextValidatorRateRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2448,18 +2464,17 @@ impl<'de> serde::Deserialize<'de> for NextValidatorRateRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NextValidatorRateRequest;
+            type Value = IbcChannelsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.NextValidatorRateRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcChannelsRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NextValidatorRateRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcChannelsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut identity_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -2468,24 +2483,17 @@ impl<'de> serde::Deserialize<'de> for NextValidatorRateRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
-                            }
-                            identity_key__ = map.next_value()?;
-                        }
                     }
                 }
-                Ok(NextValidatorRateRequest {
+                Ok(IbcChannelsRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.NextValidatorRateRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcChannelsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NextValidatorRateResponse {
+impl serde::Serialize for IbcChannelsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2493,29 +2501,29 @@ impl serde::Serialize for NextValidatorRateResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.channel.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.NextValidatorRateResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcChannelsResponse", len)?;
+        if let Some(v) = self.channel.as_ref() {
+            struct_ser.serialize_field("channel", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NextValidatorRateResponse {
+impl<'de> serde::Deserialize<'de> for IbcChannelsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "channel",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            Channel,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2537,7 +2545,7 @@ impl<'de> serde::Deserialize<'de> for NextValidatorRateResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "channel" => Ok(GeneratedField::Channel),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2547,36 +2555,36 @@ impl<'de> serde::Deserialize<'de> for NextValidatorRateResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NextValidatorRateResponse;
+            type Value = IbcChannelsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.NextValidatorRateResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcChannelsResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NextValidatorRateResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcChannelsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut channel__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Channel => {
+                            if channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channel"));
                             }
-                            data__ = map.next_value()?;
+                            channel__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(NextValidatorRateResponse {
-                    data: data__,
+                Ok(IbcChannelsResponse {
+                    channel: channel__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.NextValidatorRateResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcChannelsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PrefixValueRequest {
+impl serde::Serialize for IbcClientsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2587,20 +2595,14 @@ impl serde::Serialize for PrefixValueRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if !self.prefix.is_empty() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PrefixValueRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcClientsRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if !self.prefix.is_empty() {
-            struct_ser.serialize_field("prefix", &self.prefix)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
+impl<'de> serde::Deserialize<'de> for IbcClientsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2609,13 +2611,11 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "prefix",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            Prefix,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2638,7 +2638,6 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "prefix" => Ok(GeneratedField::Prefix),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2648,18 +2647,17 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PrefixValueRequest;
+            type Value = IbcClientsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.PrefixValueRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcClientsRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<PrefixValueRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcClientsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut prefix__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -2668,24 +2666,17 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::Prefix => {
-                            if prefix__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("prefix"));
-                            }
-                            prefix__ = Some(map.next_value()?);
-                        }
                     }
                 }
-                Ok(PrefixValueRequest {
+                Ok(IbcClientsRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    prefix: prefix__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.PrefixValueRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcClientsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PrefixValueResponse {
+impl serde::Serialize for IbcClientsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2693,37 +2684,39 @@ impl serde::Serialize for PrefixValueResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.key.is_empty() {
+        if self.client_state.is_some() {
             len += 1;
         }
-        if !self.value.is_empty() {
+        if self.latest_consensus_state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", len)?;
-        if !self.key.is_empty() {
-            struct_ser.serialize_field("key", &self.key)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcClientsResponse", len)?;
+        if let Some(v) = self.client_state.as_ref() {
+            struct_ser.serialize_field("clientState", v)?;
         }
-        if !self.value.is_empty() {
-            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        if let Some(v) = self.latest_consensus_state.as_ref() {
+            struct_ser.serialize_field("latestConsensusState", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
+impl<'de> serde::Deserialize<'de> for IbcClientsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "key",
-            "value",
+            "client_state",
+            "clientState",
+            "latest_consensus_state",
+            "latestConsensusState",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Key,
-            Value,
+            ClientState,
+            LatestConsensusState,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2745,8 +2738,8 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "key" => Ok(GeneratedField::Key),
-                            "value" => Ok(GeneratedField::Value),
+                            "clientState" | "client_state" => Ok(GeneratedField::ClientState),
+                            "latestConsensusState" | "latest_consensus_state" => Ok(GeneratedField::LatestConsensusState),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2756,46 +2749,44 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PrefixValueResponse;
+            type Value = IbcClientsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.PrefixValueResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcClientsResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<PrefixValueResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcClientsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut key__ = None;
-                let mut value__ = None;
+                let mut client_state__ = None;
+                let mut latest_consensus_state__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Key => {
-                            if key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("key"));
+                        GeneratedField::ClientState => {
+                            if client_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientState"));
                             }
-                            key__ = Some(map.next_value()?);
+                            client_state__ = map.next_value()?;
                         }
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::LatestConsensusState => {
+                            if latest_consensus_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestConsensusState"));
                             }
-                            value__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            latest_consensus_state__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(PrefixValueResponse {
-                    key: key__.unwrap_or_default(),
-                    value: value__.unwrap_or_default(),
+                Ok(IbcClientsResponse {
+                    client_state: client_state__,
+                    latest_consensus_state: latest_consensus_state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcClientsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalInfoRequest {
+impl serde::Serialize for IbcConnectionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2806,20 +2797,14 @@ impl serde::Serialize for ProposalInfoRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.proposal_id != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalInfoRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcConnectionsRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if self.proposal_id != 0 {
-            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
+impl<'de> serde::Deserialize<'de> for IbcConnectionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2828,14 +2813,11 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "proposal_id",
-            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            ProposalId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2858,7 +2840,6 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2868,18 +2849,17 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalInfoRequest;
+            type Value = IbcConnectionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ProposalInfoRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcConnectionsRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcConnectionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut proposal_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -2888,26 +2868,17 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::ProposalId => {
-                            if proposal_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalId"));
-                            }
-                            proposal_id__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                     }
                 }
-                Ok(ProposalInfoRequest {
+                Ok(IbcConnectionsRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcConnectionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalInfoResponse {
+impl serde::Serialize for IbcConnectionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2915,39 +2886,29 @@ impl serde::Serialize for ProposalInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_block_height != 0 {
-            len += 1;
-        }
-        if self.start_position != 0 {
+        if self.connection.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalInfoResponse", len)?;
-        if self.start_block_height != 0 {
-            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
-        }
-        if self.start_position != 0 {
-            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcConnectionsResponse", len)?;
+        if let Some(v) = self.connection.as_ref() {
+            struct_ser.serialize_field("connection", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
+impl<'de> serde::Deserialize<'de> for IbcConnectionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_block_height",
-            "startBlockHeight",
-            "start_position",
-            "startPosition",
+            "connection",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartBlockHeight,
-            StartPosition,
+            Connection,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2969,8 +2930,7 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
-                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "connection" => Ok(GeneratedField::Connection),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2980,48 +2940,36 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalInfoResponse;
+            type Value = IbcConnectionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ProposalInfoResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcConnectionsResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcConnectionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_block_height__ = None;
-                let mut start_position__ = None;
+                let mut connection__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::StartBlockHeight => {
-                            if start_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
-                            }
-                            start_block_height__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::StartPosition => {
-                            if start_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                        GeneratedField::Connection => {
+                            if connection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connection"));
                             }
-                            start_position__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            connection__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(ProposalInfoResponse {
-                    start_block_height: start_block_height__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
+                Ok(IbcConnectionsResponse {
+                    connection: connection__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcConnectionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalRateDataRequest {
+impl serde::Serialize for IbcPacketCommitmentsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3032,20 +2980,26 @@ impl serde::Serialize for ProposalRateDataRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.proposal_id != 0 {
+        if !self.port_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalRateDataRequest", len)?;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcPacketCommitmentsRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if self.proposal_id != 0 {
-            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        if !self.port_id.is_empty() {
+            struct_ser.serialize_field("portId", &self.port_id)?;
+        }
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
+impl<'de> serde::Deserialize<'de> for IbcPacketCommitmentsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3054,14 +3008,17 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "proposal_id",
-            "proposalId",
+            "port_id",
+            "portId",
+            "channel_id",
+            "channelId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            ProposalId,
+            PortId,
+            ChannelId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3084,7 +3041,8 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "portId" | "port_id" => Ok(GeneratedField::PortId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3094,18 +3052,19 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalRateDataRequest;
+            type Value = IbcPacketCommitmentsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ProposalRateDataRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcPacketCommitmentsRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalRateDataRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcPacketCommitmentsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut proposal_id__ = None;
+                let mut port_id__ = None;
+                let mut channel_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3114,26 +3073,31 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::ProposalId => {
-                            if proposal_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                        GeneratedField::PortId => {
+                            if port_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("portId"));
                             }
-                            proposal_id__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            port_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(ProposalRateDataRequest {
+                Ok(IbcPacketCommitmentsRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    proposal_id: proposal_id__.unwrap_or_default(),
+                    port_id: port_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalRateDataRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcPacketCommitmentsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalRateDataResponse {
+impl serde::Serialize for IbcPacketCommitmentsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3141,30 +3105,29 @@ impl serde::Serialize for ProposalRateDataResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rate_data.is_some() {
+        if self.commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalRateDataResponse", len)?;
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.IbcPacketCommitmentsResponse", len)?;
+        if let Some(v) = self.commitment.as_ref() {
+            struct_ser.serialize_field("commitment", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
+impl<'de> serde::Deserialize<'de> for IbcPacketCommitmentsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rate_data",
-            "rateData",
+            "commitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RateData,
+            Commitment,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3186,7 +3149,7 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "commitment" => Ok(GeneratedField::Commitment),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3196,36 +3159,36 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalRateDataResponse;
+            type Value = IbcPacketCommitmentsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ProposalRateDataResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.IbcPacketCommitmentsResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalRateDataResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IbcPacketCommitmentsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rate_data__ = None;
+                let mut commitment__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::Commitment => {
+                            if commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitment"));
                             }
-                            rate_data__ = map.next_value()?;
+                            commitment__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(ProposalRateDataResponse {
-                    rate_data: rate_data__,
+                Ok(IbcPacketCommitmentsResponse {
+                    commitment: commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalRateDataResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.IbcPacketCommitmentsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StubCpmmReservesRequest {
+impl serde::Serialize for Ics20EscrowRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3236,20 +3199,20 @@ impl serde::Serialize for StubCpmmReservesRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if !self.channel_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StubCPMMReservesRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Ics20EscrowRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StubCpmmReservesRequest {
+impl<'de> serde::Deserialize<'de> for Ics20EscrowRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3258,14 +3221,14 @@ impl<'de> serde::Deserialize<'de> for StubCpmmReservesRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "trading_pair",
-            "tradingPair",
+            "channel_id",
+            "channelId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            TradingPair,
+            ChannelId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3288,7 +3251,7 @@ impl<'de> serde::Deserialize<'de> for StubCpmmReservesRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3298,18 +3261,18 @@ impl<'de> serde::Deserialize<'de> for StubCpmmReservesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StubCpmmReservesRequest;
+            type Value = Ics20EscrowRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.StubCPMMReservesRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.Ics20EscrowRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<StubCpmmReservesRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20EscrowRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut trading_pair__ = None;
+                let mut channel_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3318,24 +3281,24 @@ impl<'de> serde::Deserialize<'de> for StubCpmmReservesRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
                             }
-                            trading_pair__ = map.next_value()?;
+                            channel_id__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(StubCpmmReservesRequest {
+                Ok(Ics20EscrowRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    trading_pair: trading_pair__,
+                    channel_id: channel_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.StubCPMMReservesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.Ics20EscrowRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StubCpmmReservesResponse {
+impl serde::Serialize for Ics20EscrowResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3343,29 +3306,38 @@ impl serde::Serialize for StubCpmmReservesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
+        if !self.channel_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StubCPMMReservesResponse", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Ics20EscrowResponse", len)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StubCpmmReservesResponse {
+impl<'de> serde::Deserialize<'de> for Ics20EscrowResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
+            "channel_id",
+            "channelId",
+            "value",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
+            ChannelId,
+            Value,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3387,7 +3359,8 @@ impl<'de> serde::Deserialize<'de> for StubCpmmReservesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "value" => Ok(GeneratedField::Value),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3397,36 +3370,44 @@ impl<'de> serde::Deserialize<'de> for StubCpmmReservesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StubCpmmReservesResponse;
+            type Value = Ics20EscrowResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.StubCPMMReservesResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.Ics20EscrowResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<StubCpmmReservesResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20EscrowResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
+                let mut channel_id__ = None;
+                let mut value__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
                             }
-                            reserves__ = map.next_value()?;
+                            channel_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(StubCpmmReservesResponse {
-                    reserves: reserves__,
+                Ok(Ics20EscrowResponse {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    value: value__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.StubCPMMReservesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.Ics20EscrowResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SyncInfo {
+impl serde::Serialize for KeyValueRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3434,66 +3415,46 @@ impl serde::Serialize for SyncInfo {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.latest_block_hash.is_empty() {
-            len += 1;
-        }
-        if !self.latest_app_hash.is_empty() {
-            len += 1;
-        }
-        if self.latest_block_height != 0 {
+        if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.latest_block_time.is_some() {
+        if !self.key.is_empty() {
             len += 1;
         }
-        if self.catching_up {
+        if self.proof {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SyncInfo", len)?;
-        if !self.latest_block_hash.is_empty() {
-            struct_ser.serialize_field("latestBlockHash", pbjson::private::base64::encode(&self.latest_block_hash).as_str())?;
-        }
-        if !self.latest_app_hash.is_empty() {
-            struct_ser.serialize_field("latestAppHash", pbjson::private::base64::encode(&self.latest_app_hash).as_str())?;
-        }
-        if self.latest_block_height != 0 {
-            struct_ser.serialize_field("latestBlockHeight", ToString::to_string(&self.latest_block_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if let Some(v) = self.latest_block_time.as_ref() {
-            struct_ser.serialize_field("latestBlockTime", v)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
         }
-        if self.catching_up {
-            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        if self.proof {
+            struct_ser.serialize_field("proof", &self.proof)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SyncInfo {
+impl<'de> serde::Deserialize<'de> for KeyValueRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "latest_block_hash",
-            "latestBlockHash",
-            "latest_app_hash",
-            "latestAppHash",
-            "latest_block_height",
-            "latestBlockHeight",
-            "latest_block_time",
-            "latestBlockTime",
-            "catching_up",
-            "catchingUp",
+            "chain_id",
+            "chainId",
+            "key",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            LatestBlockHash,
-            LatestAppHash,
-            LatestBlockHeight,
-            LatestBlockTime,
-            CatchingUp,
+            ChainId,
+            Key,
+            Proof,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3515,11 +3476,9 @@ impl<'de> serde::Deserialize<'de> for SyncInfo {
                         E: serde::de::Error,
                     {
                         match value {
-                            "latestBlockHash" | "latest_block_hash" => Ok(GeneratedField::LatestBlockHash),
-                            "latestAppHash" | "latest_app_hash" => Ok(GeneratedField::LatestAppHash),
-                            "latestBlockHeight" | "latest_block_height" => Ok(GeneratedField::LatestBlockHeight),
-                            "latestBlockTime" | "latest_block_time" => Ok(GeneratedField::LatestBlockTime),
-                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "key" => Ok(GeneratedField::Key),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3529,74 +3488,52 @@ impl<'de> serde::Deserialize<'de> for SyncInfo {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SyncInfo;
+            type Value = KeyValueRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.SyncInfo")
+                formatter.write_str("struct penumbra.client.v1alpha1.KeyValueRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<SyncInfo, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<KeyValueRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut latest_block_hash__ = None;
-                let mut latest_app_hash__ = None;
-                let mut latest_block_height__ = None;
-                let mut latest_block_time__ = None;
-                let mut catching_up__ = None;
+                let mut chain_id__ = None;
+                let mut key__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::LatestBlockHash => {
-                            if latest_block_hash__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestBlockHash"));
-                            }
-                            latest_block_hash__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::LatestAppHash => {
-                            if latest_app_hash__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestAppHash"));
-                            }
-                            latest_app_hash__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::LatestBlockHeight => {
-                            if latest_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestBlockHeight"));
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
                             }
-                            latest_block_height__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::LatestBlockTime => {
-                            if latest_block_time__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestBlockTime"));
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
                             }
-                            latest_block_time__ = map.next_value()?;
+                            key__ = Some(map.next_value()?);
                         }
-                        GeneratedField::CatchingUp => {
-                            if catching_up__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            catching_up__ = Some(map.next_value()?);
+                            proof__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(SyncInfo {
-                    latest_block_hash: latest_block_hash__.unwrap_or_default(),
-                    latest_app_hash: latest_app_hash__.unwrap_or_default(),
-                    latest_block_height: latest_block_height__.unwrap_or_default(),
-                    latest_block_time: latest_block_time__,
-                    catching_up: catching_up__.unwrap_or_default(),
+                Ok(KeyValueRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    key: key__.unwrap_or_default(),
+                    proof: proof__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.SyncInfo", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Tag {
+impl serde::Serialize for KeyValueResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3604,45 +3541,37 @@ impl serde::Serialize for Tag {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.key.is_empty() {
-            len += 1;
-        }
         if !self.value.is_empty() {
             len += 1;
         }
-        if self.index {
+        if self.proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Tag", len)?;
-        if !self.key.is_empty() {
-            struct_ser.serialize_field("key", pbjson::private::base64::encode(&self.key).as_str())?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueResponse", len)?;
         if !self.value.is_empty() {
             struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
         }
-        if self.index {
-            struct_ser.serialize_field("index", &self.index)?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Tag {
+impl<'de> serde::Deserialize<'de> for KeyValueResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "key",
             "value",
-            "index",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Key,
             Value,
-            Index,
+            Proof,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3664,9 +3593,8 @@ impl<'de> serde::Deserialize<'de> for Tag {
                         E: serde::de::Error,
                     {
                         match value {
-                            "key" => Ok(GeneratedField::Key),
                             "value" => Ok(GeneratedField::Value),
-                            "index" => Ok(GeneratedField::Index),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3676,29 +3604,20 @@ impl<'de> serde::Deserialize<'de> for Tag {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Tag;
+            type Value = KeyValueResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.Tag")
+                formatter.write_str("struct penumbra.client.v1alpha1.KeyValueResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<Tag, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<KeyValueResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut key__ = None;
                 let mut value__ = None;
-                let mut index__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Key => {
-                            if key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("key"));
-                            }
-                            key__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::Value => {
                             if value__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("value"));
@@ -3707,25 +3626,24 @@ impl<'de> serde::Deserialize<'de> for Tag {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Index => {
-                            if index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("index"));
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            index__ = Some(map.next_value()?);
+                            proof__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(Tag {
-                    key: key__.unwrap_or_default(),
+                Ok(KeyValueResponse {
                     value: value__.unwrap_or_default(),
-                    index: index__.unwrap_or_default(),
+                    proof: proof__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.Tag", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionByNoteRequest {
+impl serde::Serialize for NextValidatorRateRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3736,20 +3654,20 @@ impl serde::Serialize for TransactionByNoteRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.note_commitment.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TransactionByNoteRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.NextValidatorRateRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionByNoteRequest {
+impl<'de> serde::Deserialize<'de> for NextValidatorRateRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3758,14 +3676,14 @@ impl<'de> serde::Deserialize<'de> for TransactionByNoteRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "note_commitment",
-            "noteCommitment",
+            "identity_key",
+            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            NoteCommitment,
+            IdentityKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3788,7 +3706,7 @@ impl<'de> serde::Deserialize<'de> for TransactionByNoteRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3798,18 +3716,18 @@ impl<'de> serde::Deserialize<'de> for TransactionByNoteRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionByNoteRequest;
+            type Value = NextValidatorRateRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.TransactionByNoteRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.NextValidatorRateRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionByNoteRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NextValidatorRateRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut note_commitment__ = None;
+                let mut identity_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3818,24 +3736,24 @@ impl<'de> serde::Deserialize<'de> for TransactionByNoteRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            note_commitment__ = map.next_value()?;
+                            identity_key__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(TransactionByNoteRequest {
+                Ok(NextValidatorRateRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    note_commitment: note_commitment__,
+                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.TransactionByNoteRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.NextValidatorRateRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionByNoteResponse {
+impl serde::Serialize for NextValidatorRateResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3843,30 +3761,29 @@ impl serde::Serialize for TransactionByNoteResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_source.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TransactionByNoteResponse", len)?;
-        if let Some(v) = self.note_source.as_ref() {
-            struct_ser.serialize_field("noteSource", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.NextValidatorRateResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionByNoteResponse {
+impl<'de> serde::Deserialize<'de> for NextValidatorRateResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_source",
-            "noteSource",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteSource,
+            Data,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3888,7 +3805,7 @@ impl<'de> serde::Deserialize<'de> for TransactionByNoteResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteSource" | "note_source" => Ok(GeneratedField::NoteSource),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3898,36 +3815,36 @@ impl<'de> serde::Deserialize<'de> for TransactionByNoteResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionByNoteResponse;
+            type Value = NextValidatorRateResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.TransactionByNoteResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.NextValidatorRateResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionByNoteResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NextValidatorRateResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_source__ = None;
+                let mut data__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::NoteSource => {
-                            if note_source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteSource"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            note_source__ = map.next_value()?;
+                            data__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(TransactionByNoteResponse {
-                    note_source: note_source__,
+                Ok(NextValidatorRateResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.TransactionByNoteResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.NextValidatorRateResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TxResult {
+impl serde::Serialize for PrefixValueRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3935,55 +3852,38 @@ impl serde::Serialize for TxResult {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.log.is_empty() {
-            len += 1;
-        }
-        if self.gas_wanted != 0 {
-            len += 1;
-        }
-        if self.gas_used != 0 {
+        if !self.chain_id.is_empty() {
             len += 1;
         }
-        if !self.tags.is_empty() {
+        if !self.prefix.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TxResult", len)?;
-        if !self.log.is_empty() {
-            struct_ser.serialize_field("log", &self.log)?;
-        }
-        if self.gas_wanted != 0 {
-            struct_ser.serialize_field("gasWanted", ToString::to_string(&self.gas_wanted).as_str())?;
-        }
-        if self.gas_used != 0 {
-            struct_ser.serialize_field("gasUsed", ToString::to_string(&self.gas_used).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PrefixValueRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if !self.tags.is_empty() {
-            struct_ser.serialize_field("tags", &self.tags)?;
+        if !self.prefix.is_empty() {
+            struct_ser.serialize_field("prefix", &self.prefix)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TxResult {
+impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "log",
-            "gas_wanted",
-            "gasWanted",
-            "gas_used",
-            "gasUsed",
-            "tags",
+            "chain_id",
+            "chainId",
+            "prefix",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Log,
-            GasWanted,
-            GasUsed,
-            Tags,
+            ChainId,
+            Prefix,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4005,10 +3905,8 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                         E: serde::de::Error,
                     {
                         match value {
-                            "log" => Ok(GeneratedField::Log),
-                            "gasWanted" | "gas_wanted" => Ok(GeneratedField::GasWanted),
-                            "gasUsed" | "gas_used" => Ok(GeneratedField::GasUsed),
-                            "tags" => Ok(GeneratedField::Tags),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "prefix" => Ok(GeneratedField::Prefix),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4018,64 +3916,2760 @@ impl<'de> serde::Deserialize<'de> for TxResult {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TxResult;
+            type Value = PrefixValueRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.TxResult")
+                formatter.write_str("struct penumbra.client.v1alpha1.PrefixValueRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<TxResult, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PrefixValueRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut log__ = None;
-                let mut gas_wanted__ = None;
-                let mut gas_used__ = None;
-                let mut tags__ = None;
+                let mut chain_id__ = None;
+                let mut prefix__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Log => {
-                            if log__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("log"));
-                            }
-                            log__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::GasWanted => {
-                            if gas_wanted__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("gasWanted"));
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
                             }
-                            gas_wanted__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::GasUsed => {
-                            if gas_used__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("gasUsed"));
+                        GeneratedField::Prefix => {
+                            if prefix__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prefix"));
                             }
-                            gas_used__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            prefix__ = Some(map.next_value()?);
                         }
-                        GeneratedField::Tags => {
-                            if tags__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tags"));
+                    }
+                }
+                Ok(PrefixValueRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    prefix: prefix__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.PrefixValueRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PrefixValueResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
+        }
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Value,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PrefixValueResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.PrefixValueResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PrefixValueResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut value__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PrefixValueResponse {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEventsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalEventsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEventsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "start_height",
+            "startHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            StartHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEventsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalEventsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalEventsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut start_height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProposalEventsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalEventsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEventsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalEventsResponse", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            struct_ser.serialize_field("event", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEventsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "event",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Event,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "event" => Ok(GeneratedField::Event),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEventsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalEventsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalEventsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut event__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Event => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("event"));
+                            }
+                            event__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalEventsResponse {
+                    height: height__.unwrap_or_default(),
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalEventsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalInfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalInfoRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.proposal_id != 0 {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "proposal_id",
+            "proposalId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ProposalId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut proposal_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProposalInfoRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_block_height != 0 {
+            len += 1;
+        }
+        if self.start_position != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalInfoResponse", len)?;
+        if self.start_block_height != 0 {
+            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
+        }
+        if self.start_position != 0 {
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_block_height",
+            "startBlockHeight",
+            "start_position",
+            "startPosition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartBlockHeight,
+            StartPosition,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_block_height__ = None;
+                let mut start_position__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StartBlockHeight => {
+                            if start_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
+                            }
+                            start_block_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProposalInfoResponse {
+                    start_block_height: start_block_height__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalNullifierStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalNullifierStatusRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.proposal_id != 0 {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalNullifierStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "proposal_id",
+            "proposalId",
+            "nullifier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ProposalId,
+            Nullifier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalNullifierStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalNullifierStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalNullifierStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut proposal_id__ = None;
+                let mut nullifier__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalNullifierStatusRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    nullifier: nullifier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalNullifierStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalNullifierStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.voted {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalNullifierStatusResponse", len)?;
+        if self.voted {
+            struct_ser.serialize_field("voted", &self.voted)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalNullifierStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "voted",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Voted,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "voted" => Ok(GeneratedField::Voted),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalNullifierStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalNullifierStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalNullifierStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut voted__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Voted => {
+                            if voted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voted"));
+                            }
+                            voted__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ProposalNullifierStatusResponse {
+                    voted: voted__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalNullifierStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalRateDataRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalRateDataRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.proposal_id != 0 {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "proposal_id",
+            "proposalId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ProposalId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalRateDataRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalRateDataRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalRateDataRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut proposal_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProposalRateDataRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalRateDataRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalRateDataResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalRateDataResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateData,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalRateDataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalRateDataResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalRateDataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_data__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalRateDataResponse {
+                    rate_data: rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalRateDataResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StubCpmmReservesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StubCPMMReservesRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StubCpmmReservesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            TradingPair,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StubCpmmReservesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.StubCPMMReservesRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StubCpmmReservesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(StubCpmmReservesRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StubCPMMReservesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StubCpmmReservesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StubCPMMReservesResponse", len)?;
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StubCpmmReservesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reserves",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reserves,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StubCpmmReservesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.StubCPMMReservesResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StubCpmmReservesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reserves__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(StubCpmmReservesResponse {
+                    reserves: reserves__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StubCPMMReservesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SyncInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.latest_block_hash.is_empty() {
+            len += 1;
+        }
+        if !self.latest_app_hash.is_empty() {
+            len += 1;
+        }
+        if self.latest_block_height != 0 {
+            len += 1;
+        }
+        if self.latest_block_time.is_some() {
+            len += 1;
+        }
+        if self.catching_up {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SyncInfo", len)?;
+        if !self.latest_block_hash.is_empty() {
+            struct_ser.serialize_field("latestBlockHash", pbjson::private::base64::encode(&self.latest_block_hash).as_str())?;
+        }
+        if !self.latest_app_hash.is_empty() {
+            struct_ser.serialize_field("latestAppHash", pbjson::private::base64::encode(&self.latest_app_hash).as_str())?;
+        }
+        if self.latest_block_height != 0 {
+            struct_ser.serialize_field("latestBlockHeight", ToString::to_string(&self.latest_block_height).as_str())?;
+        }
+        if let Some(v) = self.latest_block_time.as_ref() {
+            struct_ser.serialize_field("latestBlockTime", v)?;
+        }
+        if self.catching_up {
+            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SyncInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "latest_block_hash",
+            "latestBlockHash",
+            "latest_app_hash",
+            "latestAppHash",
+            "latest_block_height",
+            "latestBlockHeight",
+            "latest_block_time",
+            "latestBlockTime",
+            "catching_up",
+            "catchingUp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LatestBlockHash,
+            LatestAppHash,
+            LatestBlockHeight,
+            LatestBlockTime,
+            CatchingUp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "latestBlockHash" | "latest_block_hash" => Ok(GeneratedField::LatestBlockHash),
+                            "latestAppHash" | "latest_app_hash" => Ok(GeneratedField::LatestAppHash),
+                            "latestBlockHeight" | "latest_block_height" => Ok(GeneratedField::LatestBlockHeight),
+                            "latestBlockTime" | "latest_block_time" => Ok(GeneratedField::LatestBlockTime),
+                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SyncInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.SyncInfo")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SyncInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut latest_block_hash__ = None;
+                let mut latest_app_hash__ = None;
+                let mut latest_block_height__ = None;
+                let mut latest_block_time__ = None;
+                let mut catching_up__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::LatestBlockHash => {
+                            if latest_block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestBlockHash"));
+                            }
+                            latest_block_hash__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LatestAppHash => {
+                            if latest_app_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestAppHash"));
+                            }
+                            latest_app_hash__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LatestBlockHeight => {
+                            if latest_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestBlockHeight"));
+                            }
+                            latest_block_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LatestBlockTime => {
+                            if latest_block_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestBlockTime"));
+                            }
+                            latest_block_time__ = map.next_value()?;
+                        }
+                        GeneratedField::CatchingUp => {
+                            if catching_up__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                            }
+                            catching_up__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(SyncInfo {
+                    latest_block_hash: latest_block_hash__.unwrap_or_default(),
+                    latest_app_hash: latest_app_hash__.unwrap_or_default(),
+                    latest_block_height: latest_block_height__.unwrap_or_default(),
+                    latest_block_time: latest_block_time__,
+                    catching_up: catching_up__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.SyncInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Tag {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        if self.index {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Tag", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", pbjson::private::base64::encode(&self.key).as_str())?;
+        }
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        }
+        if self.index {
+            struct_ser.serialize_field("index", &self.index)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Tag {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "value",
+            "index",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Value,
+            Index,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            "index" => Ok(GeneratedField::Index),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Tag;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.Tag")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Tag, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut value__ = None;
+                let mut index__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(Tag {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                    index: index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.Tag", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionByNoteRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TransactionByNoteRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionByNoteRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "note_commitment",
+            "noteCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            NoteCommitment,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionByNoteRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.TransactionByNoteRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionByNoteRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut note_commitment__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(TransactionByNoteRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    note_commitment: note_commitment__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.TransactionByNoteRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionByNoteResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TransactionByNoteResponse", len)?;
+        if let Some(v) = self.note_source.as_ref() {
+            struct_ser.serialize_field("noteSource", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionByNoteResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_source",
+            "noteSource",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteSource,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteSource" | "note_source" => Ok(GeneratedField::NoteSource),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionByNoteResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.TransactionByNoteResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionByNoteResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_source__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::NoteSource => {
+                            if note_source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteSource"));
+                            }
+                            note_source__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(TransactionByNoteResponse {
+                    note_source: note_source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.TransactionByNoteResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TxResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.log.is_empty() {
+            len += 1;
+        }
+        if self.gas_wanted != 0 {
+            len += 1;
+        }
+        if self.gas_used != 0 {
+            len += 1;
+        }
+        if !self.tags.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TxResult", len)?;
+        if !self.log.is_empty() {
+            struct_ser.serialize_field("log", &self.log)?;
+        }
+        if self.gas_wanted != 0 {
+            struct_ser.serialize_field("gasWanted", ToString::to_string(&self.gas_wanted).as_str())?;
+        }
+        if self.gas_used != 0 {
+            struct_ser.serialize_field("gasUsed", ToString::to_string(&self.gas_used).as_str())?;
+        }
+        if !self.tags.is_empty() {
+            struct_ser.serialize_field("tags", &self.tags)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TxResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "log",
+            "gas_wanted",
+            "gasWanted",
+            "gas_used",
+            "gasUsed",
+            "tags",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Log,
+            GasWanted,
+            GasUsed,
+            Tags,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "log" => Ok(GeneratedField::Log),
+                            "gasWanted" | "gas_wanted" => Ok(GeneratedField::GasWanted),
+                            "gasUsed" | "gas_used" => Ok(GeneratedField::GasUsed),
+                            "tags" => Ok(GeneratedField::Tags),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TxResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.TxResult")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TxResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut log__ = None;
+                let mut gas_wanted__ = None;
+                let mut gas_used__ = None;
+                let mut tags__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Log => {
+                            if log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("log"));
+                            }
+                            log__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::GasWanted => {
+                            if gas_wanted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasWanted"));
+                            }
+                            gas_wanted__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GasUsed => {
+                            if gas_used__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasUsed"));
+                            }
+                            gas_used__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Tags => {
+                            if tags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tags"));
+                            }
+                            tags__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(TxResult {
+                    log: log__.unwrap_or_default(),
+                    gas_wanted: gas_wanted__.unwrap_or_default(),
+                    gas_used: gas_used__.unwrap_or_default(),
+                    tags: tags__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.TxResult", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorEventsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if !self.identity_keys.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorEventsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if !self.identity_keys.is_empty() {
+            struct_ser.serialize_field("identityKeys", &self.identity_keys)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorEventsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "start_height",
+            "startHeight",
+            "identity_keys",
+            "identityKeys",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            StartHeight,
+            IdentityKeys,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "identityKeys" | "identity_keys" => Ok(GeneratedField::IdentityKeys),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorEventsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorEventsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorEventsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut start_height__ = None;
+                let mut identity_keys__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IdentityKeys => {
+                            if identity_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKeys"));
+                            }
+                            identity_keys__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ValidatorEventsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    identity_keys: identity_keys__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorEventsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorEventsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorEventsResponse", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            struct_ser.serialize_field("event", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorEventsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "event",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Event,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "event" => Ok(GeneratedField::Event),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorEventsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorEventsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorEventsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut event__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Event => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("event"));
+                            }
+                            event__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ValidatorEventsResponse {
+                    height: height__.unwrap_or_default(),
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorEventsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorEvidenceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorEvidenceRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorEvidenceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            IdentityKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorEvidenceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorEvidenceRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorEvidenceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut identity_key__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ValidatorEvidenceRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorEvidenceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorEvidenceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.record.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorEvidenceResponse", len)?;
+        if let Some(v) = self.record.as_ref() {
+            struct_ser.serialize_field("record", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorEvidenceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "record",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Record,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "record" => Ok(GeneratedField::Record),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorEvidenceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorEvidenceResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorEvidenceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut record__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Record => {
+                            if record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("record"));
+                            }
+                            record__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ValidatorEvidenceResponse {
+                    record: record__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorEvidenceResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorInfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.show_inactive {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorInfoRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.show_inactive {
+            struct_ser.serialize_field("showInactive", &self.show_inactive)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "show_inactive",
+            "showInactive",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ShowInactive,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "showInactive" | "show_inactive" => Ok(GeneratedField::ShowInactive),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut show_inactive__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ShowInactive => {
+                            if show_inactive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("showInactive"));
+                            }
+                            show_inactive__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ValidatorInfoRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    show_inactive: show_inactive__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorInfoResponse", len)?;
+        if let Some(v) = self.validator_info.as_ref() {
+            struct_ser.serialize_field("validatorInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_info",
+            "validatorInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorInfo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorInfo" | "validator_info" => Ok(GeneratedField::ValidatorInfo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_info__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorInfo => {
+                            if validator_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorInfo"));
+                            }
+                            validator_info__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ValidatorInfoResponse {
+                    validator_info: validator_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPenaltyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            IdentityKey,
+            StartEpochIndex,
+            EndEpochIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPenaltyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorPenaltyRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorPenaltyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ValidatorPenaltyRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPenaltyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyResponse", len)?;
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "penalty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Penalty,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPenaltyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorPenaltyResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorPenaltyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut penalty__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
                             }
-                            tags__ = Some(map.next_value()?);
+                            penalty__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(TxResult {
-                    log: log__.unwrap_or_default(),
-                    gas_wanted: gas_wanted__.unwrap_or_default(),
-                    gas_used: gas_used__.unwrap_or_default(),
-                    tags: tags__.unwrap_or_default(),
+                Ok(ValidatorPenaltyResponse {
+                    penalty: penalty__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.TxResult", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorInfoRequest {
+impl serde::Serialize for ValidatorRatesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4086,20 +6680,20 @@ impl serde::Serialize for ValidatorInfoRequest {
         if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.show_inactive {
+        if self.epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorInfoRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorRatesRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if self.show_inactive {
-            struct_ser.serialize_field("showInactive", &self.show_inactive)?;
+        if self.epoch_index != 0 {
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
+impl<'de> serde::Deserialize<'de> for ValidatorRatesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4108,14 +6702,14 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
         const FIELDS: &[&str] = &[
             "chain_id",
             "chainId",
-            "show_inactive",
-            "showInactive",
+            "epoch_index",
+            "epochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
-            ShowInactive,
+            EpochIndex,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4138,7 +6732,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
                     {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "showInactive" | "show_inactive" => Ok(GeneratedField::ShowInactive),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4148,18 +6742,18 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorInfoRequest;
+            type Value = ValidatorRatesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorInfoRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorRatesRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorRatesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
-                let mut show_inactive__ = None;
+                let mut epoch_index__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -4168,24 +6762,26 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
                             }
                             chain_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::ShowInactive => {
-                            if show_inactive__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("showInactive"));
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
                             }
-                            show_inactive__ = Some(map.next_value()?);
+                            epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(ValidatorInfoRequest {
+                Ok(ValidatorRatesRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    show_inactive: show_inactive__.unwrap_or_default(),
+                    epoch_index: epoch_index__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorRatesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorInfoResponse {
+impl serde::Serialize for ValidatorRatesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4193,30 +6789,38 @@ impl serde::Serialize for ValidatorInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_info.is_some() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorInfoResponse", len)?;
-        if let Some(v) = self.validator_info.as_ref() {
-            struct_ser.serialize_field("validatorInfo", v)?;
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorRatesResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
+impl<'de> serde::Deserialize<'de> for ValidatorRatesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_info",
-            "validatorInfo",
+            "rate_data",
+            "rateData",
+            "penalty",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorInfo,
+            RateData,
+            Penalty,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4238,7 +6842,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorInfo" | "validator_info" => Ok(GeneratedField::ValidatorInfo),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "penalty" => Ok(GeneratedField::Penalty),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4248,36 +6853,44 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorInfoResponse;
+            type Value = ValidatorRatesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorInfoResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorRatesResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorRatesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_info__ = None;
+                let mut rate_data__ = None;
+                let mut penalty__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::ValidatorInfo => {
-                            if validator_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorInfo"));
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            validator_info__ = map.next_value()?;
+                            rate_data__ = map.next_value()?;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(ValidatorInfoResponse {
-                    validator_info: validator_info__,
+                Ok(ValidatorRatesResponse {
+                    rate_data: rate_data__,
+                    penalty: penalty__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorRatesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorPenaltyRequest {
+impl serde::Serialize for ValidatorStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4291,29 +6904,17 @@ impl serde::Serialize for ValidatorPenaltyRequest {
         if self.identity_key.is_some() {
             len += 1;
         }
-        if self.start_epoch_index != 0 {
-            len += 1;
-        }
-        if self.end_epoch_index != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorStatusRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
-        if self.start_epoch_index != 0 {
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
-        }
-        if self.end_epoch_index != 0 {
-            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
+impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4324,18 +6925,12 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
             "chainId",
             "identity_key",
             "identityKey",
-            "start_epoch_index",
-            "startEpochIndex",
-            "end_epoch_index",
-            "endEpochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             IdentityKey,
-            StartEpochIndex,
-            EndEpochIndex,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4359,8 +6954,6 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4370,20 +6963,18 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorPenaltyRequest;
+            type Value = ValidatorStatusRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorPenaltyRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorStatusRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorPenaltyRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorStatusRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
                 let mut identity_key__ = None;
-                let mut start_epoch_index__ = None;
-                let mut end_epoch_index__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -4398,36 +6989,18 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
                             }
                             identity_key__ = map.next_value()?;
                         }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
-                            }
-                            start_epoch_index__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::EndEpochIndex => {
-                            if end_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
-                            }
-                            end_epoch_index__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                     }
                 }
-                Ok(ValidatorPenaltyRequest {
+                Ok(ValidatorStatusRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     identity_key: identity_key__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorStatusRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorPenaltyResponse {
+impl serde::Serialize for ValidatorStatusResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4435,29 +7008,29 @@ impl serde::Serialize for ValidatorPenaltyResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.penalty.is_some() {
+        if self.status.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyResponse", len)?;
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorStatusResponse", len)?;
+        if let Some(v) = self.status.as_ref() {
+            struct_ser.serialize_field("status", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
+impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "penalty",
+            "status",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Penalty,
+            Status,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4479,7 +7052,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "penalty" => Ok(GeneratedField::Penalty),
+                            "status" => Ok(GeneratedField::Status),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4489,36 +7062,36 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorPenaltyResponse;
+            type Value = ValidatorStatusResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorPenaltyResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorStatusResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorPenaltyResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorStatusResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut penalty__ = None;
+                let mut status__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
                             }
-                            penalty__ = map.next_value()?;
+                            status__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(ValidatorPenaltyResponse {
-                    penalty: penalty__,
+                Ok(ValidatorStatusResponse {
+                    status: status__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorPenaltyResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorStatusRequest {
+impl serde::Serialize for ValidatorUptimeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4532,17 +7105,23 @@ impl serde::Serialize for ValidatorStatusRequest {
         if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorStatusRequest", len)?;
+        if self.rate_history_epochs != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorUptimeRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
+        if self.rate_history_epochs != 0 {
+            struct_ser.serialize_field("rateHistoryEpochs", ToString::to_string(&self.rate_history_epochs).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
+impl<'de> serde::Deserialize<'de> for ValidatorUptimeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4553,12 +7132,15 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
             "chainId",
             "identity_key",
             "identityKey",
+            "rate_history_epochs",
+            "rateHistoryEpochs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             IdentityKey,
+            RateHistoryEpochs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4582,6 +7164,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "rateHistoryEpochs" | "rate_history_epochs" => Ok(GeneratedField::RateHistoryEpochs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4591,18 +7174,19 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorStatusRequest;
+            type Value = ValidatorUptimeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorStatusRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorUptimeRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorUptimeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
                 let mut identity_key__ = None;
+                let mut rate_history_epochs__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -4617,18 +7201,27 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
                             }
                             identity_key__ = map.next_value()?;
                         }
+                        GeneratedField::RateHistoryEpochs => {
+                            if rate_history_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateHistoryEpochs"));
+                            }
+                            rate_history_epochs__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
-                Ok(ValidatorStatusRequest {
+                Ok(ValidatorUptimeRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     identity_key: identity_key__,
+                    rate_history_epochs: rate_history_epochs__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorUptimeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorStatusResponse {
+impl serde::Serialize for ValidatorUptimeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4636,29 +7229,64 @@ impl serde::Serialize for ValidatorStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.status.is_some() {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorStatusResponse", len)?;
-        if let Some(v) = self.status.as_ref() {
-            struct_ser.serialize_field("status", v)?;
+        if self.uptime.is_some() {
+            len += 1;
+        }
+        if self.missed_blocks != 0 {
+            len += 1;
+        }
+        if self.missed_blocks_maximum != 0 {
+            len += 1;
+        }
+        if !self.rate_history.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorUptimeResponse", len)?;
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.uptime.as_ref() {
+            struct_ser.serialize_field("uptime", v)?;
+        }
+        if self.missed_blocks != 0 {
+            struct_ser.serialize_field("missedBlocks", ToString::to_string(&self.missed_blocks).as_str())?;
+        }
+        if self.missed_blocks_maximum != 0 {
+            struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
+        }
+        if !self.rate_history.is_empty() {
+            struct_ser.serialize_field("rateHistory", &self.rate_history)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
+impl<'de> serde::Deserialize<'de> for ValidatorUptimeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "status",
+            "state",
+            "uptime",
+            "missed_blocks",
+            "missedBlocks",
+            "missed_blocks_maximum",
+            "missedBlocksMaximum",
+            "rate_history",
+            "rateHistory",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Status,
+            State,
+            Uptime,
+            MissedBlocks,
+            MissedBlocksMaximum,
+            RateHistory,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4680,7 +7308,11 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "status" => Ok(GeneratedField::Status),
+                            "state" => Ok(GeneratedField::State),
+                            "uptime" => Ok(GeneratedField::Uptime),
+                            "missedBlocks" | "missed_blocks" => Ok(GeneratedField::MissedBlocks),
+                            "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
+                            "rateHistory" | "rate_history" => Ok(GeneratedField::RateHistory),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4690,32 +7322,68 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorStatusResponse;
+            type Value = ValidatorUptimeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorStatusResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.ValidatorUptimeResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorUptimeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut status__ = None;
+                let mut state__ = None;
+                let mut uptime__ = None;
+                let mut missed_blocks__ = None;
+                let mut missed_blocks_maximum__ = None;
+                let mut rate_history__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Status => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("status"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            status__ = map.next_value()?;
+                            state__ = map.next_value()?;
+                        }
+                        GeneratedField::Uptime => {
+                            if uptime__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uptime"));
+                            }
+                            uptime__ = map.next_value()?;
+                        }
+                        GeneratedField::MissedBlocks => {
+                            if missed_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedBlocks"));
+                            }
+                            missed_blocks__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MissedBlocksMaximum => {
+                            if missed_blocks_maximum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedBlocksMaximum"));
+                            }
+                            missed_blocks_maximum__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RateHistory => {
+                            if rate_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateHistory"));
+                            }
+                            rate_history__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(ValidatorStatusResponse {
-                    status: status__,
+                Ok(ValidatorUptimeResponse {
+                    state: state__,
+                    uptime: uptime__,
+                    missed_blocks: missed_blocks__.unwrap_or_default(),
+                    missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
+                    rate_history: rate_history__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ValidatorUptimeResponse", FIELDS, GeneratedVisitor)
     }
 }
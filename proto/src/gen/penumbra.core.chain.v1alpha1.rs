@@ -29,6 +29,15 @@ pub struct ChainParameters {
     /// The maximum number of blocks in the window each validator can miss signing without slashing.
     #[prost(uint64, tag = "12")]
     pub missed_blocks_maximum: u64,
+    /// The minimum number of blocks a jailed validator must wait before it can be released from jail.
+    #[prost(uint64, tag = "13")]
+    pub min_jail_duration: u64,
+    /// The maximum total commission a validator can charge, in basis points.
+    #[prost(uint64, tag = "14")]
+    pub max_validator_commission_bps: u64,
+    /// The maximum amount a validator can raise its total commission by in a single epoch, in basis points.
+    #[prost(uint64, tag = "15")]
+    pub max_commission_increase_bps_per_epoch: u64,
     /// Whether IBC (forming connections, processing IBC packets) is enabled.
     #[prost(bool, tag = "6")]
     pub ibc_enabled: bool,
@@ -182,6 +182,15 @@ impl serde::Serialize for ChainParameters {
         if self.missed_blocks_maximum != 0 {
             len += 1;
         }
+        if self.min_jail_duration != 0 {
+            len += 1;
+        }
+        if self.max_validator_commission_bps != 0 {
+            len += 1;
+        }
+        if self.max_commission_increase_bps_per_epoch != 0 {
+            len += 1;
+        }
         if self.ibc_enabled {
             len += 1;
         }
@@ -237,6 +246,15 @@ impl serde::Serialize for ChainParameters {
         if self.missed_blocks_maximum != 0 {
             struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
         }
+        if self.min_jail_duration != 0 {
+            struct_ser.serialize_field("minJailDuration", ToString::to_string(&self.min_jail_duration).as_str())?;
+        }
+        if self.max_validator_commission_bps != 0 {
+            struct_ser.serialize_field("maxValidatorCommissionBps", ToString::to_string(&self.max_validator_commission_bps).as_str())?;
+        }
+        if self.max_commission_increase_bps_per_epoch != 0 {
+            struct_ser.serialize_field("maxCommissionIncreaseBpsPerEpoch", ToString::to_string(&self.max_commission_increase_bps_per_epoch).as_str())?;
+        }
         if self.ibc_enabled {
             struct_ser.serialize_field("ibcEnabled", &self.ibc_enabled)?;
         }
@@ -292,6 +310,12 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             "signedBlocksWindowLen",
             "missed_blocks_maximum",
             "missedBlocksMaximum",
+            "min_jail_duration",
+            "minJailDuration",
+            "max_validator_commission_bps",
+            "maxValidatorCommissionBps",
+            "max_commission_increase_bps_per_epoch",
+            "maxCommissionIncreaseBpsPerEpoch",
             "ibc_enabled",
             "ibcEnabled",
             "inbound_ics20_transfers_enabled",
@@ -323,6 +347,9 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             SlashingPenaltyDowntime,
             SignedBlocksWindowLen,
             MissedBlocksMaximum,
+            MinJailDuration,
+            MaxValidatorCommissionBps,
+            MaxCommissionIncreaseBpsPerEpoch,
             IbcEnabled,
             InboundIcs20TransfersEnabled,
             OutboundIcs20TransfersEnabled,
@@ -362,6 +389,9 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             "slashingPenaltyDowntime" | "slashing_penalty_downtime" => Ok(GeneratedField::SlashingPenaltyDowntime),
                             "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
                             "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
+                            "minJailDuration" | "min_jail_duration" => Ok(GeneratedField::MinJailDuration),
+                            "maxValidatorCommissionBps" | "max_validator_commission_bps" => Ok(GeneratedField::MaxValidatorCommissionBps),
+                            "maxCommissionIncreaseBpsPerEpoch" | "max_commission_increase_bps_per_epoch" => Ok(GeneratedField::MaxCommissionIncreaseBpsPerEpoch),
                             "ibcEnabled" | "ibc_enabled" => Ok(GeneratedField::IbcEnabled),
                             "inboundIcs20TransfersEnabled" | "inbound_ics20_transfers_enabled" => Ok(GeneratedField::InboundIcs20TransfersEnabled),
                             "outboundIcs20TransfersEnabled" | "outbound_ics20_transfers_enabled" => Ok(GeneratedField::OutboundIcs20TransfersEnabled),
@@ -399,6 +429,9 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                 let mut slashing_penalty_downtime__ = None;
                 let mut signed_blocks_window_len__ = None;
                 let mut missed_blocks_maximum__ = None;
+                let mut min_jail_duration__ = None;
+                let mut max_validator_commission_bps__ = None;
+                let mut max_commission_increase_bps_per_epoch__ = None;
                 let mut ibc_enabled__ = None;
                 let mut inbound_ics20_transfers_enabled__ = None;
                 let mut outbound_ics20_transfers_enabled__ = None;
@@ -480,6 +513,30 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MinJailDuration => {
+                            if min_jail_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minJailDuration"));
+                            }
+                            min_jail_duration__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxValidatorCommissionBps => {
+                            if max_validator_commission_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxValidatorCommissionBps"));
+                            }
+                            max_validator_commission_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxCommissionIncreaseBpsPerEpoch => {
+                            if max_commission_increase_bps_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxCommissionIncreaseBpsPerEpoch"));
+                            }
+                            max_commission_increase_bps_per_epoch__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::IbcEnabled => {
                             if ibc_enabled__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("ibcEnabled"));
@@ -550,6 +607,9 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                     slashing_penalty_downtime: slashing_penalty_downtime__.unwrap_or_default(),
                     signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
                     missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
+                    min_jail_duration: min_jail_duration__.unwrap_or_default(),
+                    max_validator_commission_bps: max_validator_commission_bps__.unwrap_or_default(),
+                    max_commission_increase_bps_per_epoch: max_commission_increase_bps_per_epoch__.unwrap_or_default(),
                     ibc_enabled: ibc_enabled__.unwrap_or_default(),
                     inbound_ics20_transfers_enabled: inbound_ics20_transfers_enabled__.unwrap_or_default(),
                     outbound_ics20_transfers_enabled: outbound_ics20_transfers_enabled__.unwrap_or_default(),
@@ -62,6 +62,15 @@ pub struct ValidatorVoteBody {
     pub governance_key: ::core::option::Option<
         super::super::crypto::v1alpha1::GovernanceKey,
     >,
+    /// A justification of the vote, published in the clear.
+    #[prost(message, optional, tag = "5")]
+    pub reason: ::core::option::Option<ValidatorVoteReason>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorVoteReason {
+    #[prost(string, tag = "1")]
+    pub reason: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -75,8 +84,10 @@ pub struct DelegatorVote {
         super::super::crypto::v1alpha1::SpendAuthSignature,
     >,
     /// The vote proof is authorizing data.
-    #[prost(bytes = "vec", tag = "3")]
-    pub proof: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof: ::core::option::Option<
+        super::super::crypto::v1alpha1::ZkDelegatorVoteProof,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -102,6 +113,10 @@ pub struct DelegatorVoteBody {
     /// The randomized validating key for the spend authorization signature.
     #[prost(bytes = "vec", tag = "7")]
     pub rk: ::prost::alloc::vec::Vec<u8>,
+    /// Whether this vote replaces an earlier vote on the proposal with the same
+    /// nullifier, in which case no further voting receipt tokens are minted.
+    #[prost(bool, tag = "8")]
+    pub replaces_previous: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -127,6 +142,10 @@ pub struct DelegatorVotePlan {
     /// The randomizer to use for the proof of spend capability.
     #[prost(bytes = "vec", tag = "7")]
     pub randomizer: ::prost::alloc::vec::Vec<u8>,
+    /// Whether the vote replaces an earlier vote on the proposal with the same
+    /// nullifier.
+    #[prost(bool, tag = "8")]
+    pub replaces_previous: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -159,6 +178,10 @@ pub struct Vote {
     /// The vote.
     #[prost(enumeration = "vote::Vote", tag = "1")]
     pub vote: i32,
+    /// If set, the vote splits its voting power between options according to these weights, and
+    /// `vote` must be unspecified.
+    #[prost(message, optional, tag = "2")]
+    pub weights: ::core::option::Option<VoteWeights>,
 }
 /// Nested message and enum types in `Vote`.
 pub mod vote {
@@ -206,6 +229,17 @@ pub mod vote {
         }
     }
 }
+/// A split of voting power between the options of a vote, in basis points summing to 10,000.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteWeights {
+    #[prost(uint32, tag = "1")]
+    pub yes: u32,
+    #[prost(uint32, tag = "2")]
+    pub no: u32,
+    #[prost(uint32, tag = "3")]
+    pub abstain: u32,
+}
 /// The current state of a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -300,6 +334,45 @@ pub mod proposal_outcome {
         Slashed(Slashed),
     }
 }
+/// A change in the lifecycle of a proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEvent {
+    /// The proposal the event concerns.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(oneof = "proposal_event::Event", tags = "2, 3, 4")]
+    pub event: ::core::option::Option<proposal_event::Event>,
+}
+/// Nested message and enum types in `ProposalEvent`.
+pub mod proposal_event {
+    /// The proposal was submitted. Voting on a proposal starts as soon as it is submitted.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Submitted {
+        /// The title of the proposal.
+        #[prost(string, tag = "1")]
+        pub title: ::prost::alloc::string::String,
+        /// The height at which voting started.
+        #[prost(uint64, tag = "2")]
+        pub voting_start_height: u64,
+        /// The height at which voting will end.
+        #[prost(uint64, tag = "3")]
+        pub voting_end_height: u64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "2")]
+        Submitted(Submitted),
+        /// The tally of votes on the proposal changed.
+        #[prost(message, tag = "3")]
+        Tally(super::Tally),
+        /// The proposal was withdrawn, finished voting, or had its deposit claimed.
+        #[prost(message, tag = "4")]
+        State(super::ProposalState),
+    }
+}
 /// A tally of votes on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
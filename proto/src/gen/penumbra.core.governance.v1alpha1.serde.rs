@@ -302,7 +302,7 @@ impl serde::Serialize for DelegatorVote {
         if self.auth_sig.is_some() {
             len += 1;
         }
-        if !self.proof.is_empty() {
+        if self.proof.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVote", len)?;
@@ -312,8 +312,8 @@ impl serde::Serialize for DelegatorVote {
         if let Some(v) = self.auth_sig.as_ref() {
             struct_ser.serialize_field("authSig", v)?;
         }
-        if !self.proof.is_empty() {
-            struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
         }
         struct_ser.end()
     }
@@ -400,16 +400,14 @@ impl<'de> serde::Deserialize<'de> for DelegatorVote {
                             if proof__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            proof__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            proof__ = map.next_value()?;
                         }
                     }
                 }
                 Ok(DelegatorVote {
                     body: body__,
                     auth_sig: auth_sig__,
-                    proof: proof__.unwrap_or_default(),
+                    proof: proof__,
                 })
             }
         }
@@ -445,6 +443,9 @@ impl serde::Serialize for DelegatorVoteBody {
         if !self.rk.is_empty() {
             len += 1;
         }
+        if self.replaces_previous {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVoteBody", len)?;
         if self.proposal != 0 {
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
@@ -467,6 +468,9 @@ impl serde::Serialize for DelegatorVoteBody {
         if !self.rk.is_empty() {
             struct_ser.serialize_field("rk", pbjson::private::base64::encode(&self.rk).as_str())?;
         }
+        if self.replaces_previous {
+            struct_ser.serialize_field("replacesPrevious", &self.replaces_previous)?;
+        }
         struct_ser.end()
     }
 }
@@ -486,6 +490,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             "unbondedAmount",
             "nullifier",
             "rk",
+            "replaces_previous",
+            "replacesPrevious",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -497,6 +503,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             UnbondedAmount,
             Nullifier,
             Rk,
+            ReplacesPrevious,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -525,6 +532,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
                             "nullifier" => Ok(GeneratedField::Nullifier),
                             "rk" => Ok(GeneratedField::Rk),
+                            "replacesPrevious" | "replaces_previous" => Ok(GeneratedField::ReplacesPrevious),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -551,6 +559,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                 let mut unbonded_amount__ = None;
                 let mut nullifier__ = None;
                 let mut rk__ = None;
+                let mut replaces_previous__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -603,6 +612,12 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ReplacesPrevious => {
+                            if replaces_previous__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("replacesPrevious"));
+                            }
+                            replaces_previous__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(DelegatorVoteBody {
@@ -613,6 +628,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                     unbonded_amount: unbonded_amount__,
                     nullifier: nullifier__.unwrap_or_default(),
                     rk: rk__.unwrap_or_default(),
+                    replaces_previous: replaces_previous__.unwrap_or_default(),
                 })
             }
         }
@@ -648,6 +664,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.randomizer.is_empty() {
             len += 1;
         }
+        if self.replaces_previous {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVotePlan", len)?;
         if self.proposal != 0 {
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
@@ -670,6 +689,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.randomizer.is_empty() {
             struct_ser.serialize_field("randomizer", pbjson::private::base64::encode(&self.randomizer).as_str())?;
         }
+        if self.replaces_previous {
+            struct_ser.serialize_field("replacesPrevious", &self.replaces_previous)?;
+        }
         struct_ser.end()
     }
 }
@@ -691,6 +713,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             "unbonded_amount",
             "unbondedAmount",
             "randomizer",
+            "replaces_previous",
+            "replacesPrevious",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -702,6 +726,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             StakedNotePosition,
             UnbondedAmount,
             Randomizer,
+            ReplacesPrevious,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -730,6 +755,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                             "stakedNotePosition" | "staked_note_position" => Ok(GeneratedField::StakedNotePosition),
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
                             "randomizer" => Ok(GeneratedField::Randomizer),
+                            "replacesPrevious" | "replaces_previous" => Ok(GeneratedField::ReplacesPrevious),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -756,6 +782,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                 let mut staked_note_position__ = None;
                 let mut unbonded_amount__ = None;
                 let mut randomizer__ = None;
+                let mut replaces_previous__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -808,6 +835,12 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ReplacesPrevious => {
+                            if replaces_previous__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("replacesPrevious"));
+                            }
+                            replaces_previous__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(DelegatorVotePlan {
@@ -818,6 +851,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                     staked_note_position: staked_note_position__.unwrap_or_default(),
                     unbonded_amount: unbonded_amount__,
                     randomizer: randomizer__.unwrap_or_default(),
+                    replaces_previous: replaces_previous__.unwrap_or_default(),
                 })
             }
         }
@@ -1534,6 +1568,279 @@ impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositClaim", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ProposalEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent", len)?;
+        if self.proposal_id != 0 {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                proposal_event::Event::Submitted(v) => {
+                    struct_ser.serialize_field("submitted", v)?;
+                }
+                proposal_event::Event::Tally(v) => {
+                    struct_ser.serialize_field("tally", v)?;
+                }
+                proposal_event::Event::State(v) => {
+                    struct_ser.serialize_field("state", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "submitted",
+            "tally",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            Submitted,
+            Tally,
+            State,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "submitted" => Ok(GeneratedField::Submitted),
+                            "tally" => Ok(GeneratedField::Tally),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut event__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Submitted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitted"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Submitted)
+;
+                        }
+                        GeneratedField::Tally => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tally"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Tally)
+;
+                        }
+                        GeneratedField::State => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::State)
+;
+                        }
+                    }
+                }
+                Ok(ProposalEvent {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_event::Submitted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.title.is_empty() {
+            len += 1;
+        }
+        if self.voting_start_height != 0 {
+            len += 1;
+        }
+        if self.voting_end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.Submitted", len)?;
+        if !self.title.is_empty() {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if self.voting_start_height != 0 {
+            struct_ser.serialize_field("votingStartHeight", ToString::to_string(&self.voting_start_height).as_str())?;
+        }
+        if self.voting_end_height != 0 {
+            struct_ser.serialize_field("votingEndHeight", ToString::to_string(&self.voting_end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_event::Submitted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "title",
+            "voting_start_height",
+            "votingStartHeight",
+            "voting_end_height",
+            "votingEndHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Title,
+            VotingStartHeight,
+            VotingEndHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "title" => Ok(GeneratedField::Title),
+                            "votingStartHeight" | "voting_start_height" => Ok(GeneratedField::VotingStartHeight),
+                            "votingEndHeight" | "voting_end_height" => Ok(GeneratedField::VotingEndHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_event::Submitted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent.Submitted")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_event::Submitted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut title__ = None;
+                let mut voting_start_height__ = None;
+                let mut voting_end_height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::VotingStartHeight => {
+                            if voting_start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingStartHeight"));
+                            }
+                            voting_start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VotingEndHeight => {
+                            if voting_end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingEndHeight"));
+                            }
+                            voting_end_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(proposal_event::Submitted {
+                    title: title__.unwrap_or_default(),
+                    voting_start_height: voting_start_height__.unwrap_or_default(),
+                    voting_end_height: voting_end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.Submitted", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ProposalOutcome {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2869,6 +3176,9 @@ impl serde::Serialize for ValidatorVoteBody {
         if self.governance_key.is_some() {
             len += 1;
         }
+        if self.reason.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ValidatorVoteBody", len)?;
         if self.proposal != 0 {
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
@@ -2882,6 +3192,9 @@ impl serde::Serialize for ValidatorVoteBody {
         if let Some(v) = self.governance_key.as_ref() {
             struct_ser.serialize_field("governanceKey", v)?;
         }
+        if let Some(v) = self.reason.as_ref() {
+            struct_ser.serialize_field("reason", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2898,6 +3211,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
             "identityKey",
             "governance_key",
             "governanceKey",
+            "reason",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2906,6 +3220,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
             Vote,
             IdentityKey,
             GovernanceKey,
+            Reason,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2931,6 +3246,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                             "vote" => Ok(GeneratedField::Vote),
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "reason" => Ok(GeneratedField::Reason),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2954,6 +3270,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                 let mut vote__ = None;
                 let mut identity_key__ = None;
                 let mut governance_key__ = None;
+                let mut reason__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -2982,6 +3299,12 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                             }
                             governance_key__ = map.next_value()?;
                         }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(ValidatorVoteBody {
@@ -2989,12 +3312,104 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                     vote: vote__,
                     identity_key: identity_key__,
                     governance_key: governance_key__,
+                    reason: reason__,
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ValidatorVoteBody", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorVoteReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ValidatorVoteReason", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorVoteReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorVoteReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ValidatorVoteReason")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorVoteReason, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ValidatorVoteReason {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ValidatorVoteReason", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Vote {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3006,12 +3421,18 @@ impl serde::Serialize for Vote {
         if self.vote != 0 {
             len += 1;
         }
+        if self.weights.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Vote", len)?;
         if self.vote != 0 {
             let v = vote::Vote::from_i32(self.vote)
                 .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.vote)))?;
             struct_ser.serialize_field("vote", &v)?;
         }
+        if let Some(v) = self.weights.as_ref() {
+            struct_ser.serialize_field("weights", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -3023,11 +3444,13 @@ impl<'de> serde::Deserialize<'de> for Vote {
     {
         const FIELDS: &[&str] = &[
             "vote",
+            "weights",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Vote,
+            Weights,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3050,6 +3473,7 @@ impl<'de> serde::Deserialize<'de> for Vote {
                     {
                         match value {
                             "vote" => Ok(GeneratedField::Vote),
+                            "weights" => Ok(GeneratedField::Weights),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3070,6 +3494,7 @@ impl<'de> serde::Deserialize<'de> for Vote {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut vote__ = None;
+                let mut weights__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Vote => {
@@ -3078,10 +3503,17 @@ impl<'de> serde::Deserialize<'de> for Vote {
                             }
                             vote__ = Some(map.next_value::<vote::Vote>()? as i32);
                         }
+                        GeneratedField::Weights => {
+                            if weights__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weights"));
+                            }
+                            weights__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Vote {
                     vote: vote__.unwrap_or_default(),
+                    weights: weights__,
                 })
             }
         }
@@ -3167,3 +3599,134 @@ impl<'de> serde::Deserialize<'de> for vote::Vote {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for VoteWeights {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.yes != 0 {
+            len += 1;
+        }
+        if self.no != 0 {
+            len += 1;
+        }
+        if self.abstain != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.VoteWeights", len)?;
+        if self.yes != 0 {
+            struct_ser.serialize_field("yes", &self.yes)?;
+        }
+        if self.no != 0 {
+            struct_ser.serialize_field("no", &self.no)?;
+        }
+        if self.abstain != 0 {
+            struct_ser.serialize_field("abstain", &self.abstain)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteWeights {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "yes",
+            "no",
+            "abstain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Yes,
+            No,
+            Abstain,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "yes" => Ok(GeneratedField::Yes),
+                            "no" => Ok(GeneratedField::No),
+                            "abstain" => Ok(GeneratedField::Abstain),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteWeights;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.VoteWeights")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<VoteWeights, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut yes__ = None;
+                let mut no__ = None;
+                let mut abstain__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Yes => {
+                            if yes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("yes"));
+                            }
+                            yes__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::No => {
+                            if no__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("no"));
+                            }
+                            no__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Abstain => {
+                            if abstain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("abstain"));
+                            }
+                            abstain__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VoteWeights {
+                    yes: yes__.unwrap_or_default(),
+                    no: no__.unwrap_or_default(),
+                    abstain: abstain__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.VoteWeights", FIELDS, GeneratedVisitor)
+    }
+}
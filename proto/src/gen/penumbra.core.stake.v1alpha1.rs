@@ -316,6 +316,119 @@ pub struct Undelegate {
         super::super::crypto::v1alpha1::Amount,
     >,
 }
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting for the unbonding period.
+///
+/// The redelegated stake remains liable for slashing of the source validator
+/// until the end of the unbonding period it skipped, so rather than the
+/// destination validator's delegation tokens, this action produces redelegation
+/// tokens, which are exchanged for them by a `RedelegateClaim`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Redelegate {
+    /// The identity key of the validator to redelegate from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The identity key of the validator to redelegate to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    #[prost(uint64, tag = "3")]
+    pub epoch_index: u64,
+    /// The index of the epoch in which unbonding from the source validator would
+    /// have completed.
+    #[prost(uint64, tag = "4")]
+    pub end_epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    #[prost(message, optional, tag = "5")]
+    pub from_delegation_amount: ::core::option::Option<
+        super::super::crypto::v1alpha1::Amount,
+    >,
+    /// The amount of stake moved between the delegation pools.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified
+    /// epoch (and should be checked in transaction validation!), but including it
+    /// allows stateless verification that the transaction is internally consistent.
+    #[prost(message, optional, tag = "6")]
+    pub unbonded_amount: ::core::option::Option<super::super::crypto::v1alpha1::Amount>,
+    /// The amount of redelegation tokens produced by this action, in units of the
+    /// destination validator's delegation tokens.
+    ///
+    /// This is implied by the destination validator's exchange rate in the
+    /// specified epoch (and should be checked in transaction validation!).
+    #[prost(message, optional, tag = "7")]
+    pub to_delegation_amount: ::core::option::Option<
+        super::super::crypto::v1alpha1::Amount,
+    >,
+}
+/// A transaction action finishing a redelegation, converting redelegation
+/// tokens to the destination validator's delegation tokens, less any penalty
+/// for slashing of the source validator while the stake was liable for it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RedelegateClaim {
+    /// The identity key of the validator the stake was redelegated from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The identity key of the validator the stake was redelegated to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The epoch in which the redelegation was performed, used to verify the penalty.
+    #[prost(uint64, tag = "3")]
+    pub start_epoch_index: u64,
+    /// The epoch in which the redelegated stake stopped being liable for slashing
+    /// of the source validator, used to verify the penalty.
+    #[prost(uint64, tag = "4")]
+    pub end_epoch_index: u64,
+    /// The penalty applied to the redelegated stake, in bps^2 (10e-8).
+    /// In the happy path (no slashing), this is 0.
+    #[prost(message, optional, tag = "5")]
+    pub penalty: ::core::option::Option<Penalty>,
+    /// The amount of redelegation tokens to claim.
+    /// This is a bare number because its denom is determined by the preceding data.
+    #[prost(message, optional, tag = "6")]
+    pub redelegation_amount: ::core::option::Option<
+        super::super::crypto::v1alpha1::Amount,
+    >,
+}
+/// A transaction action releasing a validator from jail, once its minimum jail
+/// duration has elapsed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Unjail {
+    /// The effecting data for the unjailing.
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<UnjailBody>,
+    /// A signature by the validator's identity key over the body.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::crypto::v1alpha1::SpendAuthSignature,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnjailBody {
+    /// The identity key of the validator to release.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The height at which the validator was jailed.
+    ///
+    /// This binds the signature to a single jailing, so that it can't be reused
+    /// to release the validator from a later one.
+    #[prost(uint64, tag = "2")]
+    pub jailed_at_height: u64,
+}
 /// A transaction action finishing an undelegation, converting (slashable)
 /// "unbonding tokens" to (unslashable) staking tokens.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -323,8 +436,10 @@ pub struct Undelegate {
 pub struct UndelegateClaim {
     #[prost(message, optional, tag = "1")]
     pub body: ::core::option::Option<UndelegateClaimBody>,
-    #[prost(bytes = "vec", tag = "2")]
-    pub proof: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub proof: ::core::option::Option<
+        super::super::crypto::v1alpha1::ZkUndelegateClaimProof,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -384,6 +499,8 @@ pub struct DelegationChanges {
     pub delegations: ::prost::alloc::vec::Vec<Delegate>,
     #[prost(message, repeated, tag = "2")]
     pub undelegations: ::prost::alloc::vec::Vec<Undelegate>,
+    #[prost(message, repeated, tag = "3")]
+    pub redelegations: ::prost::alloc::vec::Vec<Redelegate>,
 }
 /// Track's a validator's uptime.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -413,3 +530,119 @@ pub struct Penalty {
     #[prost(uint64, tag = "1")]
     pub inner: u64,
 }
+/// A record of evidence of validator misbehavior processed by the chain, and the
+/// slashing penalty it caused.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvidenceRecord {
+    /// The validator that misbehaved.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The kind of misbehavior.
+    #[prost(enumeration = "evidence_record::EvidenceKind", tag = "2")]
+    pub kind: i32,
+    /// The height at which the misbehavior occurred.
+    #[prost(uint64, tag = "3")]
+    pub evidence_height: u64,
+    /// The height at which the evidence was processed.
+    #[prost(uint64, tag = "4")]
+    pub processed_height: u64,
+    /// The slashing penalty applied to the validator.
+    #[prost(message, optional, tag = "5")]
+    pub penalty: ::core::option::Option<Penalty>,
+}
+/// Nested message and enum types in `EvidenceRecord`.
+pub mod evidence_record {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum EvidenceKind {
+        Unspecified = 0,
+        DuplicateVote = 1,
+        LightClientAttack = 2,
+    }
+    impl EvidenceKind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                EvidenceKind::Unspecified => "EVIDENCE_KIND_UNSPECIFIED",
+                EvidenceKind::DuplicateVote => "EVIDENCE_KIND_DUPLICATE_VOTE",
+                EvidenceKind::LightClientAttack => "EVIDENCE_KIND_LIGHT_CLIENT_ATTACK",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "EVIDENCE_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+                "EVIDENCE_KIND_DUPLICATE_VOTE" => Some(Self::DuplicateVote),
+                "EVIDENCE_KIND_LIGHT_CLIENT_ATTACK" => Some(Self::LightClientAttack),
+                _ => None,
+            }
+        }
+    }
+}
+/// A change to a validator, recorded so that clients can follow the validator set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorEvent {
+    /// The validator the event concerns.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::crypto::v1alpha1::IdentityKey,
+    >,
+    #[prost(oneof = "validator_event::Event", tags = "2, 3, 4")]
+    pub event: ::core::option::Option<validator_event::Event>,
+}
+/// Nested message and enum types in `ValidatorEvent`.
+pub mod validator_event {
+    /// The validator moved from one state to another.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StateChange {
+        #[prost(message, optional, tag = "1")]
+        pub old_state: ::core::option::Option<super::ValidatorState>,
+        #[prost(message, optional, tag = "2")]
+        pub new_state: ::core::option::Option<super::ValidatorState>,
+    }
+    /// The validator was slashed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Slash {
+        /// The slashing penalty applied to the validator.
+        #[prost(message, optional, tag = "1")]
+        pub penalty: ::core::option::Option<super::Penalty>,
+        /// The kind of misbehavior the validator was slashed for, or unspecified if
+        /// it was slashed for downtime.
+        #[prost(enumeration = "super::evidence_record::EvidenceKind", tag = "2")]
+        pub evidence_kind: i32,
+        /// The height of the misbehavior the validator was slashed for, or 0 if it
+        /// was slashed for downtime.
+        #[prost(uint64, tag = "3")]
+        pub evidence_height: u64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "2")]
+        StateChange(StateChange),
+        /// The validator's rate for the next epoch was set.
+        #[prost(message, tag = "3")]
+        RateChange(super::RateData),
+        #[prost(message, tag = "4")]
+        Slash(Slash),
+    }
+}
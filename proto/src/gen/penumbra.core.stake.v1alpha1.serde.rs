@@ -578,6 +578,9 @@ impl serde::Serialize for DelegationChanges {
         if !self.undelegations.is_empty() {
             len += 1;
         }
+        if !self.redelegations.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.DelegationChanges", len)?;
         if !self.delegations.is_empty() {
             struct_ser.serialize_field("delegations", &self.delegations)?;
@@ -585,6 +588,9 @@ impl serde::Serialize for DelegationChanges {
         if !self.undelegations.is_empty() {
             struct_ser.serialize_field("undelegations", &self.undelegations)?;
         }
+        if !self.redelegations.is_empty() {
+            struct_ser.serialize_field("redelegations", &self.redelegations)?;
+        }
         struct_ser.end()
     }
 }
@@ -597,12 +603,14 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
         const FIELDS: &[&str] = &[
             "delegations",
             "undelegations",
+            "redelegations",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Delegations,
             Undelegations,
+            Redelegations,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -626,6 +634,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                         match value {
                             "delegations" => Ok(GeneratedField::Delegations),
                             "undelegations" => Ok(GeneratedField::Undelegations),
+                            "redelegations" => Ok(GeneratedField::Redelegations),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -647,6 +656,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
             {
                 let mut delegations__ = None;
                 let mut undelegations__ = None;
+                let mut redelegations__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Delegations => {
@@ -661,17 +671,268 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                             }
                             undelegations__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Redelegations => {
+                            if redelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegations"));
+                            }
+                            redelegations__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(DelegationChanges {
                     delegations: delegations__.unwrap_or_default(),
                     undelegations: undelegations__.unwrap_or_default(),
+                    redelegations: redelegations__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.DelegationChanges", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EvidenceRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.kind != 0 {
+            len += 1;
+        }
+        if self.evidence_height != 0 {
+            len += 1;
+        }
+        if self.processed_height != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.EvidenceRecord", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.kind != 0 {
+            let v = evidence_record::EvidenceKind::from_i32(self.kind)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        if self.evidence_height != 0 {
+            struct_ser.serialize_field("evidenceHeight", ToString::to_string(&self.evidence_height).as_str())?;
+        }
+        if self.processed_height != 0 {
+            struct_ser.serialize_field("processedHeight", ToString::to_string(&self.processed_height).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EvidenceRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "kind",
+            "evidence_height",
+            "evidenceHeight",
+            "processed_height",
+            "processedHeight",
+            "penalty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            Kind,
+            EvidenceHeight,
+            ProcessedHeight,
+            Penalty,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "kind" => Ok(GeneratedField::Kind),
+                            "evidenceHeight" | "evidence_height" => Ok(GeneratedField::EvidenceHeight),
+                            "processedHeight" | "processed_height" => Ok(GeneratedField::ProcessedHeight),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EvidenceRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.EvidenceRecord")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<EvidenceRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut kind__ = None;
+                let mut evidence_height__ = None;
+                let mut processed_height__ = None;
+                let mut penalty__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map.next_value::<evidence_record::EvidenceKind>()? as i32);
+                        }
+                        GeneratedField::EvidenceHeight => {
+                            if evidence_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("evidenceHeight"));
+                            }
+                            evidence_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProcessedHeight => {
+                            if processed_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("processedHeight"));
+                            }
+                            processed_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(EvidenceRecord {
+                    identity_key: identity_key__,
+                    kind: kind__.unwrap_or_default(),
+                    evidence_height: evidence_height__.unwrap_or_default(),
+                    processed_height: processed_height__.unwrap_or_default(),
+                    penalty: penalty__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.EvidenceRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for evidence_record::EvidenceKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "EVIDENCE_KIND_UNSPECIFIED",
+            Self::DuplicateVote => "EVIDENCE_KIND_DUPLICATE_VOTE",
+            Self::LightClientAttack => "EVIDENCE_KIND_LIGHT_CLIENT_ATTACK",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for evidence_record::EvidenceKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "EVIDENCE_KIND_UNSPECIFIED",
+            "EVIDENCE_KIND_DUPLICATE_VOTE",
+            "EVIDENCE_KIND_LIGHT_CLIENT_ATTACK",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = evidence_record::EvidenceKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(evidence_record::EvidenceKind::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(evidence_record::EvidenceKind::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "EVIDENCE_KIND_UNSPECIFIED" => Ok(evidence_record::EvidenceKind::Unspecified),
+                    "EVIDENCE_KIND_DUPLICATE_VOTE" => Ok(evidence_record::EvidenceKind::DuplicateVote),
+                    "EVIDENCE_KIND_LIGHT_CLIENT_ATTACK" => Ok(evidence_record::EvidenceKind::LightClientAttack),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for FundingStream {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1233,7 +1494,7 @@ impl<'de> serde::Deserialize<'de> for RateData {
         deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.RateData", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Undelegate {
+impl serde::Serialize for Redelegate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1241,66 +1502,84 @@ impl serde::Serialize for Undelegate {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_identity.is_some() {
+        if self.from_validator_identity.is_some() {
             len += 1;
         }
-        if self.start_epoch_index != 0 {
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
             len += 1;
         }
         if self.end_epoch_index != 0 {
             len += 1;
         }
+        if self.from_delegation_amount.is_some() {
+            len += 1;
+        }
         if self.unbonded_amount.is_some() {
             len += 1;
         }
-        if self.delegation_amount.is_some() {
+        if self.to_delegation_amount.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Undelegate", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Redelegate", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
         }
-        if self.start_epoch_index != 0 {
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.epoch_index != 0 {
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
         if self.end_epoch_index != 0 {
             struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
         }
+        if let Some(v) = self.from_delegation_amount.as_ref() {
+            struct_ser.serialize_field("fromDelegationAmount", v)?;
+        }
         if let Some(v) = self.unbonded_amount.as_ref() {
             struct_ser.serialize_field("unbondedAmount", v)?;
         }
-        if let Some(v) = self.delegation_amount.as_ref() {
-            struct_ser.serialize_field("delegationAmount", v)?;
+        if let Some(v) = self.to_delegation_amount.as_ref() {
+            struct_ser.serialize_field("toDelegationAmount", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Undelegate {
+impl<'de> serde::Deserialize<'de> for Redelegate {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
-            "start_epoch_index",
-            "startEpochIndex",
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "epoch_index",
+            "epochIndex",
             "end_epoch_index",
             "endEpochIndex",
+            "from_delegation_amount",
+            "fromDelegationAmount",
             "unbonded_amount",
             "unbondedAmount",
-            "delegation_amount",
-            "delegationAmount",
+            "to_delegation_amount",
+            "toDelegationAmount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
-            StartEpochIndex,
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            EpochIndex,
             EndEpochIndex,
+            FromDelegationAmount,
             UnbondedAmount,
-            DelegationAmount,
+            ToDelegationAmount,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1322,11 +1601,13 @@ impl<'de> serde::Deserialize<'de> for Undelegate {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
                             "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            "fromDelegationAmount" | "from_delegation_amount" => Ok(GeneratedField::FromDelegationAmount),
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
-                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "toDelegationAmount" | "to_delegation_amount" => Ok(GeneratedField::ToDelegationAmount),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1336,34 +1617,42 @@ impl<'de> serde::Deserialize<'de> for Undelegate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Undelegate;
+            type Value = Redelegate;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.Undelegate")
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.Redelegate")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<Undelegate, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Redelegate, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
-                let mut start_epoch_index__ = None;
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut epoch_index__ = None;
                 let mut end_epoch_index__ = None;
+                let mut from_delegation_amount__ = None;
                 let mut unbonded_amount__ = None;
-                let mut delegation_amount__ = None;
+                let mut to_delegation_amount__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
                             }
-                            validator_identity__ = map.next_value()?;
+                            from_validator_identity__ = map.next_value()?;
                         }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
                             }
-                            start_epoch_index__ = 
+                            to_validator_identity__ = map.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -1375,33 +1664,41 @@ impl<'de> serde::Deserialize<'de> for Undelegate {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::FromDelegationAmount => {
+                            if from_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromDelegationAmount"));
+                            }
+                            from_delegation_amount__ = map.next_value()?;
+                        }
                         GeneratedField::UnbondedAmount => {
                             if unbonded_amount__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("unbondedAmount"));
                             }
                             unbonded_amount__ = map.next_value()?;
                         }
-                        GeneratedField::DelegationAmount => {
-                            if delegation_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                        GeneratedField::ToDelegationAmount => {
+                            if to_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toDelegationAmount"));
                             }
-                            delegation_amount__ = map.next_value()?;
+                            to_delegation_amount__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(Undelegate {
-                    validator_identity: validator_identity__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                Ok(Redelegate {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
                     end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                    from_delegation_amount: from_delegation_amount__,
                     unbonded_amount: unbonded_amount__,
-                    delegation_amount: delegation_amount__,
+                    to_delegation_amount: to_delegation_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Undelegate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Redelegate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UndelegateClaim {
+impl serde::Serialize for RedelegateClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1409,117 +1706,10 @@ impl serde::Serialize for UndelegateClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.body.is_some() {
-            len += 1;
-        }
-        if !self.proof.is_empty() {
+        if self.from_validator_identity.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaim", len)?;
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
-        }
-        if !self.proof.is_empty() {
-            struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
-        }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for UndelegateClaim {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "body",
-            "proof",
-        ];
-
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            Body,
-            Proof,
-        }
-        impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                struct GeneratedVisitor;
-
-                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-                    type Value = GeneratedField;
-
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        write!(formatter, "expected one of: {:?}", &FIELDS)
-                    }
-
-                    #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
-                    where
-                        E: serde::de::Error,
-                    {
-                        match value {
-                            "body" => Ok(GeneratedField::Body),
-                            "proof" => Ok(GeneratedField::Proof),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
-                        }
-                    }
-                }
-                deserializer.deserialize_identifier(GeneratedVisitor)
-            }
-        }
-        struct GeneratedVisitor;
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UndelegateClaim;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.UndelegateClaim")
-            }
-
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<UndelegateClaim, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
-            {
-                let mut body__ = None;
-                let mut proof__ = None;
-                while let Some(k) = map.next_key()? {
-                    match k {
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
-                            }
-                            body__ = map.next_value()?;
-                        }
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
-                            }
-                            proof__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                    }
-                }
-                Ok(UndelegateClaim {
-                    body: body__,
-                    proof: proof__.unwrap_or_default(),
-                })
-            }
-        }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaim", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for UndelegateClaimBody {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.validator_identity.is_some() {
+        if self.to_validator_identity.is_some() {
             len += 1;
         }
         if self.start_epoch_index != 0 {
@@ -1531,12 +1721,15 @@ impl serde::Serialize for UndelegateClaimBody {
         if self.penalty.is_some() {
             len += 1;
         }
-        if self.balance_commitment.is_some() {
+        if self.redelegation_amount.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimBody", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.RedelegateClaim", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
         }
         if self.start_epoch_index != 0 {
             struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
@@ -1547,37 +1740,40 @@ impl serde::Serialize for UndelegateClaimBody {
         if let Some(v) = self.penalty.as_ref() {
             struct_ser.serialize_field("penalty", v)?;
         }
-        if let Some(v) = self.balance_commitment.as_ref() {
-            struct_ser.serialize_field("balanceCommitment", v)?;
+        if let Some(v) = self.redelegation_amount.as_ref() {
+            struct_ser.serialize_field("redelegationAmount", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
+impl<'de> serde::Deserialize<'de> for RedelegateClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
             "start_epoch_index",
             "startEpochIndex",
             "end_epoch_index",
             "endEpochIndex",
             "penalty",
-            "balance_commitment",
-            "balanceCommitment",
+            "redelegation_amount",
+            "redelegationAmount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
+            FromValidatorIdentity,
+            ToValidatorIdentity,
             StartEpochIndex,
             EndEpochIndex,
             Penalty,
-            BalanceCommitment,
+            RedelegationAmount,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1599,11 +1795,12 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
                             "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
                             "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
                             "penalty" => Ok(GeneratedField::Penalty),
-                            "balanceCommitment" | "balance_commitment" => Ok(GeneratedField::BalanceCommitment),
+                            "redelegationAmount" | "redelegation_amount" => Ok(GeneratedField::RedelegationAmount),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1613,28 +1810,35 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UndelegateClaimBody;
+            type Value = RedelegateClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.UndelegateClaimBody")
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.RedelegateClaim")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<UndelegateClaimBody, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RedelegateClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
                 let mut start_epoch_index__ = None;
                 let mut end_epoch_index__ = None;
                 let mut penalty__ = None;
-                let mut balance_commitment__ = None;
+                let mut redelegation_amount__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
                             }
-                            validator_identity__ = map.next_value()?;
+                            from_validator_identity__ = map.next_value()?;
+                        }
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
+                            }
+                            to_validator_identity__ = map.next_value()?;
                         }
                         GeneratedField::StartEpochIndex => {
                             if start_epoch_index__.is_some() {
@@ -1658,27 +1862,28 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
                             }
                             penalty__ = map.next_value()?;
                         }
-                        GeneratedField::BalanceCommitment => {
-                            if balance_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("balanceCommitment"));
+                        GeneratedField::RedelegationAmount => {
+                            if redelegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegationAmount"));
                             }
-                            balance_commitment__ = map.next_value()?;
+                            redelegation_amount__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(UndelegateClaimBody {
-                    validator_identity: validator_identity__,
+                Ok(RedelegateClaim {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
                     start_epoch_index: start_epoch_index__.unwrap_or_default(),
                     end_epoch_index: end_epoch_index__.unwrap_or_default(),
                     penalty: penalty__,
-                    balance_commitment: balance_commitment__,
+                    redelegation_amount: redelegation_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.RedelegateClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UndelegateClaimPlan {
+impl serde::Serialize for Undelegate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1695,16 +1900,13 @@ impl serde::Serialize for UndelegateClaimPlan {
         if self.end_epoch_index != 0 {
             len += 1;
         }
-        if self.penalty.is_some() {
-            len += 1;
-        }
-        if self.unbonding_amount.is_some() {
+        if self.unbonded_amount.is_some() {
             len += 1;
         }
-        if !self.balance_blinding.is_empty() {
+        if self.delegation_amount.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimPlan", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Undelegate", len)?;
         if let Some(v) = self.validator_identity.as_ref() {
             struct_ser.serialize_field("validatorIdentity", v)?;
         }
@@ -1714,19 +1916,16 @@ impl serde::Serialize for UndelegateClaimPlan {
         if self.end_epoch_index != 0 {
             struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
         }
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
-        }
-        if let Some(v) = self.unbonding_amount.as_ref() {
-            struct_ser.serialize_field("unbondingAmount", v)?;
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
         }
-        if !self.balance_blinding.is_empty() {
-            struct_ser.serialize_field("balanceBlinding", pbjson::private::base64::encode(&self.balance_blinding).as_str())?;
+        if let Some(v) = self.delegation_amount.as_ref() {
+            struct_ser.serialize_field("delegationAmount", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
+impl<'de> serde::Deserialize<'de> for Undelegate {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1739,11 +1938,10 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
             "startEpochIndex",
             "end_epoch_index",
             "endEpochIndex",
-            "penalty",
-            "unbonding_amount",
-            "unbondingAmount",
-            "balance_blinding",
-            "balanceBlinding",
+            "unbonded_amount",
+            "unbondedAmount",
+            "delegation_amount",
+            "delegationAmount",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1751,9 +1949,8 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
             ValidatorIdentity,
             StartEpochIndex,
             EndEpochIndex,
-            Penalty,
-            UnbondingAmount,
-            BalanceBlinding,
+            UnbondedAmount,
+            DelegationAmount,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1778,9 +1975,8 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
                             "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
                             "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
                             "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
-                            "penalty" => Ok(GeneratedField::Penalty),
-                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
-                            "balanceBlinding" | "balance_blinding" => Ok(GeneratedField::BalanceBlinding),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1790,22 +1986,21 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UndelegateClaimPlan;
+            type Value = Undelegate;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.UndelegateClaimPlan")
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.Undelegate")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<UndelegateClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Undelegate, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut validator_identity__ = None;
                 let mut start_epoch_index__ = None;
                 let mut end_epoch_index__ = None;
-                let mut penalty__ = None;
-                let mut unbonding_amount__ = None;
-                let mut balance_blinding__ = None;
+                let mut unbonded_amount__ = None;
+                let mut delegation_amount__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ValidatorIdentity => {
@@ -1830,42 +2025,33 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
-                            }
-                            penalty__ = map.next_value()?;
-                        }
-                        GeneratedField::UnbondingAmount => {
-                            if unbonding_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
                             }
-                            unbonding_amount__ = map.next_value()?;
+                            unbonded_amount__ = map.next_value()?;
                         }
-                        GeneratedField::BalanceBlinding => {
-                            if balance_blinding__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("balanceBlinding"));
+                        GeneratedField::DelegationAmount => {
+                            if delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
                             }
-                            balance_blinding__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            delegation_amount__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(UndelegateClaimPlan {
+                Ok(Undelegate {
                     validator_identity: validator_identity__,
                     start_epoch_index: start_epoch_index__.unwrap_or_default(),
                     end_epoch_index: end_epoch_index__.unwrap_or_default(),
-                    penalty: penalty__,
-                    unbonding_amount: unbonding_amount__,
-                    balance_blinding: balance_blinding__.unwrap_or_default(),
+                    unbonded_amount: unbonded_amount__,
+                    delegation_amount: delegation_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Undelegate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Uptime {
+impl serde::Serialize for UndelegateClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1873,40 +2059,723 @@ impl serde::Serialize for Uptime {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.as_of_block_height != 0 {
-            len += 1;
-        }
-        if self.window_len != 0 {
+        if self.body.is_some() {
             len += 1;
         }
-        if !self.bitvec.is_empty() {
+        if self.proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Uptime", len)?;
-        if self.as_of_block_height != 0 {
-            struct_ser.serialize_field("asOfBlockHeight", ToString::to_string(&self.as_of_block_height).as_str())?;
-        }
-        if self.window_len != 0 {
-            struct_ser.serialize_field("windowLen", &self.window_len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaim", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
-        if !self.bitvec.is_empty() {
-            struct_ser.serialize_field("bitvec", pbjson::private::base64::encode(&self.bitvec).as_str())?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Uptime {
+impl<'de> serde::Deserialize<'de> for UndelegateClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "as_of_block_height",
-            "asOfBlockHeight",
-            "window_len",
-            "windowLen",
-            "bitvec",
+            "body",
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            Proof,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.UndelegateClaim")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UndelegateClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map.next_value()?;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UndelegateClaim {
+                    body: body__,
+                    proof: proof__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UndelegateClaimBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.balance_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimBody", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.balance_commitment.as_ref() {
+            struct_ser.serialize_field("balanceCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+            "penalty",
+            "balance_commitment",
+            "balanceCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            EndEpochIndex,
+            Penalty,
+            BalanceCommitment,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "balanceCommitment" | "balance_commitment" => Ok(GeneratedField::BalanceCommitment),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateClaimBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.UndelegateClaimBody")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UndelegateClaimBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut balance_commitment__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                            }
+                            validator_identity__ = map.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map.next_value()?;
+                        }
+                        GeneratedField::BalanceCommitment => {
+                            if balance_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceCommitment"));
+                            }
+                            balance_commitment__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UndelegateClaimBody {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    balance_commitment: balance_commitment__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UndelegateClaimPlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.unbonding_amount.is_some() {
+            len += 1;
+        }
+        if !self.balance_blinding.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimPlan", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.unbonding_amount.as_ref() {
+            struct_ser.serialize_field("unbondingAmount", v)?;
+        }
+        if !self.balance_blinding.is_empty() {
+            struct_ser.serialize_field("balanceBlinding", pbjson::private::base64::encode(&self.balance_blinding).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+            "penalty",
+            "unbonding_amount",
+            "unbondingAmount",
+            "balance_blinding",
+            "balanceBlinding",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            EndEpochIndex,
+            Penalty,
+            UnbondingAmount,
+            BalanceBlinding,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
+                            "balanceBlinding" | "balance_blinding" => Ok(GeneratedField::BalanceBlinding),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateClaimPlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.UndelegateClaimPlan")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UndelegateClaimPlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut unbonding_amount__ = None;
+                let mut balance_blinding__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                            }
+                            validator_identity__ = map.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map.next_value()?;
+                        }
+                        GeneratedField::UnbondingAmount => {
+                            if unbonding_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
+                            }
+                            unbonding_amount__ = map.next_value()?;
+                        }
+                        GeneratedField::BalanceBlinding => {
+                            if balance_blinding__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceBlinding"));
+                            }
+                            balance_blinding__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(UndelegateClaimPlan {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    unbonding_amount: unbonding_amount__,
+                    balance_blinding: balance_blinding__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UndelegateClaimPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Unjail {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.auth_sig.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Unjail", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Unjail {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            AuthSig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Unjail;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.Unjail")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Unjail, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Unjail {
+                    body: body__,
+                    auth_sig: auth_sig__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Unjail", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnjailBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.jailed_at_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.UnjailBody", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.jailed_at_height != 0 {
+            struct_ser.serialize_field("jailedAtHeight", ToString::to_string(&self.jailed_at_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnjailBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "jailed_at_height",
+            "jailedAtHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            JailedAtHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "jailedAtHeight" | "jailed_at_height" => Ok(GeneratedField::JailedAtHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnjailBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.UnjailBody")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnjailBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut jailed_at_height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::JailedAtHeight => {
+                            if jailed_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jailedAtHeight"));
+                            }
+                            jailed_at_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(UnjailBody {
+                    identity_key: identity_key__,
+                    jailed_at_height: jailed_at_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.UnjailBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Uptime {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.as_of_block_height != 0 {
+            len += 1;
+        }
+        if self.window_len != 0 {
+            len += 1;
+        }
+        if !self.bitvec.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Uptime", len)?;
+        if self.as_of_block_height != 0 {
+            struct_ser.serialize_field("asOfBlockHeight", ToString::to_string(&self.as_of_block_height).as_str())?;
+        }
+        if self.window_len != 0 {
+            struct_ser.serialize_field("windowLen", &self.window_len)?;
+        }
+        if !self.bitvec.is_empty() {
+            struct_ser.serialize_field("bitvec", pbjson::private::base64::encode(&self.bitvec).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Uptime {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "as_of_block_height",
+            "asOfBlockHeight",
+            "window_len",
+            "windowLen",
+            "bitvec",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1935,9 +2804,497 @@ impl<'de> serde::Deserialize<'de> for Uptime {
                         E: serde::de::Error,
                     {
                         match value {
-                            "asOfBlockHeight" | "as_of_block_height" => Ok(GeneratedField::AsOfBlockHeight),
-                            "windowLen" | "window_len" => Ok(GeneratedField::WindowLen),
-                            "bitvec" => Ok(GeneratedField::Bitvec),
+                            "asOfBlockHeight" | "as_of_block_height" => Ok(GeneratedField::AsOfBlockHeight),
+                            "windowLen" | "window_len" => Ok(GeneratedField::WindowLen),
+                            "bitvec" => Ok(GeneratedField::Bitvec),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Uptime;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.Uptime")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Uptime, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut as_of_block_height__ = None;
+                let mut window_len__ = None;
+                let mut bitvec__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AsOfBlockHeight => {
+                            if as_of_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asOfBlockHeight"));
+                            }
+                            as_of_block_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WindowLen => {
+                            if window_len__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowLen"));
+                            }
+                            window_len__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Bitvec => {
+                            if bitvec__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bitvec"));
+                            }
+                            bitvec__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Uptime {
+                    as_of_block_height: as_of_block_height__.unwrap_or_default(),
+                    window_len: window_len__.unwrap_or_default(),
+                    bitvec: bitvec__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Uptime", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Validator {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if !self.consensus_key.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.website.is_empty() {
+            len += 1;
+        }
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        if self.enabled {
+            len += 1;
+        }
+        if !self.funding_streams.is_empty() {
+            len += 1;
+        }
+        if self.sequence_number != 0 {
+            len += 1;
+        }
+        if self.governance_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Validator", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if !self.consensus_key.is_empty() {
+            struct_ser.serialize_field("consensusKey", pbjson::private::base64::encode(&self.consensus_key).as_str())?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.website.is_empty() {
+            struct_ser.serialize_field("website", &self.website)?;
+        }
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if self.enabled {
+            struct_ser.serialize_field("enabled", &self.enabled)?;
+        }
+        if !self.funding_streams.is_empty() {
+            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
+        }
+        if self.sequence_number != 0 {
+            struct_ser.serialize_field("sequenceNumber", &self.sequence_number)?;
+        }
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Validator {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "consensus_key",
+            "consensusKey",
+            "name",
+            "website",
+            "description",
+            "enabled",
+            "funding_streams",
+            "fundingStreams",
+            "sequence_number",
+            "sequenceNumber",
+            "governance_key",
+            "governanceKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            ConsensusKey,
+            Name,
+            Website,
+            Description,
+            Enabled,
+            FundingStreams,
+            SequenceNumber,
+            GovernanceKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "consensusKey" | "consensus_key" => Ok(GeneratedField::ConsensusKey),
+                            "name" => Ok(GeneratedField::Name),
+                            "website" => Ok(GeneratedField::Website),
+                            "description" => Ok(GeneratedField::Description),
+                            "enabled" => Ok(GeneratedField::Enabled),
+                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
+                            "sequenceNumber" | "sequence_number" => Ok(GeneratedField::SequenceNumber),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Validator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.Validator")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Validator, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut consensus_key__ = None;
+                let mut name__ = None;
+                let mut website__ = None;
+                let mut description__ = None;
+                let mut enabled__ = None;
+                let mut funding_streams__ = None;
+                let mut sequence_number__ = None;
+                let mut governance_key__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::ConsensusKey => {
+                            if consensus_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consensusKey"));
+                            }
+                            consensus_key__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Website => {
+                            if website__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("website"));
+                            }
+                            website__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Enabled => {
+                            if enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enabled"));
+                            }
+                            enabled__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FundingStreams => {
+                            if funding_streams__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                            }
+                            funding_streams__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SequenceNumber => {
+                            if sequence_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequenceNumber"));
+                            }
+                            sequence_number__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
+                            }
+                            governance_key__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Validator {
+                    identity_key: identity_key__,
+                    consensus_key: consensus_key__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    website: website__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    enabled: enabled__.unwrap_or_default(),
+                    funding_streams: funding_streams__.unwrap_or_default(),
+                    sequence_number: sequence_number__.unwrap_or_default(),
+                    governance_key: governance_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Validator", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorDefinition {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator.is_some() {
+            len += 1;
+        }
+        if !self.auth_sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.ValidatorDefinition", len)?;
+        if let Some(v) = self.validator.as_ref() {
+            struct_ser.serialize_field("validator", v)?;
+        }
+        if !self.auth_sig.is_empty() {
+            struct_ser.serialize_field("authSig", pbjson::private::base64::encode(&self.auth_sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Validator,
+            AuthSig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validator" => Ok(GeneratedField::Validator),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorDefinition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.ValidatorDefinition")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorDefinition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Validator => {
+                            if validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validator"));
+                            }
+                            validator__ = map.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ValidatorDefinition {
+                    validator: validator__,
+                    auth_sig: auth_sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.ValidatorDefinition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.ValidatorEvent", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                validator_event::Event::StateChange(v) => {
+                    struct_ser.serialize_field("stateChange", v)?;
+                }
+                validator_event::Event::RateChange(v) => {
+                    struct_ser.serialize_field("rateChange", v)?;
+                }
+                validator_event::Event::Slash(v) => {
+                    struct_ser.serialize_field("slash", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "state_change",
+            "stateChange",
+            "rate_change",
+            "rateChange",
+            "slash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            StateChange,
+            RateChange,
+            Slash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "stateChange" | "state_change" => Ok(GeneratedField::StateChange),
+                            "rateChange" | "rate_change" => Ok(GeneratedField::RateChange),
+                            "slash" => Ok(GeneratedField::Slash),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1947,58 +3304,59 @@ impl<'de> serde::Deserialize<'de> for Uptime {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Uptime;
+            type Value = ValidatorEvent;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.Uptime")
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.ValidatorEvent")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<Uptime, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorEvent, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut as_of_block_height__ = None;
-                let mut window_len__ = None;
-                let mut bitvec__ = None;
+                let mut identity_key__ = None;
+                let mut event__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::AsOfBlockHeight => {
-                            if as_of_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asOfBlockHeight"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            as_of_block_height__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            identity_key__ = map.next_value()?;
                         }
-                        GeneratedField::WindowLen => {
-                            if window_len__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("windowLen"));
+                        GeneratedField::StateChange => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateChange"));
                             }
-                            window_len__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(validator_event::Event::StateChange)
+;
                         }
-                        GeneratedField::Bitvec => {
-                            if bitvec__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bitvec"));
+                        GeneratedField::RateChange => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateChange"));
                             }
-                            bitvec__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(validator_event::Event::RateChange)
+;
+                        }
+                        GeneratedField::Slash => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slash"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(validator_event::Event::Slash)
+;
                         }
                     }
                 }
-                Ok(Uptime {
-                    as_of_block_height: as_of_block_height__.unwrap_or_default(),
-                    window_len: window_len__.unwrap_or_default(),
-                    bitvec: bitvec__.unwrap_or_default(),
+                Ok(ValidatorEvent {
+                    identity_key: identity_key__,
+                    event: event__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Uptime", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.ValidatorEvent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Validator {
+impl serde::Serialize for validator_event::Slash {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2006,98 +3364,49 @@ impl serde::Serialize for Validator {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.identity_key.is_some() {
-            len += 1;
-        }
-        if !self.consensus_key.is_empty() {
-            len += 1;
-        }
-        if !self.name.is_empty() {
-            len += 1;
-        }
-        if !self.website.is_empty() {
-            len += 1;
-        }
-        if !self.description.is_empty() {
-            len += 1;
-        }
-        if self.enabled {
-            len += 1;
-        }
-        if !self.funding_streams.is_empty() {
+        if self.penalty.is_some() {
             len += 1;
         }
-        if self.sequence_number != 0 {
+        if self.evidence_kind != 0 {
             len += 1;
         }
-        if self.governance_key.is_some() {
+        if self.evidence_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.Validator", len)?;
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
-        }
-        if !self.consensus_key.is_empty() {
-            struct_ser.serialize_field("consensusKey", pbjson::private::base64::encode(&self.consensus_key).as_str())?;
-        }
-        if !self.name.is_empty() {
-            struct_ser.serialize_field("name", &self.name)?;
-        }
-        if !self.website.is_empty() {
-            struct_ser.serialize_field("website", &self.website)?;
-        }
-        if !self.description.is_empty() {
-            struct_ser.serialize_field("description", &self.description)?;
-        }
-        if self.enabled {
-            struct_ser.serialize_field("enabled", &self.enabled)?;
-        }
-        if !self.funding_streams.is_empty() {
-            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.ValidatorEvent.Slash", len)?;
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
         }
-        if self.sequence_number != 0 {
-            struct_ser.serialize_field("sequenceNumber", &self.sequence_number)?;
+        if self.evidence_kind != 0 {
+            let v = evidence_record::EvidenceKind::from_i32(self.evidence_kind)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.evidence_kind)))?;
+            struct_ser.serialize_field("evidenceKind", &v)?;
         }
-        if let Some(v) = self.governance_key.as_ref() {
-            struct_ser.serialize_field("governanceKey", v)?;
+        if self.evidence_height != 0 {
+            struct_ser.serialize_field("evidenceHeight", ToString::to_string(&self.evidence_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Validator {
+impl<'de> serde::Deserialize<'de> for validator_event::Slash {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "identity_key",
-            "identityKey",
-            "consensus_key",
-            "consensusKey",
-            "name",
-            "website",
-            "description",
-            "enabled",
-            "funding_streams",
-            "fundingStreams",
-            "sequence_number",
-            "sequenceNumber",
-            "governance_key",
-            "governanceKey",
+            "penalty",
+            "evidence_kind",
+            "evidenceKind",
+            "evidence_height",
+            "evidenceHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IdentityKey,
-            ConsensusKey,
-            Name,
-            Website,
-            Description,
-            Enabled,
-            FundingStreams,
-            SequenceNumber,
-            GovernanceKey,
+            Penalty,
+            EvidenceKind,
+            EvidenceHeight,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2119,15 +3428,9 @@ impl<'de> serde::Deserialize<'de> for Validator {
                         E: serde::de::Error,
                     {
                         match value {
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            "consensusKey" | "consensus_key" => Ok(GeneratedField::ConsensusKey),
-                            "name" => Ok(GeneratedField::Name),
-                            "website" => Ok(GeneratedField::Website),
-                            "description" => Ok(GeneratedField::Description),
-                            "enabled" => Ok(GeneratedField::Enabled),
-                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
-                            "sequenceNumber" | "sequence_number" => Ok(GeneratedField::SequenceNumber),
-                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "evidenceKind" | "evidence_kind" => Ok(GeneratedField::EvidenceKind),
+                            "evidenceHeight" | "evidence_height" => Ok(GeneratedField::EvidenceHeight),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2137,104 +3440,54 @@ impl<'de> serde::Deserialize<'de> for Validator {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Validator;
+            type Value = validator_event::Slash;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.Validator")
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.ValidatorEvent.Slash")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<Validator, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<validator_event::Slash, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut identity_key__ = None;
-                let mut consensus_key__ = None;
-                let mut name__ = None;
-                let mut website__ = None;
-                let mut description__ = None;
-                let mut enabled__ = None;
-                let mut funding_streams__ = None;
-                let mut sequence_number__ = None;
-                let mut governance_key__ = None;
+                let mut penalty__ = None;
+                let mut evidence_kind__ = None;
+                let mut evidence_height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
-                            }
-                            identity_key__ = map.next_value()?;
-                        }
-                        GeneratedField::ConsensusKey => {
-                            if consensus_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("consensusKey"));
-                            }
-                            consensus_key__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Name => {
-                            if name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("name"));
-                            }
-                            name__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::Website => {
-                            if website__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("website"));
-                            }
-                            website__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::Description => {
-                            if description__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("description"));
-                            }
-                            description__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::Enabled => {
-                            if enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("enabled"));
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
                             }
-                            enabled__ = Some(map.next_value()?);
+                            penalty__ = map.next_value()?;
                         }
-                        GeneratedField::FundingStreams => {
-                            if funding_streams__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                        GeneratedField::EvidenceKind => {
+                            if evidence_kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("evidenceKind"));
                             }
-                            funding_streams__ = Some(map.next_value()?);
+                            evidence_kind__ = Some(map.next_value::<evidence_record::EvidenceKind>()? as i32);
                         }
-                        GeneratedField::SequenceNumber => {
-                            if sequence_number__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequenceNumber"));
+                        GeneratedField::EvidenceHeight => {
+                            if evidence_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("evidenceHeight"));
                             }
-                            sequence_number__ = 
+                            evidence_height__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::GovernanceKey => {
-                            if governance_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("governanceKey"));
-                            }
-                            governance_key__ = map.next_value()?;
-                        }
                     }
                 }
-                Ok(Validator {
-                    identity_key: identity_key__,
-                    consensus_key: consensus_key__.unwrap_or_default(),
-                    name: name__.unwrap_or_default(),
-                    website: website__.unwrap_or_default(),
-                    description: description__.unwrap_or_default(),
-                    enabled: enabled__.unwrap_or_default(),
-                    funding_streams: funding_streams__.unwrap_or_default(),
-                    sequence_number: sequence_number__.unwrap_or_default(),
-                    governance_key: governance_key__,
+                Ok(validator_event::Slash {
+                    penalty: penalty__,
+                    evidence_kind: evidence_kind__.unwrap_or_default(),
+                    evidence_height: evidence_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Validator", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.ValidatorEvent.Slash", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorDefinition {
+impl serde::Serialize for validator_event::StateChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2242,38 +3495,39 @@ impl serde::Serialize for ValidatorDefinition {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator.is_some() {
+        if self.old_state.is_some() {
             len += 1;
         }
-        if !self.auth_sig.is_empty() {
+        if self.new_state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.ValidatorDefinition", len)?;
-        if let Some(v) = self.validator.as_ref() {
-            struct_ser.serialize_field("validator", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.ValidatorEvent.StateChange", len)?;
+        if let Some(v) = self.old_state.as_ref() {
+            struct_ser.serialize_field("oldState", v)?;
         }
-        if !self.auth_sig.is_empty() {
-            struct_ser.serialize_field("authSig", pbjson::private::base64::encode(&self.auth_sig).as_str())?;
+        if let Some(v) = self.new_state.as_ref() {
+            struct_ser.serialize_field("newState", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
+impl<'de> serde::Deserialize<'de> for validator_event::StateChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator",
-            "auth_sig",
-            "authSig",
+            "old_state",
+            "oldState",
+            "new_state",
+            "newState",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Validator,
-            AuthSig,
+            OldState,
+            NewState,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2295,8 +3549,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validator" => Ok(GeneratedField::Validator),
-                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            "oldState" | "old_state" => Ok(GeneratedField::OldState),
+                            "newState" | "new_state" => Ok(GeneratedField::NewState),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2306,43 +3560,41 @@ impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorDefinition;
+            type Value = validator_event::StateChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.stake.v1alpha1.ValidatorDefinition")
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.ValidatorEvent.StateChange")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ValidatorDefinition, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<validator_event::StateChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator__ = None;
-                let mut auth_sig__ = None;
+                let mut old_state__ = None;
+                let mut new_state__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Validator => {
-                            if validator__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validator"));
+                        GeneratedField::OldState => {
+                            if old_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldState"));
                             }
-                            validator__ = map.next_value()?;
+                            old_state__ = map.next_value()?;
                         }
-                        GeneratedField::AuthSig => {
-                            if auth_sig__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("authSig"));
+                        GeneratedField::NewState => {
+                            if new_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newState"));
                             }
-                            auth_sig__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            new_state__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(ValidatorDefinition {
-                    validator: validator__,
-                    auth_sig: auth_sig__.unwrap_or_default(),
+                Ok(validator_event::StateChange {
+                    old_state: old_state__,
+                    new_state: new_state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.ValidatorDefinition", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.ValidatorEvent.StateChange", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ValidatorInfo {
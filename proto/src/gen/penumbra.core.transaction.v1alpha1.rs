@@ -52,7 +52,7 @@ pub struct TransactionBody {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 43, 44, 50, 51, 52, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -102,6 +102,12 @@ pub mod action {
         Undelegate(super::super::super::stake::v1alpha1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::stake::v1alpha1::UndelegateClaim),
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "44")]
+        Unjail(super::super::super::stake::v1alpha1::Unjail),
+        #[prost(message, tag = "45")]
+        RedelegateClaim(super::super::super::stake::v1alpha1::RedelegateClaim),
         /// DAO
         #[prost(message, tag = "50")]
         DaoSpend(super::super::super::governance::v1alpha1::DaoSpend),
@@ -187,7 +193,7 @@ pub struct TransactionView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 44, 45, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -247,6 +253,12 @@ pub mod action_view {
         /// of the transaction. is that fine?
         #[prost(message, tag = "43")]
         UndelegateClaim(super::super::super::stake::v1alpha1::UndelegateClaim),
+        #[prost(message, tag = "44")]
+        Redelegate(super::super::super::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "45")]
+        Unjail(super::super::super::stake::v1alpha1::Unjail),
+        #[prost(message, tag = "46")]
+        RedelegateClaim(super::super::super::stake::v1alpha1::RedelegateClaim),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::ibc::v1alpha1::Ics20Withdrawal),
     }
@@ -479,7 +491,7 @@ pub struct TransactionPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 43, 44, 50, 51, 52, 200"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -534,6 +546,14 @@ pub mod action_plan {
         Undelegate(super::super::super::stake::v1alpha1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::stake::v1alpha1::UndelegateClaimPlan),
+        /// Redelegations are also fully transparent, so they have no Plan type.
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "44")]
+        Unjail(super::super::super::stake::v1alpha1::Unjail),
+        /// Redelegation claims are also fully transparent, so they have no Plan type.
+        #[prost(message, tag = "45")]
+        RedelegateClaim(super::super::super::stake::v1alpha1::RedelegateClaim),
         /// DAO
         #[prost(message, tag = "50")]
         DaoSpend(super::super::super::governance::v1alpha1::DaoSpend),
@@ -541,6 +561,9 @@ pub mod action_plan {
         DaoOutput(super::super::super::governance::v1alpha1::DaoOutput),
         #[prost(message, tag = "52")]
         DaoDeposit(super::super::super::governance::v1alpha1::DaoDeposit),
+        /// An ICS-20 withdrawal is fully transparent, so it has no Plan type.
+        #[prost(message, tag = "200")]
+        Ics20Withdrawal(super::super::super::ibc::v1alpha1::Ics20Withdrawal),
     }
 }
 /// Describes a plan for forming a `Clue`.
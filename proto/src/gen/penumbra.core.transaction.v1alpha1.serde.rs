@@ -66,6 +66,15 @@ impl serde::Serialize for Action {
                 action::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action::Action::Unjail(v) => {
+                    struct_ser.serialize_field("unjail", v)?;
+                }
+                action::Action::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action::Action::DaoSpend(v) => {
                     struct_ser.serialize_field("daoSpend", v)?;
                 }
@@ -121,6 +130,10 @@ impl<'de> serde::Deserialize<'de> for Action {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
+            "unjail",
+            "redelegate_claim",
+            "redelegateClaim",
             "dao_spend",
             "daoSpend",
             "dao_output",
@@ -151,6 +164,9 @@ impl<'de> serde::Deserialize<'de> for Action {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
+            Unjail,
+            RedelegateClaim,
             DaoSpend,
             DaoOutput,
             DaoDeposit,
@@ -194,6 +210,9 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "unjail" => Ok(GeneratedField::Unjail),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "daoOutput" | "dao_output" => Ok(GeneratedField::DaoOutput),
                             "daoDeposit" | "dao_deposit" => Ok(GeneratedField::DaoDeposit),
@@ -344,6 +363,27 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map.next_value::<::std::option::Option<_>>()?.map(action::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action::Action::Redelegate)
+;
+                        }
+                        GeneratedField::Unjail => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unjail"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action::Action::Unjail)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action::Action::RedelegateClaim)
 ;
                         }
                         GeneratedField::DaoSpend => {
@@ -452,6 +492,15 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_plan::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action_plan::Action::Unjail(v) => {
+                    struct_ser.serialize_field("unjail", v)?;
+                }
+                action_plan::Action::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action_plan::Action::DaoSpend(v) => {
                     struct_ser.serialize_field("daoSpend", v)?;
                 }
@@ -461,6 +510,9 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::DaoDeposit(v) => {
                     struct_ser.serialize_field("daoDeposit", v)?;
                 }
+                action_plan::Action::Ics20Withdrawal(v) => {
+                    struct_ser.serialize_field("ics20Withdrawal", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -504,12 +556,18 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
+            "unjail",
+            "redelegate_claim",
+            "redelegateClaim",
             "dao_spend",
             "daoSpend",
             "dao_output",
             "daoOutput",
             "dao_deposit",
             "daoDeposit",
+            "ics20_withdrawal",
+            "ics20Withdrawal",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -532,9 +590,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
+            Unjail,
+            RedelegateClaim,
             DaoSpend,
             DaoOutput,
             DaoDeposit,
+            Ics20Withdrawal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -574,9 +636,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "unjail" => Ok(GeneratedField::Unjail),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "daoOutput" | "dao_output" => Ok(GeneratedField::DaoOutput),
                             "daoDeposit" | "dao_deposit" => Ok(GeneratedField::DaoDeposit),
+                            "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -723,6 +789,27 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Redelegate)
+;
+                        }
+                        GeneratedField::Unjail => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unjail"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Unjail)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::RedelegateClaim)
 ;
                         }
                         GeneratedField::DaoSpend => {
@@ -744,6 +831,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("daoDeposit"));
                             }
                             action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::DaoDeposit)
+;
+                        }
+                        GeneratedField::Ics20Withdrawal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Ics20Withdrawal)
 ;
                         }
                     }
@@ -833,6 +927,15 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_view::ActionView::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action_view::ActionView::Unjail(v) => {
+                    struct_ser.serialize_field("unjail", v)?;
+                }
+                action_view::ActionView::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action_view::ActionView::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
@@ -885,6 +988,10 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "daoDeposit",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
+            "unjail",
+            "redelegate_claim",
+            "redelegateClaim",
             "ics20_withdrawal",
             "ics20Withdrawal",
         ];
@@ -912,6 +1019,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             DaoOutput,
             DaoDeposit,
             UndelegateClaim,
+            Redelegate,
+            Unjail,
+            RedelegateClaim,
             Ics20Withdrawal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -955,6 +1065,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "daoOutput" | "dao_output" => Ok(GeneratedField::DaoOutput),
                             "daoDeposit" | "dao_deposit" => Ok(GeneratedField::DaoDeposit),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "unjail" => Ok(GeneratedField::Unjail),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -1123,6 +1236,27 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action_view__ = map.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action_view__ = map.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Redelegate)
+;
+                        }
+                        GeneratedField::Unjail => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unjail"));
+                            }
+                            action_view__ = map.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Unjail)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action_view__ = map.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::RedelegateClaim)
 ;
                         }
                         GeneratedField::Ics20Withdrawal => {
@@ -292,6 +292,58 @@ pub struct NotesForVotingRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationHistoryRequest {
+    /// If set, only return the history of delegations to this validator.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+    /// Identifies the FVK for the notes to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
+    /// Authorizes the request.
+    #[prost(message, optional, tag = "15")]
+    pub token: ::core::option::Option<ViewAuthToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondingsRequest {
+    /// If set, only return unbondings that can be claimed now.
+    #[prost(bool, tag = "1")]
+    pub claimable_only: bool,
+    /// Identifies the FVK for the notes to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
+    /// Authorizes the request.
+    #[prost(message, optional, tag = "15")]
+    pub token: ::core::option::Option<ViewAuthToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingPowerRequest {
+    /// The proposal to compute voting power for.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// If set, only return notes with the specified address index.
+    #[prost(message, optional, tag = "2")]
+    pub address_index: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AddressIndex,
+    >,
+    /// Identifies the FVK for the notes to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
+    /// Authorizes the request.
+    #[prost(message, optional, tag = "15")]
+    pub token: ::core::option::Option<ViewAuthToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WitnessRequest {
     /// The note commitments to obtain auth paths for.
     #[prost(message, repeated, tag = "2")]
@@ -335,10 +387,35 @@ pub struct WitnessAndBuildRequest {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WitnessAndBuildResponse {
+    /// The built transaction, set only on the final message of the stream.
     #[prost(message, optional, tag = "1")]
     pub transaction: ::core::option::Option<
         super::super::core::transaction::v1alpha1::Transaction,
     >,
+    /// Progress proving one of the transaction's actions, set on every other
+    /// message of the stream.
+    #[prost(message, optional, tag = "2")]
+    pub progress: ::core::option::Option<BuildProgress>,
+}
+/// Progress proving one of the actions of a transaction being built.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuildProgress {
+    /// The index of the action among the actions that need proofs.
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    /// The number of actions that need proofs.
+    #[prost(uint64, tag = "2")]
+    pub total: u64,
+    /// The kind of action being proved, such as "spend".
+    #[prost(string, tag = "3")]
+    pub action: ::prost::alloc::string::String,
+    /// Whether proving has finished: if not, it has just started.
+    #[prost(bool, tag = "4")]
+    pub finished: bool,
+    /// How long proving took, in milliseconds, if it has finished.
+    #[prost(uint64, tag = "5")]
+    pub duration_ms: u64,
 }
 /// Requests all assets known to the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -565,6 +642,93 @@ pub struct NotesForVotingResponse {
         super::super::core::crypto::v1alpha1::IdentityKey,
     >,
 }
+/// The wallet's delegation to a validator during one epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationHistoryResponse {
+    /// The validator's rate data for the epoch.
+    #[prost(message, optional, tag = "1")]
+    pub rate_data: ::core::option::Option<super::super::core::stake::v1alpha1::RateData>,
+    /// The amount of delegation tokens held at the start of the epoch.
+    #[prost(message, optional, tag = "2")]
+    pub delegation_amount: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+    /// The value of the delegation tokens in the staking token at the start of the epoch.
+    #[prost(message, optional, tag = "3")]
+    pub unbonded_amount: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+    /// The staking rewards accrued by the delegation tokens over the epoch.
+    ///
+    /// This is unset for the current epoch, whose rewards are not yet known.
+    #[prost(message, optional, tag = "4")]
+    pub reward_amount: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+    /// The value lost to slashing penalties applied at the end of the epoch.
+    ///
+    /// This is unset for the current epoch, whose penalties are not yet known.
+    #[prost(message, optional, tag = "5")]
+    pub slashed_amount: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+}
+/// The wallet's unspent unbonding tokens of a single kind, held by a single address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondingsResponse {
+    /// The validator the stake was undelegated from.
+    #[prost(message, optional, tag = "1")]
+    pub validator_identity: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The epoch in which unbonding began.
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+    /// The epoch from which the unbonding tokens can be claimed.
+    #[prost(uint64, tag = "3")]
+    pub end_epoch_index: u64,
+    /// The address index holding the unbonding tokens.
+    #[prost(message, optional, tag = "4")]
+    pub address_index: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AddressIndex,
+    >,
+    /// The notes holding the unbonding tokens.
+    #[prost(message, repeated, tag = "5")]
+    pub note_records: ::prost::alloc::vec::Vec<SpendableNoteRecord>,
+    /// Whether the unbonding tokens can be claimed now.
+    #[prost(bool, tag = "6")]
+    pub claimable: bool,
+    /// The slashing penalty to apply when claiming the unbonding tokens.
+    ///
+    /// This is only set for claimable unbondings, since the penalty isn't final
+    /// until unbonding ends.
+    #[prost(message, optional, tag = "7")]
+    pub penalty: ::core::option::Option<super::super::core::stake::v1alpha1::Penalty>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingPowerResponse {
+    /// The delegation note that can be voted with.
+    #[prost(message, optional, tag = "1")]
+    pub note_record: ::core::option::Option<SpendableNoteRecord>,
+    /// The validator the note is delegated to.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The voting power the note carries, in the staking token, using the
+    /// validator's exchange rate at the start of the proposal.
+    #[prost(message, optional, tag = "3")]
+    pub voting_power: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+    /// Whether the note's nullifier has already been used to vote on the
+    /// proposal.  A repeated vote replaces the earlier one.
+    #[prost(bool, tag = "4")]
+    pub voted: bool,
+}
 /// A note plaintext with associated metadata about its status.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -791,6 +955,78 @@ pub mod view_protocol_service_client {
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
+        /// Queries for the value of the wallet's delegations in each epoch, and the
+        /// rewards and slashing losses they accrued.
+        pub async fn delegation_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DelegationHistoryRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::DelegationHistoryResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/DelegationHistory",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// Queries for the wallet's pending unbondings, along with whether they can
+        /// be claimed yet.
+        pub async fn unbondings(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnbondingsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::UnbondingsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/Unbondings",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// Queries for the notes the wallet can vote with on a proposal, along with
+        /// the voting power each carries and whether it has already voted.
+        pub async fn voting_power(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VotingPowerRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::VotingPowerResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/VotingPower",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -816,10 +1052,15 @@ pub mod view_protocol_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Witnesses and builds a transaction, streaming progress as each of its
+        /// actions is proved, followed by the built transaction.
         pub async fn witness_and_build(
             &mut self,
             request: impl tonic::IntoRequest<super::WitnessAndBuildRequest>,
-        ) -> Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status> {
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::WitnessAndBuildResponse>>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -833,7 +1074,7 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/WitnessAndBuild",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            self.inner.server_streaming(request.into_request(), path, codec).await
         }
         /// Queries for assets.
         pub async fn assets(
@@ -1311,6 +1552,42 @@ pub mod view_protocol_service_server {
             &self,
             request: tonic::Request<super::NotesForVotingRequest>,
         ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status>;
+        /// Server streaming response type for the DelegationHistory method.
+        type DelegationHistoryStream: futures_core::Stream<
+                Item = Result<super::DelegationHistoryResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for the value of the wallet's delegations in each epoch, and the
+        /// rewards and slashing losses they accrued.
+        async fn delegation_history(
+            &self,
+            request: tonic::Request<super::DelegationHistoryRequest>,
+        ) -> Result<tonic::Response<Self::DelegationHistoryStream>, tonic::Status>;
+        /// Server streaming response type for the Unbondings method.
+        type UnbondingsStream: futures_core::Stream<
+                Item = Result<super::UnbondingsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for the wallet's pending unbondings, along with whether they can
+        /// be claimed yet.
+        async fn unbondings(
+            &self,
+            request: tonic::Request<super::UnbondingsRequest>,
+        ) -> Result<tonic::Response<Self::UnbondingsStream>, tonic::Status>;
+        /// Server streaming response type for the VotingPower method.
+        type VotingPowerStream: futures_core::Stream<
+                Item = Result<super::VotingPowerResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for the notes the wallet can vote with on a proposal, along with
+        /// the voting power each carries and whether it has already voted.
+        async fn voting_power(
+            &self,
+            request: tonic::Request<super::VotingPowerRequest>,
+        ) -> Result<tonic::Response<Self::VotingPowerStream>, tonic::Status>;
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -1321,10 +1598,18 @@ pub mod view_protocol_service_server {
             &self,
             request: tonic::Request<super::WitnessRequest>,
         ) -> Result<tonic::Response<super::WitnessResponse>, tonic::Status>;
+        /// Server streaming response type for the WitnessAndBuild method.
+        type WitnessAndBuildStream: futures_core::Stream<
+                Item = Result<super::WitnessAndBuildResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Witnesses and builds a transaction, streaming progress as each of its
+        /// actions is proved, followed by the built transaction.
         async fn witness_and_build(
             &self,
             request: tonic::Request<super::WitnessAndBuildRequest>,
-        ) -> Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status>;
+        ) -> Result<tonic::Response<Self::WitnessAndBuildStream>, tonic::Status>;
         /// Server streaming response type for the Assets method.
         type AssetsStream: futures_core::Stream<
                 Item = Result<super::AssetsResponse, tonic::Status>,
@@ -1660,6 +1945,128 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/DelegationHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegationHistorySvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<
+                        super::DelegationHistoryRequest,
+                    > for DelegationHistorySvc<T> {
+                        type Response = super::DelegationHistoryResponse;
+                        type ResponseStream = T::DelegationHistoryStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DelegationHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).delegation_history(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DelegationHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/Unbondings" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnbondingsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<super::UnbondingsRequest>
+                    for UnbondingsSvc<T> {
+                        type Response = super::UnbondingsResponse;
+                        type ResponseStream = T::UnbondingsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnbondingsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).unbondings(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnbondingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/VotingPower" => {
+                    #[allow(non_camel_case_types)]
+                    struct VotingPowerSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<super::VotingPowerRequest>
+                    for VotingPowerSvc<T> {
+                        type Response = super::VotingPowerResponse;
+                        type ResponseStream = T::VotingPowerStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VotingPowerRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).voting_power(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VotingPowerSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/Witness" => {
                     #[allow(non_camel_case_types)]
                     struct WitnessSvc<T: ViewProtocolService>(pub Arc<T>);
@@ -1703,11 +2110,13 @@ pub mod view_protocol_service_server {
                     struct WitnessAndBuildSvc<T: ViewProtocolService>(pub Arc<T>);
                     impl<
                         T: ViewProtocolService,
-                    > tonic::server::UnaryService<super::WitnessAndBuildRequest>
-                    for WitnessAndBuildSvc<T> {
+                    > tonic::server::ServerStreamingService<
+                        super::WitnessAndBuildRequest,
+                    > for WitnessAndBuildSvc<T> {
                         type Response = super::WitnessAndBuildResponse;
+                        type ResponseStream = T::WitnessAndBuildStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
@@ -1733,7 +2142,7 @@ pub mod view_protocol_service_server {
                                 accept_compression_encodings,
                                 send_compression_encodings,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
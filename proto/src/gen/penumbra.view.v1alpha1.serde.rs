@@ -870,6 +870,172 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.BroadcastTransactionResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for BuildProgress {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.index != 0 {
+            len += 1;
+        }
+        if self.total != 0 {
+            len += 1;
+        }
+        if !self.action.is_empty() {
+            len += 1;
+        }
+        if self.finished {
+            len += 1;
+        }
+        if self.duration_ms != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.BuildProgress", len)?;
+        if self.index != 0 {
+            struct_ser.serialize_field("index", ToString::to_string(&self.index).as_str())?;
+        }
+        if self.total != 0 {
+            struct_ser.serialize_field("total", ToString::to_string(&self.total).as_str())?;
+        }
+        if !self.action.is_empty() {
+            struct_ser.serialize_field("action", &self.action)?;
+        }
+        if self.finished {
+            struct_ser.serialize_field("finished", &self.finished)?;
+        }
+        if self.duration_ms != 0 {
+            struct_ser.serialize_field("durationMs", ToString::to_string(&self.duration_ms).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BuildProgress {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "index",
+            "total",
+            "action",
+            "finished",
+            "duration_ms",
+            "durationMs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Index,
+            Total,
+            Action,
+            Finished,
+            DurationMs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "index" => Ok(GeneratedField::Index),
+                            "total" => Ok(GeneratedField::Total),
+                            "action" => Ok(GeneratedField::Action),
+                            "finished" => Ok(GeneratedField::Finished),
+                            "durationMs" | "duration_ms" => Ok(GeneratedField::DurationMs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BuildProgress;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.BuildProgress")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BuildProgress, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut index__ = None;
+                let mut total__ = None;
+                let mut action__ = None;
+                let mut finished__ = None;
+                let mut duration_ms__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Total => {
+                            if total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("total"));
+                            }
+                            total__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
+                            }
+                            action__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Finished => {
+                            if finished__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("finished"));
+                            }
+                            finished__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::DurationMs => {
+                            if duration_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("durationMs"));
+                            }
+                            duration_ms__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(BuildProgress {
+                    index: index__.unwrap_or_default(),
+                    total: total__.unwrap_or_default(),
+                    action: action__.unwrap_or_default(),
+                    finished: finished__.unwrap_or_default(),
+                    duration_ms: duration_ms__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.BuildProgress", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ChainParametersRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1032,7 +1198,7 @@ impl<'de> serde::Deserialize<'de> for ChainParametersResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.ChainParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EphemeralAddressRequest {
+impl serde::Serialize for DelegationHistoryRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1040,30 +1206,47 @@ impl serde::Serialize for EphemeralAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        if self.token.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.DelegationHistoryRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        if let Some(v) = self.token.as_ref() {
+            struct_ser.serialize_field("token", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
+impl<'de> serde::Deserialize<'de> for DelegationHistoryRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "identity_key",
+            "identityKey",
+            "account_group_id",
+            "accountGroupId",
+            "token",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            IdentityKey,
+            AccountGroupId,
+            Token,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1085,7 +1268,9 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            "token" => Ok(GeneratedField::Token),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1095,36 +1280,52 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EphemeralAddressRequest;
+            type Value = DelegationHistoryRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.EphemeralAddressRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.DelegationHistoryRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<EphemeralAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DelegationHistoryRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut identity_key__ = None;
+                let mut account_group_id__ = None;
+                let mut token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            address_index__ = map.next_value()?;
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Token => {
+                            if token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("token"));
+                            }
+                            token__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(EphemeralAddressRequest {
-                    address_index: address_index__,
+                Ok(DelegationHistoryRequest {
+                    identity_key: identity_key__,
+                    account_group_id: account_group_id__,
+                    token: token__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.DelegationHistoryRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EphemeralAddressResponse {
+impl serde::Serialize for DelegationHistoryResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1132,29 +1333,66 @@ impl serde::Serialize for EphemeralAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressResponse", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if self.delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.reward_amount.is_some() {
+            len += 1;
+        }
+        if self.slashed_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.DelegationHistoryResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        if let Some(v) = self.delegation_amount.as_ref() {
+            struct_ser.serialize_field("delegationAmount", v)?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.reward_amount.as_ref() {
+            struct_ser.serialize_field("rewardAmount", v)?;
+        }
+        if let Some(v) = self.slashed_amount.as_ref() {
+            struct_ser.serialize_field("slashedAmount", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
+impl<'de> serde::Deserialize<'de> for DelegationHistoryResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "rate_data",
+            "rateData",
+            "delegation_amount",
+            "delegationAmount",
+            "unbonded_amount",
+            "unbondedAmount",
+            "reward_amount",
+            "rewardAmount",
+            "slashed_amount",
+            "slashedAmount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            RateData,
+            DelegationAmount,
+            UnbondedAmount,
+            RewardAmount,
+            SlashedAmount,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1176,7 +1414,11 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "rewardAmount" | "reward_amount" => Ok(GeneratedField::RewardAmount),
+                            "slashedAmount" | "slashed_amount" => Ok(GeneratedField::SlashedAmount),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1186,58 +1428,99 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EphemeralAddressResponse;
+            type Value = DelegationHistoryResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.EphemeralAddressResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.DelegationHistoryResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<EphemeralAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DelegationHistoryResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut rate_data__ = None;
+                let mut delegation_amount__ = None;
+                let mut unbonded_amount__ = None;
+                let mut reward_amount__ = None;
+                let mut slashed_amount__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            address__ = map.next_value()?;
+                            rate_data__ = map.next_value()?;
+                        }
+                        GeneratedField::DelegationAmount => {
+                            if delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                            }
+                            delegation_amount__ = map.next_value()?;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map.next_value()?;
+                        }
+                        GeneratedField::RewardAmount => {
+                            if reward_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardAmount"));
+                            }
+                            reward_amount__ = map.next_value()?;
+                        }
+                        GeneratedField::SlashedAmount => {
+                            if slashed_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashedAmount"));
+                            }
+                            slashed_amount__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(EphemeralAddressResponse {
-                    address: address__,
+                Ok(DelegationHistoryResponse {
+                    rate_data: rate_data__,
+                    delegation_amount: delegation_amount__,
+                    unbonded_amount: unbonded_amount__,
+                    reward_amount: reward_amount__,
+                    slashed_amount: slashed_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.EphemeralAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.DelegationHistoryResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FmdParametersRequest {
+impl serde::Serialize for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.FMDParametersRequest", len)?;
+        let mut len = 0;
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            AddressIndex,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1258,7 +1541,10 @@ impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1266,27 +1552,36 @@ impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FmdParametersRequest;
+            type Value = EphemeralAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.FMDParametersRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.EphemeralAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<FmdParametersRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<EphemeralAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut address_index__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map.next_value()?;
+                        }
+                    }
                 }
-                Ok(FmdParametersRequest {
+                Ok(EphemeralAddressRequest {
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.FMDParametersRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FmdParametersResponse {
+impl serde::Serialize for EphemeralAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1294,10 +1589,172 @@ impl serde::Serialize for FmdParametersResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.parameters.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.FMDParametersResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressResponse", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EphemeralAddressResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.EphemeralAddressResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<EphemeralAddressResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(EphemeralAddressResponse {
+                    address: address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.EphemeralAddressResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FmdParametersRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.FMDParametersRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FmdParametersRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.FMDParametersRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FmdParametersRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(FmdParametersRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.FMDParametersRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FmdParametersResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.parameters.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.FMDParametersResponse", len)?;
         if let Some(v) = self.parameters.as_ref() {
             struct_ser.serialize_field("parameters", v)?;
         }
@@ -5152,22 +5609,573 @@ impl<'de> serde::Deserialize<'de> for TransactionsRequest {
                             if end_height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("endHeight"));
                             }
-                            end_height__ = 
-                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
-                            ;
+                            end_height__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TransactionsRequest {
+                    start_height: start_height__,
+                    end_height: end_height__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.tx.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionsResponse", len)?;
+        if self.block_height != 0 {
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", pbjson::private::base64::encode(&self.tx_hash).as_str())?;
+        }
+        if let Some(v) = self.tx.as_ref() {
+            struct_ser.serialize_field("tx", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_height",
+            "blockHeight",
+            "tx_hash",
+            "txHash",
+            "tx",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockHeight,
+            TxHash,
+            Tx,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "tx" => Ok(GeneratedField::Tx),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_height__ = None;
+                let mut tx_hash__ = None;
+                let mut tx__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Tx => {
+                            if tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tx"));
+                            }
+                            tx__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(TransactionsResponse {
+                    block_height: block_height__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    tx: tx__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnbondingsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.claimable_only {
+            len += 1;
+        }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        if self.token.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.UnbondingsRequest", len)?;
+        if self.claimable_only {
+            struct_ser.serialize_field("claimableOnly", &self.claimable_only)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        if let Some(v) = self.token.as_ref() {
+            struct_ser.serialize_field("token", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnbondingsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "claimable_only",
+            "claimableOnly",
+            "account_group_id",
+            "accountGroupId",
+            "token",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClaimableOnly,
+            AccountGroupId,
+            Token,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "claimableOnly" | "claimable_only" => Ok(GeneratedField::ClaimableOnly),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            "token" => Ok(GeneratedField::Token),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnbondingsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.UnbondingsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnbondingsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut claimable_only__ = None;
+                let mut account_group_id__ = None;
+                let mut token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ClaimableOnly => {
+                            if claimable_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimableOnly"));
+                            }
+                            claimable_only__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Token => {
+                            if token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("token"));
+                            }
+                            token__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UnbondingsRequest {
+                    claimable_only: claimable_only__.unwrap_or_default(),
+                    account_group_id: account_group_id__,
+                    token: token__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.UnbondingsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnbondingsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if !self.note_records.is_empty() {
+            len += 1;
+        }
+        if self.claimable {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.UnbondingsResponse", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if !self.note_records.is_empty() {
+            struct_ser.serialize_field("noteRecords", &self.note_records)?;
+        }
+        if self.claimable {
+            struct_ser.serialize_field("claimable", &self.claimable)?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnbondingsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+            "address_index",
+            "addressIndex",
+            "note_records",
+            "noteRecords",
+            "claimable",
+            "penalty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            EndEpochIndex,
+            AddressIndex,
+            NoteRecords,
+            Claimable,
+            Penalty,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "noteRecords" | "note_records" => Ok(GeneratedField::NoteRecords),
+                            "claimable" => Ok(GeneratedField::Claimable),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnbondingsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.UnbondingsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnbondingsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                let mut address_index__ = None;
+                let mut note_records__ = None;
+                let mut claimable__ = None;
+                let mut penalty__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                            }
+                            validator_identity__ = map.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map.next_value()?;
+                        }
+                        GeneratedField::NoteRecords => {
+                            if note_records__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecords"));
+                            }
+                            note_records__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Claimable => {
+                            if claimable__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimable"));
+                            }
+                            claimable__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UnbondingsResponse {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                    address_index: address_index__,
+                    note_records: note_records__.unwrap_or_default(),
+                    claimable: claimable__.unwrap_or_default(),
+                    penalty: penalty__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.UnbondingsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ViewAuthRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.fvk.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.ViewAuthRequest", len)?;
+        if let Some(v) = self.fvk.as_ref() {
+            struct_ser.serialize_field("fvk", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ViewAuthRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fvk",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Fvk,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fvk" => Ok(GeneratedField::Fvk),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ViewAuthRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.ViewAuthRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ViewAuthRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fvk__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Fvk => {
+                            if fvk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fvk"));
+                            }
+                            fvk__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(TransactionsRequest {
-                    start_height: start_height__,
-                    end_height: end_height__,
+                Ok(ViewAuthRequest {
+                    fvk: fvk__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.ViewAuthRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionsResponse {
+impl serde::Serialize for ViewAuthResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5175,47 +6183,29 @@ impl serde::Serialize for TransactionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.block_height != 0 {
-            len += 1;
-        }
-        if !self.tx_hash.is_empty() {
-            len += 1;
-        }
-        if self.tx.is_some() {
+        if self.token.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionsResponse", len)?;
-        if self.block_height != 0 {
-            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
-        }
-        if !self.tx_hash.is_empty() {
-            struct_ser.serialize_field("txHash", pbjson::private::base64::encode(&self.tx_hash).as_str())?;
-        }
-        if let Some(v) = self.tx.as_ref() {
-            struct_ser.serialize_field("tx", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.ViewAuthResponse", len)?;
+        if let Some(v) = self.token.as_ref() {
+            struct_ser.serialize_field("token", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionsResponse {
+impl<'de> serde::Deserialize<'de> for ViewAuthResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "block_height",
-            "blockHeight",
-            "tx_hash",
-            "txHash",
-            "tx",
+            "token",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            BlockHeight,
-            TxHash,
-            Tx,
+            Token,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5237,9 +6227,7 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
-                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
-                            "tx" => Ok(GeneratedField::Tx),
+                            "token" => Ok(GeneratedField::Token),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5249,56 +6237,36 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionsResponse;
+            type Value = ViewAuthResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.TransactionsResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.ViewAuthResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionsResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ViewAuthResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut block_height__ = None;
-                let mut tx_hash__ = None;
-                let mut tx__ = None;
+                let mut token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::BlockHeight => {
-                            if block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("blockHeight"));
-                            }
-                            block_height__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::TxHash => {
-                            if tx_hash__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("txHash"));
-                            }
-                            tx_hash__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Tx => {
-                            if tx__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tx"));
+                        GeneratedField::Token => {
+                            if token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("token"));
                             }
-                            tx__ = map.next_value()?;
+                            token__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(TransactionsResponse {
-                    block_height: block_height__.unwrap_or_default(),
-                    tx_hash: tx_hash__.unwrap_or_default(),
-                    tx: tx__,
+                Ok(ViewAuthResponse {
+                    token: token__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.ViewAuthResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ViewAuthRequest {
+impl serde::Serialize for ViewAuthToken {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5306,29 +6274,29 @@ impl serde::Serialize for ViewAuthRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.fvk.is_some() {
+        if !self.inner.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.ViewAuthRequest", len)?;
-        if let Some(v) = self.fvk.as_ref() {
-            struct_ser.serialize_field("fvk", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.ViewAuthToken", len)?;
+        if !self.inner.is_empty() {
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ViewAuthRequest {
+impl<'de> serde::Deserialize<'de> for ViewAuthToken {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "fvk",
+            "inner",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Fvk,
+            Inner,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5350,7 +6318,7 @@ impl<'de> serde::Deserialize<'de> for ViewAuthRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fvk" => Ok(GeneratedField::Fvk),
+                            "inner" => Ok(GeneratedField::Inner),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5360,36 +6328,38 @@ impl<'de> serde::Deserialize<'de> for ViewAuthRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ViewAuthRequest;
+            type Value = ViewAuthToken;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.ViewAuthRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.ViewAuthToken")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ViewAuthRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ViewAuthToken, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut fvk__ = None;
+                let mut inner__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Fvk => {
-                            if fvk__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fvk"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            fvk__ = map.next_value()?;
+                            inner__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(ViewAuthRequest {
-                    fvk: fvk__,
+                Ok(ViewAuthToken {
+                    inner: inner__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.ViewAuthRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.ViewAuthToken", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ViewAuthResponse {
+impl serde::Serialize for VotingPowerRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5397,28 +6367,55 @@ impl serde::Serialize for ViewAuthResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         if self.token.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.ViewAuthResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.VotingPowerRequest", len)?;
+        if self.proposal_id != 0 {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         if let Some(v) = self.token.as_ref() {
             struct_ser.serialize_field("token", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ViewAuthResponse {
+impl<'de> serde::Deserialize<'de> for VotingPowerRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "address_index",
+            "addressIndex",
+            "account_group_id",
+            "accountGroupId",
             "token",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            ProposalId,
+            AddressIndex,
+            AccountGroupId,
             Token,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5441,6 +6438,9 @@ impl<'de> serde::Deserialize<'de> for ViewAuthResponse {
                         E: serde::de::Error,
                     {
                         match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             "token" => Ok(GeneratedField::Token),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -5451,19 +6451,42 @@ impl<'de> serde::Deserialize<'de> for ViewAuthResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ViewAuthResponse;
+            type Value = VotingPowerRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.ViewAuthResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.VotingPowerRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ViewAuthResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<VotingPowerRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut proposal_id__ = None;
+                let mut address_index__ = None;
+                let mut account_group_id__ = None;
                 let mut token__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map.next_value()?;
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                         GeneratedField::Token => {
                             if token__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("token"));
@@ -5472,15 +6495,18 @@ impl<'de> serde::Deserialize<'de> for ViewAuthResponse {
                         }
                     }
                 }
-                Ok(ViewAuthResponse {
+                Ok(VotingPowerRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    address_index: address_index__,
+                    account_group_id: account_group_id__,
                     token: token__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.ViewAuthResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.VotingPowerRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ViewAuthToken {
+impl serde::Serialize for VotingPowerResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5488,29 +6514,56 @@ impl serde::Serialize for ViewAuthToken {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.ViewAuthToken", len)?;
-        if !self.inner.is_empty() {
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.voting_power.is_some() {
+            len += 1;
+        }
+        if self.voted {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.VotingPowerResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.voting_power.as_ref() {
+            struct_ser.serialize_field("votingPower", v)?;
+        }
+        if self.voted {
+            struct_ser.serialize_field("voted", &self.voted)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ViewAuthToken {
+impl<'de> serde::Deserialize<'de> for VotingPowerResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
+            "note_record",
+            "noteRecord",
+            "identity_key",
+            "identityKey",
+            "voting_power",
+            "votingPower",
+            "voted",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
+            NoteRecord,
+            IdentityKey,
+            VotingPower,
+            Voted,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5532,7 +6585,10 @@ impl<'de> serde::Deserialize<'de> for ViewAuthToken {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
+                            "voted" => Ok(GeneratedField::Voted),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5542,35 +6598,57 @@ impl<'de> serde::Deserialize<'de> for ViewAuthToken {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ViewAuthToken;
+            type Value = VotingPowerResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.ViewAuthToken")
+                formatter.write_str("struct penumbra.view.v1alpha1.VotingPowerResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ViewAuthToken, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<VotingPowerResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
+                let mut note_record__ = None;
+                let mut identity_key__ = None;
+                let mut voting_power__ = None;
+                let mut voted__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            inner__ = 
-                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            note_record__ = map.next_value()?;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                            }
+                            voting_power__ = map.next_value()?;
+                        }
+                        GeneratedField::Voted => {
+                            if voted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voted"));
+                            }
+                            voted__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(ViewAuthToken {
-                    inner: inner__.unwrap_or_default(),
+                Ok(VotingPowerResponse {
+                    note_record: note_record__,
+                    identity_key: identity_key__,
+                    voting_power: voting_power__,
+                    voted: voted__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.ViewAuthToken", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.VotingPowerResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for WitnessAndBuildRequest {
@@ -5694,10 +6772,16 @@ impl serde::Serialize for WitnessAndBuildResponse {
         if self.transaction.is_some() {
             len += 1;
         }
+        if self.progress.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WitnessAndBuildResponse", len)?;
         if let Some(v) = self.transaction.as_ref() {
             struct_ser.serialize_field("transaction", v)?;
         }
+        if let Some(v) = self.progress.as_ref() {
+            struct_ser.serialize_field("progress", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5709,11 +6793,13 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildResponse {
     {
         const FIELDS: &[&str] = &[
             "transaction",
+            "progress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Transaction,
+            Progress,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5736,6 +6822,7 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildResponse {
                     {
                         match value {
                             "transaction" => Ok(GeneratedField::Transaction),
+                            "progress" => Ok(GeneratedField::Progress),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5756,6 +6843,7 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut transaction__ = None;
+                let mut progress__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Transaction => {
@@ -5764,10 +6852,17 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildResponse {
                             }
                             transaction__ = map.next_value()?;
                         }
+                        GeneratedField::Progress => {
+                            if progress__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("progress"));
+                            }
+                            progress__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(WitnessAndBuildResponse {
                     transaction: transaction__,
+                    progress: progress__,
                 })
             }
         }
@@ -1,40 +1,267 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{
     env, fs,
-    io::{BufWriter, Result},
+    io::{BufReader, BufWriter, Write},
 };
 
-use ark_groth16::{ProvingKey, VerifyingKey};
+use anyhow::{Context, Result};
+use ark_groth16::{PreparedVerifyingKey, ProvingKey, VerifyingKey};
 use ark_serialize::CanonicalSerialize;
+use clap::Parser;
 use decaf377::Bls12_377;
-use penumbra_crypto::proofs::groth16::{OutputCircuit, ParameterSetup, SpendCircuit, SwapCircuit};
+use penumbra_crypto::proofs::groth16::{
+    DelegatorVoteCircuit, OutputCircuit, ParameterSetup, Phase1Parameters, Phase2Parameters,
+    SpendCircuit, SwapCircuit, SwapClaimCircuit, UndelegateClaimCircuit, VerifyingKeyExt,
+};
+use rand_core::OsRng;
+
+/// The circuits to generate parameters for, by the name used in their parameter files.
+const CIRCUITS: &[&str] = &[
+    "spend",
+    "output",
+    "swap",
+    "swapclaim",
+    "delegatorvote",
+    "undelegateclaim",
+];
+// NOTE: New proofs go here, and in `test_parameters` and `initial_parameters` below.
+
+#[derive(Debug, Parser)]
+#[clap(about = "Generate the proving and verification keys for Penumbra's circuits.")]
+enum Command {
+    /// Generate parameters for testing, sampled locally by a single party.
+    ///
+    /// These parameters are only suitable for testnets, since whoever runs this knows the toxic
+    /// waste and can forge proofs.
+    TestParameters,
+    /// Start a phase 2 ceremony, deriving the initial parameters for each circuit from the output
+    /// of a phase 1 ceremony and writing them to a directory.
+    Init {
+        /// The powers of tau published by the phase 1 ceremony.
+        #[clap(long)]
+        phase1: PathBuf,
+        /// The directory to write the initial parameters to.
+        #[clap(long)]
+        output: PathBuf,
+    },
+    /// Contribute to the ceremony, reading the latest parameters from one directory and writing
+    /// the updated parameters to another.
+    ///
+    /// The latest parameters are first verified against the initial parameters derived from the
+    /// phase 1 ceremony, so that nobody contributes to a broken or forged transcript.
+    Contribute {
+        /// The powers of tau published by the phase 1 ceremony.
+        #[clap(long)]
+        phase1: PathBuf,
+        /// The directory containing the latest parameters.
+        #[clap(long)]
+        input: PathBuf,
+        /// The directory to write the updated parameters to.
+        #[clap(long)]
+        output: PathBuf,
+    },
+    /// Verify every contribution made between two sets of parameters.
+    Verify {
+        /// The directory containing the earlier parameters, such as the initial parameters.
+        #[clap(long)]
+        previous: PathBuf,
+        /// The directory containing the later parameters.
+        #[clap(long)]
+        current: PathBuf,
+    },
+    /// Verify the ceremony transcript from the initial parameters derived from the phase 1
+    /// ceremony, then write out the final proving and verification keys along with a transcript
+    /// file for each circuit.
+    Export {
+        /// The powers of tau published by the phase 1 ceremony.
+        #[clap(long)]
+        phase1: PathBuf,
+        /// The directory containing the final parameters.
+        #[clap(long)]
+        current: PathBuf,
+        /// The directory to write the keys to [default: proof-params/src/gen]
+        #[clap(long)]
+        target: Option<PathBuf>,
+    },
+}
 
 fn main() -> Result<()> {
+    match Command::parse() {
+        Command::TestParameters => {
+            let target_dir = default_target_dir();
+            println!("{}", target_dir.display());
+
+            // Generate the parameters for the current proofs and serialize them
+            // to files in the target directory.
+            for name in CIRCUITS {
+                let (pk, vk) = test_parameters(name);
+                write_params(&target_dir, name, &pk, &vk)?;
+            }
+        }
+        Command::Init { phase1, output } => {
+            let phase1 = read_phase1(&phase1)?;
+            fs::create_dir_all(&output)?;
+            for name in CIRCUITS {
+                let params = initial_parameters(name, &phase1)?;
+                write_phase2(&output, name, &params)?;
+                println!(
+                    "{}: initial parameters {}",
+                    name,
+                    hex::encode(params.initial_hash())
+                );
+            }
+        }
+        Command::Contribute {
+            phase1,
+            input,
+            output,
+        } => {
+            let phase1 = read_phase1(&phase1)?;
+            fs::create_dir_all(&output)?;
+            for name in CIRCUITS {
+                let mut params = read_phase2(&input, name)?;
+                params
+                    .verify(&initial_parameters(name, &phase1)?)
+                    .with_context(|| format!("{} parameters did not verify", name))?;
+                let hash = params.contribute(&mut OsRng)?;
+                write_phase2(&output, name, &params)?;
+                println!("{}: contribution {}", name, hex::encode(hash));
+            }
+        }
+        Command::Verify { previous, current } => {
+            for name in CIRCUITS {
+                let hashes = read_phase2(&current, name)?
+                    .verify(&read_phase2(&previous, name)?)
+                    .with_context(|| format!("{} parameters did not verify", name))?;
+                for hash in hashes {
+                    println!("{}: contribution {}", name, hex::encode(hash));
+                }
+            }
+        }
+        Command::Export {
+            phase1,
+            current,
+            target,
+        } => {
+            let target_dir = target.unwrap_or_else(default_target_dir);
+            let phase1 = read_phase1(&phase1)?;
+            for name in CIRCUITS {
+                let initial = initial_parameters(name, &phase1)?;
+                let params = read_phase2(&current, name)?;
+                let hashes = params
+                    .verify(&initial)
+                    .with_context(|| format!("{} parameters did not verify", name))?;
+                anyhow::ensure!(!hashes.is_empty(), "{} has no contributions", name);
+
+                write_params(
+                    &target_dir,
+                    name,
+                    params.proving_key(),
+                    params.verifying_key(),
+                )?;
+                let transcript = write_transcript(&target_dir, name, &params, &hashes)?;
+                print!("{}", transcript);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn default_target_dir() -> PathBuf {
     let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    println!("{}", root.display());
-    let target_dir = root
-        .join("..")
+    root.join("..")
         .join("..")
         .join("proof-params")
         .join("src")
-        .join("gen");
-    println!("{}", target_dir.display());
-
-    // Generate the parameters for the current proofs and serialize them
-    // to files in the target directory.
-    let (spend_pk, spend_vk) = SpendCircuit::generate_test_parameters();
-    write_params(&target_dir, "spend", &spend_pk, &spend_vk)?;
-    let (output_pk, output_vk) = OutputCircuit::generate_test_parameters();
-    write_params(&target_dir, "output", &output_pk, &output_vk)?;
-    let (swap_pk, swap_vk) = SwapCircuit::generate_test_parameters();
-    write_params(&target_dir, "swap", &swap_pk, &swap_vk)?;
-    // NOTE: New proofs go here following the approach above.
+        .join("gen")
+}
 
+fn test_parameters(name: &str) -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+    match name {
+        "spend" => SpendCircuit::generate_test_parameters(),
+        "output" => OutputCircuit::generate_test_parameters(),
+        "swap" => SwapCircuit::generate_test_parameters(),
+        "swapclaim" => SwapClaimCircuit::generate_test_parameters(),
+        "delegatorvote" => DelegatorVoteCircuit::generate_test_parameters(),
+        "undelegateclaim" => UndelegateClaimCircuit::generate_test_parameters(),
+        _ => unreachable!("unknown circuit {}", name),
+    }
+}
+
+/// Derive the initial parameters for a circuit from the output of the phase 1 ceremony, so that
+/// nobody knows the toxic waste behind them.
+fn initial_parameters(name: &str, phase1: &Phase1Parameters) -> Result<Phase2Parameters> {
+    let pk = match name {
+        "spend" => phase1.circuit_parameters(SpendCircuit::example_circuit()),
+        "output" => phase1.circuit_parameters(OutputCircuit::example_circuit()),
+        "swap" => phase1.circuit_parameters(SwapCircuit::example_circuit()),
+        "swapclaim" => phase1.circuit_parameters(SwapClaimCircuit::example_circuit()),
+        "delegatorvote" => phase1.circuit_parameters(DelegatorVoteCircuit::example_circuit()),
+        "undelegateclaim" => phase1.circuit_parameters(UndelegateClaimCircuit::example_circuit()),
+        _ => unreachable!("unknown circuit {}", name),
+    }
+    .with_context(|| format!("could not derive {} parameters", name))?;
+    Phase2Parameters::new(pk)
+}
+
+/// Read the powers of tau published by the phase 1 ceremony, checking that they are consistent.
+fn read_phase1(location: &Path) -> Result<Phase1Parameters> {
+    let file = fs::File::open(location)
+        .with_context(|| format!("could not open {}", location.display()))?;
+    let phase1 = Phase1Parameters::read(BufReader::new(file))
+        .with_context(|| format!("could not read {}", location.display()))?;
+    phase1
+        .verify()
+        .with_context(|| format!("{} is not a valid phase 1 transcript", location.display()))?;
+    Ok(phase1)
+}
+
+fn read_phase2(dir: &Path, name: &str) -> Result<Phase2Parameters> {
+    let location = dir.join(format!("{}.phase2", name));
+    let file = fs::File::open(&location)
+        .with_context(|| format!("could not open {}", location.display()))?;
+    Phase2Parameters::read(BufReader::new(file))
+        .with_context(|| format!("could not read {}", location.display()))
+}
+
+fn write_phase2(dir: &Path, name: &str, params: &Phase2Parameters) -> Result<()> {
+    let location = dir.join(format!("{}.phase2", name));
+    let mut writer = BufWriter::new(fs::File::create(&location)?);
+    params.write(&mut writer)?;
+    writer.flush()?;
     Ok(())
 }
 
+/// Write a record of the ceremony for a circuit, so that anyone can check the contributions made
+/// and the `debug_id` of the resulting verification key.
+fn write_transcript(
+    target_dir: &Path,
+    name: &str,
+    params: &Phase2Parameters,
+    hashes: &[[u8; 64]],
+) -> Result<String> {
+    let pvk: PreparedVerifyingKey<Bls12_377> = params.verifying_key().clone().into();
+
+    let mut transcript = format!(
+        "circuit: {}\ninitial parameters: {}\n",
+        name,
+        hex::encode(params.initial_hash())
+    );
+    for (index, hash) in hashes.iter().enumerate() {
+        transcript.push_str(&format!("contribution {}: {}\n", index, hex::encode(hash)));
+    }
+    transcript.push_str(&format!("verification key: {}\n", pvk.debug_id()));
+
+    fs::write(
+        target_dir.join(format!("{}_transcript.txt", name)),
+        &transcript,
+    )?;
+    Ok(transcript)
+}
+
 fn write_params(
-    target_dir: &PathBuf,
+    target_dir: &Path,
     name: &str,
     pk: &ProvingKey<Bls12_377>,
     vk: &VerifyingKey<Bls12_377>,
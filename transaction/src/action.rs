@@ -17,18 +17,21 @@ mod position;
 mod proposal_deposit_claim;
 mod proposal_submit;
 mod proposal_withdraw;
+mod redelegate;
+mod redelegate_claim;
 pub mod spend;
 pub mod swap;
 pub mod swap_claim;
 mod undelegate;
 mod undelegate_claim;
+mod unjail;
 mod validator_vote;
 
 use crate::{ActionView, TransactionPerspective};
 
 pub use self::ibc::Ics20Withdrawal;
 pub use crate::proposal::{Proposal, ProposalKind, ProposalPayload};
-pub use crate::vote::Vote;
+pub use crate::vote::{Vote, VoteWeights};
 pub use dao_deposit::DaoDeposit;
 pub use dao_output::DaoOutput;
 pub use dao_spend::DaoSpend;
@@ -39,12 +42,15 @@ pub use position::{PositionClose, PositionOpen, PositionRewardClaim, PositionWit
 pub use proposal_deposit_claim::ProposalDepositClaim;
 pub use proposal_submit::ProposalSubmit;
 pub use proposal_withdraw::ProposalWithdraw;
+pub use redelegate::Redelegate;
+pub use redelegate_claim::RedelegateClaim;
 pub use spend::Spend;
 pub use swap::Swap;
 pub use swap_claim::SwapClaim;
 pub use undelegate::Undelegate;
 pub use undelegate_claim::{UndelegateClaim, UndelegateClaimBody};
-pub use validator_vote::{ValidatorVote, ValidatorVoteBody};
+pub use unjail::{Unjail, UnjailBody};
+pub use validator_vote::{ValidatorVote, ValidatorVoteBody, ValidatorVoteReason};
 
 /// Common behavior between Penumbra actions.
 pub trait IsAction {
@@ -76,6 +82,9 @@ pub enum Action {
     Delegate(Delegate),
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaim),
+    Redelegate(Redelegate),
+    RedelegateClaim(RedelegateClaim),
+    Unjail(Unjail),
 
     Ics20Withdrawal(Ics20Withdrawal),
 
@@ -118,6 +127,9 @@ impl Action {
             Action::Delegate(_) => tracing::info_span!("Delegate", ?idx),
             Action::Undelegate(_) => tracing::info_span!("Undelegate", ?idx),
             Action::UndelegateClaim(_) => tracing::info_span!("UndelegateClaim", ?idx),
+            Action::Redelegate(_) => tracing::info_span!("Redelegate", ?idx),
+            Action::RedelegateClaim(_) => tracing::info_span!("RedelegateClaim", ?idx),
+            Action::Unjail(_) => tracing::info_span!("Unjail", ?idx),
             Action::Ics20Withdrawal(_) => tracing::info_span!("Ics20Withdrawal", ?idx),
             Action::DaoDeposit(_) => tracing::info_span!("DaoDeposit", ?idx),
             Action::DaoSpend(_) => tracing::info_span!("DaoSpend", ?idx),
@@ -134,6 +146,9 @@ impl IsAction for Action {
             Action::Delegate(delegate) => delegate.balance_commitment(),
             Action::Undelegate(undelegate) => undelegate.balance_commitment(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.balance_commitment(),
+            Action::Redelegate(redelegate) => redelegate.balance_commitment(),
+            Action::RedelegateClaim(claim) => claim.balance_commitment(),
+            Action::Unjail(unjail) => unjail.balance_commitment(),
             Action::Swap(swap) => swap.balance_commitment(),
             Action::SwapClaim(swap_claim) => swap_claim.balance_commitment(),
             Action::ProposalSubmit(submit) => submit.balance_commitment(),
@@ -165,6 +180,9 @@ impl IsAction for Action {
             Action::Delegate(x) => x.view_from_perspective(txp),
             Action::Undelegate(x) => x.view_from_perspective(txp),
             Action::UndelegateClaim(x) => x.view_from_perspective(txp),
+            Action::Redelegate(x) => x.view_from_perspective(txp),
+            Action::RedelegateClaim(x) => x.view_from_perspective(txp),
+            Action::Unjail(x) => x.view_from_perspective(txp),
             Action::ProposalSubmit(x) => x.view_from_perspective(txp),
             Action::ProposalWithdraw(x) => x.view_from_perspective(txp),
             Action::DelegatorVote(x) => x.view_from_perspective(txp),
@@ -207,6 +225,15 @@ impl From<Action> for pb::Action {
             Action::UndelegateClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::UndelegateClaim(inner.into())),
             },
+            Action::Redelegate(inner) => pb::Action {
+                action: Some(pb::action::Action::Redelegate(inner.into())),
+            },
+            Action::RedelegateClaim(inner) => pb::Action {
+                action: Some(pb::action::Action::RedelegateClaim(inner.into())),
+            },
+            Action::Unjail(inner) => pb::Action {
+                action: Some(pb::action::Action::Unjail(inner.into())),
+            },
             Action::ValidatorDefinition(inner) => pb::Action {
                 action: Some(pb::action::Action::ValidatorDefinition(inner)),
             },
@@ -276,6 +303,11 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::UndelegateClaim(inner) => {
                 Ok(Action::UndelegateClaim(inner.try_into()?))
             }
+            pb::action::Action::Redelegate(inner) => Ok(Action::Redelegate(inner.try_into()?)),
+            pb::action::Action::RedelegateClaim(inner) => {
+                Ok(Action::RedelegateClaim(inner.try_into()?))
+            }
+            pb::action::Action::Unjail(inner) => Ok(Action::Unjail(inner.try_into()?)),
             pb::action::Action::ValidatorDefinition(inner) => {
                 Ok(Action::ValidatorDefinition(inner))
             }
@@ -3,7 +3,7 @@ use ark_ff::Zero;
 use decaf377::Fr;
 use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
 use penumbra_crypto::{
-    proofs::transparent::DelegatorVoteProof, Amount, Nullifier, Value, VotingReceiptToken,
+    proofs::groth16::DelegatorVoteProof, Amount, Balance, Nullifier, Value, VotingReceiptToken,
 };
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
@@ -22,11 +22,7 @@ pub struct DelegatorVote {
 
 impl IsAction for DelegatorVote {
     fn balance_commitment(&self) -> penumbra_crypto::balance::Commitment {
-        Value {
-            asset_id: VotingReceiptToken::new(self.body.proposal).id(),
-            amount: self.body.unbonded_amount,
-        }
-        .commit(Fr::zero())
+        self.balance().commit(Fr::zero())
     }
 
     fn view_from_perspective(&self, txp: &TransactionPerspective) -> ActionView {
@@ -44,6 +40,23 @@ impl IsAction for DelegatorVote {
     }
 }
 
+impl DelegatorVote {
+    /// Return the balance of the vote, which mints voting receipt tokens for the
+    /// voting power used, unless the vote replaces an earlier one that already
+    /// minted them.
+    pub fn balance(&self) -> Balance {
+        if self.body.replaces_previous {
+            return Balance::default();
+        }
+
+        Value {
+            asset_id: VotingReceiptToken::new(self.body.proposal).id(),
+            amount: self.body.unbonded_amount,
+        }
+        .into()
+    }
+}
+
 /// The body of a delegator vote.
 #[derive(Debug, Clone)]
 pub struct DelegatorVoteBody {
@@ -61,6 +74,8 @@ pub struct DelegatorVoteBody {
     pub nullifier: Nullifier,
     /// The randomized validating key for the spend authorization signature.
     pub rk: VerificationKey<SpendAuth>,
+    /// Whether this vote replaces an earlier vote on the proposal with the same nullifier.
+    pub replaces_previous: bool,
 }
 
 impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
@@ -73,6 +88,7 @@ impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
             unbonded_amount: Some(value.unbonded_amount.into()),
             nullifier: value.nullifier.to_bytes().into(),
             rk: value.rk.to_bytes().into(),
+            replaces_previous: value.replaces_previous,
         }
     }
 }
@@ -111,6 +127,7 @@ impl TryFrom<pb::DelegatorVoteBody> for DelegatorVoteBody {
                     .try_into()
                     .context("invalid  rk in `DelegatorVote`")?
             },
+            replaces_previous: msg.replaces_previous,
         })
     }
 }
@@ -124,7 +141,7 @@ impl From<DelegatorVote> for pb::DelegatorVote {
         pb::DelegatorVote {
             body: Some(value.body.into()),
             auth_sig: Some(value.auth_sig.into()),
-            proof: value.proof.into(),
+            proof: Some(value.proof.into()),
         }
     }
 }
@@ -142,7 +159,11 @@ impl TryFrom<pb::DelegatorVote> for DelegatorVote {
                 .auth_sig
                 .ok_or_else(|| anyhow::anyhow!("missing auth sig in `DelegatorVote`"))?
                 .try_into()?,
-            proof: msg.proof[..].try_into()?,
+            proof: msg
+                .proof
+                .ok_or_else(|| anyhow::anyhow!("missing proof in `DelegatorVote`"))?
+                .try_into()
+                .context("delegator vote proof malformed")?,
         })
     }
 }
@@ -0,0 +1,137 @@
+use ark_ff::Zero;
+use penumbra_crypto::{
+    asset::Amount,
+    stake::{DelegationToken, IdentityKey, RedelegationToken},
+    Balance, Fr, Value,
+};
+use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{ActionView, IsAction, TransactionPerspective};
+
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting for the unbonding period.
+///
+/// The redelegated stake remains liable for slashing of the source validator
+/// until `end_epoch_index`, when unbonding from it would have completed, so
+/// this action produces [`RedelegationToken`]s, which are exchanged for the
+/// destination validator's delegation tokens by a
+/// [`RedelegateClaim`](crate::action::RedelegateClaim).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Redelegate", into = "pb::Redelegate")]
+pub struct Redelegate {
+    /// The identity key of the validator to redelegate from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator to redelegate to.
+    pub to_validator_identity: IdentityKey,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    pub epoch_index: u64,
+    /// The index of the epoch in which unbonding from the source validator
+    /// would have completed.
+    pub end_epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    pub from_delegation_amount: Amount,
+    /// The amount of stake moved between the delegation pools.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    pub unbonded_amount: Amount,
+    /// The amount of redelegation tokens produced by this action, in units of
+    /// the destination validator's delegation tokens.
+    ///
+    /// This is implied by the destination validator's exchange rate in the specified
+    /// epoch (and should be checked in transaction validation!).
+    pub to_delegation_amount: Amount,
+}
+
+impl IsAction for Redelegate {
+    fn balance_commitment(&self) -> penumbra_crypto::balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::Redelegate(self.to_owned())
+    }
+}
+
+impl Redelegate {
+    /// Return the balance after consuming the source validator's delegation tokens,
+    /// and producing redelegation tokens.
+    pub fn balance(&self) -> Balance {
+        let from = Balance::from(Value {
+            amount: self.from_delegation_amount,
+            asset_id: self.from_delegation_token().id(),
+        });
+
+        let to = Balance::from(Value {
+            amount: self.to_delegation_amount,
+            asset_id: self.redelegation_token().id(),
+        });
+
+        // We consume the source delegation tokens and produce the redelegation tokens.
+        to - from
+    }
+
+    pub fn from_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.from_validator_identity.clone())
+    }
+
+    pub fn redelegation_token(&self) -> RedelegationToken {
+        RedelegationToken::new(
+            self.from_validator_identity,
+            self.to_validator_identity,
+            self.epoch_index,
+            self.end_epoch_index,
+        )
+    }
+}
+
+impl DomainType for Redelegate {
+    type Proto = pb::Redelegate;
+}
+
+impl From<Redelegate> for pb::Redelegate {
+    fn from(d: Redelegate) -> Self {
+        pb::Redelegate {
+            from_validator_identity: Some(d.from_validator_identity.into()),
+            to_validator_identity: Some(d.to_validator_identity.into()),
+            epoch_index: d.epoch_index,
+            end_epoch_index: d.end_epoch_index,
+            from_delegation_amount: Some(d.from_delegation_amount.into()),
+            unbonded_amount: Some(d.unbonded_amount.into()),
+            to_delegation_amount: Some(d.to_delegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Redelegate> for Redelegate {
+    type Error = anyhow::Error;
+    fn try_from(d: pb::Redelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: d
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: d
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            epoch_index: d.epoch_index,
+            end_epoch_index: d.end_epoch_index,
+            from_delegation_amount: d
+                .from_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing source delegation amount"))?
+                .try_into()?,
+            unbonded_amount: d
+                .unbonded_amount
+                .ok_or_else(|| anyhow::anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            to_delegation_amount: d
+                .to_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing destination delegation amount"))?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use ark_ff::Zero;
+use penumbra_crypto::{
+    asset::Amount,
+    stake::{DelegationToken, IdentityKey, Penalty, RedelegationToken},
+    Balance, Fr, Value,
+};
+use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{ActionView, IsAction, TransactionPerspective};
+
+/// A transaction action finishing a redelegation, converting redelegation
+/// tokens to the destination validator's delegation tokens, less any penalty
+/// for slashing of the source validator while the stake was liable for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::RedelegateClaim", into = "pb::RedelegateClaim")]
+pub struct RedelegateClaim {
+    /// The identity key of the validator the stake was redelegated from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator the stake was redelegated to.
+    pub to_validator_identity: IdentityKey,
+    /// The epoch in which the redelegation was performed.
+    pub start_epoch_index: u64,
+    /// The epoch in which the redelegated stake stopped being liable for
+    /// slashing of the source validator.
+    pub end_epoch_index: u64,
+    /// The penalty applied to the redelegated stake over `[start_epoch_index, end_epoch_index)`.
+    pub penalty: Penalty,
+    /// The amount of redelegation tokens to claim.
+    pub redelegation_amount: Amount,
+}
+
+impl IsAction for RedelegateClaim {
+    fn balance_commitment(&self) -> penumbra_crypto::balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::RedelegateClaim(self.to_owned())
+    }
+}
+
+impl RedelegateClaim {
+    /// Return the balance after consuming the redelegation tokens, and
+    /// producing the destination validator's delegation tokens, less the penalty.
+    pub fn balance(&self) -> Balance {
+        let redelegation = Balance::from(Value {
+            amount: self.redelegation_amount,
+            asset_id: self.redelegation_token().id(),
+        });
+
+        let delegation = Balance::from(Value {
+            amount: self.delegation_amount(),
+            asset_id: self.to_delegation_token().id(),
+        });
+
+        // We consume the redelegation tokens and produce the destination delegation tokens.
+        delegation - redelegation
+    }
+
+    /// The amount of the destination validator's delegation tokens produced by this claim.
+    pub fn delegation_amount(&self) -> Amount {
+        self.penalty.apply_to(self.redelegation_amount)
+    }
+
+    pub fn redelegation_token(&self) -> RedelegationToken {
+        RedelegationToken::new(
+            self.from_validator_identity,
+            self.to_validator_identity,
+            self.start_epoch_index,
+            self.end_epoch_index,
+        )
+    }
+
+    pub fn to_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.to_validator_identity)
+    }
+}
+
+impl DomainType for RedelegateClaim {
+    type Proto = pb::RedelegateClaim;
+}
+
+impl From<RedelegateClaim> for pb::RedelegateClaim {
+    fn from(d: RedelegateClaim) -> Self {
+        pb::RedelegateClaim {
+            from_validator_identity: Some(d.from_validator_identity.into()),
+            to_validator_identity: Some(d.to_validator_identity.into()),
+            start_epoch_index: d.start_epoch_index,
+            end_epoch_index: d.end_epoch_index,
+            penalty: Some(d.penalty.into()),
+            redelegation_amount: Some(d.redelegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::RedelegateClaim> for RedelegateClaim {
+    type Error = anyhow::Error;
+    fn try_from(d: pb::RedelegateClaim) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: d
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: d
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            start_epoch_index: d.start_epoch_index,
+            end_epoch_index: d.end_epoch_index,
+            penalty: d
+                .penalty
+                .ok_or_else(|| anyhow::anyhow!("missing penalty"))?
+                .try_into()?,
+            redelegation_amount: d
+                .redelegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing redelegation amount"))?
+                .try_into()?,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use penumbra_crypto::{
     balance,
-    proofs::transparent::UndelegateClaimProof,
+    proofs::groth16::UndelegateClaimProof,
     stake::{IdentityKey, Penalty},
 };
 use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
@@ -86,7 +86,7 @@ impl From<UndelegateClaim> for pb::UndelegateClaim {
     fn from(d: UndelegateClaim) -> Self {
         pb::UndelegateClaim {
             body: Some(d.body.into()),
-            proof: d.proof.encode_to_vec(),
+            proof: Some(d.proof.into()),
         }
     }
 }
@@ -99,7 +99,10 @@ impl TryFrom<pb::UndelegateClaim> for UndelegateClaim {
                 .body
                 .ok_or_else(|| anyhow::anyhow!("missing body"))?
                 .try_into()?,
-            proof: UndelegateClaimProof::decode(d.proof.as_slice())?,
+            proof: d
+                .proof
+                .ok_or_else(|| anyhow::anyhow!("missing undelegate claim proof"))?
+                .try_into()?,
         })
     }
 }
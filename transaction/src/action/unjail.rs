@@ -0,0 +1,97 @@
+use decaf377_rdsa::{Signature, SpendAuth};
+use penumbra_crypto::stake::IdentityKey;
+use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{ActionView, IsAction, TransactionPerspective};
+
+/// A transaction action releasing a validator from jail, once its minimum jail
+/// duration has elapsed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::Unjail", into = "pb::Unjail")]
+pub struct Unjail {
+    /// The body of the unjailing.
+    pub body: UnjailBody,
+    /// The signature authorizing the unjailing (signed with the identity key over the body).
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl IsAction for Unjail {
+    fn balance_commitment(&self) -> penumbra_crypto::balance::Commitment {
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::Unjail(self.to_owned())
+    }
+}
+
+impl DomainType for Unjail {
+    type Proto = pb::Unjail;
+}
+
+impl From<Unjail> for pb::Unjail {
+    fn from(msg: Unjail) -> Self {
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Unjail> for Unjail {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Unjail) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing unjail body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing unjail auth sig"))?
+                .try_into()?,
+        })
+    }
+}
+
+/// A request by a validator to be released from jail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::UnjailBody", into = "pb::UnjailBody")]
+pub struct UnjailBody {
+    /// The identity of the validator to release.
+    pub identity_key: IdentityKey,
+    /// The height at which the validator was jailed.
+    ///
+    /// This binds the signature to a single jailing, so that it can't be reused
+    /// to release the validator from a later one.
+    pub jailed_at_height: u64,
+}
+
+impl DomainType for UnjailBody {
+    type Proto = pb::UnjailBody;
+}
+
+impl From<UnjailBody> for pb::UnjailBody {
+    fn from(value: UnjailBody) -> Self {
+        pb::UnjailBody {
+            identity_key: Some(value.identity_key.into()),
+            jailed_at_height: value.jailed_at_height,
+        }
+    }
+}
+
+impl TryFrom<pb::UnjailBody> for UnjailBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::UnjailBody) -> Result<Self, Self::Error> {
+        Ok(UnjailBody {
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key in unjail body"))?
+                .try_into()?,
+            jailed_at_height: msg.jailed_at_height,
+        })
+    }
+}
@@ -63,6 +63,8 @@ pub struct ValidatorVoteBody {
     pub identity_key: IdentityKey,
     /// The governance key for the validator who is voting.
     pub governance_key: GovernanceKey,
+    /// A justification of the vote, which may be empty.
+    pub reason: ValidatorVoteReason,
 }
 
 impl From<ValidatorVoteBody> for pb::ValidatorVoteBody {
@@ -72,6 +74,7 @@ impl From<ValidatorVoteBody> for pb::ValidatorVoteBody {
             vote: Some(value.vote.into()),
             identity_key: Some(value.identity_key.into()),
             governance_key: Some(value.governance_key.into()),
+            reason: Some(value.reason.into()),
         }
     }
 }
@@ -96,6 +99,8 @@ impl TryFrom<pb::ValidatorVoteBody> for ValidatorVoteBody {
                     anyhow::anyhow!("missing validator governance key in `ValidatorVote`")
                 })?
                 .try_into()?,
+            // Votes without a reason are equivalent to votes with an empty reason.
+            reason: msg.reason.map(Into::into).unwrap_or_default(),
         })
     }
 }
@@ -103,3 +108,29 @@ impl TryFrom<pb::ValidatorVoteBody> for ValidatorVoteBody {
 impl DomainType for ValidatorVoteBody {
     type Proto = pb::ValidatorVoteBody;
 }
+
+/// A validator's justification of their vote, published in the clear alongside it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "pb::ValidatorVoteReason", into = "pb::ValidatorVoteReason")]
+pub struct ValidatorVoteReason(pub String);
+
+impl ValidatorVoteReason {
+    /// The maximum length of a vote reason, in bytes.
+    pub const MAX_LEN: usize = 1024;
+}
+
+impl From<ValidatorVoteReason> for pb::ValidatorVoteReason {
+    fn from(value: ValidatorVoteReason) -> Self {
+        pb::ValidatorVoteReason { reason: value.0 }
+    }
+}
+
+impl From<pb::ValidatorVoteReason> for ValidatorVoteReason {
+    fn from(msg: pb::ValidatorVoteReason) -> Self {
+        ValidatorVoteReason(msg.reason)
+    }
+}
+
+impl DomainType for ValidatorVoteReason {
+    type Proto = pb::ValidatorVoteReason;
+}
@@ -11,7 +11,8 @@ use crate::{
         output, spend, swap, swap_claim, DaoDeposit, DaoOutput, DaoSpend, Delegate, DelegatorVote,
         DelegatorVoteBody, Ics20Withdrawal, PositionClose, PositionOpen, PositionRewardClaim,
         PositionWithdraw, Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-        Undelegate, UndelegateClaimBody, ValidatorVote, ValidatorVoteBody, Vote,
+        Redelegate, RedelegateClaim, Undelegate, UndelegateClaimBody, Unjail, UnjailBody,
+        ValidatorVote, ValidatorVoteBody, Vote,
     },
     plan::TransactionPlan,
     proposal, Action, Transaction, TransactionBody,
@@ -184,6 +185,15 @@ impl TransactionPlan {
         for plan in self.undelegate_claim_plans() {
             state.update(plan.undelegate_claim_body().effect_hash().as_bytes());
         }
+        for redelegation in self.redelegations() {
+            state.update(redelegation.effect_hash().as_bytes());
+        }
+        for redelegate_claim in self.redelegate_claims() {
+            state.update(redelegate_claim.effect_hash().as_bytes());
+        }
+        for unjail in self.unjails() {
+            state.update(unjail.effect_hash().as_bytes());
+        }
         for proposal_submit in self.proposal_submits() {
             state.update(proposal_submit.effect_hash().as_bytes());
         }
@@ -230,6 +240,9 @@ impl TransactionPlan {
         for position_open in self.position_openings() {
             state.update(position_open.effect_hash().as_bytes());
         }
+        for withdrawal in self.ics20_withdrawals() {
+            state.update(withdrawal.effect_hash().as_bytes());
+        }
         let num_clues = self.clue_plans.len() as u32;
         state.update(&num_clues.to_le_bytes());
         for clue_plan in self.clue_plans() {
@@ -254,6 +267,9 @@ impl EffectingData for Action {
             Action::Delegate(delegate) => delegate.effect_hash(),
             Action::Undelegate(undelegate) => undelegate.effect_hash(),
             Action::UndelegateClaim(claim) => claim.body.effect_hash(),
+            Action::Redelegate(redelegate) => redelegate.effect_hash(),
+            Action::RedelegateClaim(claim) => claim.effect_hash(),
+            Action::Unjail(unjail) => unjail.effect_hash(),
             Action::ProposalSubmit(submit) => submit.effect_hash(),
             Action::ProposalWithdraw(withdraw) => withdraw.effect_hash(),
             Action::ProposalDepositClaim(claim) => claim.effect_hash(),
@@ -395,6 +411,66 @@ impl EffectingData for Undelegate {
     }
 }
 
+impl EffectingData for Redelegate {
+    fn effect_hash(&self) -> EffectHash {
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"PAH:redelegate")
+            .to_state();
+
+        // All of these fields are fixed-length, so we can just throw them
+        // in the hash one after the other.
+        state.update(&self.from_validator_identity.0.to_bytes());
+        state.update(&self.to_validator_identity.0.to_bytes());
+        state.update(&self.epoch_index.to_le_bytes());
+        state.update(&self.end_epoch_index.to_le_bytes());
+        state.update(&self.from_delegation_amount.to_le_bytes());
+        state.update(&self.unbonded_amount.to_le_bytes());
+        state.update(&self.to_delegation_amount.to_le_bytes());
+
+        EffectHash(state.finalize().as_array().clone())
+    }
+}
+
+impl EffectingData for RedelegateClaim {
+    fn effect_hash(&self) -> EffectHash {
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"PAH:redelegate_claim")
+            .to_state();
+
+        // All of these fields are fixed-length, so we can just throw them
+        // in the hash one after the other.
+        state.update(&self.from_validator_identity.0.to_bytes());
+        state.update(&self.to_validator_identity.0.to_bytes());
+        state.update(&self.start_epoch_index.to_le_bytes());
+        state.update(&self.end_epoch_index.to_le_bytes());
+        state.update(&self.penalty.0.to_le_bytes());
+        state.update(&self.redelegation_amount.to_le_bytes());
+
+        EffectHash(state.finalize().as_array().clone())
+    }
+}
+
+impl EffectingData for Unjail {
+    fn effect_hash(&self) -> EffectHash {
+        self.body.effect_hash()
+    }
+}
+
+impl EffectingData for UnjailBody {
+    fn effect_hash(&self) -> EffectHash {
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"PAH:unjail_body")
+            .to_state();
+
+        // All of these fields are fixed-length, so we can just throw them
+        // in the hash one after the other.
+        state.update(&self.identity_key.0.to_bytes());
+        state.update(&self.jailed_at_height.to_le_bytes());
+
+        EffectHash(state.finalize().as_array().clone())
+    }
+}
+
 impl EffectingData for UndelegateClaimBody {
     fn effect_hash(&self) -> EffectHash {
         let mut state = blake2b_simd::Params::default()
@@ -467,12 +543,19 @@ impl EffectingData for Vote {
             .personal(b"PAH:vote")
             .to_state();
 
-        state.update(match self {
+        match self {
             // Manually choose a distinct byte for each vote type
-            Vote::Yes => b"Y",
-            Vote::No => b"N",
-            Vote::Abstain => b"A",
-        });
+            Vote::Yes => state.update(b"Y"),
+            Vote::No => state.update(b"N"),
+            Vote::Abstain => state.update(b"A"),
+            Vote::Weighted(weights) => {
+                // The weights are fixed-length, so they can follow the tag directly.
+                state.update(b"W");
+                state.update(&weights.yes().to_le_bytes());
+                state.update(&weights.no().to_le_bytes());
+                state.update(&weights.abstain().to_le_bytes())
+            }
+        };
 
         EffectHash(state.finalize().as_array().clone())
     }
@@ -491,6 +574,10 @@ impl EffectingData for ValidatorVoteBody {
         state.update(&self.identity_key.0.to_bytes());
         state.update(&self.governance_key.0.to_bytes());
 
+        // The reason is variable-length, so it's prefixed with its length.
+        state.update(&(self.reason.0.len() as u64).to_le_bytes());
+        state.update(self.reason.0.as_bytes());
+
         EffectHash(state.finalize().as_array().clone())
     }
 }
@@ -505,6 +592,7 @@ impl EffectingData for DelegatorVoteBody {
             unbonded_amount,
             nullifier,
             rk,
+            replaces_previous,
         } = self;
 
         let mut state = blake2b_simd::Params::default()
@@ -521,6 +609,7 @@ impl EffectingData for DelegatorVoteBody {
         state.update(&unbonded_amount.to_le_bytes());
         state.update(&nullifier.0.to_bytes());
         state.update(&rk.to_bytes());
+        state.update(&[u8::from(*replaces_previous)]);
 
         EffectHash(state.finalize().as_array().clone())
     }
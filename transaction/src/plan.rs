@@ -11,8 +11,9 @@ use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 
 use crate::action::{
-    DaoDeposit, DaoOutput, DaoSpend, Delegate, PositionOpen, ProposalDepositClaim, ProposalSubmit,
-    ProposalWithdraw, Undelegate, ValidatorVote,
+    DaoDeposit, DaoOutput, DaoSpend, Delegate, Ics20Withdrawal, PositionOpen, ProposalDepositClaim,
+    ProposalSubmit, ProposalWithdraw, Redelegate, RedelegateClaim, Undelegate, Unjail,
+    ValidatorVote,
 };
 
 mod action;
@@ -25,6 +26,8 @@ pub use action::{
     ActionPlan, DelegatorVotePlan, OutputPlan, SpendPlan, SwapClaimPlan, SwapPlan,
     UndelegateClaimPlan,
 };
+#[cfg(feature = "parallel")]
+pub use build::{BuildOptions, BuildProgress};
 pub use clue::CluePlan;
 pub use memo::MemoPlan;
 
@@ -97,6 +100,36 @@ impl TransactionPlan {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Redelegate(r) = action {
+                Some(r)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn redelegate_claims(&self) -> impl Iterator<Item = &RedelegateClaim> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::RedelegateClaim(c) = action {
+                Some(c)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn unjails(&self) -> impl Iterator<Item = &Unjail> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Unjail(u) = action {
+                Some(u)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn ibc_actions(&self) -> impl Iterator<Item = &pb_ibc::IbcAction> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::IBCAction(ibc_action) = action {
@@ -227,6 +260,16 @@ impl TransactionPlan {
         })
     }
 
+    pub fn ics20_withdrawals(&self) -> impl Iterator<Item = &Ics20Withdrawal> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Ics20Withdrawal(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Convenience method to get all the destination addresses for each `OutputPlan`s.
     pub fn dest_addresses(&self) -> Vec<Address> {
         self.output_plans()
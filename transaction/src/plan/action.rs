@@ -22,8 +22,9 @@ pub use swap_claim::SwapClaimPlan;
 pub use undelegate_claim::UndelegateClaimPlan;
 
 use crate::action::{
-    DaoDeposit, DaoOutput, DaoSpend, Delegate, PositionClose, PositionOpen, ProposalDepositClaim,
-    ProposalSubmit, ProposalWithdraw, Undelegate, ValidatorVote,
+    DaoDeposit, DaoOutput, DaoSpend, Delegate, Ics20Withdrawal, PositionClose, PositionOpen,
+    ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, Redelegate, RedelegateClaim,
+    Undelegate, Unjail, ValidatorVote,
 };
 
 /// A declaration of a planned [`Action`], for use in transaction creation.
@@ -46,6 +47,12 @@ pub enum ActionPlan {
     /// because we don't yet use flow encryption.
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaimPlan),
+    /// Redelegations are also fully transparent, so they're passed through as-is.
+    Redelegate(Redelegate),
+    /// Redelegation claims are also fully transparent, so they're passed through as-is.
+    RedelegateClaim(RedelegateClaim),
+    /// Unjailing is fully transparent, so it's passed through as-is.
+    Unjail(Unjail),
     ValidatorDefinition(pb_stake::ValidatorDefinition),
     /// Describes a proposed swap.
     Swap(SwapPlan),
@@ -75,6 +82,9 @@ pub enum ActionPlan {
     DaoSpend(DaoSpend),
     DaoOutput(DaoOutput),
     DaoDeposit(DaoDeposit),
+
+    /// An ICS-20 withdrawal is fully transparent, so it's passed through as-is.
+    Ics20Withdrawal(Ics20Withdrawal),
 }
 
 impl ActionPlan {
@@ -87,6 +97,8 @@ impl ActionPlan {
             Delegate(delegate) => delegate.balance(),
             Undelegate(undelegate) => undelegate.balance(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance(),
+            Redelegate(redelegate) => redelegate.balance(),
+            RedelegateClaim(redelegate_claim) => redelegate_claim.balance(),
             Swap(swap) => swap.balance(),
             SwapClaim(swap_claim) => swap_claim.balance(),
             ProposalSubmit(proposal_submit) => proposal_submit.balance(),
@@ -100,8 +112,11 @@ impl ActionPlan {
             PositionClose(position_close) => position_close.balance(),
             PositionWithdraw(position_withdraw) => position_withdraw.balance(),
             PositionRewardClaim(position_reward_claim) => position_reward_claim.balance(),
+            Ics20Withdrawal(withdrawal) => withdrawal.balance(),
             // None of these contribute to transaction balance:
-            IBCAction(_) | ValidatorDefinition(_) | ValidatorVote(_) => Balance::default(),
+            IBCAction(_) | ValidatorDefinition(_) | ValidatorVote(_) | Unjail(_) => {
+                Balance::default()
+            }
         }
     }
 }
@@ -144,6 +159,24 @@ impl From<Undelegate> for ActionPlan {
     }
 }
 
+impl From<Redelegate> for ActionPlan {
+    fn from(inner: Redelegate) -> ActionPlan {
+        ActionPlan::Redelegate(inner)
+    }
+}
+
+impl From<RedelegateClaim> for ActionPlan {
+    fn from(inner: RedelegateClaim) -> ActionPlan {
+        ActionPlan::RedelegateClaim(inner)
+    }
+}
+
+impl From<Unjail> for ActionPlan {
+    fn from(inner: Unjail) -> ActionPlan {
+        ActionPlan::Unjail(inner)
+    }
+}
+
 impl From<pb_stake::ValidatorDefinition> for ActionPlan {
     fn from(inner: pb_stake::ValidatorDefinition) -> ActionPlan {
         ActionPlan::ValidatorDefinition(inner)
@@ -198,6 +231,12 @@ impl From<PositionRewardClaimPlan> for ActionPlan {
     }
 }
 
+impl From<Ics20Withdrawal> for ActionPlan {
+    fn from(inner: Ics20Withdrawal) -> ActionPlan {
+        ActionPlan::Ics20Withdrawal(inner)
+    }
+}
+
 impl DomainType for ActionPlan {
     type Proto = pb_t::ActionPlan;
 }
@@ -220,6 +259,15 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::UndelegateClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::UndelegateClaim(inner.into())),
             },
+            ActionPlan::Redelegate(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Redelegate(inner.into())),
+            },
+            ActionPlan::RedelegateClaim(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::RedelegateClaim(inner.into())),
+            },
+            ActionPlan::Unjail(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Unjail(inner.into())),
+            },
             ActionPlan::ValidatorDefinition(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ValidatorDefinition(inner)),
             },
@@ -274,6 +322,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::DaoOutput(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::DaoOutput(inner.into())),
             },
+            ActionPlan::Ics20Withdrawal(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Ics20Withdrawal(inner.into())),
+            },
         }
     }
 }
@@ -298,6 +349,13 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::UndelegateClaim(inner) => {
                 Ok(ActionPlan::UndelegateClaim(inner.try_into()?))
             }
+            pb_t::action_plan::Action::Redelegate(inner) => {
+                Ok(ActionPlan::Redelegate(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::RedelegateClaim(inner) => {
+                Ok(ActionPlan::RedelegateClaim(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::Unjail(inner) => Ok(ActionPlan::Unjail(inner.try_into()?)),
             pb_t::action_plan::Action::ValidatorDefinition(inner) => {
                 Ok(ActionPlan::ValidatorDefinition(inner))
             }
@@ -342,6 +400,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::DaoOutput(inner) => {
                 Ok(ActionPlan::DaoOutput(inner.try_into()?))
             }
+            pb_t::action_plan::Action::Ics20Withdrawal(inner) => {
+                Ok(ActionPlan::Ics20Withdrawal(inner.try_into()?))
+            }
         }
     }
 }
@@ -2,12 +2,13 @@ use ark_ff::{UniformRand, Zero};
 use decaf377::{FieldExt, Fr};
 use decaf377_rdsa::{Signature, SpendAuth};
 use penumbra_crypto::{
-    proofs::transparent::{DelegatorVoteProof, SpendProof},
-    Amount, FullViewingKey, Note, VotingReceiptToken,
+    proofs::groth16::DelegatorVoteProof, Amount, FullViewingKey, Note, VotingReceiptToken,
 };
+use penumbra_proof_params::DELEGATOR_VOTE_PROOF_PROVING_KEY;
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
 use rand::{CryptoRng, RngCore};
+use rand_core::OsRng;
 use serde::{Deserialize, Serialize};
 
 use crate::action::{DelegatorVote, DelegatorVoteBody, Vote};
@@ -30,6 +31,8 @@ pub struct DelegatorVotePlan {
     pub position: tct::Position,
     /// The randomizer to use.
     pub randomizer: Fr,
+    /// Whether the vote replaces an earlier vote on the proposal with the same nullifier.
+    pub replaces_previous: bool,
 }
 
 impl DelegatorVotePlan {
@@ -43,6 +46,7 @@ impl DelegatorVotePlan {
         staked_note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
+        replaces_previous: bool,
     ) -> DelegatorVotePlan {
         DelegatorVotePlan {
             proposal,
@@ -52,6 +56,7 @@ impl DelegatorVotePlan {
             unbonded_amount,
             position,
             randomizer: Fr::rand(rng),
+            replaces_previous,
         }
     }
 
@@ -79,6 +84,7 @@ impl DelegatorVotePlan {
             unbonded_amount: self.unbonded_amount,
             nullifier: fvk.derive_nullifier(self.position, &self.staked_note.commit()),
             rk: fvk.spend_verification_key().randomize(&self.randomizer),
+            replaces_previous: self.replaces_previous,
         }
     }
 
@@ -88,19 +94,29 @@ impl DelegatorVotePlan {
         fvk: &FullViewingKey,
         state_commitment_proof: tct::Proof,
     ) -> DelegatorVoteProof {
-        DelegatorVoteProof {
-            spend_proof: SpendProof {
-                state_commitment_proof,
-                note: self.staked_note.clone(),
-                v_blinding: Fr::zero(),
-                spend_auth_randomizer: self.randomizer,
-                ak: *fvk.spend_verification_key(),
-                nk: *fvk.nullifier_key(),
-            },
-        }
+        DelegatorVoteProof::prove(
+            &mut OsRng,
+            &DELEGATOR_VOTE_PROOF_PROVING_KEY,
+            state_commitment_proof.clone(),
+            self.staked_note.clone(),
+            self.randomizer,
+            *fvk.spend_verification_key(),
+            *fvk.nullifier_key(),
+            state_commitment_proof.root(),
+            self.staked_note.value().commit(Fr::zero()),
+            fvk.derive_nullifier(self.position, &self.staked_note.commit()),
+            fvk.spend_verification_key().randomize(&self.randomizer),
+            self.start_position,
+        )
+        .expect("can generate ZKDelegatorVoteProof")
     }
 
     pub fn balance(&self) -> penumbra_crypto::Balance {
+        // A replacement vote doesn't mint voting receipt tokens again.
+        if self.replaces_previous {
+            return penumbra_crypto::Balance::default();
+        }
+
         penumbra_crypto::Value {
             amount: self.unbonded_amount,
             asset_id: VotingReceiptToken::new(self.proposal).id(),
@@ -119,6 +135,7 @@ impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
             unbonded_amount: Some(inner.unbonded_amount.into()),
             staked_note_position: inner.position.into(),
             randomizer: inner.randomizer.to_bytes().to_vec(),
+            replaces_previous: inner.replaces_previous,
         }
     }
 }
@@ -149,6 +166,7 @@ impl TryFrom<pb::DelegatorVotePlan> for DelegatorVotePlan {
                     .try_into()
                     .map_err(|_| anyhow::anyhow!("invalid randomizer"))?,
             )?,
+            replaces_previous: value.replaces_previous,
         })
     }
 }
@@ -1,11 +1,13 @@
 use penumbra_crypto::{
     dex::{swap::SwapPlaintext, BatchSwapOutputData},
     keys::{IncomingViewingKey, NullifierKey},
-    proofs::transparent::SwapClaimProof,
+    proofs::groth16::SwapClaimProof,
     FullViewingKey, Value,
 };
+use penumbra_proof_params::SWAPCLAIM_PROOF_PROVING_KEY;
 use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
+use rand_core::OsRng;
 
 use serde::{Deserialize, Serialize};
 use tct::Position;
@@ -32,7 +34,7 @@ impl SwapClaimPlan {
     ) -> SwapClaim {
         SwapClaim {
             body: self.swap_claim_body(fvk),
-            proof: self.swap_claim_proof(state_commitment_proof, fvk.nullifier_key()),
+            proof: self.swap_claim_proof(state_commitment_proof, fvk),
             epoch_duration: self.epoch_duration,
         }
     }
@@ -42,20 +44,29 @@ impl SwapClaimPlan {
     pub fn swap_claim_proof(
         &self,
         state_commitment_proof: &tct::Proof,
-        nk: &NullifierKey,
+        fvk: &FullViewingKey,
     ) -> SwapClaimProof {
-        let (lambda_1_i, lambda_2_i) = self.output_data.pro_rata_outputs((
-            self.swap_plaintext.delta_1_i.into(),
-            self.swap_plaintext.delta_2_i.into(),
-        ));
-
-        SwapClaimProof {
-            swap_plaintext: self.swap_plaintext.clone(),
-            lambda_1_i,
-            lambda_2_i,
-            nk: nk.clone(),
-            swap_commitment_proof: state_commitment_proof.clone(),
-        }
+        let (output_1_note, output_2_note) = self.swap_plaintext.output_notes(&self.output_data);
+        let nk: &NullifierKey = fvk.nullifier_key();
+        let anchor = state_commitment_proof.root();
+
+        SwapClaimProof::prove(
+            &mut OsRng,
+            &SWAPCLAIM_PROOF_PROVING_KEY,
+            self.swap_plaintext.clone(),
+            state_commitment_proof.clone(),
+            *nk,
+            output_1_note.note_blinding(),
+            output_2_note.note_blinding(),
+            anchor,
+            fvk.derive_nullifier(self.position, &self.swap_plaintext.swap_commitment()),
+            self.swap_plaintext.claim_fee.clone(),
+            self.output_data,
+            self.epoch_duration,
+            output_1_note.commit(),
+            output_2_note.commit(),
+        )
+        .expect("can generate ZKSwapClaimProof")
     }
 
     /// Construct the [`swap_claim::Body`] described by this plan.
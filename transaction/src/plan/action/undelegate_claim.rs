@@ -1,9 +1,12 @@
 use penumbra_crypto::{
-    proofs::transparent::UndelegateClaimProof,
+    asset,
+    proofs::groth16::UndelegateClaimProof,
     stake::{IdentityKey, Penalty, UnbondingToken},
     Amount, FieldExt, Fr,
 };
+use penumbra_proof_params::UNDELEGATECLAIM_PROOF_PROVING_KEY;
 use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
+use rand_core::OsRng;
 
 use serde::{Deserialize, Serialize};
 
@@ -49,18 +52,31 @@ impl UndelegateClaimPlan {
 
     /// Construct the [`UndelegateClaimProof`] required by the [`UndelegateClaimBody`] described by this [`UndelegateClaimPlan`].
     pub fn undelegate_claim_proof(&self) -> UndelegateClaimProof {
-        UndelegateClaimProof::new(self.unbonding_amount, self.balance_blinding)
+        UndelegateClaimProof::prove(
+            &mut OsRng,
+            &UNDELEGATECLAIM_PROOF_PROVING_KEY,
+            self.unbonding_amount,
+            self.balance_blinding,
+            self.balance().commit(self.balance_blinding),
+            self.unbonding_id(),
+            self.penalty,
+        )
+        .expect("can generate ZKUndelegateClaimProof")
     }
 
-    pub fn balance(&self) -> penumbra_crypto::Balance {
-        let unbonding_id = UnbondingToken::new(
+    /// The asset ID of the unbonding tokens being claimed.
+    pub fn unbonding_id(&self) -> asset::Id {
+        UnbondingToken::new(
             self.validator_identity,
             self.start_epoch_index,
             self.end_epoch_index,
         )
-        .id();
+        .id()
+    }
+
+    pub fn balance(&self) -> penumbra_crypto::Balance {
         self.penalty
-            .balance_for_claim(unbonding_id, self.unbonding_amount)
+            .balance_for_claim(self.unbonding_id(), self.unbonding_amount)
     }
 }
 
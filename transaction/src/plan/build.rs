@@ -4,6 +4,14 @@ use penumbra_crypto::{
 };
 use rand_core::{CryptoRng, RngCore};
 
+#[cfg(feature = "parallel")]
+mod concurrent;
+
+#[cfg(feature = "parallel")]
+use concurrent::ProofJob;
+#[cfg(feature = "parallel")]
+pub use concurrent::{BuildOptions, BuildProgress};
+
 use super::TransactionPlan;
 use crate::{
     action::Action, AuthorizationData, AuthorizingData, Transaction, TransactionBody, WitnessData,
@@ -121,6 +129,15 @@ impl TransactionPlan {
             let undelegate_claim = plan.undelegate_claim();
             actions.push(Action::UndelegateClaim(undelegate_claim));
         }
+        for redelegation in self.redelegations().cloned() {
+            actions.push(Action::Redelegate(redelegation))
+        }
+        for redelegate_claim in self.redelegate_claims().cloned() {
+            actions.push(Action::RedelegateClaim(redelegate_claim))
+        }
+        for unjail in self.unjails().cloned() {
+            actions.push(Action::Unjail(unjail))
+        }
         for proposal_submit in self.proposal_submits().cloned() {
             actions.push(Action::ProposalSubmit(proposal_submit))
         }
@@ -167,6 +184,9 @@ impl TransactionPlan {
         for position_open in self.position_openings().cloned() {
             actions.push(Action::PositionOpen(position_open))
         }
+        for withdrawal in self.ics20_withdrawals().cloned() {
+            actions.push(Action::Ics20Withdrawal(withdrawal))
+        }
 
         let transaction_body = TransactionBody {
             actions,
@@ -201,6 +221,22 @@ impl TransactionPlan {
         fvk: &FullViewingKey,
         auth_data: AuthorizationData,
         witness_data: WitnessData,
+    ) -> Result<Transaction> {
+        self.build_concurrent_with(rng, fvk, auth_data, witness_data, BuildOptions::default())
+            .await
+    }
+
+    #[cfg(feature = "parallel")]
+    /// Build the transaction this plan describes while proving concurrently, using the provided
+    /// [`BuildOptions`] to limit concurrency, report progress, or cancel the build.
+    /// This can be used in environments that support tokio tasks.
+    pub async fn build_concurrent_with<R: CryptoRng + RngCore>(
+        self,
+        rng: R,
+        fvk: &FullViewingKey,
+        auth_data: AuthorizationData,
+        witness_data: WitnessData,
+        options: BuildOptions,
     ) -> Result<Transaction> {
         // Do some basic input sanity-checking.
         let spend_count = self.spend_plans().count();
@@ -228,9 +264,12 @@ impl TransactionPlan {
         // outputs, etc.  This order has to align with the ordering in
         // TransactionPlan::effect_hash, which computes the auth hash of the
         // transaction we'll build here without actually building it.
+        //
+        // Actions with ZK proofs are slow to build, so we collect a job for each
+        // of them, in the order they appear in the transaction, to prove concurrently.
+        let mut proof_jobs = Vec::new();
 
-        // Start building the transaction's spends.
-        let mut in_progress_spend_actions = Vec::new();
+        // The transaction's spends.
         for (spend_plan, auth_sig) in self
             .spend_plans()
             .cloned()
@@ -245,13 +284,12 @@ impl TransactionPlan {
 
             synthetic_blinding_factor += spend_plan.value_blinding;
             let fvk_ = fvk.clone();
-            in_progress_spend_actions.push(tokio::spawn(async move {
-                spend_plan.spend(&fvk_, auth_sig, auth_path)
+            proof_jobs.push(ProofJob::new("spend", move || {
+                Action::Spend(spend_plan.spend(&fvk_, auth_sig, auth_path))
             }));
         }
 
-        // Start building the transaction's outputs.
-        let mut in_progress_output_actions = Vec::new();
+        // The transaction's outputs.
         let dummy_payload_key: PayloadKey = [0u8; 32].into();
         // If the memo_key is None, then there is no memo, and we populate the memo key
         // field with a dummy key.
@@ -260,21 +298,21 @@ impl TransactionPlan {
             synthetic_blinding_factor += output_plan.value_blinding;
             let ovk = fvk.outgoing().clone();
             let memo_key = memo_key.as_ref().unwrap_or(&dummy_payload_key).clone();
-            in_progress_output_actions.push(tokio::spawn(async move {
-                output_plan.output(&ovk, &memo_key)
+            proof_jobs.push(ProofJob::new("output", move || {
+                Action::Output(output_plan.output(&ovk, &memo_key))
             }));
         }
 
-        // Start building the transaction's swaps.
-        let mut in_progress_swap_actions = Vec::new();
+        // The transaction's swaps.
         for swap_plan in self.swap_plans().cloned() {
             synthetic_blinding_factor += swap_plan.fee_blinding;
             let fvk_ = fvk.clone();
-            in_progress_swap_actions.push(tokio::spawn(async move { swap_plan.swap(&fvk_) }));
+            proof_jobs.push(ProofJob::new("swap", move || {
+                Action::Swap(swap_plan.swap(&fvk_))
+            }));
         }
 
-        // Start building the transaction's swap claims.
-        let mut in_progress_swap_claim_actions = Vec::new();
+        // The transaction's swap claims.
         for swap_claim_plan in self.swap_claim_plans().cloned() {
             let note_commitment = swap_claim_plan.swap_plaintext.swap_commitment();
             let auth_path = witness_data
@@ -283,14 +321,22 @@ impl TransactionPlan {
                 .context(format!("could not get proof for {note_commitment:?}"))?
                 .clone();
             let fvk_ = fvk.clone();
+            proof_jobs.push(ProofJob::new("swap claim", move || {
+                Action::SwapClaim(swap_claim_plan.swap_claim(&fvk_, &auth_path))
+            }));
+        }
+        let shielded_count = proof_jobs.len();
 
-            in_progress_swap_claim_actions.push(tokio::spawn(async move {
-                swap_claim_plan.swap_claim(&fvk_, &auth_path)
+        // The transaction's undelegate claims.
+        let undelegate_claim_count = self.undelegate_claim_plans().count();
+        for plan in self.undelegate_claim_plans().cloned() {
+            synthetic_blinding_factor += plan.balance_blinding;
+            proof_jobs.push(ProofJob::new("undelegate claim", move || {
+                Action::UndelegateClaim(plan.undelegate_claim())
             }));
         }
 
-        // Start building the transaction's delegator votes.
-        let mut in_progress_delegator_vote_actions = Vec::new();
+        // The transaction's delegator votes.
         for (delegator_vote_plan, auth_sig) in self
             .delegator_vote_plans()
             .zip(auth_data.delegator_vote_auths.into_iter())
@@ -303,9 +349,10 @@ impl TransactionPlan {
                 .context(format!("could not get proof for {note_commitment:?}"))?
                 .clone();
             let fvk_ = fvk.clone();
-
-            in_progress_delegator_vote_actions.push(tokio::spawn(async move {
-                delegator_vote_plan.delegator_vote(&fvk_, auth_sig, auth_path.clone())
+            proof_jobs.push(ProofJob::new("delegator vote", move || {
+                Action::DelegatorVote(
+                    delegator_vote_plan.delegator_vote(&fvk_, auth_sig, auth_path),
+                )
             }));
         }
 
@@ -314,29 +361,12 @@ impl TransactionPlan {
             fmd_clues.push(clue_plan.clue());
         }
 
-        // Actions with ZK proofs are slow to build and were done concurrently,
-        // so we resolve the corresponding `JoinHandle`s in the order the tasks were started.
+        // Prove everything, getting back the proved actions in the order the jobs were added.
+        let mut proved_actions = options.prove(proof_jobs).await?.into_iter();
+
         let mut actions = Vec::new();
-        // Collect the spend actions.
-        for action in in_progress_spend_actions {
-            actions.push(Action::Spend(action.await.expect("can form spend action")));
-        }
-        // Collect the output actions.
-        for action in in_progress_output_actions {
-            actions.push(Action::Output(
-                action.await.expect("can form output action"),
-            ));
-        }
-        // Collect the swap actions.
-        for action in in_progress_swap_actions {
-            actions.push(Action::Swap(action.await.expect("can form swap action")));
-        }
-        // Collect the swap claim actions.
-        for action in in_progress_swap_claim_actions {
-            actions.push(Action::SwapClaim(
-                action.await.expect("can form swap claim action"),
-            ));
-        }
+        // Collect the spend, output, swap and swap claim actions.
+        actions.extend(proved_actions.by_ref().take(shielded_count));
 
         // All of these actions have "transparent" value balance with no
         // blinding factor, so they don't contribute to the
@@ -347,10 +377,16 @@ impl TransactionPlan {
         for undelegation in self.undelegations().cloned() {
             actions.push(Action::Undelegate(undelegation))
         }
-        for plan in self.undelegate_claim_plans() {
-            synthetic_blinding_factor += plan.balance_blinding;
-            let undelegate_claim = plan.undelegate_claim();
-            actions.push(Action::UndelegateClaim(undelegate_claim));
+        // Collect the undelegate claim actions.
+        actions.extend(proved_actions.by_ref().take(undelegate_claim_count));
+        for redelegation in self.redelegations().cloned() {
+            actions.push(Action::Redelegate(redelegation))
+        }
+        for redelegate_claim in self.redelegate_claims().cloned() {
+            actions.push(Action::RedelegateClaim(redelegate_claim))
+        }
+        for unjail in self.unjails().cloned() {
+            actions.push(Action::Unjail(unjail))
         }
         for proposal_submit in self.proposal_submits().cloned() {
             actions.push(Action::ProposalSubmit(proposal_submit))
@@ -361,13 +397,8 @@ impl TransactionPlan {
         for validator_vote in self.validator_votes().cloned() {
             actions.push(Action::ValidatorVote(validator_vote))
         }
-        for delegator_vote in in_progress_delegator_vote_actions {
-            actions.push(Action::DelegatorVote(
-                delegator_vote
-                    .await
-                    .expect("can form delegator vote action"),
-            ));
-        }
+        // Collect the delegator vote actions, which are all that remain.
+        actions.extend(proved_actions);
         for proposal_deposit_claim in self.proposal_deposit_claims().cloned() {
             actions.push(Action::ProposalDepositClaim(proposal_deposit_claim))
         }
@@ -389,6 +420,9 @@ impl TransactionPlan {
         for position_open in self.position_openings().cloned() {
             actions.push(Action::PositionOpen(position_open))
         }
+        for withdrawal in self.ics20_withdrawals().cloned() {
+            actions.push(Action::Ics20Withdrawal(withdrawal))
+        }
 
         let transaction_body = TransactionBody {
             actions,
@@ -0,0 +1,161 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use tokio::{
+    sync::{mpsc, Semaphore},
+    task::JoinSet,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::Action;
+
+/// Progress building a transaction with
+/// [`TransactionPlan::build_concurrent_with`](super::TransactionPlan::build_concurrent_with),
+/// reported for each action that needs a proof.
+#[derive(Clone, Debug)]
+pub enum BuildProgress {
+    /// Proving has started for an action.
+    ProofStarted {
+        /// The index of the action among the actions that need proofs.
+        index: usize,
+        /// The number of actions that need proofs.
+        total: usize,
+        /// The kind of action, such as `"spend"`.
+        action: &'static str,
+    },
+    /// Proving has finished for an action.
+    ProofFinished {
+        /// The index of the action among the actions that need proofs.
+        index: usize,
+        /// The number of actions that need proofs.
+        total: usize,
+        /// The kind of action, such as `"spend"`.
+        action: &'static str,
+        /// How long it took to prove the action.
+        duration: Duration,
+    },
+}
+
+/// Options for building a transaction with
+/// [`TransactionPlan::build_concurrent_with`](super::TransactionPlan::build_concurrent_with).
+#[derive(Clone, Debug, Default)]
+pub struct BuildOptions {
+    max_concurrent_proofs: Option<usize>,
+    cancellation_token: CancellationToken,
+    progress: Option<mpsc::UnboundedSender<BuildProgress>>,
+}
+
+impl BuildOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prove at most `max` actions at once, rather than proving every action at once.
+    pub fn max_concurrent_proofs(mut self, max: usize) -> Self {
+        self.max_concurrent_proofs = Some(max.max(1));
+        self
+    }
+
+    /// Stop building the transaction if `token` is cancelled.
+    ///
+    /// Proofs that haven't started yet are abandoned; proofs that are already running can't be
+    /// interrupted, but their results are discarded.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = token;
+        self
+    }
+
+    /// Report progress on `progress` as each action is proved.
+    pub fn progress(mut self, progress: mpsc::UnboundedSender<BuildProgress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Build the actions which need proofs concurrently, returning them in the same order as
+    /// `jobs`.
+    pub(super) async fn prove(&self, jobs: Vec<ProofJob>) -> Result<Vec<Action>> {
+        let total = jobs.len();
+        let semaphore = self
+            .max_concurrent_proofs
+            .map(|max| Arc::new(Semaphore::new(max)));
+
+        let mut tasks = JoinSet::new();
+        for (index, ProofJob { action, build }) in jobs.into_iter().enumerate() {
+            let semaphore = semaphore.clone();
+            let progress = self.progress.clone();
+            tasks.spawn(async move {
+                // Hold a permit for the duration of the proof, if the number of proofs is capped.
+                let _permit = match semaphore {
+                    Some(semaphore) => Some(semaphore.acquire_owned().await?),
+                    None => None,
+                };
+                let report = |event| {
+                    if let Some(progress) = &progress {
+                        // The receiver may have stopped listening, which shouldn't stop the build.
+                        let _ = progress.send(event);
+                    }
+                };
+
+                report(BuildProgress::ProofStarted {
+                    index,
+                    total,
+                    action,
+                });
+                let start = Instant::now();
+                let built = tokio::task::spawn_blocking(build).await?;
+                report(BuildProgress::ProofFinished {
+                    index,
+                    total,
+                    action,
+                    duration: start.elapsed(),
+                });
+
+                anyhow::Ok((index, built))
+            });
+        }
+
+        let mut actions: Vec<Option<Action>> = (0..total).map(|_| None).collect();
+        loop {
+            tokio::select! {
+                biased;
+                // Returning drops `tasks`, which aborts any proofs that haven't started.
+                _ = self.cancellation_token.cancelled() => {
+                    anyhow::bail!("building the transaction was cancelled")
+                }
+                next = tasks.join_next() => match next {
+                    Some(result) => {
+                        let (index, action) = result??;
+                        actions[index] = Some(action);
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        Ok(actions
+            .into_iter()
+            .map(|action| action.expect("every proof job has finished"))
+            .collect())
+    }
+}
+
+/// An action which needs a proof, along with the (slow) work needed to build it.
+pub(super) struct ProofJob {
+    action: &'static str,
+    build: Box<dyn FnOnce() -> Action + Send>,
+}
+
+impl ProofJob {
+    pub(super) fn new(
+        action: &'static str,
+        build: impl FnOnce() -> Action + Send + 'static,
+    ) -> Self {
+        Self {
+            action,
+            build: Box::new(build),
+        }
+    }
+}
@@ -24,7 +24,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     action::{
         DaoDeposit, DaoOutput, DaoSpend, Delegate, DelegatorVote, Output, PositionOpen,
-        ProposalSubmit, ProposalWithdraw, Swap, Undelegate, ValidatorVote,
+        ProposalSubmit, ProposalWithdraw, Redelegate, Swap, Undelegate, ValidatorVote,
     },
     view::action_view::OutputView,
     Action, ActionView, Id, IsAction, TransactionPerspective, TransactionView,
@@ -119,6 +119,9 @@ impl Transaction {
                 | Action::Delegate(_)
                 | Action::Undelegate(_)
                 | Action::UndelegateClaim(_)
+                | Action::Redelegate(_)
+                | Action::RedelegateClaim(_)
+                | Action::Unjail(_)
                 | Action::ValidatorDefinition(_)
                 | Action::IBCAction(_)
                 | Action::ProposalSubmit(_)
@@ -203,6 +206,16 @@ impl Transaction {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions().filter_map(|action| {
+            if let Action::Redelegate(r) = action {
+                Some(r)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn proposal_submits(&self) -> impl Iterator<Item = &ProposalSubmit> {
         self.actions().filter_map(|action| {
             if let Action::ProposalSubmit(s) = action {
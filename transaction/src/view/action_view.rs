@@ -17,7 +17,7 @@ pub use swap_view::SwapView;
 use crate::action::{
     DaoDeposit, DaoOutput, DaoSpend, Delegate, Ics20Withdrawal, PositionClose, PositionOpen,
     PositionRewardClaim, PositionWithdraw, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-    Undelegate, UndelegateClaim, ValidatorVote,
+    Redelegate, RedelegateClaim, Undelegate, UndelegateClaim, Unjail, ValidatorVote,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -44,6 +44,9 @@ pub enum ActionView {
     Delegate(Delegate),
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaim),
+    Redelegate(Redelegate),
+    RedelegateClaim(RedelegateClaim),
+    Unjail(Unjail),
     Ics20Withdrawal(Ics20Withdrawal),
     DaoDeposit(DaoDeposit),
     DaoSpend(DaoSpend),
@@ -69,6 +72,9 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::Output(x) => ActionView::Output(x.try_into()?),
                 AV::Undelegate(x) => ActionView::Undelegate(x.try_into()?),
                 AV::UndelegateClaim(x) => ActionView::UndelegateClaim(x.try_into()?),
+                AV::Redelegate(x) => ActionView::Redelegate(x.try_into()?),
+                AV::RedelegateClaim(x) => ActionView::RedelegateClaim(x.try_into()?),
+                AV::Unjail(x) => ActionView::Unjail(x.try_into()?),
                 AV::Swap(x) => ActionView::Swap(x.try_into()?),
                 AV::SwapClaim(x) => ActionView::SwapClaim(x.try_into()?),
                 AV::ValidatorDefinition(x) => ActionView::ValidatorDefinition(x),
@@ -103,6 +109,9 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::Delegate(x) => AV::Delegate(x.into()),
                 ActionView::Undelegate(x) => AV::Undelegate(x.into()),
                 ActionView::UndelegateClaim(x) => AV::UndelegateClaim(x.into()),
+                ActionView::Redelegate(x) => AV::Redelegate(x.into()),
+                ActionView::RedelegateClaim(x) => AV::RedelegateClaim(x.into()),
+                ActionView::Unjail(x) => AV::Unjail(x.into()),
                 ActionView::ValidatorDefinition(x) => AV::ValidatorDefinition(x),
                 ActionView::IBCAction(x) => AV::IbcAction(x),
                 ActionView::ProposalSubmit(x) => AV::ProposalSubmit(x.into()),
@@ -22,6 +22,151 @@ pub enum Vote {
     /// Vote to abstain from the proposal.
     #[cfg_attr(feature = "clap", clap(display_order = 300))]
     Abstain,
+    /// Split the voting power between the options according to the given weights.
+    #[cfg_attr(feature = "clap", clap(display_order = 400))]
+    #[cfg_attr(test, proptest(skip))]
+    Weighted(VoteWeights),
+}
+
+/// A split of voting power between the options of a vote, in basis points.
+///
+/// The weights always sum to [`VoteWeights::TOTAL`], so a weighted vote always accounts for all of
+/// the voting power it is cast with. On the command line, the weights are given as percentages.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(try_from = "pb::VoteWeights", into = "pb::VoteWeights")]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct VoteWeights {
+    /// The percentage of voting power in favor of the proposal.
+    #[cfg_attr(
+        feature = "clap",
+        clap(long, default_value = "0", parse(try_from_str = VoteWeights::parse_percentage))
+    )]
+    yes: u16,
+    /// The percentage of voting power against the proposal.
+    #[cfg_attr(
+        feature = "clap",
+        clap(long, default_value = "0", parse(try_from_str = VoteWeights::parse_percentage))
+    )]
+    no: u16,
+    /// The percentage of voting power abstaining from the proposal.
+    #[cfg_attr(
+        feature = "clap",
+        clap(long, default_value = "0", parse(try_from_str = VoteWeights::parse_percentage))
+    )]
+    abstain: u16,
+}
+
+impl VoteWeights {
+    /// The total of the weights of every vote, in basis points.
+    pub const TOTAL: u16 = 10_000;
+
+    /// Create a new set of vote weights, checking that they sum to [`VoteWeights::TOTAL`].
+    pub fn new(yes: u16, no: u16, abstain: u16) -> anyhow::Result<Self> {
+        let weights = Self { yes, no, abstain };
+        weights.check()?;
+        Ok(weights)
+    }
+
+    /// Check that the weights sum to [`VoteWeights::TOTAL`].
+    pub fn check(&self) -> anyhow::Result<()> {
+        let total = u32::from(self.yes) + u32::from(self.no) + u32::from(self.abstain);
+        if total != u32::from(Self::TOTAL) {
+            return Err(anyhow!(
+                "vote weights must sum to {} basis points, but sum to {}",
+                Self::TOTAL,
+                total
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a percentage with at most two decimal places, like `60` or `33.33%`, into basis
+    /// points.
+    pub fn parse_percentage(s: &str) -> anyhow::Result<u16> {
+        let digits = s.trim_end_matches('%');
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if fraction.len() > 2 {
+            return Err(anyhow!("percentage {} has more than two decimal places", s));
+        }
+        let whole = whole.parse::<u32>()?;
+        let fraction = format!("{fraction:0<2}").parse::<u32>()?;
+        let basis_points = whole
+            .checked_mul(100)
+            .and_then(|whole| whole.checked_add(fraction))
+            .filter(|basis_points| *basis_points <= u32::from(Self::TOTAL))
+            .ok_or_else(|| anyhow!("percentage {} is greater than 100", s))?;
+        Ok(basis_points as u16)
+    }
+
+    pub fn yes(&self) -> u16 {
+        self.yes
+    }
+
+    pub fn no(&self) -> u16 {
+        self.no
+    }
+
+    pub fn abstain(&self) -> u16 {
+        self.abstain
+    }
+
+    /// Split the given voting power into `(yes, no, abstain)` according to the weights.
+    ///
+    /// The parts always sum to exactly `power`: any remainder from rounding down is assigned to
+    /// the most heavily weighted option.
+    pub fn split(&self, power: u64) -> (u64, u64, u64) {
+        let part =
+            |weight: u16| (u128::from(power) * u128::from(weight) / u128::from(Self::TOTAL)) as u64;
+        let (mut yes, mut no, mut abstain) = (part(self.yes), part(self.no), part(self.abstain));
+        let remainder = power - yes - no - abstain;
+        if self.yes >= self.no && self.yes >= self.abstain {
+            yes += remainder;
+        } else if self.no >= self.abstain {
+            no += remainder;
+        } else {
+            abstain += remainder;
+        }
+        (yes, no, abstain)
+    }
+}
+
+impl Display for VoteWeights {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), fmt::Error> {
+        let percent = |weight: u16| format!("{}.{:02}%", weight / 100, weight % 100);
+        write!(
+            f,
+            "{} yes, {} no, {} abstain",
+            percent(self.yes),
+            percent(self.no),
+            percent(self.abstain)
+        )
+    }
+}
+
+impl From<VoteWeights> for pb::VoteWeights {
+    fn from(value: VoteWeights) -> Self {
+        pb::VoteWeights {
+            yes: value.yes.into(),
+            no: value.no.into(),
+            abstain: value.abstain.into(),
+        }
+    }
+}
+
+impl TryFrom<pb::VoteWeights> for VoteWeights {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::VoteWeights) -> Result<Self, Self::Error> {
+        VoteWeights::new(
+            msg.yes.try_into()?,
+            msg.no.try_into()?,
+            msg.abstain.try_into()?,
+        )
+    }
+}
+
+impl DomainType for VoteWeights {
+    type Proto = pb::VoteWeights;
 }
 
 impl FromStr for Vote {
@@ -43,6 +188,7 @@ impl Display for Vote {
             Vote::Yes => write!(f, "yes"),
             Vote::No => write!(f, "no"),
             Vote::Abstain => write!(f, "abstain"),
+            Vote::Weighted(weights) => write!(f, "{weights}"),
         }
     }
 }
@@ -58,12 +204,23 @@ const fn pb_from_vote(vote: Vote) -> pb::Vote {
     match vote {
         Vote::Yes => pb::Vote {
             vote: pb::vote::Vote::Yes as i32,
+            weights: None,
         },
         Vote::No => pb::Vote {
             vote: pb::vote::Vote::No as i32,
+            weights: None,
         },
         Vote::Abstain => pb::Vote {
             vote: pb::vote::Vote::Abstain as i32,
+            weights: None,
+        },
+        Vote::Weighted(VoteWeights { yes, no, abstain }) => pb::Vote {
+            vote: pb::vote::Vote::Unspecified as i32,
+            weights: Some(pb::VoteWeights {
+                yes: yes as u32,
+                no: no as u32,
+                abstain: abstain as u32,
+            }),
         },
     }
 }
@@ -73,13 +230,15 @@ impl TryFrom<pb::Vote> for Vote {
 
     fn try_from(msg: pb::Vote) -> Result<Self, Self::Error> {
         let Some(vote_state) = pb::vote::Vote::from_i32(msg.vote) else {
-            return Err(anyhow!("invalid vote state"))
+            return Err(anyhow!("invalid vote state"));
         };
-        match vote_state {
-            pb::vote::Vote::Abstain => Ok(Vote::Abstain),
-            pb::vote::Vote::Yes => Ok(Vote::Yes),
-            pb::vote::Vote::No => Ok(Vote::No),
-            pb::vote::Vote::Unspecified => Err(anyhow!("unspecified vote state")),
+        match (vote_state, msg.weights) {
+            (pb::vote::Vote::Abstain, None) => Ok(Vote::Abstain),
+            (pb::vote::Vote::Yes, None) => Ok(Vote::Yes),
+            (pb::vote::Vote::No, None) => Ok(Vote::No),
+            (pb::vote::Vote::Unspecified, Some(weights)) => Ok(Vote::Weighted(weights.try_into()?)),
+            (pb::vote::Vote::Unspecified, None) => Err(anyhow!("unspecified vote state")),
+            (_, Some(_)) => Err(anyhow!("weighted vote must not specify a vote state")),
         }
     }
 }
@@ -95,6 +254,40 @@ mod test {
             let vote2 = super::Vote::try_from(pb_vote).unwrap();
             assert_eq!(vote, vote2);
         }
+
+        #[test]
+        fn weighted_vote_split_sums_to_power(yes in 0u16..=10_000, no_share in 0u16..=10_000, power: u64) {
+            let no = no_share.min(super::VoteWeights::TOTAL - yes);
+            let abstain = super::VoteWeights::TOTAL - yes - no;
+            let weights = super::VoteWeights::new(yes, no, abstain).unwrap();
+
+            let pb_vote: super::pb::Vote = super::Vote::Weighted(weights).into();
+            assert_eq!(super::Vote::try_from(pb_vote).unwrap(), super::Vote::Weighted(weights));
+
+            let (yes, no, abstain) = weights.split(power);
+            assert_eq!(yes as u128 + no as u128 + abstain as u128, power as u128);
+        }
+    }
+
+    #[test]
+    fn vote_weights_must_sum_to_total() {
+        assert!(super::VoteWeights::new(6_000, 4_000, 0).is_ok());
+        assert!(super::VoteWeights::new(6_000, 3_000, 0).is_err());
+        assert!(super::VoteWeights::new(6_000, 4_000, 1).is_err());
+    }
+
+    #[test]
+    fn parse_percentages() {
+        use super::VoteWeights;
+        assert_eq!(VoteWeights::parse_percentage("60").unwrap(), 6_000);
+        assert_eq!(VoteWeights::parse_percentage("33.33%").unwrap(), 3_333);
+        assert_eq!(VoteWeights::parse_percentage("0.5").unwrap(), 50);
+        assert_eq!(VoteWeights::parse_percentage("100").unwrap(), 10_000);
+        assert!(VoteWeights::parse_percentage("100.01").is_err());
+        assert!(VoteWeights::parse_percentage("1.234").is_err());
+        // Large inputs are rejected rather than overflowing.
+        assert!(VoteWeights::parse_percentage("4294967295").is_err());
+        assert!(VoteWeights::parse_percentage("42949673").is_err());
     }
 }
 
@@ -0,0 +1,11 @@
+-- The rates of validators we're delegated to, recorded at each epoch boundary,
+-- used to track the value of our delegations over time.
+CREATE TABLE validator_rates (
+    identity_key            BLOB NOT NULL,
+    epoch_index             BIGINT NOT NULL,
+    -- the encoded `RateData` for the epoch
+    rate_data               BLOB NOT NULL,
+    -- the slashing penalty applied to the validator's rate at the start of the epoch
+    penalty                 BIGINT NOT NULL,
+    PRIMARY KEY (identity_key, epoch_index)
+);
@@ -19,7 +19,10 @@ use penumbra_transaction::{
 use tonic::codegen::Bytes;
 use tracing::instrument;
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord};
+use crate::{
+    DelegationHistoryRecord, SpendableNoteRecord, StatusStreamResponse, SwapRecord, Unbonding,
+    VotingPowerRecord,
+};
 
 /// The view protocol is used by a view client, who wants to do some
 /// transaction-related actions, to request data from a view service, which is
@@ -79,6 +82,29 @@ pub trait ViewClient {
         Box<dyn Future<Output = Result<Vec<(SpendableNoteRecord, IdentityKey)>>> + Send + 'static>,
     >;
 
+    /// Queries for the history of the wallet's delegations, optionally
+    /// restricted to a single validator.
+    fn delegation_history(
+        &mut self,
+        account_group_id: AccountGroupId,
+        identity_key: Option<IdentityKey>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<DelegationHistoryRecord>>> + Send + 'static>>;
+
+    /// Queries for the wallet's pending unbondings, optionally only those that
+    /// can be claimed now.
+    fn unbondings(
+        &mut self,
+        account_group_id: AccountGroupId,
+        claimable_only: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Unbonding>>> + Send + 'static>>;
+
+    /// Queries for the notes that can vote on a proposal, with the voting power each carries.
+    fn voting_power(
+        &mut self,
+        account_group_id: AccountGroupId,
+        proposal_id: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<VotingPowerRecord>>> + Send + 'static>>;
+
     /// Queries for account balance by address
     fn balance_by_address(
         &mut self,
@@ -135,7 +161,8 @@ pub trait ViewClient {
         plan: &TransactionPlan,
     ) -> Pin<Box<dyn Future<Output = Result<WitnessData>> + Send + 'static>>;
 
-    /// Returns a transaction built from the provided TransactionPlan and AuthorizationData
+    /// Returns a transaction built from the provided TransactionPlan and AuthorizationData,
+    /// logging the progress of each proof as it's built.
     fn witness_and_build(
         &mut self,
         plan: TransactionPlan,
@@ -419,6 +446,69 @@ where
         .boxed()
     }
 
+    fn delegation_history(
+        &mut self,
+        account_group_id: AccountGroupId,
+        identity_key: Option<IdentityKey>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<DelegationHistoryRecord>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let req = self2.delegation_history(tonic::Request::new(pb::DelegationHistoryRequest {
+                account_group_id: Some(account_group_id.into()),
+                identity_key: identity_key.map(Into::into),
+                ..Default::default()
+            }));
+            let pb_history: Vec<_> = req.await?.into_inner().try_collect().await?;
+
+            pb_history
+                .into_iter()
+                .map(DelegationHistoryRecord::try_from)
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn unbondings(
+        &mut self,
+        account_group_id: AccountGroupId,
+        claimable_only: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Unbonding>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let req = self2.unbondings(tonic::Request::new(pb::UnbondingsRequest {
+                account_group_id: Some(account_group_id.into()),
+                claimable_only,
+                ..Default::default()
+            }));
+            let pb_unbondings: Vec<_> = req.await?.into_inner().try_collect().await?;
+
+            pb_unbondings.into_iter().map(Unbonding::try_from).collect()
+        }
+        .boxed()
+    }
+
+    fn voting_power(
+        &mut self,
+        account_group_id: AccountGroupId,
+        proposal_id: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<VotingPowerRecord>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let req = self2.voting_power(tonic::Request::new(pb::VotingPowerRequest {
+                account_group_id: Some(account_group_id.into()),
+                proposal_id,
+                ..Default::default()
+            }));
+            let pb_records: Vec<_> = req.await?.into_inner().try_collect().await?;
+
+            pb_records
+                .into_iter()
+                .map(VotingPowerRecord::try_from)
+                .collect()
+        }
+        .boxed()
+    }
+
     fn note_by_commitment(
         &mut self,
         account_group_id: AccountGroupId,
@@ -793,16 +883,31 @@ where
         };
         let mut self2 = self.clone();
         async move {
-            let rsp = self2.witness_and_build(tonic::Request::new(request));
-
-            let tx = rsp
+            let mut rsp = self2
+                .witness_and_build(tonic::Request::new(request))
                 .await?
-                .into_inner()
-                .transaction
-                .ok_or_else(|| anyhow::anyhow!("empty WitnessAndBuildResponse message"))?
-                .try_into()?;
+                .into_inner();
+
+            // The stream reports progress on each proof before sending the transaction.
+            while let Some(msg) = rsp.message().await? {
+                if let Some(progress) = msg.progress {
+                    tracing::debug!(
+                        index = progress.index,
+                        total = progress.total,
+                        action = %progress.action,
+                        finished = progress.finished,
+                        duration_ms = progress.duration_ms,
+                        "transaction build progress"
+                    );
+                }
+                if let Some(tx) = msg.transaction {
+                    return Ok(tx.try_into()?);
+                }
+            }
 
-            Ok(tx)
+            Err(anyhow::anyhow!(
+                "WitnessAndBuild stream ended without a transaction"
+            ))
         }
         .boxed()
     }
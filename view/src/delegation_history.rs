@@ -0,0 +1,259 @@
+use penumbra_chain::Epoch;
+use penumbra_component::stake::rate::RateData;
+use penumbra_crypto::{stake::Penalty, Amount};
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::SpendableNoteRecord;
+
+/// The wallet's delegation to a validator during one epoch.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(
+    try_from = "pb::DelegationHistoryResponse",
+    into = "pb::DelegationHistoryResponse"
+)]
+pub struct DelegationHistoryRecord {
+    /// The validator's rate data for the epoch.
+    pub rate_data: RateData,
+    /// The amount of delegation tokens held at the start of the epoch.
+    pub delegation_amount: Amount,
+    /// The value of the delegation tokens in the staking token at the start of the epoch.
+    pub unbonded_amount: Amount,
+    /// The staking rewards accrued by the delegation tokens over the epoch,
+    /// or `None` if the epoch hasn't ended yet.
+    pub reward_amount: Option<Amount>,
+    /// The value lost to slashing penalties applied at the end of the epoch,
+    /// or `None` if the epoch hasn't ended yet.
+    pub slashed_amount: Option<Amount>,
+}
+
+impl DomainType for DelegationHistoryRecord {
+    type Proto = pb::DelegationHistoryResponse;
+}
+
+impl From<DelegationHistoryRecord> for pb::DelegationHistoryResponse {
+    fn from(v: DelegationHistoryRecord) -> Self {
+        pb::DelegationHistoryResponse {
+            rate_data: Some(v.rate_data.into()),
+            delegation_amount: Some(v.delegation_amount.into()),
+            unbonded_amount: Some(v.unbonded_amount.into()),
+            reward_amount: v.reward_amount.map(Into::into),
+            slashed_amount: v.slashed_amount.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::DelegationHistoryResponse> for DelegationHistoryRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::DelegationHistoryResponse) -> Result<Self, Self::Error> {
+        Ok(DelegationHistoryRecord {
+            rate_data: v
+                .rate_data
+                .ok_or_else(|| anyhow::anyhow!("missing rate data"))?
+                .try_into()?,
+            delegation_amount: v
+                .delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing delegation amount"))?
+                .try_into()?,
+            unbonded_amount: v
+                .unbonded_amount
+                .ok_or_else(|| anyhow::anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            reward_amount: v.reward_amount.map(TryInto::try_into).transpose()?,
+            slashed_amount: v.slashed_amount.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+/// Computes the history of a delegation to a single validator.
+///
+/// The `rates` are the validator's recorded rates, with the slashing penalty
+/// applied at the start of each epoch, ordered by epoch; the `notes` are all of
+/// the wallet's notes of the validator's delegation token, spent or unspent.
+///
+/// Delegations and undelegations are priced at the rate of the epoch after
+/// the one they happen in, so the delegation tokens that accrue rewards over
+/// an epoch are exactly the ones held at its start.
+pub(crate) fn delegation_history(
+    rates: &[(RateData, Penalty)],
+    notes: &[SpendableNoteRecord],
+    epoch_duration: u64,
+) -> Vec<DelegationHistoryRecord> {
+    let mut history = Vec::new();
+
+    for (i, (rate_data, _)) in rates.iter().enumerate() {
+        let start_height = Epoch {
+            index: rate_data.epoch_index,
+            duration: epoch_duration,
+        }
+        .start_height()
+        .value();
+
+        let delegation_amount = notes
+            .iter()
+            .filter(|record| {
+                record.height_created < start_height
+                    && record
+                        .height_spent
+                        .map_or(true, |height_spent| height_spent >= start_height)
+            })
+            .map(|record| u64::from(record.note.amount()))
+            .sum::<u64>();
+        if delegation_amount == 0 {
+            continue;
+        }
+        let unbonded_amount = rate_data.unbonded_amount(delegation_amount);
+
+        // The rewards and slashing penalties for the epoch show up in the
+        // rate of the following one.
+        let (reward_amount, slashed_amount) = match rates.get(i + 1) {
+            Some((next_rate_data, penalty))
+                if next_rate_data.epoch_index == rate_data.epoch_index + 1 =>
+            {
+                let next_unbonded_amount = next_rate_data.unbonded_amount(delegation_amount);
+                // Undo the penalty to recover what the delegation would have
+                // been worth without slashing.
+                let retained = 1_0000_0000 - penalty.0.min(1_0000_0000);
+                let unslashed_amount = if retained == 0 {
+                    unbonded_amount
+                } else {
+                    (next_unbonded_amount as u128 * 1_0000_0000 / retained as u128) as u64
+                };
+                (
+                    Some(unslashed_amount.saturating_sub(unbonded_amount).into()),
+                    Some(unslashed_amount.saturating_sub(next_unbonded_amount).into()),
+                )
+            }
+            _ => (None, None),
+        };
+
+        history.push(DelegationHistoryRecord {
+            rate_data: rate_data.clone(),
+            delegation_amount: delegation_amount.into(),
+            unbonded_amount: unbonded_amount.into(),
+            reward_amount,
+            slashed_amount,
+        });
+    }
+
+    history
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_chain::{test_keys, NoteSource};
+    use penumbra_crypto::{
+        keys::AddressIndex,
+        rdsa::SigningKey,
+        stake::{DelegationToken, IdentityKey},
+        Fq, Note, Nullifier, Value,
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+
+    const EPOCH_DURATION: u64 = 10;
+
+    fn rate(identity_key: IdentityKey, epoch_index: u64, exchange_rate: u64) -> RateData {
+        RateData {
+            identity_key,
+            epoch_index,
+            validator_reward_rate: 0,
+            validator_exchange_rate: exchange_rate,
+        }
+    }
+
+    fn note(
+        identity_key: IdentityKey,
+        amount: u64,
+        height_created: u64,
+        height_spent: Option<u64>,
+    ) -> SpendableNoteRecord {
+        let note = Note::generate(
+            &mut OsRng,
+            &*test_keys::ADDRESS_0,
+            Value {
+                amount: amount.into(),
+                asset_id: DelegationToken::new(identity_key).id(),
+            },
+        );
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            note,
+            address_index: AddressIndex::from(0u32),
+            nullifier: Nullifier(Fq::from(height_created)),
+            height_created,
+            height_spent,
+            position: 0u64.into(),
+            source: NoteSource::default(),
+        }
+    }
+
+    fn amounts(record: &DelegationHistoryRecord) -> (u64, u64, Option<u64>, Option<u64>) {
+        (
+            record.delegation_amount.into(),
+            record.unbonded_amount.into(),
+            record.reward_amount.map(Into::into),
+            record.slashed_amount.map(Into::into),
+        )
+    }
+
+    #[test]
+    fn rewards_and_slashing_follow_rate_changes() {
+        let ik = IdentityKey((&SigningKey::new(OsRng)).into());
+        let rates = vec![
+            (rate(ik, 1, 1_0000_0000), Penalty(0)),
+            (rate(ik, 2, 1_1000_0000), Penalty(0)),
+            // A 10% penalty was applied at the start of epoch 3, on top of the
+            // 10% reward over epoch 2.
+            (rate(ik, 3, 1_0890_0000), Penalty(1000_0000)),
+        ];
+        let notes = vec![note(ik, 1000, 5, None)];
+
+        let history = delegation_history(&rates, &notes, EPOCH_DURATION);
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(amounts(&history[0]), (1000, 1000, Some(100), Some(0)));
+        assert_eq!(amounts(&history[1]), (1000, 1100, Some(110), Some(121)));
+        // The last epoch hasn't ended, so its rewards aren't known yet.
+        assert_eq!(amounts(&history[2]), (1000, 1089, None, None));
+    }
+
+    #[test]
+    fn partial_undelegation_reduces_later_delegation() {
+        let ik = IdentityKey((&SigningKey::new(OsRng)).into());
+        let rates = vec![
+            (rate(ik, 1, 1_0000_0000), Penalty(0)),
+            (rate(ik, 2, 1_0000_0000), Penalty(0)),
+        ];
+        // Undelegating 200 of the 600 delegation tokens during epoch 1 spends
+        // the original note, and returns the rest as change.
+        let notes = vec![note(ik, 600, 5, Some(15)), note(ik, 400, 15, None)];
+
+        let history = delegation_history(&rates, &notes, EPOCH_DURATION);
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(amounts(&history[0]), (600, 600, Some(0), Some(0)));
+        assert_eq!(amounts(&history[1]), (400, 400, None, None));
+    }
+
+    #[test]
+    fn epochs_without_delegation_are_skipped() {
+        let ik = IdentityKey((&SigningKey::new(OsRng)).into());
+        let rates = (1..=4)
+            .map(|epoch_index| (rate(ik, epoch_index, 1_0000_0000), Penalty(0)))
+            .collect::<Vec<_>>();
+
+        assert!(delegation_history(&rates, &[], EPOCH_DURATION).is_empty());
+
+        // Delegated during epoch 2, and fully undelegated during epoch 3, so
+        // only held at the start of epoch 3.
+        let notes = vec![note(ik, 500, 25, Some(35))];
+        let history = delegation_history(&rates, &notes, EPOCH_DURATION);
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].rate_data.epoch_index, 3);
+        assert_eq!(amounts(&history[0]), (500, 500, Some(0), Some(0)));
+    }
+}
@@ -1,6 +1,8 @@
 #![recursion_limit = "256"]
 mod client;
+mod delegation_history;
 mod metrics;
+mod multi;
 mod note_record;
 mod planner;
 mod service;
@@ -8,15 +10,21 @@ mod status;
 mod storage;
 mod swap_record;
 mod sync;
+mod unbonding;
+mod voting_power;
 mod worker;
 
 use worker::Worker;
 
 pub use crate::metrics::register_metrics;
 pub use client::ViewClient;
+pub use delegation_history::DelegationHistoryRecord;
+pub use multi::MultiViewService;
 pub use note_record::SpendableNoteRecord;
 pub use planner::Planner;
 pub use service::ViewService;
 pub use status::StatusStreamResponse;
 pub use storage::Storage;
 pub use swap_record::SwapRecord;
+pub use unbonding::Unbonding;
+pub use voting_power::VotingPowerRecord;
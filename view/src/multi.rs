@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use penumbra_crypto::keys::AccountGroupId;
+use penumbra_proto::{
+    core::crypto::v1alpha1 as pbc,
+    view::v1alpha1::{self as pb, view_protocol_service_server::ViewProtocolService},
+};
+use tonic::async_trait;
+
+use crate::ViewService;
+
+/// A view service which synchronizes several full viewing keys at once, one per account.
+///
+/// Each account is backed by its own [`ViewService`], with its own storage and sync task.
+/// Requests which name an account group are routed to that account's service; requests which
+/// don't (such as address lookups or transaction building) are routed to the default account.
+#[derive(Clone)]
+pub struct MultiViewService {
+    services: BTreeMap<AccountGroupId, ViewService>,
+    default: AccountGroupId,
+}
+
+impl MultiViewService {
+    /// Constructs a new [`MultiViewService`] from the services for each account, routing
+    /// requests which don't name an account group to the first one.
+    pub fn new(services: Vec<ViewService>) -> anyhow::Result<Self> {
+        let mut accounts = BTreeMap::new();
+        let mut default = None;
+        for service in services {
+            let account_group_id = service.account_group_id();
+            default.get_or_insert(account_group_id);
+            if accounts.insert(account_group_id, service).is_some() {
+                return Err(anyhow!("account group {account_group_id} is synced twice"));
+            }
+        }
+
+        Ok(Self {
+            services: accounts,
+            default: default.ok_or_else(|| anyhow!("no accounts to sync"))?,
+        })
+    }
+
+    /// The services for each account, keyed by account group.
+    pub fn services(&self) -> &BTreeMap<AccountGroupId, ViewService> {
+        &self.services
+    }
+
+    fn route(
+        &self,
+        account_group_id: Option<&pbc::AccountGroupId>,
+    ) -> Result<&ViewService, tonic::Status> {
+        let account_group_id = match account_group_id {
+            Some(id) => AccountGroupId::try_from(id.clone())
+                .map_err(|e| tonic::Status::new(tonic::Code::InvalidArgument, format!("{e:#}")))?,
+            None => self.default,
+        };
+        self.services
+            .get(&account_group_id)
+            .ok_or_else(|| tonic::Status::new(tonic::Code::InvalidArgument, "Invalid account ID"))
+    }
+
+    fn default_service(&self) -> &ViewService {
+        &self.services[&self.default]
+    }
+}
+
+#[async_trait]
+impl ViewProtocolService for MultiViewService {
+    type NotesStream = <ViewService as ViewProtocolService>::NotesStream;
+    type NotesForVotingStream = <ViewService as ViewProtocolService>::NotesForVotingStream;
+    type DelegationHistoryStream = <ViewService as ViewProtocolService>::DelegationHistoryStream;
+    type UnbondingsStream = <ViewService as ViewProtocolService>::UnbondingsStream;
+    type VotingPowerStream = <ViewService as ViewProtocolService>::VotingPowerStream;
+    type WitnessAndBuildStream = <ViewService as ViewProtocolService>::WitnessAndBuildStream;
+    type AssetsStream = <ViewService as ViewProtocolService>::AssetsStream;
+    type StatusStreamStream = <ViewService as ViewProtocolService>::StatusStreamStream;
+    type TransactionHashesStream = <ViewService as ViewProtocolService>::TransactionHashesStream;
+    type TransactionsStream = <ViewService as ViewProtocolService>::TransactionsStream;
+    type BalanceByAddressStream = <ViewService as ViewProtocolService>::BalanceByAddressStream;
+
+    async fn broadcast_transaction(
+        &self,
+        request: tonic::Request<pb::BroadcastTransactionRequest>,
+    ) -> Result<tonic::Response<pb::BroadcastTransactionResponse>, tonic::Status> {
+        ViewProtocolService::broadcast_transaction(self.default_service(), request).await
+    }
+
+    async fn transaction_planner(
+        &self,
+        request: tonic::Request<pb::TransactionPlannerRequest>,
+    ) -> Result<tonic::Response<pb::TransactionPlannerResponse>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::transaction_planner(service, request).await
+    }
+
+    async fn address_by_index(
+        &self,
+        request: tonic::Request<pb::AddressByIndexRequest>,
+    ) -> Result<tonic::Response<pb::AddressByIndexResponse>, tonic::Status> {
+        ViewProtocolService::address_by_index(self.default_service(), request).await
+    }
+
+    async fn index_by_address(
+        &self,
+        request: tonic::Request<pb::IndexByAddressRequest>,
+    ) -> Result<tonic::Response<pb::IndexByAddressResponse>, tonic::Status> {
+        ViewProtocolService::index_by_address(self.default_service(), request).await
+    }
+
+    async fn ephemeral_address(
+        &self,
+        request: tonic::Request<pb::EphemeralAddressRequest>,
+    ) -> Result<tonic::Response<pb::EphemeralAddressResponse>, tonic::Status> {
+        ViewProtocolService::ephemeral_address(self.default_service(), request).await
+    }
+
+    async fn transaction_perspective(
+        &self,
+        request: tonic::Request<pb::TransactionPerspectiveRequest>,
+    ) -> Result<tonic::Response<pb::TransactionPerspectiveResponse>, tonic::Status> {
+        ViewProtocolService::transaction_perspective(self.default_service(), request).await
+    }
+
+    async fn swap_by_commitment(
+        &self,
+        request: tonic::Request<pb::SwapByCommitmentRequest>,
+    ) -> Result<tonic::Response<pb::SwapByCommitmentResponse>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::swap_by_commitment(service, request).await
+    }
+
+    async fn balance_by_address(
+        &self,
+        request: tonic::Request<pb::BalanceByAddressRequest>,
+    ) -> Result<tonic::Response<Self::BalanceByAddressStream>, tonic::Status> {
+        ViewProtocolService::balance_by_address(self.default_service(), request).await
+    }
+
+    async fn note_by_commitment(
+        &self,
+        request: tonic::Request<pb::NoteByCommitmentRequest>,
+    ) -> Result<tonic::Response<pb::NoteByCommitmentResponse>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::note_by_commitment(service, request).await
+    }
+
+    async fn nullifier_status(
+        &self,
+        request: tonic::Request<pb::NullifierStatusRequest>,
+    ) -> Result<tonic::Response<pb::NullifierStatusResponse>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::nullifier_status(service, request).await
+    }
+
+    async fn status(
+        &self,
+        request: tonic::Request<pb::StatusRequest>,
+    ) -> Result<tonic::Response<pb::StatusResponse>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::status(service, request).await
+    }
+
+    async fn status_stream(
+        &self,
+        request: tonic::Request<pb::StatusStreamRequest>,
+    ) -> Result<tonic::Response<Self::StatusStreamStream>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::status_stream(service, request).await
+    }
+
+    async fn notes(
+        &self,
+        request: tonic::Request<pb::NotesRequest>,
+    ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::notes(service, request).await
+    }
+
+    async fn notes_for_voting(
+        &self,
+        request: tonic::Request<pb::NotesForVotingRequest>,
+    ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::notes_for_voting(service, request).await
+    }
+
+    async fn delegation_history(
+        &self,
+        request: tonic::Request<pb::DelegationHistoryRequest>,
+    ) -> Result<tonic::Response<Self::DelegationHistoryStream>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::delegation_history(service, request).await
+    }
+
+    async fn unbondings(
+        &self,
+        request: tonic::Request<pb::UnbondingsRequest>,
+    ) -> Result<tonic::Response<Self::UnbondingsStream>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::unbondings(service, request).await
+    }
+
+    async fn voting_power(
+        &self,
+        request: tonic::Request<pb::VotingPowerRequest>,
+    ) -> Result<tonic::Response<Self::VotingPowerStream>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::voting_power(service, request).await
+    }
+
+    async fn assets(
+        &self,
+        request: tonic::Request<pb::AssetsRequest>,
+    ) -> Result<tonic::Response<Self::AssetsStream>, tonic::Status> {
+        ViewProtocolService::assets(self.default_service(), request).await
+    }
+
+    async fn transaction_hashes(
+        &self,
+        request: tonic::Request<pb::TransactionHashesRequest>,
+    ) -> Result<tonic::Response<Self::TransactionHashesStream>, tonic::Status> {
+        ViewProtocolService::transaction_hashes(self.default_service(), request).await
+    }
+
+    async fn transactions(
+        &self,
+        request: tonic::Request<pb::TransactionsRequest>,
+    ) -> Result<tonic::Response<Self::TransactionsStream>, tonic::Status> {
+        ViewProtocolService::transactions(self.default_service(), request).await
+    }
+
+    async fn transaction_by_hash(
+        &self,
+        request: tonic::Request<pb::TransactionByHashRequest>,
+    ) -> Result<tonic::Response<pb::TransactionByHashResponse>, tonic::Status> {
+        ViewProtocolService::transaction_by_hash(self.default_service(), request).await
+    }
+
+    async fn witness(
+        &self,
+        request: tonic::Request<pb::WitnessRequest>,
+    ) -> Result<tonic::Response<pb::WitnessResponse>, tonic::Status> {
+        let service = self.route(request.get_ref().account_group_id.as_ref())?;
+        ViewProtocolService::witness(service, request).await
+    }
+
+    async fn witness_and_build(
+        &self,
+        request: tonic::Request<pb::WitnessAndBuildRequest>,
+    ) -> Result<tonic::Response<Self::WitnessAndBuildStream>, tonic::Status> {
+        ViewProtocolService::witness_and_build(self.default_service(), request).await
+    }
+
+    async fn chain_parameters(
+        &self,
+        request: tonic::Request<pb::ChainParametersRequest>,
+    ) -> Result<tonic::Response<pb::ChainParametersResponse>, tonic::Status> {
+        ViewProtocolService::chain_parameters(self.default_service(), request).await
+    }
+
+    async fn fmd_parameters(
+        &self,
+        request: tonic::Request<pb::FmdParametersRequest>,
+    ) -> Result<tonic::Response<pb::FmdParametersResponse>, tonic::Status> {
+        ViewProtocolService::fmd_parameters(self.default_service(), request).await
+    }
+}
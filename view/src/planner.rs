@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt::{self, Debug, Formatter},
     mem,
 };
@@ -16,15 +16,15 @@ use penumbra_crypto::{
     memo::MemoPlaintext,
     stake::IdentityKey,
     transaction::Fee,
-    Address, Note, Value,
+    Address, Note, Nullifier, Value,
 };
 use penumbra_crypto::{dex::lp::Reserves, Balance};
 use penumbra_proto::view::v1alpha1::{NotesForVotingRequest, NotesRequest};
 use penumbra_tct as tct;
 use penumbra_transaction::{
     action::{
-        DaoDeposit, PositionOpen, Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-        ValidatorVote, Vote,
+        DaoDeposit, Ics20Withdrawal, PositionOpen, Proposal, ProposalDepositClaim, ProposalSubmit,
+        ProposalWithdraw, RedelegateClaim, Unjail, ValidatorVote, Vote,
     },
     plan::{
         ActionPlan, DelegatorVotePlan, MemoPlan, OutputPlan, SpendPlan, SwapClaimPlan, SwapPlan,
@@ -258,6 +258,27 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation to this transaction, moving `delegation_amount` of the
+    /// delegation tokens described by `from_rate_data` into the pool described by
+    /// `to_rate_data`.
+    ///
+    /// The `end_epoch_index` is the epoch in which undelegating from the source
+    /// validator would have completed.
+    #[instrument(skip(self))]
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from_rate_data: RateData,
+        to_rate_data: RateData,
+        end_epoch_index: u64,
+    ) -> &mut Self {
+        let redelegation = from_rate_data
+            .build_redelegate(&to_rate_data, delegation_amount, end_epoch_index)
+            .into();
+        self.action(redelegation);
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     #[instrument(skip(self))]
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
@@ -265,6 +286,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegate claim to this transaction.
+    #[instrument(skip(self))]
+    pub fn redelegate_claim(&mut self, claim: RedelegateClaim) -> &mut Self {
+        self.action(ActionPlan::RedelegateClaim(claim));
+        self
+    }
+
     /// Upload a validator definition in this transaction.
     #[instrument(skip(self))]
     pub fn validator_definition(&mut self, new_validator: validator::Definition) -> &mut Self {
@@ -315,6 +343,16 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add an outbound ICS-20 withdrawal to this transaction.
+    ///
+    /// The withdrawn value is spent from the transaction's balance, so spends will be filled in
+    /// automatically to cover it.
+    #[instrument(skip(self))]
+    pub fn ics20_withdrawal(&mut self, withdrawal: Ics20Withdrawal) -> &mut Self {
+        self.action(ActionPlan::Ics20Withdrawal(withdrawal));
+        self
+    }
+
     /// Cast a validator vote in this transaction.
     #[instrument(skip(self))]
     pub fn validator_vote(&mut self, vote: ValidatorVote) -> &mut Self {
@@ -322,6 +360,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Release a validator from jail in this transaction.
+    #[instrument(skip(self))]
+    pub fn unjail(&mut self, unjail: Unjail) -> &mut Self {
+        self.action(ActionPlan::Unjail(unjail));
+        self
+    }
+
     /// Vote with all possible vote weight on a given proposal.
     ///
     /// Voting twice on the same proposal in the same planner will overwrite the previous vote.
@@ -348,9 +393,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Vote with a specific positioned note in the transaction.
     ///
+    /// If the note has already been used to vote on the proposal, `replaces_previous` must be set,
+    /// and the vote will replace the earlier one.
+    ///
     /// If you don't use this method to specify votes, they will be filled in automatically from the
     /// implied voting intent by [`vote`](Planner::vote) when the plan is
     /// [`finish`](Planner::finish)ed.
+    #[allow(clippy::too_many_arguments)]
     #[instrument(skip(self, start_position))]
     pub fn delegator_vote_precise(
         &mut self,
@@ -360,6 +409,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
+        replaces_previous: bool,
     ) -> &mut Self {
         let vote = DelegatorVotePlan::new(
             &mut self.rng,
@@ -369,6 +419,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             note,
             position,
             unbonded_amount,
+            replaces_previous,
         )
         .into();
         self.action(vote);
@@ -408,6 +459,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             let notes = view.notes_for_voting(request).await?;
             voting_notes.push(notes);
         }
+        // Votes with notes that have already voted on a proposal replace the earlier votes
+        let mut voted_nullifiers = BTreeSet::new();
+        for &proposal in self.vote_intents.keys() {
+            for record in view.voting_power(account_group_id, proposal).await? {
+                if record.voted {
+                    voted_nullifiers.insert((proposal, record.note_record.nullifier));
+                }
+            }
+        }
 
         // Plan the transaction using the gathered information
 
@@ -418,6 +478,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             source,
             spendable_notes,
             voting_notes,
+            voted_nullifiers,
             self_address,
         )
     }
@@ -427,6 +488,9 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     /// collected responses to the requests generated by an immediately preceding call to
     /// [`Planner::note_requests`].
     ///
+    /// Votes with any of the `voted_nullifiers`, keyed by proposal, are planned as replacements
+    /// of the earlier votes made with them.
+    ///
     /// Clears the contents of the planner, which can be re-used.
     #[instrument(skip(self, chain_params, fmd_params, self_address, spendable_notes))]
     pub fn plan_with_spendable_and_votable_notes(
@@ -436,6 +500,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         source: AddressIndex,
         spendable_notes: Vec<SpendableNoteRecord>,
         votable_notes: Vec<Vec<(SpendableNoteRecord, IdentityKey)>>,
+        voted_nullifiers: BTreeSet<(u64, Nullifier)>,
         self_address: Address,
     ) -> anyhow::Result<TransactionPlan> {
         tracing::debug!(plan = ?self.plan, balance = ?self.balance, "finalizing transaction");
@@ -474,7 +539,9 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                 // at voting start time. If the validator was not active at the start of the
                 // proposal, the vote will be rejected by stateful verification, so skip the note
                 // and continue to the next one.
-                let Some(rate_data) = rate_data.get(&identity_key) else { continue };
+                let Some(rate_data) = rate_data.get(&identity_key) else {
+                    continue;
+                };
                 let unbonded_amount = rate_data
                     .unbonded_amount(record.note.amount().into())
                     .into();
@@ -486,6 +553,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     self.spend(record.note.clone(), record.position);
                 }
 
+                let replaces_previous = voted_nullifiers.contains(&(proposal, record.nullifier));
                 self.delegator_vote_precise(
                     proposal,
                     start_position,
@@ -493,6 +561,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     record.note,
                     record.position,
                     unbonded_amount,
+                    replaces_previous,
                 );
 
                 voted = true;
@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     pin::Pin,
     sync::{Arc, Mutex},
 };
@@ -8,16 +8,20 @@ use anyhow::{anyhow, Context};
 use async_stream::try_stream;
 use camino::Utf8Path;
 use futures::stream::{StreamExt, TryStreamExt};
+use penumbra_chain::Epoch;
+use penumbra_component::stake::rate::RateData;
 use penumbra_crypto::{
     asset,
     keys::{AccountGroupId, AddressIndex, FullViewingKey},
+    stake::IdentityKey,
     transaction::Fee,
     Address, AddressView, Amount,
 };
 use penumbra_proto::{
     client::v1alpha1::{
+        specific_query_service_client::SpecificQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
-        GetStatusRequest,
+        GetStatusRequest, ProposalInfoRequest, ProposalRateDataRequest, ValidatorPenaltyRequest,
     },
     core::crypto::v1alpha1 as pbc,
     view::v1alpha1::{
@@ -31,6 +35,8 @@ use penumbra_proto::{
     DomainType,
 };
 use penumbra_tct::{Commitment, Proof};
+#[cfg(feature = "parallel")]
+use penumbra_transaction::plan::{BuildOptions, BuildProgress};
 use penumbra_transaction::{
     plan::{ActionPlan, TransactionPlan},
     AuthorizationData, Transaction, TransactionPerspective, WitnessData,
@@ -39,10 +45,15 @@ use rand::Rng;
 use rand_core::OsRng;
 use tokio::sync::{watch, RwLock};
 use tokio_stream::wrappers::WatchStream;
+#[cfg(feature = "parallel")]
+use tokio_util::sync::CancellationToken;
 use tonic::{async_trait, transport::Channel};
 use tracing::instrument;
 
-use crate::{Planner, Storage, Worker};
+use crate::{
+    delegation_history::delegation_history, Planner, SpendableNoteRecord, Storage, Unbonding,
+    VotingPowerRecord, Worker,
+};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -109,6 +120,11 @@ impl ViewService {
         })
     }
 
+    /// The account group whose full viewing key this service is synchronizing.
+    pub fn account_group_id(&self) -> AccountGroupId {
+        self.account_group_id
+    }
+
     async fn check_fvk(&self, fvk: Option<&pbc::AccountGroupId>) -> Result<(), tonic::Status> {
         // Takes an Option to avoid making the caller handle missing fields,
         // should error on None or wrong account ID
@@ -222,6 +238,14 @@ impl ViewService {
         Ok(client)
     }
 
+    async fn specific_client(&self) -> Result<SpecificQueryServiceClient<Channel>, anyhow::Error> {
+        let client =
+            SpecificQueryServiceClient::connect(format!("http://{}:{}", self.node, self.pd_port))
+                .await?;
+
+        Ok(client)
+    }
+
     /// Return the latest block height known by the fullnode or its peers, as
     /// well as whether the fullnode is caught up with that height.
     #[instrument(skip(self))]
@@ -291,6 +315,18 @@ impl ViewProtocolService for ViewService {
     type NotesForVotingStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::NotesForVotingResponse, tonic::Status>> + Send>,
     >;
+    type DelegationHistoryStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::DelegationHistoryResponse, tonic::Status>> + Send,
+        >,
+    >;
+    type UnbondingsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::UnbondingsResponse, tonic::Status>> + Send>>;
+    type VotingPowerStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::VotingPowerResponse, tonic::Status>> + Send>>;
+    type WitnessAndBuildStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::WitnessAndBuildResponse, tonic::Status>> + Send>,
+    >;
     type AssetsStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::AssetsResponse, tonic::Status>> + Send>>;
     type StatusStreamStream = Pin<
@@ -825,6 +861,250 @@ impl ViewProtocolService for ViewService {
         ))
     }
 
+    async fn delegation_history(
+        &self,
+        request: tonic::Request<pb::DelegationHistoryRequest>,
+    ) -> Result<tonic::Response<Self::DelegationHistoryStream>, tonic::Status> {
+        self.check_worker().await?;
+        self.check_fvk(request.get_ref().account_group_id.as_ref())
+            .await?;
+
+        let identity_key = request
+            .into_inner()
+            .identity_key
+            .map(IdentityKey::try_from)
+            .transpose()
+            .map_err(|_| tonic::Status::invalid_argument("invalid identity key"))?;
+
+        let epoch_duration = self
+            .storage
+            .chain_params()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting chain params: {e}")))?
+            .epoch_duration;
+
+        // Group the delegation notes by validator.
+        let mut notes_by_validator = BTreeMap::<IdentityKey, Vec<SpendableNoteRecord>>::new();
+        for (record, validator) in self
+            .storage
+            .delegation_notes()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {e}")))?
+        {
+            if identity_key.map_or(true, |identity_key| identity_key == validator) {
+                notes_by_validator
+                    .entry(validator)
+                    .or_default()
+                    .push(record);
+            }
+        }
+
+        let mut history = Vec::new();
+        for (validator, notes) in notes_by_validator {
+            let rates = self
+                .storage
+                .validator_rates(&validator)
+                .await
+                .map_err(|e| {
+                    tonic::Status::unavailable(format!("error fetching validator rates: {e}"))
+                })?;
+            history.extend(delegation_history(&rates, &notes, epoch_duration));
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(history.into_iter().map(|record| Ok(record.into()))).boxed(),
+        ))
+    }
+
+    async fn unbondings(
+        &self,
+        request: tonic::Request<pb::UnbondingsRequest>,
+    ) -> Result<tonic::Response<Self::UnbondingsStream>, tonic::Status> {
+        self.check_worker().await?;
+        self.check_fvk(request.get_ref().account_group_id.as_ref())
+            .await?;
+
+        let claimable_only = request.get_ref().claimable_only;
+
+        let chain_params =
+            self.storage.chain_params().await.map_err(|e| {
+                tonic::Status::unavailable(format!("error getting chain params: {e}"))
+            })?;
+        let sync_height = self
+            .storage
+            .last_sync_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting sync height: {e}")))?
+            .unwrap_or(0);
+        let current_epoch = Epoch::from_height(sync_height, chain_params.epoch_duration);
+
+        // Group the unbonding notes by token and by the address holding them,
+        // since each group is claimed separately.
+        let mut unbondings = BTreeMap::<(asset::Id, AddressIndex), Unbonding>::new();
+        for (record, token) in self.storage.unbonding_notes().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error fetching unbonding notes: {e}"))
+        })? {
+            unbondings
+                .entry((token.id(), record.address_index))
+                .or_insert_with(|| Unbonding {
+                    validator_identity: token.validator(),
+                    start_epoch_index: token.start_epoch_index(),
+                    end_epoch_index: token.end_epoch_index(),
+                    address_index: record.address_index,
+                    note_records: Vec::new(),
+                    claimable: token.end_epoch_index() <= current_epoch.index,
+                    penalty: None,
+                })
+                .note_records
+                .push(record);
+        }
+
+        let mut responses = Vec::new();
+        let mut specific_client = None;
+        for mut unbonding in unbondings.into_values() {
+            if !unbonding.claimable {
+                if !claimable_only {
+                    responses.push(unbonding.into());
+                }
+                continue;
+            }
+
+            // The penalty over the unbonding period is final once it ends, so
+            // fetch it so that the unbonding tokens can be claimed.
+            if specific_client.is_none() {
+                specific_client = Some(self.specific_client().await.map_err(|e| {
+                    tonic::Status::unavailable(format!("error connecting to pd: {e}"))
+                })?);
+            }
+            let penalty = specific_client
+                .as_mut()
+                .expect("specific client was just connected")
+                .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
+                    chain_id: chain_params.chain_id.clone(),
+                    identity_key: Some(unbonding.validator_identity.into()),
+                    start_epoch_index: unbonding.start_epoch_index,
+                    end_epoch_index: unbonding.end_epoch_index,
+                }))
+                .await?
+                .into_inner()
+                .penalty
+                .ok_or_else(|| tonic::Status::unavailable("no penalty returned for validator"))?
+                .try_into()
+                .map_err(|e| tonic::Status::unavailable(format!("invalid penalty: {e}")))?;
+            unbonding.penalty = Some(penalty);
+
+            responses.push(unbonding.into());
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses.into_iter().map(Ok)).boxed(),
+        ))
+    }
+
+    async fn voting_power(
+        &self,
+        request: tonic::Request<pb::VotingPowerRequest>,
+    ) -> Result<tonic::Response<Self::VotingPowerStream>, tonic::Status> {
+        self.check_worker().await?;
+        self.check_fvk(request.get_ref().account_group_id.as_ref())
+            .await?;
+
+        let proposal_id = request.get_ref().proposal_id;
+        let address_index = request
+            .get_ref()
+            .address_index
+            .to_owned()
+            .map(AddressIndex::try_from)
+            .transpose()
+            .map_err(|_| tonic::Status::invalid_argument("invalid address index"))?;
+
+        let chain_id = self
+            .storage
+            .chain_params()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting chain params: {e}")))?
+            .chain_id;
+        let mut client = self
+            .specific_client()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error connecting to pd: {e}")))?;
+
+        // The notes that can vote are those delegated before the proposal started, and the
+        // voting power they carry is fixed by the exchange rates snapshotted at that point.
+        let start_block_height = client
+            .proposal_info(tonic::Request::new(ProposalInfoRequest {
+                chain_id: chain_id.clone(),
+                proposal_id,
+            }))
+            .await?
+            .into_inner()
+            .start_block_height;
+        let rate_data = client
+            .proposal_rate_data(tonic::Request::new(ProposalRateDataRequest {
+                chain_id,
+                proposal_id,
+            }))
+            .await?
+            .into_inner()
+            .map(|response| {
+                let rate_data: RateData = response?
+                    .rate_data
+                    .ok_or_else(|| tonic::Status::unavailable("missing rate data"))?
+                    .try_into()
+                    .map_err(|e| tonic::Status::unavailable(format!("invalid rate data: {e}")))?;
+                Ok::<_, tonic::Status>((rate_data.identity_key, rate_data))
+            })
+            .try_collect::<BTreeMap<_, _>>()
+            .await?;
+
+        let notes = self
+            .storage
+            .notes_for_voting(address_index, start_block_height)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {e}")))?;
+
+        // Work out which notes have already voted from the wallet's own votes,
+        // rather than asking the full node, which would reveal the nullifiers
+        // of the wallet's unspent notes.
+        let voted_nullifiers = self
+            .storage
+            .transactions(Some(start_block_height), None)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching transactions: {e}")))?
+            .into_iter()
+            .flat_map(|(_, _, tx)| {
+                tx.delegator_votes()
+                    .filter(|vote| vote.body.proposal == proposal_id)
+                    .map(|vote| vote.body.nullifier)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<BTreeSet<_>>();
+
+        let mut responses = Vec::new();
+        for (note_record, identity_key) in notes {
+            let voting_power = rate_data
+                .get(&identity_key)
+                .map(|rate_data| rate_data.unbonded_amount(note_record.note.amount().into()))
+                .unwrap_or(0)
+                .into();
+            let voted = voted_nullifiers.contains(&note_record.nullifier);
+
+            responses.push(
+                VotingPowerRecord {
+                    note_record,
+                    identity_key,
+                    voting_power,
+                    voted,
+                }
+                .into(),
+            );
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses.into_iter().map(Ok)).boxed(),
+        ))
+    }
+
     async fn assets(
         &self,
         request: tonic::Request<pb::AssetsRequest>,
@@ -1053,7 +1333,7 @@ impl ViewProtocolService for ViewService {
     async fn witness_and_build(
         &self,
         request: tonic::Request<pb::WitnessAndBuildRequest>,
-    ) -> Result<tonic::Response<pb::WitnessAndBuildResponse>, tonic::Status> {
+    ) -> Result<tonic::Response<Self::WitnessAndBuildStream>, tonic::Status> {
         let pb::WitnessAndBuildRequest {
             transaction_plan,
             authorization_data,
@@ -1111,16 +1391,55 @@ impl ViewProtocolService for ViewService {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
             })?;
 
-        let transaction = Some(
-            transaction_plan
-                .build(&mut OsRng, &fvk, authorization_data, witness_data)
-                .map_err(|_| tonic::Status::failed_precondition("Error building transaction"))?
-                .into(),
-        );
+        #[cfg(not(feature = "parallel"))]
+        let stream = try_stream! {
+            let transaction =
+                transaction_plan.build(&mut OsRng, &fvk, authorization_data, witness_data)?;
+            yield pb::WitnessAndBuildResponse {
+                transaction: Some(transaction.into()),
+                progress: None,
+            };
+        };
 
-        Ok(tonic::Response::new(pb::WitnessAndBuildResponse {
-            transaction,
-        }))
+        #[cfg(feature = "parallel")]
+        let stream = {
+            let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+            let cancellation_token = CancellationToken::new();
+            // Cancel the build if the client goes away, since that drops the stream.
+            let cancel_on_drop = cancellation_token.clone().drop_guard();
+            let options = BuildOptions::new()
+                .cancellation_token(cancellation_token)
+                .progress(progress_tx);
+            let build = tokio::spawn(async move {
+                transaction_plan
+                    .build_concurrent_with(OsRng, &fvk, authorization_data, witness_data, options)
+                    .await
+            });
+
+            try_stream! {
+                let _cancel_on_drop = cancel_on_drop;
+                // The progress channel closes once the build is done with it.
+                while let Some(progress) = progress_rx.recv().await {
+                    yield pb::WitnessAndBuildResponse {
+                        transaction: None,
+                        progress: Some(build_progress_to_proto(progress)),
+                    };
+                }
+                let transaction = build.await??;
+                yield pb::WitnessAndBuildResponse {
+                    transaction: Some(transaction.into()),
+                    progress: None,
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::failed_precondition(format!("error building transaction: {e:#}"))
+                })
+                .boxed(),
+        ))
     }
 
     async fn chain_parameters(
@@ -1159,3 +1478,32 @@ impl ViewProtocolService for ViewService {
         Ok(tonic::Response::new(response))
     }
 }
+
+#[cfg(feature = "parallel")]
+fn build_progress_to_proto(progress: BuildProgress) -> pb::BuildProgress {
+    match progress {
+        BuildProgress::ProofStarted {
+            index,
+            total,
+            action,
+        } => pb::BuildProgress {
+            index: index as u64,
+            total: total as u64,
+            action: action.to_string(),
+            finished: false,
+            duration_ms: 0,
+        },
+        BuildProgress::ProofFinished {
+            index,
+            total,
+            action,
+            duration,
+        } => pb::BuildProgress {
+            index: index as u64,
+            total: total as u64,
+            action: action.to_string(),
+            finished: true,
+            duration_ms: duration.as_millis() as u64,
+        },
+    }
+}
@@ -3,10 +3,11 @@ use camino::Utf8Path;
 use futures::Future;
 use parking_lot::Mutex;
 use penumbra_chain::params::{ChainParameters, FmdParameters};
+use penumbra_component::stake::rate::RateData;
 use penumbra_crypto::{
     asset::{self, Denom, Id},
     note,
-    stake::{DelegationToken, IdentityKey},
+    stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken},
     Address, Amount, Asset, FieldExt, Fq, FullViewingKey, Note, Nullifier, Rseed, Value,
 };
 use penumbra_proto::{
@@ -838,6 +839,133 @@ impl Storage {
         Ok(results)
     }
 
+    /// Returns all of the wallet's notes of delegation tokens, spent or unspent,
+    /// along with the identity key of the validator each was delegated to.
+    pub async fn delegation_notes(
+        &self,
+    ) -> anyhow::Result<Vec<(SpendableNoteRecord, IdentityKey)>> {
+        let rows = sqlx::query(
+            "SELECT notes.note_commitment,
+                    spendable_notes.height_created,
+                    notes.address,
+                    notes.amount,
+                    notes.asset_id,
+                    notes.rseed,
+                    spendable_notes.address_index,
+                    spendable_notes.source,
+                    spendable_notes.height_spent,
+                    spendable_notes.nullifier,
+                    spendable_notes.position,
+                    assets.denom
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN assets ON notes.asset_id = assets.asset_id
+            WHERE assets.denom LIKE '_delegation\\_%' ESCAPE '\\'",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            let record: SpendableNoteRecord = sqlx::FromRow::from_row(&row)?;
+            let identity_key = DelegationToken::from_str(row.get::<&str, _>("denom"))
+                .context("invalid delegation token denom")?
+                .validator();
+            results.push((record, identity_key));
+        }
+
+        Ok(results)
+    }
+
+    /// Returns the unspent notes holding unbonding tokens, along with the
+    /// unbonding token each one holds.
+    pub async fn unbonding_notes(
+        &self,
+    ) -> anyhow::Result<Vec<(SpendableNoteRecord, UnbondingToken)>> {
+        let rows = sqlx::query(
+            "SELECT notes.note_commitment,
+                    spendable_notes.height_created,
+                    notes.address,
+                    notes.amount,
+                    notes.asset_id,
+                    notes.rseed,
+                    spendable_notes.address_index,
+                    spendable_notes.source,
+                    spendable_notes.height_spent,
+                    spendable_notes.nullifier,
+                    spendable_notes.position,
+                    assets.denom
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN assets ON notes.asset_id = assets.asset_id
+            WHERE assets.denom LIKE 'uunbonding\\_%' ESCAPE '\\'
+            AND spendable_notes.height_spent IS NULL",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            let record: SpendableNoteRecord = sqlx::FromRow::from_row(&row)?;
+            let token = UnbondingToken::from_str(row.get::<&str, _>("denom"))
+                .context("invalid unbonding token denom")?;
+            results.push((record, token));
+        }
+
+        Ok(results)
+    }
+
+    /// Records the rates of validators in some epoch, along with the slashing
+    /// penalty applied to each rate at the start of the epoch.
+    pub async fn record_validator_rates(
+        &self,
+        rates: Vec<(RateData, Penalty)>,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for (rate_data, penalty) in rates {
+            sqlx::query(
+                "INSERT OR REPLACE INTO validator_rates
+                    (identity_key, epoch_index, rate_data, penalty)
+                    VALUES (?, ?, ?, ?)",
+            )
+            .bind(rate_data.identity_key.encode_to_vec())
+            .bind(rate_data.epoch_index as i64)
+            .bind(rate_data.encode_to_vec())
+            .bind(penalty.0 as i64)
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Returns the recorded rates of the given validator, ordered by epoch.
+    pub async fn validator_rates(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> anyhow::Result<Vec<(RateData, Penalty)>> {
+        let rows = sqlx::query(
+            "SELECT rate_data, penalty
+            FROM validator_rates
+            WHERE identity_key = ?
+            ORDER BY epoch_index ASC",
+        )
+        .bind(identity_key.encode_to_vec())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let rate_data = RateData::decode(row.get::<&[u8], _>("rate_data"))?;
+                let penalty = Penalty(row.get::<i64, _>("penalty") as u64);
+                Ok((rate_data, penalty))
+            })
+            .collect()
+    }
+
     pub async fn record_asset(&self, asset: Asset) -> anyhow::Result<()> {
         let mut tx = self.pool.begin().await?;
 
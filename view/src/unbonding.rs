@@ -0,0 +1,117 @@
+use ark_ff::UniformRand;
+use penumbra_crypto::{
+    keys::AddressIndex,
+    stake::{IdentityKey, Penalty, UnbondingToken},
+    Amount, Fr,
+};
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+use penumbra_transaction::plan::UndelegateClaimPlan;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::SpendableNoteRecord;
+
+/// The wallet's unspent unbonding tokens of a single kind, held by a single
+/// address.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::UnbondingsResponse", into = "pb::UnbondingsResponse")]
+pub struct Unbonding {
+    /// The validator the stake was undelegated from.
+    pub validator_identity: IdentityKey,
+    /// The epoch in which unbonding began.
+    pub start_epoch_index: u64,
+    /// The epoch from which the unbonding tokens can be claimed.
+    pub end_epoch_index: u64,
+    /// The address index holding the unbonding tokens.
+    pub address_index: AddressIndex,
+    /// The notes holding the unbonding tokens.
+    pub note_records: Vec<SpendableNoteRecord>,
+    /// Whether the unbonding tokens can be claimed now.
+    pub claimable: bool,
+    /// The slashing penalty to apply when claiming the unbonding tokens, known
+    /// only once they're claimable.
+    pub penalty: Option<Penalty>,
+}
+
+impl Unbonding {
+    /// The unbonding token held by this unbonding.
+    pub fn token(&self) -> UnbondingToken {
+        UnbondingToken::new(
+            self.validator_identity,
+            self.start_epoch_index,
+            self.end_epoch_index,
+        )
+    }
+
+    /// The total amount of unbonding tokens.
+    pub fn unbonding_amount(&self) -> Amount {
+        self.note_records
+            .iter()
+            .map(|record| u64::from(record.note.amount()))
+            .sum::<u64>()
+            .into()
+    }
+
+    /// Plans the claim of these unbonding tokens, if they're claimable.
+    pub fn undelegate_claim_plan<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Option<UndelegateClaimPlan> {
+        if !self.claimable {
+            return None;
+        }
+
+        Some(UndelegateClaimPlan {
+            validator_identity: self.validator_identity,
+            start_epoch_index: self.start_epoch_index,
+            end_epoch_index: self.end_epoch_index,
+            penalty: self.penalty?,
+            unbonding_amount: self.unbonding_amount(),
+            balance_blinding: Fr::rand(rng),
+        })
+    }
+}
+
+impl DomainType for Unbonding {
+    type Proto = pb::UnbondingsResponse;
+}
+
+impl From<Unbonding> for pb::UnbondingsResponse {
+    fn from(v: Unbonding) -> Self {
+        pb::UnbondingsResponse {
+            validator_identity: Some(v.validator_identity.into()),
+            start_epoch_index: v.start_epoch_index,
+            end_epoch_index: v.end_epoch_index,
+            address_index: Some(v.address_index.into()),
+            note_records: v.note_records.into_iter().map(Into::into).collect(),
+            claimable: v.claimable,
+            penalty: v.penalty.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::UnbondingsResponse> for Unbonding {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::UnbondingsResponse) -> Result<Self, Self::Error> {
+        Ok(Unbonding {
+            validator_identity: v
+                .validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing validator identity"))?
+                .try_into()?,
+            start_epoch_index: v.start_epoch_index,
+            end_epoch_index: v.end_epoch_index,
+            address_index: v
+                .address_index
+                .ok_or_else(|| anyhow::anyhow!("missing address index"))?
+                .try_into()?,
+            note_records: v
+                .note_records
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            claimable: v.claimable,
+            penalty: v.penalty.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
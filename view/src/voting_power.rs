@@ -0,0 +1,62 @@
+use penumbra_crypto::{stake::IdentityKey, Amount};
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::SpendableNoteRecord;
+
+/// A delegation note the wallet can vote with on a proposal, along with the
+/// voting power it carries.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::VotingPowerResponse", into = "pb::VotingPowerResponse")]
+pub struct VotingPowerRecord {
+    /// The delegation note that can be voted with.
+    pub note_record: SpendableNoteRecord,
+    /// The validator the note is delegated to.
+    pub identity_key: IdentityKey,
+    /// The voting power of the note, in the staking token, at the validator's
+    /// exchange rate at the start of the proposal.
+    ///
+    /// This is zero if the validator was not active when the proposal started,
+    /// since such notes can't be used to vote.
+    pub voting_power: Amount,
+    /// Whether the note's nullifier has already been used to vote on the proposal,
+    /// as seen in the wallet's own transactions.
+    pub voted: bool,
+}
+
+impl DomainType for VotingPowerRecord {
+    type Proto = pb::VotingPowerResponse;
+}
+
+impl From<VotingPowerRecord> for pb::VotingPowerResponse {
+    fn from(v: VotingPowerRecord) -> Self {
+        pb::VotingPowerResponse {
+            note_record: Some(v.note_record.into()),
+            identity_key: Some(v.identity_key.into()),
+            voting_power: Some(v.voting_power.into()),
+            voted: v.voted,
+        }
+    }
+}
+
+impl TryFrom<pb::VotingPowerResponse> for VotingPowerRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::VotingPowerResponse) -> Result<Self, Self::Error> {
+        Ok(VotingPowerRecord {
+            note_record: v
+                .note_record
+                .ok_or_else(|| anyhow::anyhow!("missing note record"))?
+                .try_into()?,
+            identity_key: v
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key"))?
+                .try_into()?,
+            voting_power: v
+                .voting_power
+                .ok_or_else(|| anyhow::anyhow!("missing voting power"))?
+                .try_into()?,
+            voted: v.voted,
+        })
+    }
+}
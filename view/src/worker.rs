@@ -4,13 +4,14 @@ use std::{
 };
 
 use penumbra_chain::{sync::CompactBlock, Epoch};
-use penumbra_crypto::{Asset, FullViewingKey, Nullifier};
+use penumbra_component::stake::rate::RateData;
+use penumbra_crypto::{stake::Penalty, Asset, FullViewingKey, Nullifier};
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
         oblivious_query_service_client::ObliviousQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, AssetListRequest,
-        CompactBlockRangeRequest, GetBlockByHeightRequest,
+        CompactBlockRangeRequest, GetBlockByHeightRequest, ValidatorRatesRequest,
     },
     DomainType,
 };
@@ -188,6 +189,61 @@ impl Worker {
         Ok(transactions)
     }
 
+    /// Records the rates of the validators we're delegated to in the epoch
+    /// following `epoch`, so that we can track the value of our delegations
+    /// over time.
+    async fn record_validator_rates(&mut self, epoch: Epoch) -> Result<(), anyhow::Error> {
+        // Delegation tokens undelegated during the ending epoch were priced
+        // at the next epoch's rate, so their validators' rates are needed too.
+        let start_height = epoch.start_height().value();
+        let validators = self
+            .storage
+            .delegation_notes()
+            .await?
+            .into_iter()
+            .filter(|(record, _)| {
+                record
+                    .height_spent
+                    .map_or(true, |height_spent| height_spent >= start_height)
+            })
+            .map(|(_, identity_key)| identity_key)
+            .collect::<BTreeSet<_>>();
+        if validators.is_empty() {
+            return Ok(());
+        }
+
+        let next_epoch = epoch.next();
+        tracing::debug!(epoch_index = next_epoch.index, "fetching validator rates");
+
+        // Request the rates of all validators, rather than only the ones we
+        // care about, so that we don't reveal who we're delegated to.
+        let mut rates = Vec::new();
+        let mut stream = self
+            .client
+            .validator_rates(tonic::Request::new(ValidatorRatesRequest {
+                chain_id: self.storage.chain_params().await?.chain_id,
+                epoch_index: next_epoch.index,
+            }))
+            .await?
+            .into_inner();
+        while let Some(response) = stream.message().await? {
+            let rate_data: RateData = response
+                .rate_data
+                .ok_or_else(|| anyhow::anyhow!("empty ValidatorRatesResponse message"))?
+                .try_into()?;
+            if !validators.contains(&rate_data.identity_key) {
+                continue;
+            }
+            let penalty: Penalty = response
+                .penalty
+                .ok_or_else(|| anyhow::anyhow!("empty ValidatorRatesResponse message"))?
+                .try_into()?;
+            rates.push((rate_data, penalty));
+        }
+
+        self.storage.record_validator_rates(rates).await
+    }
+
     pub async fn sync(&mut self) -> Result<(), anyhow::Error> {
         // Do a single sync run, up to whatever the latest block height is
         tracing::info!("starting client sync");
@@ -275,6 +331,15 @@ impl Worker {
             // Release the SCT RwLock
             drop(sct_guard);
 
+            let epoch = Epoch::from_height(height, epoch_duration);
+            if epoch.is_epoch_end(height) {
+                // Delegation history is best-effort, so failing to fetch the rates (for instance,
+                // from a node that doesn't serve them) shouldn't stop the wallet from scanning.
+                if let Err(e) = self.record_validator_rates(epoch).await {
+                    tracing::warn!(?e, epoch = epoch.index, "failed to record validator rates");
+                }
+            }
+
             // Check if we should stop waiting for blocks to arrive, because the view
             // services are dropped and we're supposed to shut down.
             if self.sync_height_tx.is_closed() {
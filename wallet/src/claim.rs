@@ -0,0 +1,41 @@
+use anyhow::Result;
+use penumbra_crypto::{stake::UnbondingToken, transaction::Fee, FullViewingKey};
+use penumbra_custody::CustodyClient;
+use penumbra_transaction::Id;
+use penumbra_view::ViewClient;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{build_transaction, plan};
+
+/// Claims every undelegation that has finished unbonding, into the address index that holds its
+/// unbonding tokens, returning the tokens claimed and the id of each claim transaction.
+///
+/// Each claim is planned only once the previous one is confirmed, so that they don't select the
+/// same note to pay the fee.
+pub async fn claim_undelegations<V, C, R>(
+    fvk: &FullViewingKey,
+    view: &mut V,
+    custody: &mut C,
+    mut rng: R,
+    fee: Fee,
+) -> Result<Vec<(UnbondingToken, Id)>>
+where
+    V: ViewClient,
+    C: CustodyClient,
+    R: RngCore + CryptoRng,
+{
+    let account_group_id = fvk.account_group_id();
+
+    let mut claimed = Vec::new();
+    for unbonding in view.unbondings(account_group_id, true).await? {
+        let plan =
+            plan::undelegate_claim(account_group_id, view, &mut rng, fee.clone(), &unbonding)
+                .await?;
+        let tx = build_transaction(fvk, view, custody, &mut rng, plan).await?;
+        let id = view.broadcast_transaction(tx, true).await?;
+        tracing::info!(%id, token = %unbonding.token(), "claimed undelegation");
+        claimed.push((unbonding.token(), id));
+    }
+
+    Ok(claimed)
+}
@@ -1,51 +1,381 @@
+use std::fmt;
+
 use anyhow::Context;
 use penumbra_crypto::keys::{SeedPhrase, SpendKey};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
-/// A wallet file storing a single spend authority.
+mod encrypted;
+use encrypted::EncryptedKeyStore;
+
+/// The label given to the first account in a wallet.
+pub const DEFAULT_ACCOUNT_LABEL: &str = "default";
+
+/// A wallet file storing one or more spend authorities (accounts).
+///
+/// When the wallet was created from a seed phrase, each account is derived from the seed phrase
+/// at its own index, so that further accounts can be added later.
+///
+/// Wallet files are normally encrypted with a key derived from a passphrase using Argon2id, and
+/// then stored alongside a versioned header recording how to derive the key again. Plaintext
+/// wallet files can still be loaded, and re-saved with a passphrase to encrypt them, but they
+/// never store the seed phrase, only the spend keys of the accounts already derived from it.
 #[serde_as]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "serde_helpers::KeyStoreHelper")]
 pub struct KeyStore {
+    /// The seed phrase the accounts are derived from, if known.
+    ///
+    /// Wallets migrated from a single spend key don't have one, and neither do wallets loaded
+    /// from a plaintext file, so they can't add accounts.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed_phrase: Option<SeedPhrase>,
+    /// The accounts in the wallet, of which the first is the default.
+    accounts: Vec<Account>,
+}
+
+/// A single spend authority within a [`KeyStore`].
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    /// A human-readable name for the account, unique within the wallet.
+    pub label: String,
+    /// The index the account's spend key was derived at.
+    pub index: u64,
     #[serde_as(as = "DisplayFromStr")]
     pub spend_key: SpendKey,
 }
 
+impl fmt::Debug for KeyStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid accidentally printing the seed phrase in log output.
+        f.debug_struct("KeyStore")
+            .field("has_seed_phrase", &self.seed_phrase.is_some())
+            .field("accounts", &self.accounts)
+            .finish()
+    }
+}
+
 impl KeyStore {
-    /// Write the wallet data to the provided path.
-    pub fn save(&self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+    /// Write the wallet data to the provided path, encrypted with `passphrase` if one is given.
+    pub fn save(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        passphrase: Option<&str>,
+    ) -> anyhow::Result<()> {
         if path.as_ref().exists() {
             return Err(anyhow::anyhow!(
                 "Wallet file already exists, refusing to overwrite it"
             ));
         }
+        self.write(path, passphrase)
+    }
+
+    /// Replace the existing wallet data at the provided path, such as after adding an account.
+    pub fn update(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        passphrase: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(anyhow::anyhow!("No wallet file exists at {path:?}"));
+        }
+
+        // Write the new wallet data alongside the old, so that the wallet file is never left
+        // half-written.
+        let tmp_path = path.with_extension("tmp");
+        self.write(&tmp_path, passphrase)?;
+        std::fs::rename(&tmp_path, path).with_context(|| format!("can't replace file {path:?}"))?;
+        Ok(())
+    }
+
+    fn write(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        passphrase: Option<&str>,
+    ) -> anyhow::Result<()> {
         use std::io::Write;
         let path = path.as_ref();
-        let mut file =
-            std::fs::File::create(path).with_context(|| format!("can't create file {path:?}"))?;
-        let data = serde_json::to_vec(self).context("can't serialize wallet")?;
+
+        let data = match passphrase {
+            Some(passphrase) => serde_json::to_vec(&EncryptedKeyStore::encrypt(self, passphrase)?)
+                .context("can't serialize encrypted wallet")?,
+            None => {
+                // The seed phrase is only ever written encrypted.
+                let key_store = KeyStore {
+                    seed_phrase: None,
+                    accounts: self.accounts.clone(),
+                };
+                serde_json::to_vec(&key_store).context("can't serialize wallet")?
+            }
+        };
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        // Only the owner should be able to read the wallet file.
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options
+            .open(path)
+            .with_context(|| format!("can't create file {path:?}"))?;
         file.write_all(&data)
             .with_context(|| format!("can't write file {path:?}"))?;
         Ok(())
     }
 
     /// Read the wallet data from the provided path.
-    pub fn load(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+    ///
+    /// If the wallet is encrypted, the `passphrase` is used to decrypt it; wallets stored in
+    /// plaintext don't need one.
+    pub fn load(
+        path: impl AsRef<std::path::Path>,
+        passphrase: Option<&str>,
+    ) -> anyhow::Result<Self> {
         let path = path.as_ref();
+        let value = Self::read_json(path)?;
+
+        if EncryptedKeyStore::is_encrypted(&value) {
+            let passphrase = passphrase.ok_or_else(|| {
+                anyhow::anyhow!("Wallet file {path:?} is encrypted, and no passphrase was given")
+            })?;
+            EncryptedKeyStore::from_json(value)?
+                .decrypt(passphrase)
+                .with_context(|| format!("can't decrypt wallet file {path:?}"))
+        } else {
+            tracing::warn!(
+                ?path,
+                "wallet file is not encrypted, so its spend keys are stored in plaintext"
+            );
+            serde_json::from_value(value).map_err(Into::into)
+        }
+    }
+
+    /// Whether the wallet file at the provided path is encrypted.
+    pub fn is_encrypted(path: impl AsRef<std::path::Path>) -> anyhow::Result<bool> {
+        Ok(EncryptedKeyStore::is_encrypted(&Self::read_json(
+            path.as_ref(),
+        )?))
+    }
+
+    fn read_json(path: &std::path::Path) -> anyhow::Result<serde_json::Value> {
         serde_json::from_slice(
             std::fs::read(path)
                 .with_context(|| format!("can't read file {path:?}"))?
                 .as_slice(),
         )
-        .map_err(Into::into)
+        .with_context(|| format!("can't parse wallet file {path:?}"))
     }
 
-    /// Create a new wallet.
+    /// Create a new wallet, with a default account derived from the seed phrase at index 0.
     pub fn from_seed_phrase(seed_phrase: SeedPhrase) -> Self {
-        // Currently we support a single spend authority per wallet. In the future,
-        // we can derive multiple spend seeds from a single seed phrase.
-        let spend_key = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let spend_key = SpendKey::from_seed_phrase(seed_phrase.clone(), 0);
+
+        Self {
+            seed_phrase: Some(seed_phrase),
+            accounts: vec![Account {
+                label: DEFAULT_ACCOUNT_LABEL.to_string(),
+                index: 0,
+                spend_key,
+            }],
+        }
+    }
+
+    /// Create a new wallet holding a single spend key, which isn't derived from a known seed
+    /// phrase.
+    pub fn from_spend_key(spend_key: SpendKey) -> Self {
+        Self {
+            seed_phrase: None,
+            accounts: vec![Account {
+                label: DEFAULT_ACCOUNT_LABEL.to_string(),
+                index: 0,
+                spend_key,
+            }],
+        }
+    }
+
+    /// The accounts in the wallet.
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// The default account, used when no other account is selected.
+    pub fn default_account(&self) -> &Account {
+        self.accounts
+            .first()
+            .expect("wallets always have at least one account")
+    }
+
+    /// Look up an account by its label, or failing that, by its index.
+    pub fn account(&self, name: &str) -> anyhow::Result<&Account> {
+        if let Some(account) = self.accounts.iter().find(|a| a.label == name) {
+            return Ok(account);
+        }
+        name.parse::<u64>()
+            .ok()
+            .and_then(|index| self.accounts.iter().find(|a| a.index == index))
+            .ok_or_else(|| anyhow::anyhow!("No account named {name:?} exists in the wallet"))
+    }
+
+    /// Derive a new account from the wallet's seed phrase, at the next unused index.
+    pub fn add_account(&mut self, label: String) -> anyhow::Result<&Account> {
+        let seed_phrase = self.seed_phrase.clone().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Wallet has no seed phrase, so it can't derive new accounts (plaintext wallets don't store their seed phrase)"
+            )
+        })?;
+        if self.accounts.iter().any(|a| a.label == label) {
+            return Err(anyhow::anyhow!(
+                "An account named {label:?} already exists in the wallet"
+            ));
+        }
+
+        let index = self
+            .accounts
+            .iter()
+            .map(|a| a.index + 1)
+            .max()
+            .unwrap_or_default();
+        self.accounts.push(Account {
+            label,
+            index,
+            spend_key: SpendKey::from_seed_phrase(seed_phrase, index),
+        });
+
+        Ok(self.accounts.last().expect("just added an account"))
+    }
+}
+
+mod serde_helpers {
+    use super::*;
+
+    /// Wallet files either list several accounts, or (in the older format) store a single spend
+    /// key, which becomes the default account.
+    #[serde_as]
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    pub enum KeyStoreHelper {
+        Accounts {
+            #[serde_as(as = "Option<DisplayFromStr>")]
+            #[serde(default)]
+            seed_phrase: Option<SeedPhrase>,
+            accounts: Vec<Account>,
+        },
+        SingleSpendKey {
+            #[serde_as(as = "DisplayFromStr")]
+            spend_key: SpendKey,
+        },
+    }
+
+    impl TryFrom<KeyStoreHelper> for KeyStore {
+        type Error = anyhow::Error;
+
+        fn try_from(helper: KeyStoreHelper) -> anyhow::Result<Self> {
+            match helper {
+                KeyStoreHelper::Accounts {
+                    seed_phrase,
+                    accounts,
+                } => {
+                    if accounts.is_empty() {
+                        return Err(anyhow::anyhow!("wallet has no accounts"));
+                    }
+                    Ok(Self {
+                        seed_phrase,
+                        accounts,
+                    })
+                }
+                KeyStoreHelper::SingleSpendKey { spend_key } => Ok(Self::from_spend_key(spend_key)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn legacy_single_spend_key_wallet_loads_as_default_account() {
+        let spend_key = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let json = serde_json::json!({ "spend_key": spend_key.to_string() });
+
+        let mut key_store: KeyStore = serde_json::from_value(json).unwrap();
+        assert_eq!(key_store.accounts().len(), 1);
+        let account = key_store.default_account();
+        assert_eq!(account.label, DEFAULT_ACCOUNT_LABEL);
+        assert_eq!(account.index, 0);
+        assert_eq!(account.spend_key, spend_key);
+
+        // There's no seed phrase to derive further accounts from.
+        assert!(key_store.add_account("savings".to_string()).is_err());
+    }
+
+    #[test]
+    fn add_account_uses_next_unused_index() {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let mut key_store = KeyStore::from_seed_phrase(seed_phrase.clone());
+
+        let account = key_store.add_account("savings".to_string()).unwrap();
+        assert_eq!(account.index, 1);
+        assert_eq!(
+            account.spend_key,
+            SpendKey::from_seed_phrase(seed_phrase.clone(), 1)
+        );
+
+        // Indices are allocated after the highest one in use, even if there are gaps below it.
+        key_store.accounts.push(Account {
+            label: "old".to_string(),
+            index: 5,
+            spend_key: SpendKey::from_seed_phrase(seed_phrase.clone(), 5),
+        });
+        let account = key_store.add_account("spending".to_string()).unwrap();
+        assert_eq!(account.index, 6);
+        assert_eq!(
+            account.spend_key,
+            SpendKey::from_seed_phrase(seed_phrase, 6)
+        );
+    }
+
+    #[test]
+    fn add_account_rejects_duplicate_labels() {
+        let mut key_store = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+
+        assert!(key_store
+            .add_account(DEFAULT_ACCOUNT_LABEL.to_string())
+            .is_err());
+        key_store.add_account("savings".to_string()).unwrap();
+        assert!(key_store.add_account("savings".to_string()).is_err());
+
+        assert_eq!(key_store.accounts().len(), 2);
+        assert_eq!(key_store.account("savings").unwrap().index, 1);
+    }
+
+    #[test]
+    fn plaintext_wallet_file_omits_seed_phrase() {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let key_store = KeyStore::from_seed_phrase(seed_phrase.clone());
+        let dir = tempfile::tempdir().unwrap();
+
+        let plaintext_path = dir.path().join("plaintext.json");
+        key_store.save(&plaintext_path, None).unwrap();
+        let contents = std::fs::read_to_string(&plaintext_path).unwrap();
+        assert!(!contents.contains(&seed_phrase.to_string()));
+        let mut loaded = KeyStore::load(&plaintext_path, None).unwrap();
+        assert_eq!(
+            loaded.default_account().spend_key,
+            key_store.default_account().spend_key
+        );
+        assert!(loaded.add_account("savings".to_string()).is_err());
 
-        Self { spend_key }
+        // Encrypted wallet files keep the seed phrase, so they can still add accounts.
+        let encrypted_path = dir.path().join("encrypted.json");
+        key_store
+            .save(&encrypted_path, Some("correct horse"))
+            .unwrap();
+        let mut loaded = KeyStore::load(&encrypted_path, Some("correct horse")).unwrap();
+        assert_eq!(loaded.add_account("savings".to_string()).unwrap().index, 1);
     }
 }
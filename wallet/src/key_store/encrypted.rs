@@ -0,0 +1,232 @@
+use anyhow::{anyhow, Context, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use rand_core::{CryptoRng, OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use zeroize::Zeroizing;
+
+use super::KeyStore;
+
+/// The version of the encrypted wallet format written by this software.
+const VERSION: u32 = 1;
+
+/// The Argon2id memory cost used for new wallets, in KiB.
+const MEMORY_KIB: u32 = 64 * 1024;
+/// The Argon2id iteration count used for new wallets.
+const ITERATIONS: u32 = 3;
+/// The Argon2id parallelism used for new wallets.
+const PARALLELISM: u32 = 1;
+
+/// The largest Argon2id costs accepted from a wallet file, so that a corrupted or tampered header
+/// can't make unlocking the wallet exhaust memory or run indefinitely.
+const MAX_MEMORY_KIB: u32 = 4 * MEMORY_KIB;
+const MAX_ITERATIONS: u32 = 4 * ITERATIONS;
+const MAX_PARALLELISM: u32 = 16;
+
+/// A [`KeyStore`] encrypted under a key derived from a passphrase.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub(super) struct EncryptedKeyStore {
+    header: Header,
+    /// The serialized key store, encrypted with ChaCha20-Poly1305 so as to also authenticate the
+    /// header.
+    #[serde_as(as = "Hex")]
+    ciphertext: Vec<u8>,
+}
+
+/// Everything besides the passphrase needed to decrypt the wallet.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+struct Header {
+    version: u32,
+    kdf: Kdf,
+    #[serde_as(as = "Hex")]
+    nonce: [u8; 12],
+}
+
+/// The function used to derive the encryption key from the passphrase.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "algorithm", rename_all = "snake_case")]
+enum Kdf {
+    Argon2id {
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+        #[serde_as(as = "Hex")]
+        salt: [u8; 16],
+    },
+}
+
+impl Kdf {
+    fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut salt = [0u8; 16];
+        rng.fill_bytes(&mut salt);
+        Self::Argon2id {
+            memory_kib: MEMORY_KIB,
+            iterations: ITERATIONS,
+            parallelism: PARALLELISM,
+            salt,
+        }
+    }
+
+    fn derive_key(&self, passphrase: &str) -> Result<Zeroizing<[u8; 32]>> {
+        match self {
+            Self::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+                salt,
+            } => {
+                if *memory_kib > MAX_MEMORY_KIB
+                    || *iterations > MAX_ITERATIONS
+                    || *parallelism > MAX_PARALLELISM
+                {
+                    return Err(anyhow!(
+                        "key derivation parameters exceed the supported maximum of {MAX_MEMORY_KIB} KiB of memory, {MAX_ITERATIONS} iterations and parallelism {MAX_PARALLELISM}"
+                    ));
+                }
+                let params = Params::new(*memory_kib, *iterations, *parallelism, Some(32))
+                    .map_err(|e| anyhow!("invalid key derivation parameters: {e}"))?;
+                let mut key = Zeroizing::new([0u8; 32]);
+                Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                    .hash_password_into(passphrase.as_bytes(), salt, &mut *key)
+                    .map_err(|e| anyhow!("can't derive key from passphrase: {e}"))?;
+                Ok(key)
+            }
+        }
+    }
+}
+
+impl Header {
+    /// The associated data for the ciphertext, so that the header can't be tampered with.
+    fn associated_data(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).context("can't serialize wallet header")
+    }
+}
+
+impl EncryptedKeyStore {
+    /// Whether the JSON contents of a wallet file are an encrypted wallet.
+    pub(super) fn is_encrypted(value: &serde_json::Value) -> bool {
+        value.get("header").is_some()
+    }
+
+    /// Parse an encrypted wallet, checking first that its format version is supported.
+    pub(super) fn from_json(value: serde_json::Value) -> Result<Self> {
+        let version = value
+            .get("header")
+            .and_then(|header| header.get("version"))
+            .and_then(|version| version.as_u64())
+            .ok_or_else(|| anyhow!("encrypted wallet has no format version"))?;
+        if version != u64::from(VERSION) {
+            return Err(anyhow!(
+                "encrypted wallet has format version {version}, but only version {VERSION} is supported"
+            ));
+        }
+
+        serde_json::from_value(value).context("can't deserialize encrypted wallet")
+    }
+
+    pub(super) fn encrypt(key_store: &KeyStore, passphrase: &str) -> Result<Self> {
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+        let header = Header {
+            version: VERSION,
+            kdf: Kdf::new(&mut OsRng),
+            nonce,
+        };
+
+        let key = header.kdf.derive_key(passphrase)?;
+        let plaintext =
+            Zeroizing::new(serde_json::to_vec(key_store).context("can't serialize wallet")?);
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&*key))
+            .encrypt(
+                Nonce::from_slice(&header.nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &header.associated_data()?,
+                },
+            )
+            .map_err(|_| anyhow!("can't encrypt wallet"))?;
+
+        Ok(Self { header, ciphertext })
+    }
+
+    pub(super) fn decrypt(&self, passphrase: &str) -> Result<KeyStore> {
+        let key = self.header.kdf.derive_key(passphrase)?;
+        let plaintext = Zeroizing::new(
+            ChaCha20Poly1305::new(Key::from_slice(&*key))
+                .decrypt(
+                    Nonce::from_slice(&self.header.nonce),
+                    Payload {
+                        msg: &self.ciphertext,
+                        aad: &self.header.associated_data()?,
+                    },
+                )
+                .map_err(|_| anyhow!("wrong passphrase, or the wallet file is corrupted"))?,
+        );
+
+        serde_json::from_slice(&plaintext).context("can't deserialize decrypted wallet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::keys::SeedPhrase;
+
+    use super::*;
+
+    #[test]
+    fn encrypted_key_store_round_trip() {
+        let key_store = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+        let encrypted = EncryptedKeyStore::encrypt(&key_store, "correct horse").unwrap();
+
+        let json = serde_json::to_value(&encrypted).unwrap();
+        assert!(EncryptedKeyStore::is_encrypted(&json));
+        let decrypted = EncryptedKeyStore::from_json(json)
+            .unwrap()
+            .decrypt("correct horse")
+            .unwrap();
+        assert_eq!(
+            decrypted.default_account().spend_key,
+            key_store.default_account().spend_key
+        );
+
+        assert!(encrypted.decrypt("battery staple").is_err());
+    }
+
+    #[test]
+    fn tampered_header_is_rejected() {
+        let key_store = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+        let mut encrypted = EncryptedKeyStore::encrypt(&key_store, "correct horse").unwrap();
+
+        encrypted.header.nonce[0] ^= 1;
+        assert!(encrypted.decrypt("correct horse").is_err());
+
+        let mut json = serde_json::to_value(&encrypted).unwrap();
+        json["header"]["version"] = (VERSION + 1).into();
+        assert!(EncryptedKeyStore::from_json(json).is_err());
+    }
+
+    #[test]
+    fn excessive_kdf_costs_are_rejected() {
+        let key_store = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+        let encrypted = EncryptedKeyStore::encrypt(&key_store, "correct horse").unwrap();
+        let json = serde_json::to_value(&encrypted).unwrap();
+
+        for (field, value) in [
+            ("memory_kib", MAX_MEMORY_KIB + 1),
+            ("iterations", MAX_ITERATIONS + 1),
+            ("parallelism", MAX_PARALLELISM + 1),
+        ] {
+            let mut json = json.clone();
+            json["header"]["kdf"][field] = value.into();
+            let tampered = EncryptedKeyStore::from_json(json).unwrap();
+            assert!(tampered.decrypt("correct horse").is_err());
+        }
+    }
+}
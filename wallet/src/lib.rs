@@ -1,6 +1,9 @@
 mod build;
+mod claim;
 mod key_store;
 pub use build::build_transaction;
-pub use key_store::KeyStore;
+pub use claim::claim_undelegations;
+pub use key_store::{Account, KeyStore};
 
+pub mod passphrase;
 pub mod plan;
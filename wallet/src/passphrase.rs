@@ -0,0 +1,46 @@
+//! Getting the passphrases used to encrypt wallet files, from the environment or the terminal.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::KeyStore;
+
+/// The environment variable which, if set, supplies the wallet passphrase instead of prompting
+/// for it.
+pub const PASSPHRASE_ENV_VAR: &str = "PENUMBRA_WALLET_PASSPHRASE";
+
+/// Get the passphrase needed to load the wallet file at `path`, or `None` if it isn't encrypted.
+pub fn unlock(path: impl AsRef<Path>) -> Result<Option<String>> {
+    let path = path.as_ref();
+    if !KeyStore::is_encrypted(path)? {
+        return Ok(None);
+    }
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR) {
+        return Ok(Some(passphrase));
+    }
+
+    let passphrase =
+        rpassword::prompt_password(format!("Enter the passphrase for {}: ", path.display()))?;
+    Ok(Some(passphrase))
+}
+
+/// Choose a passphrase to encrypt a new wallet file with, asking for it twice if prompting.
+pub fn choose() -> Result<String> {
+    let passphrase = match std::env::var(PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) => passphrase,
+        Err(_) => {
+            let passphrase = rpassword::prompt_password("Choose a passphrase for the wallet: ")?;
+            let confirmation = rpassword::prompt_password("Enter the passphrase again: ")?;
+            if passphrase != confirmation {
+                return Err(anyhow::anyhow!("Passphrases did not match"));
+            }
+            passphrase
+        }
+    };
+
+    if passphrase.is_empty() {
+        return Err(anyhow::anyhow!("Wallet passphrase must not be empty"));
+    }
+    Ok(passphrase)
+}
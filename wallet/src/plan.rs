@@ -2,24 +2,28 @@ use std::collections::BTreeMap;
 use tonic::transport::Channel;
 
 use anyhow::{Context, Result};
+use penumbra_chain::Epoch;
 use penumbra_component::stake::rate::RateData;
 use penumbra_component::stake::validator;
 use penumbra_crypto::{
     keys::{AccountGroupId, AddressIndex},
     memo::MemoPlaintext,
+    stake::{Penalty, RedelegationToken},
     transaction::Fee,
     Address, Amount, FullViewingKey, Value,
 };
 use penumbra_proto::{
-    client::v1alpha1::specific_query_service_client::SpecificQueryServiceClient,
+    client::v1alpha1::{
+        specific_query_service_client::SpecificQueryServiceClient, ValidatorPenaltyRequest,
+    },
     view::v1alpha1::NotesRequest,
 };
 use penumbra_transaction::{
-    action::{Proposal, ValidatorVote},
+    action::{Proposal, RedelegateClaim, Unjail, ValidatorVote},
     plan::TransactionPlan,
     proposal,
 };
-use penumbra_view::{SpendableNoteRecord, ViewClient};
+use penumbra_view::{SpendableNoteRecord, Unbonding, ViewClient};
 use rand_core::{CryptoRng, RngCore};
 use tracing::instrument;
 
@@ -65,6 +69,26 @@ where
         .context("can't build validator vote plan")
 }
 
+pub async fn unjail<V, R>(
+    account_group_id: AccountGroupId,
+    view: &mut V,
+    rng: R,
+    unjail: Unjail,
+    fee: Fee,
+    source_address: AddressIndex,
+) -> Result<TransactionPlan>
+where
+    V: ViewClient,
+    R: RngCore + CryptoRng,
+{
+    Planner::new(rng)
+        .fee(fee)
+        .unjail(unjail)
+        .plan(view, account_group_id, source_address)
+        .await
+        .context("can't build unjail plan")
+}
+
 /// Generate a new transaction plan delegating stake
 #[instrument(skip(
     account_group_id,
@@ -158,6 +182,125 @@ where
     Ok(plans)
 }
 
+/// Plans a claim of the given unbonding, which must have finished unbonding.
+///
+/// The plan is only valid until another transaction from the same address is
+/// submitted, since that may spend the note selected to pay the fee, so claims
+/// of several unbondings should be planned and submitted one at a time, as
+/// [`claim_undelegations`](crate::claim_undelegations) does.
+#[instrument(skip(account_group_id, view, rng, unbonding))]
+pub async fn undelegate_claim<V, R>(
+    account_group_id: AccountGroupId,
+    view: &mut V,
+    mut rng: R,
+    fee: Fee,
+    unbonding: &Unbonding,
+) -> Result<TransactionPlan, anyhow::Error>
+where
+    V: ViewClient,
+    R: RngCore + CryptoRng,
+{
+    tracing::debug!(token = %unbonding.token(), "planning undelegate claim");
+    let claim_plan = unbonding
+        .undelegate_claim_plan(&mut rng)
+        .ok_or_else(|| anyhow::anyhow!("view service returned an unclaimable unbonding"))?;
+
+    let mut planner = Planner::new(&mut rng);
+    for record in &unbonding.note_records {
+        planner.spend(record.note.clone(), record.position);
+    }
+
+    planner
+        .undelegate_claim(claim_plan)
+        .fee(fee)
+        .plan(view, account_group_id, unbonding.address_index)
+        .await
+        .context("can't build undelegate claim transaction")
+}
+
+/// Plans claims for all of the wallet's redelegation tokens that are no longer
+/// liable for slashing of the validator they were redelegated from, with one
+/// transaction for each kind of redelegation token held by each address.
+#[instrument(skip(account_group_id, view, rng, specific_client))]
+pub async fn redelegate_claims<V, R>(
+    account_group_id: AccountGroupId,
+    view: &mut V,
+    mut rng: R,
+    fee: Fee,
+    mut specific_client: SpecificQueryServiceClient<Channel>,
+) -> Result<Vec<TransactionPlan>, anyhow::Error>
+where
+    V: ViewClient,
+    R: RngCore + CryptoRng,
+{
+    let chain_params = view.chain_params().await?;
+    let sync_height = view.status(account_group_id).await?.sync_height;
+    let current_epoch = Epoch::from_height(sync_height, chain_params.epoch_duration);
+    let asset_cache = view.assets().await?;
+
+    let mut plans = Vec::new();
+
+    for (address_index, notes_by_asset) in view
+        .unspent_notes_by_address_and_asset(account_group_id)
+        .await?
+    {
+        for (asset_id, records) in notes_by_asset {
+            let Some(denom) = asset_cache.get(&asset_id) else {
+                continue;
+            };
+            let Ok(token) = RedelegationToken::try_from(denom.clone()) else {
+                continue;
+            };
+            if token.end_epoch_index() > current_epoch.index {
+                tracing::debug!(%token, "redelegation is not yet claimable");
+                continue;
+            }
+
+            // The penalty over the redelegation's liability period is final
+            // once it ends.
+            let penalty: Penalty = specific_client
+                .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
+                    chain_id: chain_params.chain_id.clone(),
+                    identity_key: Some(token.from_validator().into()),
+                    start_epoch_index: token.start_epoch_index(),
+                    end_epoch_index: token.end_epoch_index(),
+                }))
+                .await?
+                .into_inner()
+                .penalty
+                .ok_or_else(|| anyhow::anyhow!("no penalty returned for validator"))?
+                .try_into()?;
+
+            tracing::debug!(%token, ?penalty, "planning redelegate claim");
+            let mut planner = Planner::new(&mut rng);
+            for record in &records {
+                planner.spend(record.note.clone(), record.position);
+            }
+
+            let plan = planner
+                .redelegate_claim(RedelegateClaim {
+                    from_validator_identity: token.from_validator(),
+                    to_validator_identity: token.to_validator(),
+                    start_epoch_index: token.start_epoch_index(),
+                    end_epoch_index: token.end_epoch_index(),
+                    penalty,
+                    redelegation_amount: records
+                        .iter()
+                        .map(|record| u64::from(record.note.amount()))
+                        .sum::<u64>()
+                        .into(),
+                })
+                .fee(fee.clone())
+                .plan(view, account_group_id, address_index)
+                .await
+                .context("can't build redelegate claim transaction")?;
+            plans.push(plan);
+        }
+    }
+
+    Ok(plans)
+}
+
 //#[instrument(skip(_fvk, _view, _rng))]
 pub async fn claim_unclaimed_swaps<V, R>(
     _fvk: &FullViewingKey,